@@ -29,6 +29,17 @@ run_standard_checks() {
     cargo clippy --all-targets -- -D warnings
     echo -e "${GREEN}✓ Clippy passed${NC}\n"
 
+    # no_std (bare-metal target, skipped if not installed)
+    if rustup target list --installed 2>/dev/null | grep -q thumbv7em-none-eabihf; then
+        echo -e "${YELLOW}==> Building no_std crates${NC}"
+        cargo build -p qtty-core -p qtty --no-default-features --target thumbv7em-none-eabihf
+        cargo build -p qtty-core -p qtty --no-default-features --features serde --target thumbv7em-none-eabihf
+        cargo test -p qtty-core --no-default-features
+        echo -e "${GREEN}✓ no_std build passed${NC}\n"
+    else
+        echo -e "${YELLOW}==> Skipping no_std build (rustup target add thumbv7em-none-eabihf)${NC}\n"
+    fi
+
     # Tests
     echo -e "${YELLOW}==> Running tests${NC}"
     cargo test --all-targets
@@ -38,6 +49,14 @@ run_standard_checks() {
     cargo test --doc
     echo -e "${GREEN}✓ Doc tests passed${NC}\n"
 
+    echo -e "${YELLOW}==> Running tests with test-util, arbitrary, quickcheck and table${NC}"
+    cargo test -p qtty-core --features test-util,arbitrary,quickcheck,table,twofloat,clap
+    echo -e "${GREEN}✓ Optional testing features passed${NC}\n"
+
+    echo -e "${YELLOW}==> Building benchmarks${NC}"
+    cargo bench -p qtty-core --no-run
+    echo -e "${GREEN}✓ Benchmarks build${NC}\n"
+
     echo -e "${GREEN}========================================${NC}"
     echo -e "${GREEN}All CI checks passed! ✓${NC}"
     echo -e "${GREEN}========================================${NC}"
@@ -0,0 +1,146 @@
+//! Raw `f64` loops versus the equivalent `Quantity` loops.
+//!
+//! Each group benchmarks the same computation twice: once on bare `f64` values with the conversion factors written
+//! out by hand, and once through the typed API. If the wrapper is free, the two lines of every group should be
+//! indistinguishable.
+//!
+//! Run with `cargo bench -p qtty-core`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use qtty_core::angular::{Degree, Degrees, Radian};
+use qtty_core::length::{Kilometer, Kilometers, Meter};
+use qtty_core::time::{Second, Seconds};
+use qtty_core::velocity::Velocity;
+use qtty_core::Quantity;
+
+const N: usize = 4096;
+
+fn inputs() -> Vec<f64> {
+    (0..N).map(|i| (i as f64) * 0.731 - 1000.0).collect()
+}
+
+fn conversion(c: &mut Criterion) {
+    let raw = inputs();
+    let typed: Vec<Kilometers> = raw.iter().copied().map(Kilometers::new).collect();
+    let mut group = c.benchmark_group("conversion km→m");
+    group.bench_function(BenchmarkId::new("f64", N), |b| {
+        b.iter(|| black_box(&raw).iter().map(|x| x * 1_000.0).sum::<f64>())
+    });
+    group.bench_function(BenchmarkId::new("Quantity", N), |b| {
+        b.iter(|| {
+            black_box(&typed)
+                .iter()
+                .map(|q| q.to::<Meter>().value())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+fn trig(c: &mut Criterion) {
+    let raw = inputs();
+    let typed: Vec<Degrees> = raw.iter().copied().map(Degrees::new).collect();
+    let mut group = c.benchmark_group("sin(deg)");
+    group.bench_function(BenchmarkId::new("f64", N), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .map(|x| x.to_radians().sin())
+                .sum::<f64>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("Quantity", N), |b| {
+        b.iter(|| black_box(&typed).iter().map(|q| q.sin()).sum::<f64>())
+    });
+    group.finish();
+}
+
+fn wrap(c: &mut Criterion) {
+    let raw = inputs();
+    let typed: Vec<Degrees> = raw.iter().copied().map(Degrees::new).collect();
+    let mut group = c.benchmark_group("wrap_signed(deg)");
+    group.bench_function(BenchmarkId::new("f64", N), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .map(|x| {
+                    let y = (x + 180.0).rem_euclid(360.0) - 180.0;
+                    if y <= -180.0 {
+                        y + 360.0
+                    } else {
+                        y
+                    }
+                })
+                .sum::<f64>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("Quantity", N), |b| {
+        b.iter(|| {
+            black_box(&typed)
+                .iter()
+                .map(|q| q.wrap_signed().value())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+fn per_multiplication(c: &mut Criterion) {
+    let raw = inputs();
+    let typed: Vec<Velocity<Kilometer, Second>> = raw.iter().copied().map(Velocity::new).collect();
+    let dt = Seconds::new(60.0);
+    let mut group = c.benchmark_group("velocity × time");
+    group.bench_function(BenchmarkId::new("f64", N), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .map(|v| v * black_box(60.0))
+                .sum::<f64>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("Quantity", N), |b| {
+        b.iter(|| {
+            black_box(&typed)
+                .iter()
+                .map(|v| {
+                    let d: Kilometers = *v * black_box(dt);
+                    d.value()
+                })
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+fn radian_roundtrip(c: &mut Criterion) {
+    let raw = inputs();
+    let typed: Vec<Quantity<Degree>> = raw.iter().copied().map(Quantity::new).collect();
+    let mut group = c.benchmark_group("deg→rad→deg");
+    group.bench_function(BenchmarkId::new("f64", N), |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .map(|x| x.to_radians().to_degrees())
+                .sum::<f64>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("Quantity", N), |b| {
+        b.iter(|| {
+            black_box(&typed)
+                .iter()
+                .map(|q| q.to::<Radian>().to::<Degree>().value())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    conversion,
+    trig,
+    wrap,
+    per_multiplication,
+    radian_roundtrip
+);
+criterion_main!(benches);
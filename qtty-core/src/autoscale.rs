@@ -0,0 +1,165 @@
+//! Human-readable display that picks the best unit within a dimension.
+
+use crate::unit::{apply_exact, exact_factor};
+use crate::{Dimension, Quantity, Unit, UnitInfo};
+use core::fmt::{Display, Formatter, Result};
+
+/// Dimensions that provide a preference list of units for [`Quantity::display_auto`].
+///
+/// `DISPLAY_UNITS` must be sorted by increasing [`UnitInfo::ratio`]. The built-in dimensions (length, time, angle,
+/// mass, power) implement this trait.
+#[diagnostic::on_unimplemented(
+    message = "dimension `{Self}` has no preferred display units",
+    note = "implement `AutoScale` for the dimension to use `display_auto()`"
+)]
+pub trait AutoScale: Dimension {
+    /// Candidate units for display, smallest first.
+    const DISPLAY_UNITS: &'static [UnitInfo];
+}
+
+/// Display adapter returned by [`Quantity::display_auto`].
+///
+/// The value is shown in the largest preferred unit in which its magnitude is at least one (or the smallest unit for
+/// tiny values). Zero and non-finite values keep a unit no larger than the quantity's own. Formatter flags apply to
+/// the number, and `{:#}` prints the long unit name.
+///
+/// ```rust
+/// use qtty_core::length::Meters;
+/// use qtty_core::time::Seconds;
+///
+/// assert_eq!(format!("{:.4}", Meters::new(1.5e11).display_auto()), "1.0027 au");
+/// assert_eq!(format!("{:.3}", Seconds::new(90_000.0).display_auto()), "1.042 d");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AutoDisplay<U: Unit>(Quantity<U>);
+
+impl<U: Unit> Quantity<U>
+where
+    U::Dim: AutoScale,
+{
+    /// Returns an adapter that formats this quantity in the most readable unit of its dimension.
+    #[inline]
+    pub const fn display_auto(self) -> AutoDisplay<U> {
+        AutoDisplay(self)
+    }
+}
+
+impl<U: Unit> AutoDisplay<U>
+where
+    U::Dim: AutoScale,
+{
+    /// The unit chosen for display.
+    pub fn unit(&self) -> UnitInfo {
+        let units = <U::Dim as AutoScale>::DISPLAY_UNITS;
+        let canonical = self.0.value() * U::RATIO;
+        let fallback = units
+            .iter()
+            .rev()
+            .find(|u| u.ratio <= U::RATIO)
+            .or(units.first());
+        let chosen = if canonical == 0.0 || !canonical.is_finite() {
+            fallback
+        } else {
+            units
+                .iter()
+                .rev()
+                .find(|u| (canonical / u.ratio).abs() >= 1.0)
+                .or(units.first())
+        };
+        chosen.copied().unwrap_or(UnitInfo::of::<U>())
+    }
+}
+
+impl<U: Unit> Display for AutoDisplay<U>
+where
+    U::Dim: AutoScale,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let unit = self.unit();
+        let value = match exact_factor(U::RATIO_NUM, U::RATIO_DEN, unit.ratio_num, unit.ratio_den) {
+            Some(factor) => apply_exact(self.0.value(), factor),
+            None => self.0.value() * U::RATIO / unit.ratio,
+        };
+        Display::fmt(&value, f)?;
+        let label = if f.alternate() {
+            unit.long_name
+        } else {
+            unit.symbol
+        };
+        if label.is_empty() {
+            Ok(())
+        } else {
+            write!(f, " {}", label)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::angular::{Arcseconds, Degrees};
+    use crate::units::length::{Kilometers, Meters, Parsecs};
+    use crate::units::mass::Kilograms;
+    use crate::units::time::{Days, Seconds};
+
+    #[test]
+    fn picks_largest_unit_above_one() {
+        assert_eq!(
+            format!("{:.4}", Meters::new(1.5e11).display_auto()),
+            "1.0027 au"
+        );
+        assert_eq!(format!("{}", Kilometers::new(0.25).display_auto()), "250 m");
+        assert_eq!(
+            format!("{:.3}", Seconds::new(90_000.0).display_auto()),
+            "1.042 d"
+        );
+        assert_eq!(format!("{}", Days::new(0.5).display_auto()), "12 h");
+        assert_eq!(
+            format!("{:.1}", Degrees::new(0.5).display_auto()),
+            "30.0 Arcm"
+        );
+        assert_eq!(format!("{}", Kilograms::new(0.002).display_auto()), "2 g");
+    }
+
+    #[test]
+    fn negative_values_use_magnitude() {
+        assert_eq!(
+            format!("{}", Meters::new(-2500.0).display_auto()),
+            "-2.5 Km"
+        );
+    }
+
+    #[test]
+    fn tiny_values_use_smallest_unit() {
+        let q = Arcseconds::new(1e-9).display_auto();
+        assert_eq!(q.unit().symbol, "μas");
+    }
+
+    #[test]
+    fn zero_keeps_own_unit() {
+        assert_eq!(format!("{}", Meters::new(0.0).display_auto()), "0 m");
+        assert_eq!(Parsecs::new(0.0).display_auto().unit().symbol, "pc");
+    }
+
+    #[test]
+    fn builtin_ladders_are_sorted() {
+        use super::AutoScale;
+        use crate::units::{angular, length, mass, power, time};
+        for ladder in [
+            <angular::Angular as AutoScale>::DISPLAY_UNITS,
+            <length::Length as AutoScale>::DISPLAY_UNITS,
+            <mass::Mass as AutoScale>::DISPLAY_UNITS,
+            <power::Power as AutoScale>::DISPLAY_UNITS,
+            <time::Time as AutoScale>::DISPLAY_UNITS,
+        ] {
+            assert!(ladder.windows(2).all(|w| w[0].ratio < w[1].ratio));
+        }
+    }
+
+    #[test]
+    fn alternate_prints_long_name() {
+        assert_eq!(
+            format!("{:#}", Seconds::new(120.0).display_auto()),
+            "2 minute"
+        );
+    }
+}
@@ -0,0 +1,424 @@
+//! Dimension-tagged values stored in a canonical unit, and the policies that pick that unit.
+//!
+//! Every unit's [`Unit::RATIO`] is relative to one unit per dimension with `RATIO == 1.0`. For most dimensions that
+//! is the SI unit, but angles are scaled to the degree and masses to the gram. Those choices are baked into the ratio
+//! tables and do not change; a [`CanonicalPolicy`] instead decides which unit a [`Canonical`] accumulator stores its
+//! value in, so code that exchanges raw canonical values (buffers, wire formats, other libraries) can ask for radians
+//! and kilograms with [`SiPolicy`].
+
+use crate::dimension::{Dimension, Dimensionless, DivDim, MulDim};
+use crate::quantity::Quantity;
+use crate::unit::Unit;
+use crate::units::angular::{Angular, Radian};
+use crate::units::length::Length;
+use crate::units::mass::{Kilogram, Mass};
+use crate::units::power::Power;
+use crate::units::pressure::Pressure;
+use crate::units::temperature::Temperature;
+use crate::units::time::Time;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Chooses the unit in which a [`Canonical`] value of dimension `D` is stored.
+///
+/// `SCALE` is the size of that unit in units of the dimension's `RATIO == 1.0` unit, so a quantity in unit `U` is
+/// stored as `value * U::RATIO / SCALE`.
+pub trait CanonicalPolicy<D: Dimension> {
+    /// Size of the storage unit relative to the dimension's `RATIO == 1.0` unit.
+    const SCALE: f64;
+}
+
+/// Stores every dimension in its `RATIO == 1.0` unit: degrees, grams, metres, seconds, and their quotients and
+/// products. This is the default, and costs no extra scaling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NativePolicy {}
+
+impl<D: Dimension> CanonicalPolicy<D> for NativePolicy {
+    const SCALE: f64 = 1.0;
+}
+
+/// Stores every dimension in coherent SI units: radians for angles, kilograms for mass, and metres, seconds, watts,
+/// kelvins and pascals (already the native units) for the rest.
+///
+/// Quotient and product dimensions follow from their factors, so a velocity is stored in m/s, an angular frequency in
+/// rad/s and a density in kg/m³.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SiPolicy {}
+
+impl CanonicalPolicy<Angular> for SiPolicy {
+    const SCALE: f64 = Radian::RATIO;
+}
+
+impl CanonicalPolicy<Mass> for SiPolicy {
+    const SCALE: f64 = Kilogram::RATIO;
+}
+
+macro_rules! si_native {
+    ($($dim:ty),+) => {
+        $(impl CanonicalPolicy<$dim> for SiPolicy {
+            const SCALE: f64 = 1.0;
+        })+
+    };
+}
+
+si_native!(Dimensionless, Length, Time, Power, Temperature, Pressure);
+
+impl<N: Dimension, D: Dimension> CanonicalPolicy<DivDim<N, D>> for SiPolicy
+where
+    SiPolicy: CanonicalPolicy<N> + CanonicalPolicy<D>,
+{
+    const SCALE: f64 =
+        <SiPolicy as CanonicalPolicy<N>>::SCALE / <SiPolicy as CanonicalPolicy<D>>::SCALE;
+}
+
+impl<A: Dimension, B: Dimension> CanonicalPolicy<MulDim<A, B>> for SiPolicy
+where
+    SiPolicy: CanonicalPolicy<A> + CanonicalPolicy<B>,
+{
+    const SCALE: f64 =
+        <SiPolicy as CanonicalPolicy<A>>::SCALE * <SiPolicy as CanonicalPolicy<B>>::SCALE;
+}
+
+/// A value of dimension `D` held in the canonical unit chosen by `P` (by default the unit with `RATIO == 1.0`).
+///
+/// `Quantity<U>` keeps whatever unit it was created in, so folding a mix of units into one accumulator means picking
+/// one of them and converting the rest — and converting back and forth when the pick changes. `Canonical<D>` fixes
+/// the representation instead: every incoming quantity is scaled exactly once (`value * U::RATIO`), and only the
+/// final read-out with [`to`](Self::to) applies a second ratio.
+///
+/// ```rust
+/// use qtty_core::length::{AstronomicalUnits, Kilometers, Meters, Kilometer, Length};
+/// use qtty_core::Canonical;
+///
+/// let legs = [
+///     Canonical::from(Kilometers::new(1.5)),
+///     Canonical::from(Meters::new(250.0)),
+///     Canonical::from(AstronomicalUnits::new(1e-9)),
+/// ];
+/// let total: Canonical<Length> = legs.into_iter().sum();
+/// assert!((total.to::<Kilometer>().value() - 1.899_597_870_7).abs() < 1e-9);
+/// ```
+///
+/// With [`SiPolicy`] the raw value is in SI units instead:
+///
+/// ```rust
+/// use qtty_core::angular::{Angular, Degrees};
+/// use qtty_core::{Canonical, SiPolicy};
+///
+/// let half_turn = Canonical::<Angular, SiPolicy>::from_quantity(Degrees::new(180.0));
+/// assert!((half_turn.value() - core::f64::consts::PI).abs() < 1e-15);
+/// ```
+pub struct Canonical<D: Dimension, P = NativePolicy>(f64, PhantomData<(D, P)>);
+
+impl<D: Dimension, P> Canonical<D, P> {
+    /// Zero in any dimension.
+    pub const ZERO: Self = Self::new(0.0);
+
+    /// Wraps a raw value that is already expressed in the canonical unit of `D`.
+    #[inline]
+    pub const fn new(value: f64) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// The raw value in the canonical unit.
+    #[inline]
+    pub const fn value(self) -> f64 {
+        self.0
+    }
+}
+
+impl<D: Dimension, P: CanonicalPolicy<D>> Canonical<D, P> {
+    /// Stores `quantity` in the canonical unit.
+    #[inline]
+    pub const fn from_quantity<U: Unit<Dim = D>>(quantity: Quantity<U>) -> Self {
+        Self::new(quantity.value() * U::RATIO / P::SCALE)
+    }
+
+    /// Reads the value out in unit `U`.
+    #[inline]
+    pub const fn to<U: Unit<Dim = D>>(self) -> Quantity<U> {
+        Quantity::new(self.0 * P::SCALE / U::RATIO)
+    }
+
+    /// The same value stored under policy `Q`.
+    #[inline]
+    pub const fn to_policy<Q: CanonicalPolicy<D>>(self) -> Canonical<D, Q> {
+        Canonical::new(self.0 * P::SCALE / Q::SCALE)
+    }
+}
+
+// `From` is only provided for the default policy so that `Canonical::from(q)` needs no annotation; use
+// `from_quantity` for the others.
+impl<U: Unit> From<Quantity<U>> for Canonical<U::Dim> {
+    #[inline]
+    fn from(quantity: Quantity<U>) -> Self {
+        Self::from_quantity(quantity)
+    }
+}
+
+// Manual impls: deriving would require the (uninhabited) dimension tags to implement the traits themselves.
+
+impl<D: Dimension, P> Clone for Canonical<D, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: Dimension, P> Copy for Canonical<D, P> {}
+
+impl<D: Dimension, P> fmt::Debug for Canonical<D, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Canonical").field(&self.0).finish()
+    }
+}
+
+impl<D: Dimension, P> Default for Canonical<D, P> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<D: Dimension, P> PartialEq for Canonical<D, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<D: Dimension, P> PartialOrd for Canonical<D, P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Arithmetic
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<D: Dimension, P> Add for Canonical<D, P> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl<D: Dimension, P> Sub for Canonical<D, P> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl<D: Dimension, P> AddAssign for Canonical<D, P> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<D: Dimension, P> SubAssign for Canonical<D, P> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<U: Unit, P: CanonicalPolicy<U::Dim>> Add<Quantity<U>> for Canonical<U::Dim, P> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Quantity<U>) -> Self {
+        self + Self::from_quantity(rhs)
+    }
+}
+
+impl<U: Unit, P: CanonicalPolicy<U::Dim>> Sub<Quantity<U>> for Canonical<U::Dim, P> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Quantity<U>) -> Self {
+        self - Self::from_quantity(rhs)
+    }
+}
+
+impl<U: Unit, P: CanonicalPolicy<U::Dim>> AddAssign<Quantity<U>> for Canonical<U::Dim, P> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Quantity<U>) {
+        *self += Self::from_quantity(rhs);
+    }
+}
+
+impl<U: Unit, P: CanonicalPolicy<U::Dim>> SubAssign<Quantity<U>> for Canonical<U::Dim, P> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Quantity<U>) {
+        *self -= Self::from_quantity(rhs);
+    }
+}
+
+impl<D: Dimension, P> Mul<f64> for Canonical<D, P> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.0 * rhs)
+    }
+}
+
+impl<D: Dimension, P> Div<f64> for Canonical<D, P> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.0 / rhs)
+    }
+}
+
+impl<D: Dimension, P> Neg for Canonical<D, P> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+impl<D: Dimension, P> Sum for Canonical<D, P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<U: Unit, P: CanonicalPolicy<U::Dim>> Sum<Quantity<U>> for Canonical<U::Dim, P> {
+    fn sum<I: Iterator<Item = Quantity<U>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Serde support
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "serde")]
+impl<D: Dimension, P> Serialize for Canonical<D, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D: Dimension, P> Deserialize<'de> for Canonical<D, P> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        f64::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Angular, Arcseconds, Degree, Degrees, Radians};
+    use crate::units::density::{Density, GramsPerCubicCentimeter};
+    use crate::units::frequency::{Frequency, FrequencyDim};
+    use crate::units::length::{Kilometer, Kilometers, Length, Meter, Meters};
+    use crate::units::mass::Grams;
+    use crate::units::time::{Days, Hours, Minute, Second, Time};
+    use crate::units::velocity::{Velocity, VelocityDim};
+    use approx::assert_relative_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn stores_canonical_value() {
+        let c = Canonical::from(Kilometers::new(2.0));
+        assert_eq!(c.value(), 2_000.0);
+        assert_eq!(c.to::<Meter>().value(), 2_000.0);
+        assert_eq!(c.to::<Kilometer>().value(), 2.0);
+        assert_eq!(Canonical::<Length>::default(), Canonical::ZERO);
+    }
+
+    #[test]
+    fn accumulates_mixed_units() {
+        let mut acc = Canonical::<Time>::ZERO;
+        acc += Days::new(1.0);
+        acc += Hours::new(2.0);
+        acc -= Hours::new(1.0);
+        assert_relative_eq!(acc.to::<Minute>().value(), 25.0 * 60.0);
+
+        let total: Canonical<Angular> = [Degrees::new(1.0), Degrees::new(2.0)].into_iter().sum();
+        let total = total + Arcseconds::new(3_600.0) - Radians::new(0.0);
+        assert_relative_eq!(total.to::<Degree>().value(), 4.0);
+    }
+
+    #[test]
+    fn arithmetic_and_ordering() {
+        let a = Canonical::from(Meters::new(3.0));
+        let b = Canonical::from(Kilometers::new(0.001));
+        assert!(b < a);
+        assert_eq!((a - b).value(), 2.0);
+        assert_eq!((a * 2.0 / 3.0).value(), 2.0);
+        assert_eq!((-a).value(), -3.0);
+        assert_eq!(format!("{:?}", a), "Canonical(3.0)");
+    }
+
+    #[test]
+    fn si_policy_stores_si_values() {
+        let angle = Canonical::<Angular, SiPolicy>::from_quantity(Degrees::new(90.0));
+        assert_relative_eq!(angle.value(), core::f64::consts::FRAC_PI_2);
+        assert_relative_eq!(angle.to::<Degree>().value(), 90.0);
+
+        let mass = Canonical::<Mass, SiPolicy>::from_quantity(Grams::new(2_500.0));
+        assert_eq!(mass.value(), 2.5);
+
+        // Composite dimensions scale through their factors.
+        let density =
+            Canonical::<Density, SiPolicy>::from_quantity(GramsPerCubicCentimeter::new(1.0));
+        assert_relative_eq!(density.value(), 1_000.0);
+        let spin = Canonical::<FrequencyDim, SiPolicy>::from_quantity(
+            Frequency::<Degree, Second>::new(180.0),
+        );
+        assert_relative_eq!(spin.value(), core::f64::consts::PI);
+        let v = Canonical::<VelocityDim, SiPolicy>::from_quantity(
+            Velocity::<Kilometer, Second>::new(1.0),
+        );
+        assert_eq!(v.value(), 1_000.0);
+    }
+
+    #[test]
+    fn switching_policies() {
+        let mut acc = Canonical::<Angular, SiPolicy>::ZERO;
+        acc += Degrees::new(45.0);
+        acc += Radians::new(core::f64::consts::FRAC_PI_4);
+        let native: Canonical<Angular> = acc.to_policy();
+        assert_relative_eq!(native.value(), 90.0);
+        assert_relative_eq!(native.to_policy::<SiPolicy>().value(), acc.value());
+        assert_eq!(<NativePolicy as CanonicalPolicy<Mass>>::SCALE, 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_as_plain_value() {
+        let c = Canonical::from(Kilometers::new(1.5));
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(json, "1500.0");
+        let back: Canonical<Length> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, c);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip_through_canonical(x in -1e12..1e12f64) {
+            let back = Canonical::from(Kilometers::new(x)).to::<Kilometer>().value();
+            prop_assert!((back - x).abs() <= 1e-15 * x.abs());
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! [`clap`] value parsers for typed quantities.
+//!
+//! Every `Quantity<U>` implements [`ValueParserFactory`], so derive-based CLIs can declare typed arguments directly:
+//! the value is parsed with [`FromStr`](core::str::FromStr) (a bare number in `U`, or a number followed by any symbol
+//! of the same dimension) and errors name the expected unit. [`QuantityParser`] adds optional bounds.
+//!
+//! ```rust
+//! use clap::{Arg, Command};
+//! use qtty_core::cli::QuantityParser;
+//! use qtty_core::time::{Second, Seconds};
+//!
+//! let cmd = Command::new("observe").arg(
+//!     Arg::new("exposure")
+//!         .long("exposure")
+//!         .value_parser(QuantityParser::<Second>::new().at_least(Seconds::new(0.0))),
+//! );
+//!
+//! let matches = cmd.clone().try_get_matches_from(["observe", "--exposure", "2 min"]).unwrap();
+//! assert_eq!(matches.get_one::<Seconds>("exposure").unwrap().value(), 120.0);
+//!
+//! let err = cmd.try_get_matches_from(["observe", "--exposure", "2 km"]).unwrap_err();
+//! assert!(err.to_string().contains("expected a number, optionally followed by `s`"));
+//! ```
+
+use crate::parse::Symbol;
+use crate::{Quantity, Unit};
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+use std::ffi::OsStr;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A [`TypedValueParser`] producing `Quantity<U>`, optionally restricted to a closed range.
+pub struct QuantityParser<U: Unit> {
+    min: Option<f64>,
+    max: Option<f64>,
+    unit: PhantomData<fn() -> U>,
+}
+
+impl<U: Unit> QuantityParser<U> {
+    /// A parser accepting any value.
+    pub const fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            unit: PhantomData,
+        }
+    }
+
+    /// Rejects values below `min`.
+    pub const fn at_least(mut self, min: Quantity<U>) -> Self {
+        self.min = Some(min.value());
+        self
+    }
+
+    /// Rejects values above `max`.
+    pub const fn at_most(mut self, max: Quantity<U>) -> Self {
+        self.max = Some(max.value());
+        self
+    }
+}
+
+impl<U: Unit> Default for QuantityParser<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: Unit> Clone for QuantityParser<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: Unit> Copy for QuantityParser<U> {}
+
+impl<U> TypedValueParser for QuantityParser<U>
+where
+    U: Unit + Clone + Send + Sync + 'static,
+    U::Dim: 'static,
+{
+    type Value = Quantity<U>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let text = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        let quantity = text.parse::<Quantity<U>>().map_err(|err| {
+            invalid(
+                cmd,
+                arg,
+                text,
+                format_args!("{err}; {}", Expected::<U>(PhantomData)),
+            )
+        })?;
+
+        // NaN fails whichever bound is set.
+        let v = quantity.value();
+        if let Some(min) = self.min.filter(|&min| v < min || v.is_nan()) {
+            return Err(invalid(
+                cmd,
+                arg,
+                text,
+                format_args!("must be at least {}", WithSymbol::<U>(min, PhantomData)),
+            ));
+        }
+        if let Some(max) = self.max.filter(|&max| v > max || v.is_nan()) {
+            return Err(invalid(
+                cmd,
+                arg,
+                text,
+                format_args!("must be at most {}", WithSymbol::<U>(max, PhantomData)),
+            ));
+        }
+        Ok(quantity)
+    }
+}
+
+impl<U> ValueParserFactory for Quantity<U>
+where
+    U: Unit + Clone + Send + Sync + 'static,
+    U::Dim: 'static,
+{
+    type Parser = QuantityParser<U>;
+
+    fn value_parser() -> Self::Parser {
+        QuantityParser::new()
+    }
+}
+
+fn invalid(cmd: &Command, arg: Option<&Arg>, text: &str, reason: fmt::Arguments<'_>) -> Error {
+    let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+    Error::raw(
+        ErrorKind::ValueValidation,
+        format!("invalid value '{text}' for '{arg}': {reason}\n"),
+    )
+    .with_cmd(cmd)
+}
+
+/// The accepted forms for `U`, e.g. "expected a number, optionally followed by `s` or another unit of its dimension".
+struct Expected<U>(PhantomData<U>);
+
+impl<U: Unit> fmt::Display for Expected<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = Symbol::<U>::new().to_string();
+        if symbol.is_empty() {
+            f.write_str("expected a number")
+        } else {
+            write!(
+                f,
+                "expected a number, optionally followed by `{symbol}` or another unit of the same dimension"
+            )
+        }
+    }
+}
+
+/// `"<value> <symbol>"`, or the bare value for units without a symbol.
+struct WithSymbol<U>(f64, PhantomData<U>);
+
+impl<U: Unit> fmt::Display for WithSymbol<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = Symbol::<U>::new().to_string();
+        if symbol.is_empty() {
+            write!(f, "{}", self.0)
+        } else {
+            write!(f, "{} {symbol}", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length::{Kilometer, Kilometers};
+    use crate::time::{Second, Seconds};
+    use crate::velocity::Velocity;
+    use crate::Unitless;
+    use clap::value_parser;
+
+    fn command() -> Command {
+        Command::new("observe")
+            .arg(
+                Arg::new("exposure")
+                    .long("exposure")
+                    .value_parser(QuantityParser::<Second>::new().at_least(Seconds::new(0.0))),
+            )
+            .arg(
+                Arg::new("max-airmass")
+                    .long("max-airmass")
+                    .value_parser(value_parser!(Quantity<Unitless>)),
+            )
+            .arg(
+                Arg::new("range")
+                    .long("range")
+                    .value_parser(value_parser!(Kilometers)),
+            )
+            .arg(
+                Arg::new("speed")
+                    .long("speed")
+                    .value_parser(value_parser!(Velocity<Kilometer, Second>)),
+            )
+    }
+
+    fn error(args: &[&str]) -> String {
+        let mut argv = vec!["observe"];
+        argv.extend_from_slice(args);
+        command()
+            .try_get_matches_from(argv)
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn parses_numbers_and_symbols() {
+        let matches = command()
+            .try_get_matches_from([
+                "observe",
+                "--exposure",
+                "1.5 min",
+                "--max-airmass",
+                "2",
+                "--range",
+                "2500 m",
+                "--speed",
+                "7.5 Km/s",
+            ])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<Seconds>("exposure").unwrap().value(),
+            90.0
+        );
+        let airmass = matches.get_one::<Quantity<Unitless>>("max-airmass");
+        assert_eq!(airmass.unwrap().value(), 2.0);
+        assert_eq!(matches.get_one::<Kilometers>("range").unwrap().value(), 2.5);
+        let speed = matches.get_one::<Velocity<Kilometer, Second>>("speed");
+        assert_eq!(speed.unwrap().value(), 7.5);
+    }
+
+    #[test]
+    fn errors_name_the_expected_unit() {
+        let err = error(&["--range", "3 s"]);
+        assert!(
+            err.contains("invalid value '3 s' for '--range <range>'"),
+            "{err}"
+        );
+        assert!(err.contains("dimension mismatch"), "{err}");
+        assert!(err.contains("followed by `Km`"), "{err}");
+
+        let err = error(&["--max-airmass", "two"]);
+        assert!(err.contains("expected a number\n"), "{err}");
+    }
+
+    #[test]
+    fn bounds_are_checked_in_the_target_unit() {
+        let err = error(&["--exposure=-1 min"]);
+        assert!(err.contains("must be at least 0 s"), "{err}");
+        let err = error(&["--exposure", "NaN s"]);
+        assert!(err.contains("must be at least 0 s"), "{err}");
+
+        let parser = QuantityParser::<Second>::new().at_most(Seconds::new(60.0));
+        let cmd = Command::new("t");
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("1 min")).is_ok());
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("61")).is_err());
+    }
+}
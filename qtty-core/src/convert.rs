@@ -0,0 +1,78 @@
+//! Blanket conversions between quantities of the same dimension.
+//!
+//! `From` is only implemented for the unit pairs listed in each module's `impl_unit_conversions!`, and a blanket
+//! `impl<U, V> From<Quantity<V>> for Quantity<U>` would overlap with the reflexive `From<T> for T`. [`ConvertFrom`]
+//! and [`ConvertInto`] are dedicated traits with no such conflict, so every same-dimension pair converts, including
+//! compound units such as [`Per`](crate::Per) and [`Prod`](crate::Prod).
+//!
+//! ```rust
+//! use qtty_core::length::{Kilometers, Meters};
+//! use qtty_core::ConvertInto;
+//!
+//! let m: Meters = Kilometers::new(1.5).convert();
+//! assert_eq!(m.value(), 1_500.0);
+//! ```
+
+use crate::dimension::SameDimension;
+use crate::quantity::Quantity;
+use crate::unit::Unit;
+
+/// Construction of `Self` from a quantity of any unit with the same dimension.
+pub trait ConvertFrom<T>: Sized {
+    /// Converts `value`, scaling it into `Self`'s unit.
+    fn convert_from(value: T) -> Self;
+}
+
+/// The reciprocal of [`ConvertFrom`], implemented for every type that `T` can be built from.
+pub trait ConvertInto<T>: Sized {
+    /// Converts `self` into `T`, usually with `T` inferred from context.
+    fn convert(self) -> T;
+}
+
+impl<U: Unit, V: Unit> ConvertFrom<Quantity<V>> for Quantity<U>
+where
+    V::Dim: SameDimension<U::Dim>,
+{
+    #[inline]
+    fn convert_from(value: Quantity<V>) -> Self {
+        value.to::<U>()
+    }
+}
+
+impl<T, F: ConvertFrom<T>> ConvertInto<F> for T {
+    #[inline]
+    fn convert(self) -> F {
+        F::convert_from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Degrees, Radians};
+    use crate::units::length::{AstronomicalUnits, Kilometer, Kilometers, Meter};
+    use crate::units::time::{Hour, Second};
+    use crate::units::velocity::Velocity;
+
+    #[test]
+    fn any_same_dimension_pair() {
+        // No `From` impl exists between these two.
+        let km: Kilometers = AstronomicalUnits::new(1.0).convert();
+        assert_eq!(km.value(), 149_597_870.7);
+
+        let rad = Radians::convert_from(Degrees::new(180.0));
+        assert!((rad.value() - core::f64::consts::PI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn compound_units() {
+        let v: Velocity<Meter, Second> = Velocity::<Kilometer, Hour>::new(36.0).convert();
+        assert!((v.value() - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn same_unit_is_identity() {
+        let km: Kilometers = Kilometers::new(2.5).convert();
+        assert_eq!(km.value(), 2.5);
+    }
+}
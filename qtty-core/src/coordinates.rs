@@ -0,0 +1,842 @@
+//! Range-checked celestial and geodetic coordinate angles.
+//!
+//! [`RightAscension`] and [`Declination`] wrap the raw angular quantities so that an equatorial position cannot be
+//! built with its components swapped or out of range:
+//!
+//! - right ascension is an hour angle in `[0h, 24h)`; arithmetic on it wraps around the circle;
+//! - declination is in `[-90°, +90°]`; offsets that would cross a pole are rejected.
+//!
+//! [`Latitude`] and [`Longitude`] do the same for observatory sites (`±90°` and `±180°`), printing and parsing with
+//! `N`/`S`/`E`/`W` hemisphere letters.
+//!
+//! All of them print in sexagesimal notation; the formatter precision selects the number of decimals on the seconds.
+//!
+//! ```rust
+//! use qtty_core::angular::{Degrees, HourAngles};
+//! use qtty_core::coordinates::{Declination, RightAscension};
+//!
+//! let ra = RightAscension::from_hms(5, 35, 17.3).unwrap();
+//! let dec = Declination::from_dms(-5, 23, 28.0).unwrap();
+//! assert_eq!(format!("{ra}"), "05h 35m 17.30s");
+//! assert_eq!(format!("{dec:.0}"), "-05° 23′ 28″");
+//!
+//! // Right ascension wraps; declination refuses to pass the pole.
+//! assert_eq!((ra + HourAngles::new(20.0)).hours().value().round(), 2.0);
+//! assert!(dec.checked_add(Degrees::new(100.0)).is_none());
+//! assert!(RightAscension::new(HourAngles::new(24.0)).is_err());
+//! ```
+//!
+//! ```rust
+//! use qtty_core::coordinates::{Latitude, Longitude};
+//!
+//! let lat: Latitude = "33° 52′ 04″ S".parse().unwrap();
+//! let lon: Longitude = "151.2093 E".parse().unwrap();
+//! assert!(lat.degrees().value() < 0.0);
+//! assert_eq!(format!("{lat:.0}, {lon:.0}"), "33° 52′ 04″ S, 151° 12′ 33″ E");
+//! ```
+
+use crate::math;
+use crate::units::angular::{AngularUnit, Degree, Degrees, HourAngle, HourAngles};
+use crate::Quantity;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which coordinate a [`CoordinateRangeError`] refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CoordinateKind {
+    /// Right ascension, valid in `[0h, 24h)`.
+    RightAscension,
+    /// Declination, valid in `[-90°, +90°]`.
+    Declination,
+    /// Geodetic latitude, valid in `[-90°, +90°]`.
+    Latitude,
+    /// Geodetic longitude, valid in `[-180°, +180°]`.
+    Longitude,
+}
+
+/// A coordinate value outside its valid range (or not finite).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CoordinateRangeError {
+    /// The coordinate being constructed.
+    pub kind: CoordinateKind,
+    /// The rejected value, in hours for right ascension and degrees for declination.
+    pub value: f64,
+}
+
+impl Display for CoordinateRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CoordinateKind::RightAscension => {
+                write!(f, "right ascension {}h is outside [0h, 24h)", self.value)
+            }
+            CoordinateKind::Declination => {
+                write!(f, "declination {}° is outside [-90°, +90°]", self.value)
+            }
+            CoordinateKind::Latitude => {
+                write!(f, "latitude {}° is outside [-90°, +90°]", self.value)
+            }
+            CoordinateKind::Longitude => {
+                write!(f, "longitude {}° is outside [-180°, +180°]", self.value)
+            }
+        }
+    }
+}
+
+/// Error returned when parsing a [`Latitude`] or [`Longitude`] from text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseCoordinateError {
+    /// The text is not a decimal or sexagesimal angle with an optional hemisphere letter.
+    Syntax,
+    /// The text is well formed but the angle is out of range.
+    OutOfRange(CoordinateRangeError),
+}
+
+impl Display for ParseCoordinateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax => {
+                f.write_str("invalid coordinate: expected e.g. `33.87 S` or `33° 52′ 04″ S`")
+            }
+            Self::OutOfRange(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl From<CoordinateRangeError> for ParseCoordinateError {
+    fn from(err: CoordinateRangeError) -> Self {
+        Self::OutOfRange(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCoordinateError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoordinateRangeError {}
+
+/// Splits a non-negative value into whole units, minutes and seconds, rounded to `precision` decimals on the seconds.
+///
+/// Rounding is done once on the total so carries propagate (`59.999s` at two decimals becomes the next minute).
+fn sexagesimal(value: f64, precision: usize) -> (u64, u64, u64, u64) {
+    let scale = 10u64.pow(precision as u32);
+    let ticks = math::round(value * 3_600.0 * scale as f64) as u64;
+    let per_minute = 60 * scale;
+    let per_unit = 60 * per_minute;
+    let frac = ticks % scale;
+    let seconds = (ticks % per_minute) / scale;
+    let minutes = (ticks % per_unit) / per_minute;
+    (ticks / per_unit, minutes, seconds, frac)
+}
+
+fn write_seconds(f: &mut Formatter<'_>, seconds: u64, frac: u64, precision: usize) -> fmt::Result {
+    if precision == 0 {
+        write!(f, "{:02}", seconds)
+    } else {
+        write!(f, "{:02}.{:0width$}", seconds, frac, width = precision)
+    }
+}
+
+/// Largest precision honoured by the sexagesimal [`Display`] impls; finer requests are clamped.
+const MAX_PRECISION: usize = 9;
+
+/// Sign and rounded sexagesimal parts of an angle in degrees; a value that rounds to zero is never negative.
+fn dms_parts(value: f64, precision: usize) -> (bool, (u64, u64, u64, u64)) {
+    let parts = sexagesimal(value.abs(), precision);
+    (value.is_sign_negative() && parts != (0, 0, 0, 0), parts)
+}
+
+/// Writes `DD° MM′ SS.s″`, padding the degrees to `width` digits.
+fn write_dms(
+    f: &mut Formatter<'_>,
+    (d, m, s, frac): (u64, u64, u64, u64),
+    width: usize,
+    precision: usize,
+) -> fmt::Result {
+    write!(f, "{:0width$}° {:02}′ ", d, m, width = width)?;
+    write_seconds(f, s, frac, precision)?;
+    f.write_str("″")
+}
+
+/// Parses `[±]D[.d]`, `D M[.m]` or `D M S[.s]` (any non-alphanumeric separators, e.g. `°′″`, `:` or spaces) with an
+/// optional leading or trailing hemisphere letter, returning signed degrees.
+fn parse_dms(text: &str, positive: char, negative: char) -> Result<f64, ParseCoordinateError> {
+    let mut body = text.trim();
+    let mut hemisphere = None;
+    for (letter, sign) in [(positive, 1.0), (negative, -1.0)] {
+        if let Some(rest) = body
+            .strip_suffix(letter)
+            .or_else(|| body.strip_suffix(letter.to_ascii_lowercase()))
+            .or_else(|| body.strip_prefix(letter))
+            .or_else(|| body.strip_prefix(letter.to_ascii_lowercase()))
+        {
+            body = rest;
+            hemisphere = Some(sign);
+            break;
+        }
+    }
+
+    if body.contains(char::is_alphabetic) {
+        return Err(ParseCoordinateError::Syntax);
+    }
+    let is_number = |c: char| c.is_ascii_digit() || matches!(c, '.' | '+' | '-');
+    let mut fields = [0.0; 3];
+    let mut count = 0;
+    let mut signed = false;
+    for token in body
+        .split(|c: char| !is_number(c))
+        .filter(|t| !t.is_empty())
+    {
+        if count == fields.len() {
+            return Err(ParseCoordinateError::Syntax);
+        }
+        let has_sign = token.starts_with(['+', '-']);
+        if has_sign && (count > 0 || hemisphere.is_some()) {
+            return Err(ParseCoordinateError::Syntax);
+        }
+        signed |= token.starts_with('-');
+        fields[count] = token
+            .parse::<f64>()
+            .map_err(|_| ParseCoordinateError::Syntax)?;
+        count += 1;
+    }
+    if count == 0 {
+        return Err(ParseCoordinateError::Syntax);
+    }
+    // Only the last field may be fractional, and minutes/seconds must stay below 60.
+    let (degrees, minutes, seconds) = (fields[0].abs(), fields[1], fields[2]);
+    let whole = |x: f64| x == x as u64 as f64;
+    if (count > 1 && !whole(degrees))
+        || (count > 2 && !whole(minutes))
+        || minutes >= 60.0
+        || seconds >= 60.0
+    {
+        return Err(ParseCoordinateError::Syntax);
+    }
+    let magnitude = degrees + minutes / 60.0 + seconds / 3_600.0;
+    let sign = hemisphere.unwrap_or(if signed { -1.0 } else { 1.0 });
+    Ok(sign * magnitude)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Right ascension
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Right ascension: an hour angle in `[0h, 24h)`.
+///
+/// Adding or subtracting an hour angle wraps around the circle; the difference of two right ascensions is the signed
+/// shortest separation in `(-12h, 12h]`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "HourAngles", into = "HourAngles"))]
+pub struct RightAscension(HourAngles);
+
+impl RightAscension {
+    /// Validates `hours` into a right ascension.
+    pub fn new(hours: HourAngles) -> Result<Self, CoordinateRangeError> {
+        let h = hours.value();
+        if (0.0..24.0).contains(&h) {
+            Ok(Self(hours))
+        } else {
+            Err(CoordinateRangeError {
+                kind: CoordinateKind::RightAscension,
+                value: h,
+            })
+        }
+    }
+
+    /// Builds a right ascension from any angle, wrapping it into `[0h, 24h)`.
+    ///
+    /// Non-finite input produces a NaN right ascension; use [`Self::new`] for untrusted values.
+    #[inline]
+    pub const fn wrapping<U: AngularUnit>(angle: Quantity<U>) -> Self {
+        let hours = angle.to::<HourAngle>().wrap_pos();
+        // `wrap_pos` can round a tiny negative input up to exactly one full turn.
+        if hours.value() >= 24.0 {
+            Self(HourAngles::new(0.0))
+        } else {
+            Self(hours)
+        }
+    }
+
+    /// Validates hours, minutes and seconds into a right ascension.
+    pub fn from_hms(hours: u32, minutes: u32, seconds: f64) -> Result<Self, CoordinateRangeError> {
+        Self::new(HourAngles::from_hms(hours as i32, minutes, seconds))
+    }
+
+    /// The right ascension as an hour angle.
+    #[inline]
+    pub const fn hours(self) -> HourAngles {
+        self.0
+    }
+
+    /// The right ascension in degrees (`[0°, 360°)`).
+    #[inline]
+    pub const fn degrees(self) -> Degrees {
+        self.0.to::<Degree>()
+    }
+}
+
+impl TryFrom<HourAngles> for RightAscension {
+    type Error = CoordinateRangeError;
+
+    fn try_from(hours: HourAngles) -> Result<Self, Self::Error> {
+        Self::new(hours)
+    }
+}
+
+impl From<RightAscension> for HourAngles {
+    fn from(ra: RightAscension) -> Self {
+        ra.0
+    }
+}
+
+impl Add<HourAngles> for RightAscension {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: HourAngles) -> Self {
+        Self::wrapping(self.0 + rhs)
+    }
+}
+
+impl Sub<HourAngles> for RightAscension {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: HourAngles) -> Self {
+        Self::wrapping(self.0 - rhs)
+    }
+}
+
+impl Sub for RightAscension {
+    type Output = HourAngles;
+
+    /// Signed shortest separation `self - rhs`, in `(-12h, 12h]`.
+    #[inline]
+    fn sub(self, rhs: Self) -> HourAngles {
+        self.0.signed_separation(rhs.0)
+    }
+}
+
+impl Display for RightAscension {
+    /// Formats as `HHh MMm SS.sss` with two decimals on the seconds unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2).min(MAX_PRECISION);
+        let (h, m, s, frac) = sexagesimal(self.0.value(), precision);
+        write!(f, "{:02}h {:02}m ", h % 24, m)?;
+        write_seconds(f, s, frac, precision)?;
+        f.write_str("s")
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Declination
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Declination: an angle in `[-90°, +90°]`.
+///
+/// Offsets are applied with [`Self::checked_add`] / [`Self::checked_sub`], which fail instead of silently folding
+/// over a pole (that would also flip the right ascension); the difference of two declinations is a plain angle.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Degrees", into = "Degrees"))]
+pub struct Declination(Degrees);
+
+impl Declination {
+    /// Validates `degrees` into a declination.
+    pub fn new(degrees: Degrees) -> Result<Self, CoordinateRangeError> {
+        let d = degrees.value();
+        if (-90.0..=90.0).contains(&d) {
+            Ok(Self(degrees))
+        } else {
+            Err(CoordinateRangeError {
+                kind: CoordinateKind::Declination,
+                value: d,
+            })
+        }
+    }
+
+    /// Validates any angle into a declination.
+    pub fn from_angle<U: AngularUnit>(angle: Quantity<U>) -> Result<Self, CoordinateRangeError> {
+        Self::new(angle.to::<Degree>())
+    }
+
+    /// Validates degrees, arcminutes and arcseconds (sign taken from `degrees`) into a declination.
+    ///
+    /// Use [`Degrees::from_dms_sign`] with [`Self::new`] for southern declinations above −1°.
+    pub fn from_dms(
+        degrees: i32,
+        minutes: u32,
+        seconds: f64,
+    ) -> Result<Self, CoordinateRangeError> {
+        Self::new(Degrees::from_dms(degrees, minutes, seconds))
+    }
+
+    /// The declination in degrees.
+    #[inline]
+    pub const fn degrees(self) -> Degrees {
+        self.0
+    }
+
+    /// `self + offset`, or `None` if the result would pass a pole.
+    pub fn checked_add(self, offset: Degrees) -> Option<Self> {
+        Self::new(self.0 + offset).ok()
+    }
+
+    /// `self - offset`, or `None` if the result would pass a pole.
+    pub fn checked_sub(self, offset: Degrees) -> Option<Self> {
+        Self::new(self.0 - offset).ok()
+    }
+}
+
+impl TryFrom<Degrees> for Declination {
+    type Error = CoordinateRangeError;
+
+    fn try_from(degrees: Degrees) -> Result<Self, Self::Error> {
+        Self::new(degrees)
+    }
+}
+
+impl From<Declination> for Degrees {
+    fn from(dec: Declination) -> Self {
+        dec.0
+    }
+}
+
+impl Sub for Declination {
+    type Output = Degrees;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Degrees {
+        self.0 - rhs.0
+    }
+}
+
+impl Display for Declination {
+    /// Formats as `±DD° MM′ SS.s″` with one decimal on the seconds unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(1).min(MAX_PRECISION);
+        let (negative, parts) = dms_parts(self.0.value(), precision);
+        f.write_str(if negative { "-" } else { "+" })?;
+        write_dms(f, parts, 2, precision)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Latitude
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Geodetic latitude in `[-90°, +90°]`, positive north.
+///
+/// Prints as `DD° MM′ SS.s″ N|S` and parses decimal or sexagesimal text with a hemisphere letter or sign
+/// (`"-33.87"`, `"33 52 04 S"`, `"S 33°52′04″"`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Degrees", into = "Degrees"))]
+pub struct Latitude(Degrees);
+
+impl Latitude {
+    /// Validates `degrees` into a latitude.
+    pub fn new(degrees: Degrees) -> Result<Self, CoordinateRangeError> {
+        let d = degrees.value();
+        if (-90.0..=90.0).contains(&d) {
+            Ok(Self(degrees))
+        } else {
+            Err(CoordinateRangeError {
+                kind: CoordinateKind::Latitude,
+                value: d,
+            })
+        }
+    }
+
+    /// Validates any angle into a latitude.
+    pub fn from_angle<U: AngularUnit>(angle: Quantity<U>) -> Result<Self, CoordinateRangeError> {
+        Self::new(angle.to::<Degree>())
+    }
+
+    /// The latitude in degrees (negative south).
+    #[inline]
+    pub const fn degrees(self) -> Degrees {
+        self.0
+    }
+
+    /// `true` for latitudes on or north of the equator.
+    #[inline]
+    pub const fn is_north(self) -> bool {
+        !self.0.value().is_sign_negative()
+    }
+}
+
+impl TryFrom<Degrees> for Latitude {
+    type Error = CoordinateRangeError;
+
+    fn try_from(degrees: Degrees) -> Result<Self, Self::Error> {
+        Self::new(degrees)
+    }
+}
+
+impl From<Latitude> for Degrees {
+    fn from(lat: Latitude) -> Self {
+        lat.0
+    }
+}
+
+impl FromStr for Latitude {
+    type Err = ParseCoordinateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(Degrees::new(parse_dms(s, 'N', 'S')?))?)
+    }
+}
+
+impl Display for Latitude {
+    /// Formats as `DD° MM′ SS.s″ N` with one decimal on the seconds unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(1).min(MAX_PRECISION);
+        let (negative, parts) = dms_parts(self.0.value(), precision);
+        write_dms(f, parts, 2, precision)?;
+        f.write_str(if negative { " S" } else { " N" })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Longitude
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Geodetic longitude in `[-180°, +180°]`, positive east.
+///
+/// Prints as `DDD° MM′ SS.s″ E|W` and parses like [`Latitude`] with `E`/`W` hemisphere letters. The difference of two
+/// longitudes is the signed shortest separation in `(-180°, 180°]`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Degrees", into = "Degrees"))]
+pub struct Longitude(Degrees);
+
+impl Longitude {
+    /// Validates `degrees` into a longitude.
+    pub fn new(degrees: Degrees) -> Result<Self, CoordinateRangeError> {
+        let d = degrees.value();
+        if (-180.0..=180.0).contains(&d) {
+            Ok(Self(degrees))
+        } else {
+            Err(CoordinateRangeError {
+                kind: CoordinateKind::Longitude,
+                value: d,
+            })
+        }
+    }
+
+    /// Validates any angle into a longitude.
+    pub fn from_angle<U: AngularUnit>(angle: Quantity<U>) -> Result<Self, CoordinateRangeError> {
+        Self::new(angle.to::<Degree>())
+    }
+
+    /// Builds a longitude from any angle, wrapping it into `(-180°, 180°]`.
+    #[inline]
+    pub const fn wrapping<U: AngularUnit>(angle: Quantity<U>) -> Self {
+        Self(angle.to::<Degree>().wrap_signed())
+    }
+
+    /// The longitude in degrees (negative west).
+    #[inline]
+    pub const fn degrees(self) -> Degrees {
+        self.0
+    }
+
+    /// `true` for longitudes on or east of the prime meridian.
+    #[inline]
+    pub const fn is_east(self) -> bool {
+        !self.0.value().is_sign_negative()
+    }
+}
+
+impl TryFrom<Degrees> for Longitude {
+    type Error = CoordinateRangeError;
+
+    fn try_from(degrees: Degrees) -> Result<Self, Self::Error> {
+        Self::new(degrees)
+    }
+}
+
+impl From<Longitude> for Degrees {
+    fn from(lon: Longitude) -> Self {
+        lon.0
+    }
+}
+
+impl Sub for Longitude {
+    type Output = Degrees;
+
+    /// Signed shortest separation `self - rhs`, in `(-180°, 180°]`.
+    #[inline]
+    fn sub(self, rhs: Self) -> Degrees {
+        self.0.signed_separation(rhs.0)
+    }
+}
+
+impl FromStr for Longitude {
+    type Err = ParseCoordinateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(Degrees::new(parse_dms(s, 'E', 'W')?))?)
+    }
+}
+
+impl Display for Longitude {
+    /// Formats as `DDD° MM′ SS.s″ E` with one decimal on the seconds unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(1).min(MAX_PRECISION);
+        let (negative, parts) = dms_parts(self.0.value(), precision);
+        write_dms(f, parts, 3, precision)?;
+        f.write_str(if negative { " W" } else { " E" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::Radians;
+    use approx::assert_abs_diff_eq;
+    use proptest::prelude::*;
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Validation
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn right_ascension_range() {
+        assert!(RightAscension::new(HourAngles::new(0.0)).is_ok());
+        assert!(RightAscension::new(HourAngles::new(23.999)).is_ok());
+        let err = RightAscension::new(HourAngles::new(24.0)).unwrap_err();
+        assert_eq!(err.kind, CoordinateKind::RightAscension);
+        assert!(RightAscension::new(HourAngles::new(-0.1)).is_err());
+        assert!(RightAscension::new(HourAngles::NAN).is_err());
+    }
+
+    #[test]
+    fn declination_range() {
+        assert!(Declination::new(Degrees::new(90.0)).is_ok());
+        assert!(Declination::new(Degrees::new(-90.0)).is_ok());
+        assert!(Declination::new(Degrees::new(90.1)).is_err());
+        assert!(Declination::new(Degrees::NAN).is_err());
+        assert!(Declination::from_angle(Radians::new(1.0)).is_ok());
+        assert!(Declination::from_angle(Radians::new(2.0)).is_err());
+    }
+
+    #[test]
+    fn error_messages() {
+        let ra = RightAscension::new(HourAngles::new(25.0)).unwrap_err();
+        assert_eq!(ra.to_string(), "right ascension 25h is outside [0h, 24h)");
+        let dec = Declination::new(Degrees::new(-91.0)).unwrap_err();
+        assert_eq!(dec.to_string(), "declination -91° is outside [-90°, +90°]");
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Arithmetic
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn right_ascension_wraps() {
+        let ra = RightAscension::from_hms(23, 0, 0.0).unwrap();
+        assert_abs_diff_eq!((ra + HourAngles::new(2.0)).hours().value(), 1.0);
+        assert_abs_diff_eq!((ra - HourAngles::new(25.0)).hours().value(), 22.0);
+        let other = RightAscension::from_hms(1, 0, 0.0).unwrap();
+        assert_abs_diff_eq!((other - ra).value(), 2.0);
+        assert_abs_diff_eq!((ra - other).value(), -2.0);
+        assert_abs_diff_eq!(
+            RightAscension::wrapping(Degrees::new(-15.0))
+                .hours()
+                .value(),
+            23.0
+        );
+        assert_eq!(
+            RightAscension::wrapping(HourAngles::new(-1e-20))
+                .hours()
+                .value(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn declination_offsets() {
+        let dec = Declination::new(Degrees::new(80.0)).unwrap();
+        assert_eq!(
+            dec.checked_add(Degrees::new(10.0))
+                .unwrap()
+                .degrees()
+                .value(),
+            90.0
+        );
+        assert!(dec.checked_add(Degrees::new(10.5)).is_none());
+        assert!(dec.checked_sub(Degrees::new(170.0)).is_some());
+        assert!(dec.checked_sub(Degrees::new(171.0)).is_none());
+        let south = Declination::new(Degrees::new(-30.0)).unwrap();
+        assert_eq!((dec - south).value(), 110.0);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Formatting
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn display_hms() {
+        let ra = RightAscension::from_hms(5, 35, 17.3).unwrap();
+        assert_eq!(ra.to_string(), "05h 35m 17.30s");
+        assert_eq!(format!("{:.0}", ra), "05h 35m 17s");
+        assert_eq!(format!("{:.3}", ra), "05h 35m 17.300s");
+        // Rounding carries into the minutes and hours, and 24h wraps to 0h.
+        let late = RightAscension::from_hms(23, 59, 59.999).unwrap();
+        assert_eq!(late.to_string(), "00h 00m 00.00s");
+        assert_eq!(format!("{:.3}", late), "23h 59m 59.999s");
+    }
+
+    #[test]
+    fn display_dms() {
+        let dec = Declination::from_dms(-5, 23, 28.04).unwrap();
+        assert_eq!(dec.to_string(), "-05° 23′ 28.0″");
+        assert_eq!(format!("{:.2}", dec), "-05° 23′ 28.04″");
+        let north = Declination::from_dms(41, 16, 9.0).unwrap();
+        assert_eq!(format!("{:.0}", north), "+41° 16′ 09″");
+        let south_of_equator = Declination::new(Degrees::new(-0.5)).unwrap();
+        assert_eq!(south_of_equator.to_string(), "-00° 30′ 00.0″");
+        assert_eq!(
+            Declination::new(Degrees::new(-0.0)).unwrap().to_string(),
+            "+00° 00′ 00.0″"
+        );
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Latitude / longitude
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn geodetic_ranges() {
+        assert!(Latitude::new(Degrees::new(-90.0)).is_ok());
+        assert_eq!(
+            Latitude::new(Degrees::new(91.0)).unwrap_err().to_string(),
+            "latitude 91° is outside [-90°, +90°]"
+        );
+        assert!(Longitude::new(Degrees::new(180.0)).is_ok());
+        assert!(Longitude::new(Degrees::new(-180.5)).is_err());
+        assert_eq!(
+            Longitude::wrapping(Degrees::new(190.0)).degrees().value(),
+            -170.0
+        );
+        assert!(Latitude::from_angle(Radians::new(-1.5)).is_ok());
+    }
+
+    #[test]
+    fn geodetic_display() {
+        let lat = Latitude::new(Degrees::from_dms_sign(-1, 33, 52, 4.0)).unwrap();
+        assert_eq!(lat.to_string(), "33° 52′ 04.0″ S");
+        assert!(!lat.is_north());
+        let lon = Longitude::new(Degrees::new(-70.404_2)).unwrap();
+        assert_eq!(format!("{:.0}", lon), "070° 24′ 15″ W");
+        assert!(!lon.is_east());
+        assert_eq!(
+            Latitude::new(Degrees::new(-0.0)).unwrap().to_string(),
+            "00° 00′ 00.0″ N"
+        );
+        assert_eq!(
+            Longitude::new(Degrees::new(0.0)).unwrap().to_string(),
+            "000° 00′ 00.0″ E"
+        );
+    }
+
+    #[test]
+    fn geodetic_parse() {
+        let cases = [
+            ("-33.5", -33.5),
+            ("33.5 S", -33.5),
+            ("s33.5", -33.5),
+            ("33 30 S", -33.5),
+            ("N 33°30′", 33.5),
+            ("33:30:36n", 33.51),
+            ("+12°00'36\"", 12.01),
+            ("-0 30", -0.5),
+        ];
+        for (text, expected) in cases {
+            let lat: Latitude = text.parse().unwrap_or_else(|e| panic!("{text}: {e}"));
+            assert_abs_diff_eq!(lat.degrees().value(), expected, epsilon = 1e-12);
+        }
+        let lon: Longitude = "151 12 33.5 E".parse().unwrap();
+        assert_abs_diff_eq!(
+            lon.degrees().value(),
+            151.209_305_555_555_6,
+            epsilon = 1e-12
+        );
+        let lon: Longitude = "70.4W".parse().unwrap();
+        assert_abs_diff_eq!(lon.degrees().value(), -70.4);
+    }
+
+    #[test]
+    fn geodetic_parse_errors() {
+        for text in [
+            "", "N", "33 S N", "-33 S", "33 -30", "33 60", "33.5 30", "1 2 3 4", "33.5 E", "abc",
+        ] {
+            assert_eq!(
+                text.parse::<Latitude>(),
+                Err(ParseCoordinateError::Syntax),
+                "{text:?}"
+            );
+        }
+        assert!(matches!(
+            "95 N".parse::<Latitude>(),
+            Err(ParseCoordinateError::OutOfRange(CoordinateRangeError {
+                kind: CoordinateKind::Latitude,
+                ..
+            }))
+        ));
+        assert!("181 E".parse::<Longitude>().is_err());
+    }
+
+    #[test]
+    fn longitude_difference_wraps() {
+        let a = Longitude::new(Degrees::new(170.0)).unwrap();
+        let b = Longitude::new(Degrees::new(-170.0)).unwrap();
+        assert_abs_diff_eq!((b - a).value(), 20.0, epsilon = 1e-12);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_validates() {
+        let ra = RightAscension::from_hms(12, 0, 0.0).unwrap();
+        assert_eq!(serde_json::to_string(&ra).unwrap(), "12.0");
+        assert_eq!(serde_json::from_str::<RightAscension>("12.0").unwrap(), ra);
+        assert!(serde_json::from_str::<RightAscension>("24.5").is_err());
+        assert!(serde_json::from_str::<Declination>("-95.0").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_geodetic_display_parse_roundtrip(lat in -90.0..=90.0f64, lon in -180.0..=180.0f64) {
+            let lat = Latitude::new(Degrees::new(lat)).unwrap();
+            let lon = Longitude::new(Degrees::new(lon)).unwrap();
+            let lat_back: Latitude = format!("{:.6}", lat).parse().unwrap();
+            let lon_back: Longitude = format!("{:.6}", lon).parse().unwrap();
+            prop_assert!((lat_back.degrees() - lat.degrees()).value().abs() < 1e-9);
+            prop_assert!((lon_back.degrees() - lon.degrees()).value().abs() < 1e-9);
+        }
+
+        #[test]
+        fn prop_wrapping_is_valid(h in -1e4..1e4f64) {
+            let ra = RightAscension::wrapping(HourAngles::new(h));
+            prop_assert!(RightAscension::new(ra.hours()).is_ok());
+        }
+
+        #[test]
+        fn prop_add_then_sub_roundtrip(h in 0.0..24.0f64, delta in -48.0..48.0f64) {
+            let ra = RightAscension::new(HourAngles::new(h)).unwrap();
+            let moved = ra + HourAngles::new(delta);
+            let back = moved - HourAngles::new(delta);
+            let err = (back - ra).value().abs();
+            prop_assert!(err < 1e-12, "{err}");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Dimension types and traits.
 
+use core::any::TypeId;
 use core::marker::PhantomData;
 
 /// Marker trait for **dimensions** (Length, Time, Mass …).
@@ -13,8 +14,46 @@ use core::marker::PhantomData;
 /// pub enum Length {}
 /// impl Dimension for Length {}
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a dimension",
+    note = "dimensions are usually empty enums with `impl Dimension for MyDimension {{}}`"
+)]
 pub trait Dimension {}
 
+mod sealed {
+    pub trait Sealed<D> {}
+    impl<D> Sealed<D> for D {}
+}
+
+/// Proof that two dimensions are identical, implemented only as `D: SameDimension<D>` (the trait is sealed).
+///
+/// [`Quantity::to`](crate::Quantity::to) uses it as its bound so that a conversion across dimensions reports both
+/// dimensions by name rather than an associated-type mismatch. See also [`same_dimension`](crate::same_dimension).
+#[diagnostic::on_unimplemented(
+    message = "cannot convert `{Self}` to `{D}`: the dimensions differ",
+    label = "`{Self}` quantities cannot be converted to `{D}` units",
+    note = "quantities only convert between units of the same dimension"
+)]
+pub trait SameDimension<D: Dimension>: Dimension + sealed::Sealed<D> {}
+impl<D: Dimension> SameDimension<D> for D {}
+
+/// Runtime identity of a [`Dimension`] type, comparable and hashable.
+///
+/// Obtained with [`DimensionId::of`] or [`Unit::dimension_id`](crate::Unit::dimension_id). It is built on
+/// [`TypeId`], which cannot yet be computed in a `const` context on stable Rust, so unlike the compile-time checks
+/// ([`SameDimension`], [`same_dimension`](crate::same_dimension)) it is only available at run time. Composite
+/// dimensions compare structurally by type: `DivDim<Length, Time>` is one id however it is reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DimensionId(TypeId);
+
+impl DimensionId {
+    /// The id of dimension `D`.
+    #[inline]
+    pub fn of<D: Dimension + 'static>() -> Self {
+        Self(TypeId::of::<D>())
+    }
+}
+
 /// Dimension formed by dividing one [`Dimension`] by another.
 ///
 /// This is used to model composite dimensions such as `Length/Time`
@@ -23,6 +62,14 @@ pub trait Dimension {}
 pub struct DivDim<N: Dimension, D: Dimension>(PhantomData<(N, D)>);
 impl<N: Dimension, D: Dimension> Dimension for DivDim<N, D> {}
 
+/// Dimension formed by multiplying two [`Dimension`]s.
+///
+/// Used for product dimensions such as `Length·Length` (area). Products are not reordered or flattened, so
+/// `MulDim<A, B>` and `MulDim<B, A>` are distinct types; the predefined aliases nest to the left.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MulDim<A: Dimension, B: Dimension>(PhantomData<(A, B)>);
+impl<A: Dimension, B: Dimension> Dimension for MulDim<A, B> {}
+
 /// Dimension for dimensionless quantities.
 pub enum Dimensionless {}
 impl Dimension for Dimensionless {}
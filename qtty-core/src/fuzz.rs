@@ -0,0 +1,171 @@
+//! [`arbitrary`] and [`quickcheck`] integrations for [`Quantity`].
+//!
+//! With the `arbitrary` or `quickcheck` feature enabled, `Quantity<U>` implements the corresponding `Arbitrary` trait
+//! so fuzz targets and quickcheck properties can take typed quantities directly. The generated values are always
+//! finite; use [`arbitrary_in`] / [`quickcheck_in`] when an API only accepts a narrower range.
+//!
+//! ```rust
+//! # #[cfg(feature = "arbitrary")] {
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use qtty_core::angular::Degrees;
+//! use qtty_core::fuzz::arbitrary_in;
+//!
+//! let mut u = Unstructured::new(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x42, 0x42]);
+//! let any = Degrees::arbitrary(&mut u).unwrap();
+//! assert!(any.value().is_finite());
+//!
+//! let lat: Degrees = arbitrary_in(&mut u, -90.0..=90.0).unwrap();
+//! assert!((-90.0..=90.0).contains(&lat.value()));
+//! # }
+//! ```
+
+use crate::{Quantity, Unit};
+use core::ops::RangeInclusive;
+
+/// Maps a non-finite sample onto a finite one: NaN → `0.0`, `±∞` → `±f64::MAX`.
+#[inline]
+fn finite(x: f64) -> f64 {
+    if x.is_nan() {
+        0.0
+    } else if x.is_infinite() {
+        f64::MAX.copysign(x)
+    } else {
+        x
+    }
+}
+
+/// Picks a point of `range` from 53 random bits, hitting both endpoints.
+fn lerp(range: &RangeInclusive<f64>, bits: u64) -> f64 {
+    let (lo, hi) = (*range.start(), *range.end());
+    assert!(
+        lo.is_finite() && hi.is_finite() && lo <= hi,
+        "quantity range must be finite and non-empty"
+    );
+    const STEPS: u64 = 1 << 53;
+    let t = (bits % (STEPS + 1)) as f64 / STEPS as f64;
+    // Interpolate without forming `hi - lo`, which overflows for ranges wider than `f64::MAX`.
+    (lo * (1.0 - t) + hi * t).clamp(lo, hi)
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, U: Unit> arbitrary::Arbitrary<'a> for Quantity<U> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(finite(f64::arbitrary(u)?)))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <f64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// Draws a quantity whose value lies in `range` from fuzzer input.
+///
+/// # Panics
+///
+/// Panics if either bound is not finite or `range` is empty.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_in<U: Unit>(
+    u: &mut arbitrary::Unstructured<'_>,
+    range: RangeInclusive<f64>,
+) -> arbitrary::Result<Quantity<U>> {
+    let bits: u64 = u.arbitrary()?;
+    Ok(Quantity::new(lerp(&range, bits)))
+}
+
+#[cfg(feature = "quickcheck")]
+impl<U: Unit> quickcheck::Arbitrary for Quantity<U> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::new(finite(f64::arbitrary(g)))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.value()
+                .shrink()
+                .filter(|v| v.is_finite())
+                .map(Self::new),
+        )
+    }
+}
+
+/// Generates a quantity whose value lies in `range`.
+///
+/// # Panics
+///
+/// Panics if either bound is not finite or `range` is empty.
+#[cfg(feature = "quickcheck")]
+pub fn quickcheck_in<U: Unit>(g: &mut quickcheck::Gen, range: RangeInclusive<f64>) -> Quantity<U> {
+    let bits = <u64 as quickcheck::Arbitrary>::arbitrary(g);
+    Quantity::new(lerp(&range, bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::Meters;
+
+    #[test]
+    fn finite_replaces_non_finite() {
+        assert_eq!(finite(f64::NAN), 0.0);
+        assert_eq!(finite(f64::INFINITY), f64::MAX);
+        assert_eq!(finite(f64::NEG_INFINITY), -f64::MAX);
+        assert_eq!(finite(-2.5), -2.5);
+    }
+
+    #[test]
+    fn lerp_covers_endpoints() {
+        let r = -1.0..=3.0;
+        assert_eq!(lerp(&r, 0), -1.0);
+        assert_eq!(lerp(&r, 1 << 53), 3.0);
+        assert_eq!(lerp(&r, 1 << 52), 1.0);
+        let wide = -f64::MAX..=f64::MAX;
+        assert!(lerp(&wide, u64::MAX).is_finite());
+    }
+
+    #[test]
+    #[should_panic(expected = "finite and non-empty")]
+    fn lerp_rejects_infinite_bounds() {
+        lerp(&(0.0..=f64::INFINITY), 0);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_values_are_finite() {
+        use arbitrary::{Arbitrary, Unstructured};
+        // All-ones bytes decode to a NaN bit pattern.
+        let data = [0xffu8; 64];
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let q = Meters::arbitrary(&mut u).unwrap();
+            assert!(q.value().is_finite());
+            let r: Meters = arbitrary_in(&mut u, 10.0..=20.0).unwrap();
+            assert!((10.0..=20.0).contains(&r.value()));
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_values_are_finite() {
+        use quickcheck::{Arbitrary, Gen};
+        let mut g = Gen::new(100);
+        for _ in 0..1_000 {
+            let q = Meters::arbitrary(&mut g);
+            assert!(q.value().is_finite());
+            assert!(q.shrink().all(|s| s.value().is_finite()));
+            let r: Meters = quickcheck_in(&mut g, -5.0..=5.0);
+            assert!((-5.0..=5.0).contains(&r.value()));
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_property_over_quantities() {
+        fn roundtrip(q: Meters) -> bool {
+            use crate::units::length::{Kilometer, Meter};
+            let back = q.to::<Kilometer>().to::<Meter>().value();
+            back == q.value() || (back - q.value()).abs() <= 1e-12 * q.value().abs()
+        }
+        quickcheck::quickcheck(roundtrip as fn(Meters) -> bool);
+    }
+}
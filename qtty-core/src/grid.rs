@@ -0,0 +1,325 @@
+//! Evenly spaced grids of quantities, for survey tiling and map generation.
+//!
+//! [`Quantity::linspace`] spaces `n` points from `start` to `stop` inclusive, [`Quantity::grid_by`] walks a full
+//! circle of any angular unit at a fixed step, and [`mesh`] / [`radec_grid`] combine two axes into a 2-D grid. All of
+//! them return lazy [`Grid`] / [`Mesh`] iterators, so large maps are never materialized unless collected.
+//!
+//! ```rust
+//! use qtty_core::angular::{Degrees, HourAngles};
+//! use qtty_core::grid::radec_grid;
+//! use qtty_core::Quantity;
+//!
+//! let dec: Vec<Degrees> = Degrees::linspace(Degrees::new(-30.0), Degrees::new(30.0), 5).collect();
+//! assert_eq!(dec[1].value(), -15.0);
+//! assert_eq!(dec[4].value(), 30.0);
+//!
+//! let ra = HourAngles::grid_by(Degrees::new(90.0));
+//! assert_eq!(ra.map(|h| h.value()).collect::<Vec<_>>(), [0.0, 6.0, 12.0, 18.0]);
+//!
+//! // A 1h × 30° all-sky grid: 24 right ascensions times 7 declinations from the south to the north pole.
+//! let tiles = radec_grid(HourAngles::new(1.0), Degrees::new(30.0));
+//! assert_eq!(tiles.len(), 24 * 7);
+//! ```
+
+use crate::math;
+use crate::units::angular::{AngularUnit, Degree, HourAngle};
+use crate::{Quantity, SameDimension, Unit};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Relative slack under which a step count is treated as a whole number (so `24h / 0.1h` gives 240 points, not 241).
+const WHOLE_STEPS_TOLERANCE: f64 = 1e-9;
+
+/// An evenly spaced sequence of quantities in unit `U`, yielded front to back (or back to front).
+///
+/// Element `i` is `start + i · step`, except that a [`Quantity::linspace`] grid ends exactly on its `stop`.
+#[derive(Clone, Debug)]
+pub struct Grid<U: Unit> {
+    start: f64,
+    step: f64,
+    last: f64,
+    len: usize,
+    front: usize,
+    back: usize,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> Grid<U> {
+    const fn new(start: f64, step: f64, last: f64, len: usize) -> Self {
+        Self {
+            start,
+            step,
+            last,
+            len,
+            front: 0,
+            back: len,
+            unit: PhantomData,
+        }
+    }
+
+    fn empty() -> Self {
+        Self::new(0.0, 0.0, 0.0, 0)
+    }
+
+    /// The spacing between consecutive points.
+    pub fn step(&self) -> Quantity<U> {
+        Quantity::new(self.step)
+    }
+
+    fn at(&self, i: usize) -> Quantity<U> {
+        Quantity::new(if i + 1 == self.len {
+            self.last
+        } else {
+            self.start + self.step * i as f64
+        })
+    }
+}
+
+impl<U: Unit> Iterator for Grid<U> {
+    type Item = Quantity<U>;
+
+    fn next(&mut self) -> Option<Quantity<U>> {
+        if self.front >= self.back {
+            return None;
+        }
+        let item = self.at(self.front);
+        self.front += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Quantity<U>> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<U: Unit> DoubleEndedIterator for Grid<U> {
+    fn next_back(&mut self) -> Option<Quantity<U>> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.at(self.back))
+    }
+}
+
+impl<U: Unit> ExactSizeIterator for Grid<U> {}
+
+impl<U: Unit> FusedIterator for Grid<U> {}
+
+impl<U: Unit> Quantity<U> {
+    /// `n` evenly spaced points from `start` to `stop`, both included (like NumPy's `linspace`).
+    ///
+    /// `n == 0` yields nothing and `n == 1` yields `start` alone. The last point is exactly `stop`.
+    pub fn linspace(start: Self, stop: Self, n: usize) -> Grid<U> {
+        match n {
+            0 => Grid::empty(),
+            1 => Grid::new(start.value(), 0.0, start.value(), 1),
+            _ => {
+                let step = (stop.value() - start.value()) / (n - 1) as f64;
+                Grid::new(start.value(), step, stop.value(), n)
+            }
+        }
+    }
+}
+
+impl<U: AngularUnit> Quantity<U> {
+    /// Points `0, step, 2·step, …` around one full turn, stopping before `FULL_TURN`.
+    ///
+    /// `step` may be in any angular unit. When it divides the circle the grid has exactly `FULL_TURN / step` points;
+    /// otherwise the last gap is shorter. A step that is not finite and positive yields nothing.
+    pub fn grid_by<S: Unit>(step: Quantity<S>) -> Grid<U>
+    where
+        S::Dim: SameDimension<U::Dim>,
+    {
+        let step = step.to::<U>().value();
+        if !step.is_finite() || step <= 0.0 {
+            return Grid::empty();
+        }
+        let steps = U::FULL_TURN / step;
+        let whole = math::round(steps);
+        let len = if (steps - whole).abs() <= WHOLE_STEPS_TOLERANCE * steps {
+            whole
+        } else {
+            math::ceil(steps)
+        } as usize;
+        Grid::new(0.0, step, step * (len - 1) as f64, len)
+    }
+}
+
+/// The cartesian product of two grids: every `inner` point for each `outer` point, in row-major order.
+#[derive(Clone, Debug)]
+pub struct Mesh<A: Unit, B: Unit> {
+    outer: Grid<A>,
+    current: Option<Quantity<A>>,
+    inner: Grid<B>,
+    inner_template: Grid<B>,
+}
+
+/// Pairs every point of `outer` with every point of `inner` (row-major: `inner` varies fastest).
+pub fn mesh<A: Unit, B: Unit>(mut outer: Grid<A>, inner: Grid<B>) -> Mesh<A, B> {
+    let current = if inner.len() == 0 { None } else { outer.next() };
+    Mesh {
+        outer,
+        current,
+        inner: inner.clone(),
+        inner_template: inner,
+    }
+}
+
+impl<A: Unit, B: Unit> Iterator for Mesh<A, B> {
+    type Item = (Quantity<A>, Quantity<B>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.current?;
+        match self.inner.next() {
+            Some(b) => Some((a, b)),
+            None => {
+                self.current = self.outer.next();
+                self.inner = self.inner_template.clone();
+                let b = self.inner.next()?;
+                Some((self.current?, b))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.current {
+            Some(_) => self.inner.len() + self.outer.len() * self.inner_template.len(),
+            None => 0,
+        };
+        (len, Some(len))
+    }
+}
+
+impl<A: Unit, B: Unit> ExactSizeIterator for Mesh<A, B> {}
+
+impl<A: Unit, B: Unit> FusedIterator for Mesh<A, B> {}
+
+/// An all-sky (right ascension, declination) grid.
+///
+/// Right ascension walks the full circle at `ra_step` ([`Quantity::grid_by`]). Declination runs from −90° to +90°
+/// inclusive, with `dec_step` rounded to the nearest spacing that lands on both poles. Both steps may be in any
+/// angular unit.
+pub fn radec_grid<R: Unit, D: Unit>(
+    ra_step: Quantity<R>,
+    dec_step: Quantity<D>,
+) -> Mesh<HourAngle, Degree>
+where
+    R::Dim: SameDimension<<HourAngle as Unit>::Dim>,
+    D::Dim: SameDimension<<Degree as Unit>::Dim>,
+{
+    let ra = Quantity::<HourAngle>::grid_by(ra_step);
+    let dec_step = dec_step.to::<Degree>().value();
+    let dec = if dec_step.is_finite() && dec_step > 0.0 {
+        let intervals = math::round(180.0 / dec_step).max(1.0) as usize;
+        Quantity::linspace(Quantity::new(-90.0), Quantity::new(90.0), intervals + 1)
+    } else {
+        Grid::empty()
+    };
+    mesh(ra, dec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Arcminutes, Degrees, HourAngles, Radian, Radians};
+    use crate::units::length::Meters;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn linspace_includes_both_ends() {
+        let xs: Vec<f64> = Meters::linspace(Meters::new(0.0), Meters::new(1.0), 11)
+            .map(|m| m.value())
+            .collect();
+        assert_eq!(xs.len(), 11);
+        assert_eq!(xs[0], 0.0);
+        assert_relative_eq!(xs[3], 0.3);
+        assert_eq!(xs[10], 1.0);
+
+        assert_eq!(
+            Meters::linspace(Meters::new(1.0), Meters::new(2.0), 0).count(),
+            0
+        );
+        let one: Vec<_> = Meters::linspace(Meters::new(1.0), Meters::new(2.0), 1).collect();
+        assert_eq!(one, [Meters::new(1.0)]);
+    }
+
+    #[test]
+    fn linspace_runs_backwards_and_descending() {
+        let grid = Degrees::linspace(Degrees::new(10.0), Degrees::new(-10.0), 5);
+        assert_eq!(grid.len(), 5);
+        let back: Vec<f64> = grid.rev().map(|d| d.value()).collect();
+        assert_eq!(back, [-10.0, -5.0, 0.0, 5.0, 10.0]);
+
+        let mut grid = Degrees::linspace(Degrees::new(0.0), Degrees::new(4.0), 5);
+        assert_eq!(grid.nth(2).unwrap().value(), 2.0);
+        assert_eq!(grid.next_back().unwrap().value(), 4.0);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn grid_by_covers_one_turn() {
+        assert_eq!(HourAngles::grid_by(HourAngles::new(0.1)).len(), 240);
+        assert_eq!(Degrees::grid_by(Arcminutes::new(90.0)).len(), 240);
+        let uneven: Vec<f64> = Degrees::grid_by(Degrees::new(100.0))
+            .map(|d| d.value())
+            .collect();
+        assert_eq!(uneven, [0.0, 100.0, 200.0, 300.0]);
+
+        let radians: Vec<Radians> = Quantity::<Radian>::grid_by(Degrees::new(90.0)).collect();
+        assert_eq!(radians.len(), 4);
+        assert_relative_eq!(radians[3].value(), 1.5 * core::f64::consts::PI);
+
+        assert_eq!(Degrees::grid_by(Degrees::new(0.0)).count(), 0);
+        assert_eq!(Degrees::grid_by(Degrees::new(-1.0)).count(), 0);
+    }
+
+    #[test]
+    fn mesh_is_row_major() {
+        let cells: Vec<(f64, f64)> = mesh(
+            Meters::linspace(Meters::new(0.0), Meters::new(1.0), 2),
+            Degrees::linspace(Degrees::new(0.0), Degrees::new(2.0), 3),
+        )
+        .map(|(a, b)| (a.value(), b.value()))
+        .collect();
+        assert_eq!(
+            cells,
+            [
+                (0.0, 0.0),
+                (0.0, 1.0),
+                (0.0, 2.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (1.0, 2.0)
+            ]
+        );
+
+        let empty = mesh(
+            Meters::linspace(Meters::new(0.0), Meters::new(1.0), 2),
+            Degrees::linspace(Degrees::new(0.0), Degrees::new(1.0), 0),
+        );
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.count(), 0);
+    }
+
+    #[test]
+    fn radec_grid_reaches_both_poles() {
+        let mut grid = radec_grid(Degrees::new(15.0), Degrees::new(20.0));
+        assert_eq!(grid.len(), 24 * 10);
+        let (ra, dec) = grid.next().unwrap();
+        assert_eq!((ra.value(), dec.value()), (0.0, -90.0));
+        let (ra, dec) = grid.last().unwrap();
+        assert_relative_eq!(ra.value(), 23.0);
+        assert_eq!(dec.value(), 90.0);
+        assert_eq!(
+            radec_grid(HourAngles::new(1.0), Degrees::new(0.0)).count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Kepler's third law with typed inputs and outputs.
+//!
+//! These helpers relate the orbital period, mean motion, and semi-major axis of a two-body orbit using the Gaussian
+//! gravitational constant `k = 0.01720209895` (IAU 1976), which fixes `GM☉ = k²` in `au³/day²`.
+//!
+//! The mass argument is the **total** mass of the system expressed in solar masses (central body plus orbiting body).
+//! For small bodies orbiting the Sun, `SolarMasses::new(1.0)` is the usual choice.
+//!
+//! ```rust
+//! use qtty_core::kepler::{mean_motion, period_from_sma};
+//! use qtty_core::length::AstronomicalUnits;
+//! use qtty_core::mass::SolarMasses;
+//! use qtty_core::time::Days;
+//!
+//! // The Gaussian year: a massless body at 1 au completes one orbit in ≈ 365.2569 days.
+//! let p = period_from_sma(AstronomicalUnits::new(1.0), SolarMasses::new(1.0));
+//! assert!((p.value() - 1.0).abs() < 1e-4);
+//!
+//! let n = mean_motion(Days::new(360.0));
+//! assert!((n.value() - 1.0).abs() < 1e-12);
+//! ```
+
+use crate::math;
+use crate::units::frequency::DegreesPerDay;
+use crate::units::length::AstronomicalUnits;
+use crate::units::mass::SolarMasses;
+use crate::units::time::{Day, Days, JulianYear, JulianYears};
+use core::f64::consts::TAU;
+
+/// Gaussian gravitational constant `k`, in radians per day (IAU 1976 definition).
+pub const GAUSSIAN_GRAVITATIONAL_CONSTANT: f64 = 0.017_202_098_95;
+
+/// Mean motion of an orbit with the given period (one revolution per period).
+#[inline]
+pub fn mean_motion(period: Days) -> DegreesPerDay {
+    DegreesPerDay::new(360.0 / period.value())
+}
+
+/// Orbital period corresponding to a mean motion; the inverse of [`mean_motion`].
+#[inline]
+pub fn period_from_mean_motion(n: DegreesPerDay) -> Days {
+    Days::new(360.0 / n.value())
+}
+
+/// Mean motion of an orbit with semi-major axis `a` around a system of total mass `m`.
+///
+/// Computes `n = k·√m / a^{3/2}`.
+#[inline]
+pub fn mean_motion_from_sma(a: AstronomicalUnits, m: SolarMasses) -> DegreesPerDay {
+    let a = a.value();
+    let n_rad = GAUSSIAN_GRAVITATIONAL_CONSTANT * math::sqrt(m.value()) / (a * math::sqrt(a));
+    DegreesPerDay::new(n_rad.to_degrees())
+}
+
+/// Orbital period of an orbit with semi-major axis `a` around a system of total mass `m` (Kepler's third law).
+///
+/// Computes `P = 2π·a^{3/2} / (k·√m)`.
+#[inline]
+pub fn period_from_sma(a: AstronomicalUnits, m: SolarMasses) -> JulianYears {
+    let a = a.value();
+    let days = TAU * a * math::sqrt(a) / (GAUSSIAN_GRAVITATIONAL_CONSTANT * math::sqrt(m.value()));
+    Days::new(days).to::<JulianYear>()
+}
+
+/// Semi-major axis of an orbit with period `period` around a system of total mass `m`; the inverse of
+/// [`period_from_sma`].
+///
+/// Computes `a = (k²·m·(P/2π)²)^{1/3}`.
+#[inline]
+pub fn sma_from_period(period: JulianYears, m: SolarMasses) -> AstronomicalUnits {
+    let p = period.to::<Day>().value() / TAU;
+    let k = GAUSSIAN_GRAVITATIONAL_CONSTANT;
+    AstronomicalUnits::new(math::cbrt(k * k * m.value() * p * p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn gaussian_year() {
+        // 2π/k is the Gaussian year, 365.256 898 3 days.
+        let p = period_from_sma(AstronomicalUnits::new(1.0), SolarMasses::new(1.0));
+        assert_relative_eq!(p.to::<Day>().value(), 365.256_898, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn jupiter_period() {
+        // a = 5.2026 au, m = 1 + 1/1047.35 M☉ → ≈ 11.86 yr
+        let m = SolarMasses::new(1.0 + 1.0 / 1_047.348_644);
+        let p = period_from_sma(AstronomicalUnits::new(5.2026), m);
+        assert_relative_eq!(p.value(), 11.862, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn mean_motion_of_gaussian_year_is_k() {
+        let n = mean_motion_from_sma(AstronomicalUnits::new(1.0), SolarMasses::new(1.0));
+        assert_relative_eq!(
+            n.value().to_radians(),
+            GAUSSIAN_GRAVITATIONAL_CONSTANT,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn mean_motion_of_day_period() {
+        assert_relative_eq!(mean_motion(Days::new(1.0)).value(), 360.0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_period_sma_roundtrip(a in 0.01..1e3f64, m in 1e-3..10.0f64) {
+            let a = AstronomicalUnits::new(a);
+            let m = SolarMasses::new(m);
+            let back = sma_from_period(period_from_sma(a, m), m);
+            prop_assert!((back.value() - a.value()).abs() < 1e-12 * a.value());
+        }
+
+        #[test]
+        fn prop_mean_motion_consistent(a in 0.01..1e3f64, m in 1e-3..10.0f64) {
+            let a = AstronomicalUnits::new(a);
+            let m = SolarMasses::new(m);
+            let from_period = mean_motion(period_from_sma(a, m).to());
+            let direct = mean_motion_from_sma(a, m);
+            prop_assert!((from_period.value() - direct.value()).abs() < 1e-12 * direct.value());
+        }
+
+        #[test]
+        fn prop_mean_motion_period_roundtrip(p in 1e-3..1e6f64) {
+            let back = period_from_mean_motion(mean_motion(Days::new(p)));
+            prop_assert!((back.value() - p).abs() < 1e-12 * p);
+        }
+    }
+}
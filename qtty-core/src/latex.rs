@@ -0,0 +1,83 @@
+//! LaTeX output for quantities using the `siunitx` package.
+
+use crate::{Quantity, Unit};
+use core::fmt::{Display, Formatter, Result};
+
+/// Display adapter that renders a quantity as a `siunitx` `\SI{value}{unit}` command.
+///
+/// Built by [`Quantity::latex`]. Units with a known `siunitx` spelling (see [`Unit::SIUNITX`]) use macros such as
+/// `\kilo\metre`; other units fall back to their literal symbol. Formatter precision is forwarded to the value.
+///
+/// ```rust
+/// use qtty_core::length::Kilometer;
+/// use qtty_core::time::Second;
+/// use qtty_core::velocity::Velocity;
+///
+/// let v: Velocity<Kilometer, Second> = Velocity::new(29.78);
+/// assert_eq!(v.latex().to_string(), r"\SI{29.78}{\kilo\metre\per\second}");
+/// assert_eq!(format!("{:.1}", v.latex()), r"\SI{29.8}{\kilo\metre\per\second}");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Latex<U: Unit>(Quantity<U>);
+
+impl<U: Unit> Quantity<U> {
+    /// Returns a [`Latex`] adapter that formats this quantity as `\SI{value}{unit}`.
+    #[inline]
+    pub const fn latex(self) -> Latex<U> {
+        Latex(self)
+    }
+}
+
+impl<U: Unit> Display for Latex<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("\\SI{")?;
+        Display::fmt(&self.0.value(), f)?;
+        f.write_str("}{")?;
+        U::fmt_siunitx(f, false)?;
+        f.write_str("}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::angular::Degrees;
+    use crate::units::length::{Kilometer, LightYears, Meter};
+    use crate::units::time::Second;
+    use crate::{Per, Quantity, Unitless};
+
+    #[test]
+    fn latex_simple_units() {
+        assert_eq!(Degrees::new(45.0).latex().to_string(), r"\SI{45}{\degree}");
+        assert_eq!(
+            Quantity::<Meter>::new(-1.5).latex().to_string(),
+            r"\SI{-1.5}{\metre}"
+        );
+    }
+
+    #[test]
+    fn latex_falls_back_to_symbol() {
+        assert_eq!(LightYears::new(4.2).latex().to_string(), r"\SI{4.2}{ly}");
+    }
+
+    #[test]
+    fn latex_nested_per() {
+        let a: Quantity<Per<Per<Meter, Second>, Second>> = Quantity::new(9.81);
+        assert_eq!(
+            a.latex().to_string(),
+            r"\SI{9.81}{\metre\per\second\per\second}"
+        );
+        let q: Quantity<Per<Kilometer, Per<Meter, Second>>> = Quantity::new(1.0);
+        assert_eq!(
+            q.latex().to_string(),
+            r"\SI{1}{\kilo\metre\per\metre\second}"
+        );
+    }
+
+    #[test]
+    fn latex_unitless() {
+        assert_eq!(
+            Quantity::<Unitless>::new(0.5).latex().to_string(),
+            r"\SI{0.5}{}"
+        );
+    }
+}
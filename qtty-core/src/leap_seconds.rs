@@ -0,0 +1,378 @@
+//! `TAI − UTC` tables in the IERS `Leap_Second.dat` format.
+//!
+//! The IERS publishes the leap-second history alongside Bulletin C as a short text file: `#` comment lines (one of
+//! which states when the file expires) followed by one row per step giving the MJD, the calendar date and the new
+//! value of `TAI − UTC`:
+//!
+//! ```text
+//! #  File expires on 28 June 2026
+//! #    MJD        Date        TAI-UTC (s)
+//!     41317.0    1  1 1972       10
+//!     41499.0    1  7 1972       11
+//! ```
+//!
+//! [`builtin()`] returns a compiled-in snapshot of that file, usable without `std` or any I/O. With the `std` feature,
+//! [`LeapSecondFile`] parses a newer copy from a string or a path; both hand out the same
+//! [`LeapSecondTable`] consumed by [`Epoch`](crate::timescale::Epoch) conversions.
+//!
+//! ```rust
+//! use qtty_core::leap_seconds;
+//! use qtty_core::time::Days;
+//!
+//! let table = leap_seconds::builtin();
+//! assert_eq!(table.tai_minus_utc(Days::new(60_000.0)).unwrap().value(), 37.0);
+//!
+//! # #[cfg(feature = "std")] {
+//! let file: leap_seconds::LeapSecondFile = "
+//! ##  File expires on 28 June 2026
+//!     41317.0    1  1 1972       10
+//!     41499.0    1  7 1972       11
+//! "
+//! .parse()
+//! .unwrap();
+//! assert_eq!(file.table().entries().len(), 2);
+//! assert_eq!(file.expires(), Some(Days::new(61_219.0)));
+//! # }
+//! ```
+
+use crate::timescale::{LeapSecond, LeapSecondTable};
+use crate::units::time::{Days, Seconds};
+use core::fmt::{self, Display, Formatter};
+
+const fn step(mjd: f64, tai_minus_utc: f64) -> LeapSecond {
+    LeapSecond::new(Days::new(mjd), Seconds::new(tai_minus_utc))
+}
+
+/// Every `TAI − UTC` step from 1972-01-01 (10 s) to 2017-01-01 (37 s), as published through IERS Bulletin C 70.
+pub const BUILTIN: [LeapSecond; 28] = [
+    step(41_317.0, 10.0),
+    step(41_499.0, 11.0),
+    step(41_683.0, 12.0),
+    step(42_048.0, 13.0),
+    step(42_413.0, 14.0),
+    step(42_778.0, 15.0),
+    step(43_144.0, 16.0),
+    step(43_509.0, 17.0),
+    step(43_874.0, 18.0),
+    step(44_239.0, 19.0),
+    step(44_786.0, 20.0),
+    step(45_151.0, 21.0),
+    step(45_516.0, 22.0),
+    step(46_247.0, 23.0),
+    step(47_161.0, 24.0),
+    step(47_892.0, 25.0),
+    step(48_257.0, 26.0),
+    step(48_804.0, 27.0),
+    step(49_169.0, 28.0),
+    step(49_534.0, 29.0),
+    step(50_083.0, 30.0),
+    step(50_630.0, 31.0),
+    step(51_179.0, 32.0),
+    step(53_736.0, 33.0),
+    step(54_832.0, 34.0),
+    step(56_109.0, 35.0),
+    step(57_204.0, 36.0),
+    step(57_754.0, 37.0),
+];
+
+/// MJD after which [`BUILTIN`] is no longer guaranteed complete (2026-06-28).
+pub const BUILTIN_EXPIRES: Days = Days::new(61_219.0);
+
+/// The compiled-in table, [`BUILTIN`].
+///
+/// Dates past [`BUILTIN_EXPIRES`] still resolve to the last offset; load a current file with [`LeapSecondFile`] if
+/// that matters.
+#[inline]
+pub const fn builtin() -> LeapSecondTable<'static> {
+    LeapSecondTable::new(&BUILTIN)
+}
+
+/// Error returned when a leap-second file cannot be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseLeapSecondError {
+    /// A data line is not `MJD day month year TAI-UTC`.
+    Syntax {
+        /// 1-based line number.
+        line: usize,
+    },
+    /// The MJD of a data line disagrees with its calendar date.
+    DateMismatch {
+        /// 1-based line number.
+        line: usize,
+    },
+    /// A data line does not come strictly after the previous one.
+    Unordered {
+        /// 1-based line number.
+        line: usize,
+    },
+    /// The file contains no data lines.
+    Empty,
+}
+
+impl Display for ParseLeapSecondError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { line } => {
+                write!(f, "line {}: expected `MJD day month year TAI-UTC`", line)
+            }
+            Self::DateMismatch { line } => {
+                write!(f, "line {}: MJD does not match the calendar date", line)
+            }
+            Self::Unordered { line } => {
+                write!(f, "line {}: entries must be in increasing date order", line)
+            }
+            Self::Empty => f.write_str("leap-second file has no entries"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLeapSecondError {}
+
+/// Modified Julian Date of a Gregorian calendar date (Fliegel & Van Flandern).
+#[cfg(feature = "std")]
+const fn mjd_from_date(year: i64, month: i64, day: i64) -> i64 {
+    let a = (month - 14) / 12;
+    let jdn = (1461 * (year + 4800 + a)) / 4 + (367 * (month - 2 - 12 * a)) / 12
+        - (3 * ((year + 4900 + a) / 100)) / 4
+        + day
+        - 32075;
+    jdn - 2_400_001
+}
+
+/// Parses a data line; `Ok(None)` for blank and comment lines.
+#[cfg(feature = "std")]
+fn parse_entry(text: &str, line: usize) -> Result<Option<LeapSecond>, ParseLeapSecondError> {
+    let text = text.trim();
+    if text.is_empty() || text.starts_with('#') {
+        return Ok(None);
+    }
+    let mut fields = text.split_whitespace();
+    let mut next = || fields.next().ok_or(ParseLeapSecondError::Syntax { line });
+    let syntax = ParseLeapSecondError::Syntax { line };
+    let mjd: f64 = next()?.parse().map_err(|_| syntax)?;
+    let day: i64 = next()?.parse().map_err(|_| syntax)?;
+    let month: i64 = next()?.parse().map_err(|_| syntax)?;
+    let year: i64 = next()?.parse().map_err(|_| syntax)?;
+    let offset: f64 = next()?.parse().map_err(|_| syntax)?;
+    if next().is_ok() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(syntax);
+    }
+    if mjd != mjd_from_date(year, month, day) as f64 {
+        return Err(ParseLeapSecondError::DateMismatch { line });
+    }
+    Ok(Some(step(mjd, offset)))
+}
+
+/// Reads the `File expires on 28 June 2026` comment, if `text` is one.
+#[cfg(feature = "std")]
+fn parse_expiry(text: &str) -> Option<Days> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let rest = text.trim().strip_prefix('#')?.trim();
+    let date = rest.strip_prefix("File expires on")?;
+    let mut fields = date.split_whitespace();
+    let day: i64 = fields.next()?.parse().ok()?;
+    let month = fields.next()?;
+    let month = MONTHS.iter().position(|m| m.eq_ignore_ascii_case(month))? as i64 + 1;
+    let year: i64 = fields.next()?.parse().ok()?;
+    Some(Days::new(mjd_from_date(year, month, day) as f64))
+}
+
+#[cfg(feature = "std")]
+pub use file::{LeapSecondFile, LoadLeapSecondError};
+
+#[cfg(feature = "std")]
+mod file {
+    use super::*;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    /// A leap-second table parsed at runtime from an IERS `Leap_Second.dat` file.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct LeapSecondFile {
+        entries: Vec<LeapSecond>,
+        expires: Option<Days>,
+    }
+
+    impl LeapSecondFile {
+        /// Parses the contents of a leap-second file.
+        pub fn parse(text: &str) -> Result<Self, ParseLeapSecondError> {
+            let mut entries: Vec<LeapSecond> = Vec::new();
+            let mut expires = None;
+            for (i, raw) in text.lines().enumerate() {
+                let line = i + 1;
+                expires = expires.or_else(|| parse_expiry(raw));
+                if let Some(entry) = parse_entry(raw, line)? {
+                    if entries.last().is_some_and(|last| last.mjd() >= entry.mjd()) {
+                        return Err(ParseLeapSecondError::Unordered { line });
+                    }
+                    entries.push(entry);
+                }
+            }
+            if entries.is_empty() {
+                return Err(ParseLeapSecondError::Empty);
+            }
+            Ok(Self { entries, expires })
+        }
+
+        /// Reads and parses the leap-second file at `path`.
+        pub fn load(path: impl AsRef<Path>) -> Result<Self, LoadLeapSecondError> {
+            let text = std::fs::read_to_string(path)?;
+            Ok(Self::parse(&text)?)
+        }
+
+        /// The parsed entries as a table for [`Epoch`](crate::timescale::Epoch) conversions.
+        #[inline]
+        pub fn table(&self) -> LeapSecondTable<'_> {
+            LeapSecondTable::new(&self.entries)
+        }
+
+        /// The expiry date (MJD) announced in the file's header, if present.
+        #[inline]
+        pub fn expires(&self) -> Option<Days> {
+            self.expires
+        }
+    }
+
+    impl FromStr for LeapSecondFile {
+        type Err = ParseLeapSecondError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::parse(s)
+        }
+    }
+
+    /// Error returned by [`LeapSecondFile::load`].
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum LoadLeapSecondError {
+        /// The file could not be read.
+        Io(std::io::Error),
+        /// The file was read but is not a valid leap-second file.
+        Parse(ParseLeapSecondError),
+    }
+
+    impl Display for LoadLeapSecondError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Io(err) => write!(f, "cannot read leap-second file: {}", err),
+                Self::Parse(err) => Display::fmt(err, f),
+            }
+        }
+    }
+
+    impl std::error::Error for LoadLeapSecondError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                Self::Io(err) => Some(err),
+                Self::Parse(err) => Some(err),
+            }
+        }
+    }
+
+    impl From<std::io::Error> for LoadLeapSecondError {
+        fn from(err: std::io::Error) -> Self {
+            Self::Io(err)
+        }
+    }
+
+    impl From<ParseLeapSecondError> for LoadLeapSecondError {
+        fn from(err: ParseLeapSecondError) -> Self {
+            Self::Parse(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timescale::{Epoch, Tt};
+
+    #[cfg(feature = "std")]
+    const SNAPSHOT: &str = include_str!("../data/Leap_Second.dat");
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn calendar_to_mjd() {
+        assert_eq!(mjd_from_date(1858, 11, 17), 0);
+        assert_eq!(mjd_from_date(1972, 1, 1), 41_317);
+        assert_eq!(mjd_from_date(2000, 1, 1), 51_544);
+        assert_eq!(mjd_from_date(2026, 6, 28), 61_219);
+    }
+
+    #[test]
+    fn builtin_is_sorted_and_current() {
+        assert!(BUILTIN.windows(2).all(|w| w[0].mjd() < w[1].mjd()));
+        let table = builtin();
+        assert_eq!(table.tai_minus_utc(Days::new(41_316.9)), None);
+        assert_eq!(
+            table.tai_minus_utc(Days::new(57_754.0)).unwrap().value(),
+            37.0
+        );
+        // J2000.0 TT → 11:58:55.816 UTC, 32 s after the 1999 step.
+        let utc = Epoch::<Tt>::J2000.to_tai().to_utc(&table).unwrap();
+        assert_eq!(table.tai_minus_utc(utc.mjd()).unwrap().value(), 32.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parses_the_snapshot() {
+        let file = LeapSecondFile::parse(SNAPSHOT).unwrap();
+        assert_eq!(file.table().entries(), &BUILTIN[..]);
+        assert_eq!(file.expires(), Some(BUILTIN_EXPIRES));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rejects_malformed_files() {
+        assert_eq!(
+            LeapSecondFile::parse("# only comments\n\n"),
+            Err(ParseLeapSecondError::Empty)
+        );
+        assert_eq!(
+            LeapSecondFile::parse("41317.0 1 1 1972\n"),
+            Err(ParseLeapSecondError::Syntax { line: 1 })
+        );
+        assert_eq!(
+            LeapSecondFile::parse("# header\n41317.0 1 1 1972 ten\n"),
+            Err(ParseLeapSecondError::Syntax { line: 2 })
+        );
+        assert_eq!(
+            LeapSecondFile::parse("41318.0 1 1 1972 10\n"),
+            Err(ParseLeapSecondError::DateMismatch { line: 1 })
+        );
+        assert_eq!(
+            LeapSecondFile::parse("41499.0 1 7 1972 11\n41317.0 1 1 1972 10\n"),
+            Err(ParseLeapSecondError::Unordered { line: 2 })
+        );
+        assert_eq!(
+            ParseLeapSecondError::Unordered { line: 2 }.to_string(),
+            "line 2: entries must be in increasing date order"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn loads_from_a_path() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/Leap_Second.dat");
+        let file = LeapSecondFile::load(path).unwrap();
+        assert_eq!(file.table().entries().len(), BUILTIN.len());
+
+        let err = LeapSecondFile::load("/nonexistent/Leap_Second.dat").unwrap_err();
+        assert!(matches!(err, LoadLeapSecondError::Io(_)));
+    }
+}
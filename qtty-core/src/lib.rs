@@ -4,7 +4,8 @@
 //!
 //! - A *unit* is a zero-sized marker type implementing [`Unit`].
 //! - A value tagged with a unit is a [`Quantity<U>`], backed by an `f64`.
-//! - Conversion is an explicit, type-checked scaling via [`Quantity::to`].
+//! - Conversion is an explicit, type-checked scaling via [`Quantity::to`], or [`ConvertInto::convert`] where the
+//!   target is inferred.
 //! - Derived units like velocity are expressed as [`Per<N, D>`] (e.g. `Meter/Second`).
 //!
 //! Most users should depend on `qtty` (the facade crate) unless they need direct access to these primitives.
@@ -13,7 +14,7 @@
 //!
 //! - Compile-time separation of dimensions (length vs time vs angle, …).
 //! - Zero runtime overhead for unit tags (phantom types only).
-//! - A small vocabulary to express derived units via type aliases (`Per`, `DivDim`).
+//! - A small vocabulary to express derived units via type aliases (`Per`, `DivDim`, `Prod`, `MulDim`).
 //!
 //! # What this crate does not try to solve
 //!
@@ -62,20 +63,47 @@
 //!
 //! - `std` (default): enables `std` support.
 //! - `serde`: enables `serde` support for `Quantity<U>`; serialization is the raw `f64` value only.
+//! - `table`: adds the `table` module, which reads unit annotations from table headers (`"rv [km/s]"`) and converts
+//!   raw column values into typed quantities (implies `std` and `serde`).
+//! - `twofloat`: adds the `twofloat` module and the `Quantity<U, TwoFloat>` double-double backend (~106 bits) for
+//!   long baselines such as Julian Dates at microsecond resolution.
+//! - `strict`: removes `From<f64>` and `PartialEq<f64>` for `Quantity<U>`, so a bare float never becomes a quantity
+//!   implicitly; construct with [`Quantity::new`] / [`Quantity::of`] and read back with [`Quantity::value`] /
+//!   [`Quantity::raw`].
+//! - `defmt`: implements `defmt::Format` for `Quantity<U>` (`<value> <symbol>`, no allocation) for RTT logging on
+//!   embedded targets.
+//! - `schemars`: implements `schemars::JsonSchema` for `Quantity<U>` (a number whose description names the unit)
+//!   and, with `serde`, adds `serde_with_unit::TaggedSchema<U>` for the tagged form (implies `std`).
+//! - `sqlx` / `diesel`: map `Quantity<U>` to a `DOUBLE PRECISION` column (Postgres `FLOAT8`); the stored number is the
+//!   value in `U`, so keep one unit per column (implies `std`).
+//! - `bytemuck`: implements `Pod` and `Zeroable` for `Quantity<U, S>`, so buffers of raw `f64` (for example a binary
+//!   ephemeris file) can be viewed as `&[Quantity<U>]` without copying.
+//! - `num-traits`: implements `num_traits::Zero`, `FromPrimitive` and `ToPrimitive` for `Quantity<U>` (values in `U`),
+//!   so generic numeric code such as interpolation crates accepts typed quantities. `One` is deliberately absent: the
+//!   product of two quantities is not a quantity of the same unit.
+//! - `rayon`: adds the `parallel` module, parallel bulk unit conversion of quantity slices (implies `std`).
+//! - `clap`: adds the `cli` module. Every `Quantity<U>` becomes a `clap` value parser (`ValueParserFactory`), and
+//!   `cli::QuantityParser` adds bounds (implies `std`).
+//! - `test-util`: exposes the `testutil` module (proptest strategies for typed quantities, wrapping invariant
+//!   checks) and the unit-aware `assert_quantity_eq!` / `assert_quantity_relative_eq!` macros.
+//! - `arbitrary` / `quickcheck`: implement the respective `Arbitrary` traits for `Quantity<U>` (finite values only)
+//!   and add range-limited generators in the `fuzz` module.
 //!
 //! # Panics and errors
 //!
-//! This crate does not define an error type and does not return `Result` from its core operations. Conversions and
-//! arithmetic are pure `f64` computations; they do not panic on their own, but they follow IEEE-754 behavior (NaN and
-//! infinities propagate according to the underlying operation).
+//! The core operations do not return `Result`. Conversions and arithmetic are pure `f64` computations; they do not
+//! panic on their own, but they follow IEEE-754 behavior (NaN and infinities propagate according to the underlying
+//! operation). Range-checked wrappers such as [`coordinates::RightAscension`] validate on construction and report a
+//! small error type of their own.
 //!
 //! # SemVer and stability
 //!
 //! This crate is currently `0.x`. Expect breaking changes between minor versions until `1.0`.
 
 #![deny(missing_docs)]
-#![cfg_attr(not(feature = "std"), no_std)]
-#![forbid(unsafe_code)]
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+// `unsafe` is confined to the layout-based impls in `quantity.rs`, each allowed explicitly.
+#![deny(unsafe_code)]
 
 #[cfg(not(feature = "std"))]
 extern crate libm;
@@ -84,22 +112,42 @@ extern crate libm;
 // Core modules
 // ─────────────────────────────────────────────────────────────────────────────
 
+mod autoscale;
+mod canonical;
+mod convert;
 mod dimension;
+mod latex;
 mod macros;
+mod math;
+mod parse;
 mod quantity;
+mod reduce;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+mod sql;
 mod unit;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Public re-exports of core types
 // ─────────────────────────────────────────────────────────────────────────────
 
-pub use dimension::{Dimension, Dimensionless, DivDim};
+pub use autoscale::{AutoDisplay, AutoScale};
+pub use canonical::{Canonical, CanonicalPolicy, NativePolicy, SiPolicy};
+pub use convert::{ConvertFrom, ConvertInto};
+pub use dimension::{Dimension, DimensionId, Dimensionless, DivDim, MulDim, SameDimension};
+pub use latex::Latex;
+pub use parse::{ParseQuantityError, UnitSymbol};
 pub use quantity::Quantity;
-pub use unit::{Per, Simplify, Unit, Unitless};
+pub use unit::{
+    is_dimension, same_dimension, Per, Prod, SameDimensionAs, Simplify, Unit, UnitInfo, Unitless,
+};
 
 #[cfg(feature = "serde")]
 pub use quantity::serde_with_unit;
 
+/// Serde `with`-modules that store a field in a fixed unit.
+#[cfg(feature = "serde")]
+pub mod serde_as;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Predefined unit modules (grouped by dimension)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -111,17 +159,74 @@ pub use quantity::serde_with_unit;
 pub mod units;
 
 pub use units::angular;
+pub use units::angular_acceleration;
+pub use units::area;
+pub use units::density;
+pub use units::flux;
 pub use units::frequency;
+pub use units::grav_param;
 pub use units::length;
 pub use units::mass;
 pub use units::power;
+pub use units::pressure;
+pub use units::temperature;
 pub use units::time;
 pub use units::unitless;
 pub use units::velocity;
+pub use units::volume;
+
+/// Runtime lookup of the predefined units by symbol or type.
+pub mod registry;
+
+/// Flat `f64`/`f32` conversion-ratio tables for GPU buffers.
+pub mod ratio_table;
+
+#[cfg(feature = "table")]
+pub mod table;
+
+#[cfg(feature = "twofloat")]
+pub mod twofloat;
+
+#[cfg(feature = "rayon")]
+pub mod parallel;
+
+#[cfg(feature = "clap")]
+pub mod cli;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Astronomy helpers built on the predefined units
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub mod coordinates;
+pub mod grid;
+pub mod kepler;
+pub mod leap_seconds;
+pub mod nutation;
+pub mod phase;
+pub mod precession;
+pub mod refraction;
+pub mod series;
+pub mod sidereal;
+pub mod solve;
+#[cfg(feature = "std")]
+pub mod stats;
+pub mod timescale;
+pub mod transform;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Testing support
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(any(test, feature = "test-util"))]
+pub mod testutil;
+
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+pub mod fuzz;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::macros::define_unit;
 
     // ─────────────────────────────────────────────────────────────────────────────
     // Test dimension and unit for lib.rs tests
@@ -130,44 +235,9 @@ mod tests {
     pub enum TestDim {}
     impl Dimension for TestDim {}
 
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-    pub enum TestUnit {}
-    impl Unit for TestUnit {
-        const RATIO: f64 = 1.0;
-        type Dim = TestDim;
-        const SYMBOL: &'static str = "tu";
-    }
-    impl core::fmt::Display for Quantity<TestUnit> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{} tu", self.value())
-        }
-    }
-
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-    pub enum DoubleTestUnit {}
-    impl Unit for DoubleTestUnit {
-        const RATIO: f64 = 2.0;
-        type Dim = TestDim;
-        const SYMBOL: &'static str = "dtu";
-    }
-    impl core::fmt::Display for Quantity<DoubleTestUnit> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{} dtu", self.value())
-        }
-    }
-
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-    pub enum HalfTestUnit {}
-    impl Unit for HalfTestUnit {
-        const RATIO: f64 = 0.5;
-        type Dim = TestDim;
-        const SYMBOL: &'static str = "htu";
-    }
-    impl core::fmt::Display for Quantity<HalfTestUnit> {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            write!(f, "{} htu", self.value())
-        }
-    }
+    define_unit!(pub TestUnit, TestDim, "tu", 1.0, "test unit");
+    define_unit!(pub DoubleTestUnit, TestDim, "dtu", 2.0);
+    define_unit!(pub HalfTestUnit, TestDim, "htu", 0.5);
 
     type TU = Quantity<TestUnit>;
     type Dtu = Quantity<DoubleTestUnit>;
@@ -182,6 +252,13 @@ mod tests {
         assert_eq!(q.value(), 42.0);
     }
 
+    #[test]
+    fn quantity_of_and_raw() {
+        let q = TU::of(42.0);
+        assert_eq!(q, TU::new(42.0));
+        assert_eq!(q.raw(), 42.0);
+    }
+
     #[test]
     fn quantity_nan_constant() {
         assert!(TU::NAN.value().is_nan());
@@ -195,6 +272,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "strict"))]
     fn quantity_from_f64() {
         let q: TU = 123.456.into();
         assert_eq!(q.value(), 123.456);
@@ -228,6 +306,56 @@ mod tests {
         assert!((back.value() - original.value()).abs() < 1e-12);
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Exact rational ratios
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn exact_ratios_divide_once() {
+        use crate::angular::{Arcsecond, Degree};
+        use crate::time::{Hour, Second};
+        // 1/3600 is not representable, so `x * (1/3600)` can differ from the correctly rounded `x / 3600`.
+        for x in [1.0, 7.0, 10.0, 1_296_000.0 - 1.0, 0.1] {
+            assert_eq!(
+                Quantity::<Arcsecond>::new(x).to::<Degree>().value(),
+                x / 3_600.0
+            );
+            assert_eq!(Quantity::<Second>::new(x).to::<Hour>().value(), x / 3_600.0);
+            assert_eq!(Quantity::<Hour>::new(x).to::<Second>().value(), x * 3_600.0);
+        }
+    }
+
+    #[test]
+    fn exact_ratios_compose() {
+        use crate::length::{Kilometer, Meter};
+        use crate::time::{Attosecond, Hour, Second};
+        type Kmh = Per<Kilometer, Hour>;
+        assert_eq!((Kmh::RATIO_NUM, Kmh::RATIO_DEN), (5, 18));
+        type SquareKm = Prod<Kilometer, Kilometer>;
+        assert_eq!((SquareKm::RATIO_NUM, SquareKm::RATIO_DEN), (1_000_000, 1));
+        let v = Quantity::<Kmh>::new(36.0).to::<Per<Meter, Second>>();
+        assert_eq!(v.value(), 10.0);
+        // Products that overflow u64 fall back to the f64 ratio.
+        type As2 = Prod<Attosecond, Attosecond>;
+        assert_eq!(As2::RATIO_NUM, 0);
+        assert!(
+            (Quantity::<As2>::new(1e36)
+                .to::<Prod<Second, Second>>()
+                .value()
+                - 1.0)
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn units_without_exact_ratio_use_f64() {
+        // TestUnit defines no RATIO_NUM, so the plain ratio path is taken.
+        assert_eq!(TestUnit::RATIO_NUM, 0);
+        assert_eq!(Dtu::new(3.0).to::<HalfTestUnit>().value(), 12.0);
+        assert!(crate::unit::ExactFactor::<TestUnit, DoubleTestUnit>::VALUE.is_none());
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Const helper methods: add/sub/mul/div/min
     // ─────────────────────────────────────────────────────────────────────────────
@@ -342,6 +470,7 @@ mod tests {
     // ─────────────────────────────────────────────────────────────────────────────
 
     #[test]
+    #[cfg(not(feature = "strict"))]
     fn partial_eq_f64() {
         let q = TU::new(5.0);
         assert!(q == 5.0);
@@ -402,6 +531,74 @@ mod tests {
         assert!((simplified.value() - 7.5).abs() < 1e-12);
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Grid snapping: round_to / floor_to / ceil_to
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn round_to_step_in_other_unit() {
+        // Step of 1 dtu == 2 tu.
+        assert_eq!(TU::new(4.9).round_to(Dtu::new(1.0)).value(), 4.0);
+        assert_eq!(TU::new(5.0).round_to(Dtu::new(1.0)).value(), 6.0);
+        assert_eq!(TU::new(-5.0).round_to(Dtu::new(1.0)).value(), -6.0);
+        assert_eq!(Dtu::new(1.3).round_to(TU::new(1.0)).value(), 1.5);
+    }
+
+    #[test]
+    fn floor_and_ceil_to() {
+        assert_eq!(TU::new(5.9).floor_to(TU::new(2.0)).value(), 4.0);
+        assert_eq!(TU::new(-0.1).floor_to(TU::new(2.0)).value(), -2.0);
+        assert_eq!(TU::new(4.1).ceil_to(TU::new(2.0)).value(), 6.0);
+        assert_eq!(TU::new(-3.9).ceil_to(TU::new(2.0)).value(), -2.0);
+        // Values already on the grid are unchanged, and the sign of the step is ignored.
+        assert_eq!(TU::new(6.0).floor_to(TU::new(-2.0)).value(), 6.0);
+        assert_eq!(TU::new(6.0).ceil_to(TU::new(2.0)).value(), 6.0);
+    }
+
+    #[test]
+    fn snapping_to_degenerate_step_is_nan() {
+        assert!(TU::new(1.0).round_to(TU::new(0.0)).value().is_nan());
+        assert!(TU::new(1.0)
+            .floor_to(TU::new(f64::INFINITY))
+            .value()
+            .is_nan());
+        assert!(TU::new(1.0).ceil_to(TU::NAN).value().is_nan());
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Cross-unit comparison
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn eq_and_cmp_across_units() {
+        assert!(TU::new(4.0).eq_across(Dtu::new(2.0)));
+        assert!(Dtu::new(2.0).eq_across(TU::new(4.0)));
+        assert!(!TU::new(4.0).eq_across(Dtu::new(2.5)));
+        assert!(!TU::NAN.eq_across(Dtu::NAN));
+        let mm = crate::length::Millimeters::new(4.07);
+        assert!(mm.eq_across(mm.to::<crate::length::Kilometer>()));
+
+        use core::cmp::Ordering;
+        assert_eq!(
+            TU::new(3.0).partial_cmp_across(Dtu::new(2.0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Dtu::new(2.0).partial_cmp_across(TU::new(4.0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(TU::NAN.partial_cmp_across(Dtu::new(1.0)), None);
+    }
+
+    #[test]
+    fn eq_within_tolerance_in_any_unit() {
+        assert!(TU::new(4.1).eq_within(Dtu::new(2.0), TU::new(0.1 + 1e-12)));
+        assert!(!TU::new(4.1).eq_within(Dtu::new(2.0), TU::new(0.05)));
+        // The tolerance is read in its own unit: 0.05 dtu == 0.1 tu, and its sign is ignored.
+        assert!(TU::new(4.1).eq_within(Dtu::new(2.0), Dtu::new(-0.051)));
+        assert!(!TU::NAN.eq_within(Dtu::new(2.0), TU::new(f64::INFINITY)));
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Quantity<Per<U,U>>::asin()
     // ─────────────────────────────────────────────────────────────────────────────
@@ -443,6 +640,99 @@ mod tests {
         assert_eq!(s, "2.5 tu/dtu");
     }
 
+    #[test]
+    fn display_per_quantity_forwards_flags() {
+        let q: Quantity<Per<TestUnit, DoubleTestUnit>> = Quantity::new(2.5);
+        assert_eq!(format!("{:.2}", q), "2.50 tu/dtu");
+        assert_eq!(format!("{:#}", q), "2.5 test unit per dtu");
+    }
+
+    #[test]
+    fn display_nested_per_merges_exponents() {
+        let q: Quantity<Per<Per<TestUnit, DoubleTestUnit>, DoubleTestUnit>> = Quantity::new(9.8);
+        assert_eq!(format!("{}", q), "9.8 tu/dtu^2");
+        assert_eq!(format!("{:#}", q), "9.8 test unit per dtu per dtu");
+    }
+
+    #[test]
+    fn display_nested_per_in_denominator() {
+        let q: Quantity<Per<TestUnit, Per<HalfTestUnit, DoubleTestUnit>>> = Quantity::new(1.0);
+        assert_eq!(format!("{}", q), "1 tu·dtu/htu");
+        let q: Quantity<Per<Per<TestUnit, HalfTestUnit>, DoubleTestUnit>> = Quantity::new(1.0);
+        assert_eq!(format!("{}", q), "1 tu/(htu·dtu)");
+    }
+
+    #[test]
+    fn display_per_does_not_cancel_across_sides() {
+        let q: Quantity<Per<TestUnit, TestUnit>> = Quantity::new(0.5);
+        assert_eq!(format!("{}", q), "0.5 tu/tu");
+        let q: Quantity<Per<Unitless, TestUnit>> = Quantity::new(2.0);
+        assert_eq!(format!("{}", q), "2 1/tu");
+    }
+
+    #[test]
+    fn display_too_many_factors_falls_back_to_operands() {
+        use crate::units::length::*;
+        use crate::units::time::*;
+        type Lengths = Prod<
+            Prod<
+                Prod<
+                    Prod<
+                        Prod<
+                            Prod<Prod<Prod<Meter, Kilometer>, Centimeter>, Millimeter>,
+                            Micrometer,
+                        >,
+                        Nanometer,
+                    >,
+                    Decimeter,
+                >,
+                Hectometer,
+            >,
+            Decameter,
+        >;
+        type Times = Prod<
+            Prod<
+                Prod<
+                    Prod<
+                        Prod<Prod<Prod<Prod<Second, Minute>, Hour>, Day>, Millisecond>,
+                        Microsecond,
+                    >,
+                    Nanosecond,
+                >,
+                Week,
+            >,
+            Fortnight,
+        >;
+        // 18 distinct factors: more than are tracked, so each side is printed on its own.
+        let lengths = format!("{}", Quantity::<Lengths>::new(1.0));
+        let times = format!("{}", Quantity::<Times>::new(1.0));
+        assert_eq!(
+            format!("{}", Quantity::<Per<Lengths, Times>>::new(1.0)),
+            format!("1 ({})/({})", &lengths[2..], &times[2..])
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Quantity::<Prod<Lengths, Per<Unitless, Times>>>::new(1.0)
+            ),
+            format!("1 ({})·(1/({}))", &lengths[2..], &times[2..])
+        );
+    }
+
+    #[test]
+    fn define_unit_symbol_is_not_quoted() {
+        assert_eq!(TestUnit::SYMBOL, "tu");
+        assert_eq!(format!("{}", Dtu::new(1.5)), "1.5 dtu");
+        assert!(!format!("{}", TU::new(1.0)).contains('"'));
+    }
+
+    #[test]
+    fn define_unit_long_name() {
+        assert_eq!(TestUnit::LONG_NAME, "test unit");
+        assert_eq!(HalfTestUnit::LONG_NAME, "htu");
+        assert_eq!(format!("{:#}", TU::new(3.0)), "3 test unit");
+    }
+
     #[test]
     fn display_negative_value() {
         let q = TU::new(-99.9);
@@ -492,6 +782,16 @@ mod tests {
         assert_eq!(neg_inf.value().signum(), -1.0);
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Zero-cost layout (runtime cost is measured in benches/zero_cost.rs)
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn units_are_zero_sized() {
+        assert_eq!(core::mem::size_of::<TestUnit>(), 0);
+        assert_eq!(core::mem::size_of::<Per<TestUnit, HalfTestUnit>>(), 0);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Serde tests
     // ─────────────────────────────────────────────────────────────────────────────
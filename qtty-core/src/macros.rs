@@ -1,5 +1,57 @@
 //! Macros for defining units and conversions.
 
+/// Defines a unit marker type together with its [`Unit`](crate::Unit) and `Display` impls.
+///
+/// Declarative counterpart of `#[derive(Unit)]`, kept for crate-internal units (the `Display` impl on
+/// `Quantity<_>` is only allowed inside this crate). The symbol literal is used verbatim, and an optional trailing
+/// literal sets [`Unit::LONG_NAME`](crate::Unit::LONG_NAME), which otherwise defaults to the symbol:
+///
+/// ```text
+/// define_unit!(pub Furlong, Length, "fur", 201.168, "furlong");
+/// ```
+#[allow(unused_macros)]
+macro_rules! define_unit {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $dim:ty, $symbol:literal, $ratio:expr $(,)?) => {
+        $crate::macros::define_unit!($(#[$meta])* $vis $name, $dim, $symbol, $ratio, $symbol);
+    };
+
+    ($(#[$meta:meta])* $vis:vis $name:ident, $dim:ty, $symbol:literal, $ratio:expr, $long_name:literal $(,)?) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        $vis struct $name;
+
+        impl $crate::Unit for $name {
+            const RATIO: f64 = $ratio;
+            type Dim = $dim;
+            const SYMBOL: &'static str = $symbol;
+            const LONG_NAME: &'static str = $long_name;
+        }
+
+        impl ::core::fmt::Display for $crate::Quantity<$name> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.value(), f)?;
+                if f.alternate() {
+                    write!(f, " {}", <$name as $crate::Unit>::LONG_NAME)
+                } else {
+                    write!(f, " {}", <$name as $crate::Unit>::SYMBOL)
+                }
+            }
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use define_unit;
+
+/// Builds a `&'static [RegisteredUnit]` table for units of one dimension (see [`crate::registry`]).
+///
+/// Composite aliases, whose `Unit::SYMBOL` is empty, give the symbol they print with: `SquareMeter = "m^2"`.
+macro_rules! registry_table {
+    ($dim:literal; $($unit:ty $(= $symbol:literal)?),+ $(,)?) => {
+        &[$($crate::registry::RegisteredUnit::of::<$unit>($dim)$(.with_symbol($symbol))?),+]
+    };
+}
+pub(crate) use registry_table;
+
 /// Generates `From` trait implementations for all pairs of units within a dimension.
 #[macro_export]
 macro_rules! impl_unit_conversions {
@@ -10,12 +62,14 @@ macro_rules! impl_unit_conversions {
     ($first:ty, $($rest:ty),+ $(,)?) => {
         $(
             impl From<$crate::Quantity<$first>> for $crate::Quantity<$rest> {
+                #[inline]
                 fn from(value: $crate::Quantity<$first>) -> Self {
                     value.to::<$rest>()
                 }
             }
 
             impl From<$crate::Quantity<$rest>> for $crate::Quantity<$first> {
+                #[inline]
                 fn from(value: $crate::Quantity<$rest>) -> Self {
                     value.to::<$first>()
                 }
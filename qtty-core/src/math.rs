@@ -0,0 +1,32 @@
+//! `f64` functions that are inherent methods with `std` and come from `libm` without it.
+//!
+//! Modules call these instead of repeating the `#[cfg(feature = "std")]` split at every use.
+
+#[cfg(not(feature = "std"))]
+pub(crate) use libm::{asin, atan2, cbrt, ceil, cos, floor, round, sin, sqrt, tan};
+
+#[cfg(feature = "std")]
+macro_rules! std_methods {
+    ($($name:ident($x:ident $(, $arg:ident)*);)+) => {
+        $(
+            #[inline]
+            pub(crate) fn $name($x: f64 $(, $arg: f64)*) -> f64 {
+                $x.$name($($arg),*)
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+std_methods! {
+    asin(x);
+    atan2(y, x);
+    cbrt(x);
+    ceil(x);
+    cos(x);
+    floor(x);
+    round(x);
+    sin(x);
+    sqrt(x);
+    tan(x);
+}
@@ -0,0 +1,100 @@
+//! Parallel bulk conversion of quantity slices with [`rayon`].
+//!
+//! Every predefined unit is `Send + Sync`, so slices of quantities already implement rayon's parallel iterators and
+//! `angles.par_iter().map(|a| a.sin())` works as is. [`ParConvert`] adds the bulk operation that comes up most with
+//! large catalogs: converting every element to another unit of the same dimension, either into a new `Vec` or into a
+//! caller-provided buffer. Raw `f64` columns can be typed first without copying through
+//! [`Quantity::slice_from_raw`].
+//!
+//! ```rust
+//! use qtty_core::angular::{Degree, Degrees, Radians};
+//! use qtty_core::parallel::ParConvert;
+//!
+//! let raw: Vec<f64> = (0..10_000).map(|i| i as f64 * 1e-3).collect();
+//! let degrees: Vec<Degrees> = Radians::slice_from_raw(&raw).par_to::<Degree>();
+//! assert_eq!(degrees.len(), raw.len());
+//! assert!((degrees[1_000].value() - 57.295_779_513_082_32).abs() < 1e-12);
+//! ```
+
+use crate::dimension::SameDimension;
+use crate::quantity::Quantity;
+use crate::unit::Unit;
+use rayon::prelude::*;
+
+/// Parallel unit conversion for slices of quantities.
+pub trait ParConvert<U: Unit> {
+    /// Converts every element to `T` on the rayon thread pool, preserving order.
+    fn par_to<T: Unit + Send>(&self) -> Vec<Quantity<T>>
+    where
+        U::Dim: SameDimension<T::Dim>;
+
+    /// Converts every element to `T` into `out`, on the rayon thread pool.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is not the same length as `self`.
+    fn par_to_into<T: Unit + Send>(&self, out: &mut [Quantity<T>])
+    where
+        U::Dim: SameDimension<T::Dim>;
+}
+
+impl<U: Unit + Sync> ParConvert<U> for [Quantity<U>] {
+    fn par_to<T: Unit + Send>(&self) -> Vec<Quantity<T>>
+    where
+        U::Dim: SameDimension<T::Dim>,
+    {
+        self.par_iter().map(|q| q.to::<T>()).collect()
+    }
+
+    fn par_to_into<T: Unit + Send>(&self, out: &mut [Quantity<T>])
+    where
+        U::Dim: SameDimension<T::Dim>,
+    {
+        assert_eq!(
+            self.len(),
+            out.len(),
+            "par_to_into: output length does not match input length"
+        );
+        out.par_iter_mut()
+            .zip(self.par_iter())
+            .for_each(|(dst, src)| *dst = src.to::<T>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Arcsecond, Degrees};
+    use crate::units::length::{Kilometer, Kilometers, Meters};
+
+    #[test]
+    fn par_to_matches_sequential() {
+        let meters: Vec<Meters> = (0..5_000).map(|i| Meters::new(i as f64 * 3.7)).collect();
+        let par = meters.par_to::<Kilometer>();
+        let seq: Vec<Kilometers> = meters.iter().map(|m| m.to::<Kilometer>()).collect();
+        assert_eq!(par, seq);
+    }
+
+    #[test]
+    fn par_to_into_fills_buffer() {
+        let degrees = [Degrees::new(1.0), Degrees::new(-0.5)];
+        let mut out = [Quantity::<Arcsecond>::new(0.0); 2];
+        degrees.par_to_into(&mut out);
+        assert_eq!(out[0].value(), 3_600.0);
+        assert_eq!(out[1].value(), -1_800.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "output length")]
+    fn par_to_into_checks_length() {
+        let mut out = [Kilometers::new(0.0); 1];
+        [Meters::new(1.0), Meters::new(2.0)].par_to_into(&mut out);
+    }
+
+    #[test]
+    fn slices_are_parallel_iterable() {
+        let degrees: Vec<Degrees> = (0..360).map(|d| Degrees::new(d as f64)).collect();
+        let total: f64 = degrees.par_iter().map(|d| d.value()).sum();
+        assert_eq!(total, 64_620.0);
+    }
+}
@@ -0,0 +1,422 @@
+//! Parsing quantities from `"<value> <symbol>"` text.
+
+use crate::registry;
+use crate::unit::{apply_exact, exact_factor};
+use crate::{Quantity, Unit};
+use core::any::TypeId;
+use core::fmt::{self, Display, Formatter, Write};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// Error returned by [`Quantity::try_from_str`] and by `str::parse` into a quantity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseQuantityError {
+    /// The text is not a number followed by a unit symbol.
+    Syntax,
+    /// The symbol is neither the target unit's nor a registered unit's.
+    UnknownUnit,
+    /// The symbol names a registered unit of another dimension.
+    DimensionMismatch {
+        /// Symbol of the target unit, as it displays.
+        expected: UnitSymbol,
+        /// Symbol found in the text.
+        found: &'static str,
+    },
+}
+
+/// The symbol a unit displays with, written by its [`Unit::fmt_symbol`].
+///
+/// Composite units have an empty [`Unit::SYMBOL`] and print their whole expression (`m/s`), so errors carry this
+/// instead of a `&'static str`. Two values are equal when they belong to the same unit type; a value also compares
+/// equal to the text it prints.
+#[derive(Clone, Copy)]
+pub struct UnitSymbol {
+    unit: TypeId,
+    fmt: fn(&mut Formatter<'_>) -> fmt::Result,
+}
+
+impl UnitSymbol {
+    /// The symbol of unit `U`.
+    pub fn of<U: Unit>() -> Self {
+        Self {
+            unit: TypeId::of::<U>(),
+            fmt: U::fmt_symbol,
+        }
+    }
+}
+
+impl Display for UnitSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.fmt)(f)
+    }
+}
+
+impl fmt::Debug for UnitSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
+}
+
+impl PartialEq for UnitSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.unit == other.unit
+    }
+}
+
+impl PartialEq<str> for UnitSymbol {
+    fn eq(&self, other: &str) -> bool {
+        writes_exactly(other, |w| write!(w, "{}", self))
+    }
+}
+
+impl PartialEq<&str> for UnitSymbol {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax => f.write_str("invalid quantity: expected e.g. `30 s` or `1.5e3 km`"),
+            Self::UnknownUnit => f.write_str("unknown unit symbol"),
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "dimension mismatch: `{}` cannot be converted to `{}`",
+                found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseQuantityError {}
+
+impl<U: Unit> Quantity<U>
+where
+    U::Dim: 'static,
+{
+    /// Parses `"<value> <symbol>"`, e.g. a config field like `exposure = "30 s"`.
+    ///
+    /// The symbol may be the one `U` displays with (its [`SYMBOL`](Unit::SYMBOL), or the composed symbol of a
+    /// composite such as `m/s`), `U`'s [`LONG_NAME`](Unit::LONG_NAME), or any symbol [`registry::lookup`] resolves
+    /// (or long name a registered unit has) to a unit of the same dimension, in which case the value is converted
+    /// into `U` with the same exact factor [`Quantity::to`] uses. Whitespace between the number and the symbol is
+    /// optional. Unlike [`FromStr`], a bare number is rejected.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Kilometers;
+    /// use qtty_core::time::Seconds;
+    ///
+    /// let exposure = Seconds::try_from_str("30 s").unwrap();
+    /// assert_eq!(exposure.value(), 30.0);
+    ///
+    /// let distance = Kilometers::try_from_str("1500 m").unwrap();
+    /// assert_eq!(distance.value(), 1.5);
+    ///
+    /// assert!(Kilometers::try_from_str("30 s").is_err());
+    /// ```
+    pub fn try_from_str(text: &str) -> Result<Self, ParseQuantityError> {
+        parse::<U>(text, false)
+    }
+}
+
+impl<U: Unit> TryFrom<&str> for Quantity<U>
+where
+    U::Dim: 'static,
+{
+    type Error = ParseQuantityError;
+
+    #[inline]
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(text)
+    }
+}
+
+/// Parses `"<number>"` (a value in `U`) or `"<number> <symbol>"`, validating and converting the symbol as
+/// [`Quantity::try_from_str`] does.
+///
+/// Formatting with `{}` and parsing back round-trips exactly: `format!("{}", q).parse::<Quantity<U>>()` returns `q`
+/// for every value, including infinities (and a `NaN` for `NaN`), and for every unit whose `Display` is the derived
+/// `"<value> <symbol>"` form, a composite such as `Per<Meter, Second>`, or [`Unitless`](crate::Unitless). Text
+/// written in another registered unit `V` parses to a result bit-identical to [`Quantity::to`]:
+/// `format!("{}", q).parse::<Quantity<U>>()` equals `q.to::<U>()`. This makes quantities usable directly as
+/// command-line arguments and config values.
+///
+/// ```rust
+/// use qtty_core::length::Kilometers;
+/// use qtty_core::velocity::Velocity;
+/// use qtty_core::length::Meter;
+/// use qtty_core::time::Second;
+///
+/// let d: Kilometers = "1.5".parse().unwrap();
+/// assert_eq!(d.value(), 1.5);
+/// assert_eq!("250 m".parse::<Kilometers>().unwrap().value(), 0.25);
+///
+/// let v = Velocity::<Meter, Second>::new(-0.1);
+/// assert_eq!(v.to_string().parse::<Velocity<Meter, Second>>(), Ok(v));
+/// ```
+impl<U: Unit> FromStr for Quantity<U>
+where
+    U::Dim: 'static,
+{
+    type Err = ParseQuantityError;
+
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse::<U>(text, true)
+    }
+}
+
+fn parse<U: Unit>(text: &str, allow_bare: bool) -> Result<Quantity<U>, ParseQuantityError>
+where
+    U::Dim: 'static,
+{
+    let text = text.trim();
+    if let Ok(value) = text.parse::<f64>() {
+        return if allow_bare {
+            Ok(Quantity::new(value))
+        } else {
+            Err(ParseQuantityError::Syntax)
+        };
+    }
+
+    // `inf`/`NaN` and other words `f64` accepts are only recognized when separated from the symbol by whitespace.
+    let (value, symbol) = match text.split_once(char::is_whitespace) {
+        Some((number, symbol)) if number.parse::<f64>().is_ok() => (number.parse().ok(), symbol),
+        _ => {
+            let split = text
+                .char_indices()
+                .find(|&(i, c)| !is_number_char(text, i, c))
+                .map_or(text.len(), |(i, _)| i);
+            let (number, symbol) = text.split_at(split);
+            (number.parse::<f64>().ok(), symbol)
+        }
+    };
+    let value = value.ok_or(ParseQuantityError::Syntax)?;
+    let symbol = symbol.trim_start();
+    if symbol.is_empty() {
+        return Err(ParseQuantityError::Syntax);
+    }
+    if displays_as::<U>(symbol) || (!U::LONG_NAME.is_empty() && symbol == U::LONG_NAME) {
+        return Ok(Quantity::new(value));
+    }
+
+    let unit = registry::lookup(symbol)
+        .or_else(|| {
+            registry::iter().find(|u| !u.info.long_name.is_empty() && u.info.long_name == symbol)
+        })
+        .ok_or(ParseQuantityError::UnknownUnit)?;
+    if unit.dimension_id() != TypeId::of::<U::Dim>() {
+        return Err(ParseQuantityError::DimensionMismatch {
+            expected: UnitSymbol::of::<U>(),
+            found: unit.symbol(),
+        });
+    }
+    let from = unit.info;
+    let value = match exact_factor(from.ratio_num, from.ratio_den, U::RATIO_NUM, U::RATIO_DEN) {
+        Some(factor) => apply_exact(value, factor),
+        None => value * (from.ratio / U::RATIO),
+    };
+    Ok(Quantity::new(value))
+}
+
+/// Whether `symbol` is exactly what `U::fmt_symbol` writes.
+fn displays_as<U: Unit>(symbol: &str) -> bool {
+    writes_exactly(symbol, |w| write!(w, "{}", Symbol::<U>::new()))
+}
+
+/// Whether `write` produces exactly `text`, compared chunk by chunk without allocating.
+fn writes_exactly(text: &str, write: impl FnOnce(&mut dyn Write) -> fmt::Result) -> bool {
+    struct Matcher<'a>(&'a str);
+
+    impl Write for Matcher<'_> {
+        fn write_str(&mut self, chunk: &str) -> fmt::Result {
+            self.0 = self.0.strip_prefix(chunk).ok_or(fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let mut matcher = Matcher(text);
+    write(&mut matcher).is_ok() && matcher.0.is_empty()
+}
+
+/// Displays the symbol `U` formats with (composites included).
+pub(crate) struct Symbol<U>(PhantomData<U>);
+
+impl<U: Unit> Symbol<U> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<U: Unit> Display for Symbol<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        U::fmt_symbol(f)
+    }
+}
+
+/// Whether `c` at byte `i` still belongs to the leading number (digits, sign, point, or an exponent `e` followed by a
+/// digit or sign).
+fn is_number_char(text: &str, i: usize, c: char) -> bool {
+    match c {
+        '0'..='9' | '.' | '+' | '-' => true,
+        'e' | 'E' if i > 0 => text[i + 1..]
+            .chars()
+            .next()
+            .is_some_and(|next| next.is_ascii_digit() || matches!(next, '+' | '-')),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::angular::{Degrees, Radian};
+    use crate::length::{Kilometer, Kilometers, Meter, Meters, Millimeters};
+    use crate::time::{Hours, Second, Seconds};
+    use crate::velocity::Velocity;
+    use crate::Unitless;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn parses_own_symbol() {
+        assert_eq!(Seconds::try_from_str(" 30 s ").unwrap().value(), 30.0);
+        assert_eq!(Meters::try_from("-2.5e3m").unwrap().value(), -2500.0);
+        assert_eq!(Kilometers::try_from_str("12 km").unwrap().value(), 12.0);
+    }
+
+    #[test]
+    fn converts_registered_symbols() {
+        assert_relative_eq!(Hours::try_from_str("90 min").unwrap().value(), 1.5);
+        assert_relative_eq!(
+            Kilometers::try_from_str("1 au").unwrap().value(),
+            149_597_870.7
+        );
+        assert_relative_eq!(Millimeters::try_from_str("1 Mm").unwrap().value(), 1e9);
+        assert_eq!(Meters::try_from_str("2 km").unwrap().value(), 2000.0);
+        let half_turn =
+            Degrees::try_from_str(&format!("{} {}", core::f64::consts::PI, Radian::SYMBOL));
+        assert_relative_eq!(half_turn.unwrap().value(), 180.0);
+    }
+
+    #[test]
+    fn conversion_matches_typed_to() {
+        for i in 1..2_000 {
+            let mm = Millimeters::new(f64::from(i) * 0.01);
+            let parsed = Kilometers::try_from_str(&format!("{} mm", mm.value())).unwrap();
+            assert_eq!(parsed, mm.to::<Kilometer>());
+        }
+        assert_eq!(
+            Kilometers::try_from_str("1.11 mm").unwrap(),
+            Millimeters::new(1.11).to::<Kilometer>()
+        );
+    }
+
+    #[test]
+    fn from_str_accepts_bare_numbers_and_composites() {
+        assert_eq!("42".parse::<Meters>().unwrap().value(), 42.0);
+        assert_eq!(" -1e-3 ".parse::<Seconds>().unwrap().value(), -1e-3);
+        assert_eq!(Seconds::try_from_str("42"), Err(ParseQuantityError::Syntax));
+        let v: Velocity<Kilometer, Second> = "7.5 Km/s".parse().unwrap();
+        assert_eq!(v.value(), 7.5);
+        let v: Velocity<Kilometer, Second> = "7.2 Km/h".parse().unwrap();
+        assert_relative_eq!(v.value(), 0.002);
+        assert_eq!(
+            "7.5 Km/d".parse::<Velocity<Kilometer, Second>>(),
+            Err(ParseQuantityError::UnknownUnit)
+        );
+        assert_eq!("0.5".parse::<Quantity<Unitless>>().unwrap().value(), 0.5);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for value in [
+            0.0,
+            -0.0,
+            1.0 / 3.0,
+            -2.5e-300,
+            6.02214076e23,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            let d = Degrees::new(value);
+            assert_eq!(d.to_string().parse::<Degrees>(), Ok(d));
+            let m = Meters::new(value);
+            assert_eq!(format!("{}", m).parse::<Meters>(), Ok(m));
+            let v = Velocity::<Meter, Second>::new(value);
+            assert_eq!(v.to_string().parse::<Velocity<Meter, Second>>(), Ok(v));
+            let u = Quantity::<Unitless>::new(value);
+            assert_eq!(u.to_string().parse::<Quantity<Unitless>>(), Ok(u));
+        }
+        let nan = Hours::new(f64::NAN).to_string().parse::<Hours>().unwrap();
+        assert!(nan.value().is_nan());
+    }
+
+    #[test]
+    fn display_in_another_unit_parses_like_to() {
+        let mm = Millimeters::new(1.11);
+        assert_eq!(
+            mm.to_string().parse::<Kilometers>(),
+            Ok(mm.to::<Kilometer>())
+        );
+        for i in 1..2_000 {
+            let mm = Millimeters::new(f64::from(i) * 0.01 + 0.001);
+            assert_eq!(
+                mm.to_string().parse::<Kilometers>(),
+                Ok(mm.to::<Kilometer>())
+            );
+            let h = Hours::new(f64::from(i) / 7.0);
+            assert_eq!(h.to_string().parse::<Seconds>(), Ok(h.to::<Second>()));
+        }
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(Seconds::try_from_str("30"), Err(ParseQuantityError::Syntax));
+        assert_eq!(Seconds::try_from_str("s"), Err(ParseQuantityError::Syntax));
+        assert_eq!(
+            Seconds::try_from_str("30 fortnights"),
+            Err(ParseQuantityError::UnknownUnit)
+        );
+        assert_eq!(
+            Seconds::try_from_str("30 km"),
+            Err(ParseQuantityError::DimensionMismatch {
+                expected: UnitSymbol::of::<Second>(),
+                found: "Km"
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_long_names() {
+        assert_eq!(Degrees::try_from_str("12.5 degree").unwrap().value(), 12.5);
+        assert_eq!(Meters::try_from_str("3 kilometer").unwrap().value(), 3000.0);
+        assert_eq!(
+            Seconds::try_from_str("1 meter"),
+            Err(ParseQuantityError::DimensionMismatch {
+                expected: UnitSymbol::of::<Second>(),
+                found: "m"
+            })
+        );
+    }
+
+    #[test]
+    fn mismatch_names_composite_symbols() {
+        let err = "3 s".parse::<Velocity<Meter, Second>>().unwrap_err();
+        match err {
+            ParseQuantityError::DimensionMismatch { expected, found } => {
+                assert_eq!(expected, "m/s");
+                assert_eq!(found, "s");
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+        assert_eq!(
+            err.to_string(),
+            "dimension mismatch: `s` cannot be converted to `m/s`"
+        );
+    }
+}
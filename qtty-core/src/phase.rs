@@ -0,0 +1,228 @@
+//! Cyclic phase in turns, always wrapped into `[0, 1)`.
+//!
+//! [`Phase`] is the natural coordinate for folded light curves and pulsar timing: it converts from and to any angular
+//! unit, and its arithmetic wraps after every operation, so the result never needs a manual
+//! [`wrap_pos`](crate::Quantity::wrap_pos).
+//!
+//! ```rust
+//! use qtty_core::angular::Degree;
+//! use qtty_core::phase::Phase;
+//! use qtty_core::time::Days;
+//!
+//! // Fold an observation onto the light curve of a 2.5 d variable.
+//! let phase = Phase::fold(Days::new(60_012.3), Days::new(60_000.0), Days::new(2.5));
+//! assert!((phase.turns() - 0.92).abs() < 1e-9);
+//!
+//! // Arithmetic stays in [0, 1).
+//! let shifted = phase + Phase::new(0.25);
+//! assert!((shifted.turns() - 0.17).abs() < 1e-9);
+//! assert!((shifted.to_angle::<Degree>().value() - 61.2).abs() < 1e-6);
+//! ```
+
+use crate::units::angular::{AngularUnit, Turn, Turns};
+use crate::units::time::TimeUnit;
+use crate::Quantity;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A phase in turns, wrapped into `[0, 1)`.
+///
+/// Every constructor and operator wraps its result, so a `Phase` is always in range. `NaN` and infinite inputs
+/// produce a `NaN` phase.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Phase(f64);
+
+impl Phase {
+    /// Phase zero.
+    pub const ZERO: Self = Self(0.0);
+
+    /// Wraps `turns` into `[0, 1)`.
+    #[inline]
+    pub const fn new(turns: f64) -> Self {
+        Self(Turns::new(turns).wrap_pos().value())
+    }
+
+    /// The phase in turns, in `[0, 1)`.
+    #[inline]
+    pub const fn turns(self) -> f64 {
+        self.0
+    }
+
+    /// The phase of `angle`, in whole turns of any angular unit.
+    #[inline]
+    pub const fn from_angle<U: AngularUnit>(angle: Quantity<U>) -> Self {
+        Self::new(angle.to::<Turn>().value())
+    }
+
+    /// The phase as an angle in `[0, FULL_TURN)` of `U`.
+    #[inline]
+    pub const fn to_angle<U: AngularUnit>(self) -> Quantity<U> {
+        Turns::new(self.0).to::<U>()
+    }
+
+    /// Folds time `t` on a cycle of `period` starting at `epoch`: the fractional part of `(t - epoch) / period`.
+    ///
+    /// The three quantities may use different time units. The fractional part keeps about
+    /// `16 - log10(cycles)` significant digits, so for very many cycles (millisecond pulsars over decades) subtract
+    /// the epoch in a higher-precision representation first.
+    #[inline]
+    pub fn fold<A: TimeUnit, B: TimeUnit, C: TimeUnit>(
+        t: Quantity<A>,
+        epoch: Quantity<B>,
+        period: Quantity<C>,
+    ) -> Self {
+        let elapsed = t - epoch.to::<A>();
+        Self::new(elapsed.value() / period.to::<A>().value())
+    }
+
+    /// Signed shortest difference `self - other`, in turns in `(-0.5, 0.5]`.
+    ///
+    /// ```rust
+    /// use qtty_core::phase::Phase;
+    ///
+    /// assert!((Phase::new(0.05).offset_from(Phase::new(0.95)) - 0.1).abs() < 1e-12);
+    /// assert!((Phase::new(0.95).offset_from(Phase::new(0.05)) + 0.1).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub const fn offset_from(self, other: Self) -> f64 {
+        let d = Self::new(self.0 - other.0).0;
+        if d > 0.5 {
+            d - 1.0
+        } else {
+            d
+        }
+    }
+}
+
+impl<U: AngularUnit> From<Quantity<U>> for Phase {
+    #[inline]
+    fn from(angle: Quantity<U>) -> Self {
+        Self::from_angle(angle)
+    }
+}
+
+impl<U: AngularUnit> From<Phase> for Quantity<U> {
+    #[inline]
+    fn from(phase: Phase) -> Self {
+        phase.to_angle()
+    }
+}
+
+impl Add for Phase {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Phase {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Phase {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Phase {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Phase {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+/// Scales the phase by a harmonic number (or any factor) and wraps the result.
+impl Mul<f64> for Phase {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.0 * rhs)
+    }
+}
+
+/// Formats the phase in turns, forwarding precision and width flags to the number.
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Degree, Degrees, HourAngles, Radians};
+    use crate::units::time::{Days, Hours};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn wraps_into_unit_interval() {
+        assert_eq!(Phase::new(1.25).turns(), 0.25);
+        assert_eq!(Phase::new(-0.25).turns(), 0.75);
+        assert_eq!(Phase::new(3.0).turns(), 0.0);
+        assert_eq!(Phase::new(-1e-20).turns(), 0.0);
+        assert!(Phase::new(f64::NAN).turns().is_nan());
+    }
+
+    #[test]
+    fn converts_angles() {
+        assert_relative_eq!(Phase::from_angle(Degrees::new(450.0)).turns(), 0.25);
+        assert_relative_eq!(Phase::from(HourAngles::new(-6.0)).turns(), 0.75);
+        let r: Radians = Phase::new(0.5).into();
+        assert_relative_eq!(r.value(), core::f64::consts::PI);
+        assert_relative_eq!(Phase::new(0.75).to_angle::<Degree>().value(), 270.0);
+    }
+
+    #[test]
+    fn arithmetic_stays_wrapped() {
+        let mut p = Phase::new(0.8);
+        p += Phase::new(0.3);
+        assert_relative_eq!(p.turns(), 0.1, epsilon = 1e-12);
+        p -= Phase::new(0.2);
+        assert_relative_eq!(p.turns(), 0.9, epsilon = 1e-12);
+        assert_relative_eq!((-p).turns(), 0.1, epsilon = 1e-12);
+        assert_relative_eq!((p * 3.0).turns(), 0.7, epsilon = 1e-12);
+        assert_eq!(format!("{:.2}", p), "0.90");
+    }
+
+    #[test]
+    fn folds_mixed_time_units() {
+        let phase = Phase::fold(Hours::new(30.0), Days::new(0.5), Days::new(2.0));
+        assert_relative_eq!(phase.turns(), 0.375);
+        let before = Phase::fold(Days::new(-1.0), Days::new(0.0), Days::new(4.0));
+        assert_relative_eq!(before.turns(), 0.75);
+    }
+
+    #[test]
+    fn offset_is_shortest_signed() {
+        assert_relative_eq!(
+            Phase::new(0.1).offset_from(Phase::new(0.9)),
+            0.2,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Phase::new(0.2).offset_from(Phase::new(0.7)),
+            0.5,
+            epsilon = 1e-12
+        );
+        assert_eq!(Phase::ZERO.offset_from(Phase::ZERO), 0.0);
+    }
+}
@@ -0,0 +1,156 @@
+//! Precession of the equator (IAU 2006).
+//!
+//! The mean equator and equinox of date drift away from those of J2000.0 by about 50″ a year. [`precession_angles`]
+//! evaluates the three equatorial precession angles `ζ_A`, `z_A` and `θ_A` of the IAU 2006 model (Capitaine et al.
+//! 2003; IERS Conventions 2010, eq. 5.40) as typed [`Polynomial`]s in Julian centuries of TT, and
+//! [`precess_from_j2000`] applies them to a mean J2000.0 position (Meeus, *Astronomical Algorithms*, eq. 21.4).
+//!
+//! ```rust
+//! use qtty_core::angular::{Degrees, HourAngles};
+//! use qtty_core::precession::{precess_from_j2000, precession_angles};
+//! use qtty_core::time::JulianCenturies;
+//! use qtty_core::transform::Equatorial;
+//!
+//! let t = JulianCenturies::new(0.25);
+//! // Over 25 years the equator turns by ~500″ about the y-axis.
+//! assert!((precession_angles(t).theta.value() - 501.02).abs() < 0.01);
+//!
+//! let vernal = Equatorial { right_ascension: HourAngles::new(0.0), declination: Degrees::new(0.0) };
+//! let of_date = precess_from_j2000(vernal, t);
+//! // The J2000.0 equinox has moved 1m 17s east in right ascension.
+//! assert!((of_date.right_ascension.value() * 3_600.0 - 76.9).abs() < 0.1);
+//! ```
+
+use crate::series::Polynomial;
+use crate::transform::{asin, atan2, Equatorial};
+use crate::units::angular::{Arcsecond, Arcseconds, Degree, HourAngle};
+use crate::units::time::{JulianCenturies, JulianCentury};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+type Angle = Polynomial<JulianCentury, Arcsecond, 6>;
+
+/// `ζ_A` (IAU 2006), in arcseconds per power of `T`.
+const ZETA: Angle = Polynomial::from_coefficients([
+    2.650_545,
+    2_306.083_227,
+    0.298_849_9,
+    0.018_018_28,
+    -0.000_005_971,
+    -0.000_000_317_3,
+]);
+
+/// `z_A` (IAU 2006), in arcseconds per power of `T`.
+const Z: Angle = Polynomial::from_coefficients([
+    -2.650_545,
+    2_306.077_181,
+    1.092_734_8,
+    0.018_268_37,
+    -0.000_028_596,
+    -0.000_000_290_4,
+]);
+
+/// `θ_A` (IAU 2006), in arcseconds per power of `T`.
+const THETA: Angle = Polynomial::from_coefficients([
+    0.0,
+    2_004.191_903,
+    -0.429_493_4,
+    -0.041_822_64,
+    -0.000_007_089,
+    -0.000_000_127_4,
+]);
+
+/// The equatorial precession angles from J2000.0 to a date.
+///
+/// The mean frame of date is reached from the J2000.0 frame by the rotations `R₃(−z_A) · R₂(θ_A) · R₃(−ζ_A)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrecessionAngles {
+    /// `ζ_A`, the first rotation about the J2000.0 pole.
+    pub zeta: Arcseconds,
+    /// `z_A`, the last rotation about the pole of date.
+    pub z: Arcseconds,
+    /// `θ_A`, the tilt between the two equators.
+    pub theta: Arcseconds,
+}
+
+/// The IAU 2006 precession angles at `t` Julian centuries (TT) since J2000.0.
+pub fn precession_angles(t: JulianCenturies) -> PrecessionAngles {
+    PrecessionAngles {
+        zeta: ZETA.eval(t),
+        z: Z.eval(t),
+        theta: THETA.eval(t),
+    }
+}
+
+/// Precesses a mean J2000.0 position to the mean equator and equinox at `t` Julian centuries (TT) since J2000.0.
+///
+/// Proper motion is not applied; add it to `position` first for stars. The right ascension of the result is in
+/// `[0h, 24h)`.
+pub fn precess_from_j2000(position: Equatorial, t: JulianCenturies) -> Equatorial {
+    let angles = precession_angles(t);
+    let (sin_d, cos_d) = position.declination.sin_cos();
+    let (sin_th, cos_th) = angles.theta.to::<Degree>().sin_cos();
+    let (sin_az, cos_az) =
+        (position.right_ascension.to::<Degree>() + angles.zeta.to::<Degree>()).sin_cos();
+
+    let a = cos_d * sin_az;
+    let b = cos_th * cos_d * cos_az - sin_th * sin_d;
+    let c = sin_th * cos_d * cos_az + cos_th * sin_d;
+    let ra = atan2(a, b).to::<HourAngle>() + angles.z.to::<HourAngle>();
+    Equatorial {
+        right_ascension: ra.wrap_pos(),
+        declination: asin(c).to(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Degrees, HourAngles};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn angles_at_j2000_and_one_century() {
+        let zero = precession_angles(JulianCenturies::new(0.0));
+        assert_eq!(zero.zeta.value(), 2.650_545);
+        assert_eq!(zero.z.value(), -2.650_545);
+        assert_eq!(zero.theta.value(), 0.0);
+
+        let one = precession_angles(JulianCenturies::new(1.0));
+        assert_abs_diff_eq!(one.zeta.value(), 2_309.050_633_9, epsilon = 1e-6);
+        assert_abs_diff_eq!(one.z.value(), 2_304.537_610_3, epsilon = 1e-6);
+        assert_abs_diff_eq!(one.theta.value(), 2_003.720_579_7, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn meeus_example_21b() {
+        // θ Persei at 2028 Nov 13.19 TD, J2000.0 position with proper motion already applied.
+        let j2000 = Equatorial {
+            right_ascension: Degrees::new(41.054_063).to(),
+            declination: Degrees::new(49.227_750),
+        };
+        let of_date = precess_from_j2000(j2000, JulianCenturies::new(0.288_670_500));
+        // Meeus (IAU 1976 angles): α = 41.547214°, δ = 49.348483°. The IAU 2006 rates differ by a few tenths of an
+        // arcsecond per century, hence the 0.18″ tolerance.
+        assert_abs_diff_eq!(
+            of_date.right_ascension.to::<Degree>().value(),
+            41.547_214,
+            epsilon = 5e-5
+        );
+        assert_abs_diff_eq!(of_date.declination.value(), 49.348_483, epsilon = 5e-5);
+    }
+
+    #[test]
+    fn zero_interval_is_nearly_identity() {
+        // ζ_A and z_A cancel at T = 0 up to the frame bias they absorb.
+        let p = Equatorial {
+            right_ascension: HourAngles::new(12.5),
+            declination: Degrees::new(-33.0),
+        };
+        let q = precess_from_j2000(p, JulianCenturies::new(0.0));
+        assert_abs_diff_eq!(q.right_ascension.value(), 12.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(q.declination.value(), -33.0, epsilon = 1e-12);
+    }
+}
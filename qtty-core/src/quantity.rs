@@ -1,6 +1,9 @@
 //! Quantity type and its implementations.
 
-use crate::unit::{Per, Unit};
+use crate::dimension::SameDimension;
+use crate::math;
+use crate::unit::{apply_exact, ExactFactor, Per, Prod, Unit};
+use core::fmt;
 use core::marker::PhantomData;
 use core::ops::*;
 
@@ -34,8 +37,81 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// let sum = x + y;
 /// assert_eq!(sum.value(), 8.0);
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-pub struct Quantity<U: Unit>(f64, PhantomData<U>);
+///
+/// The second parameter is the scalar storage and defaults to `f64`; the `twofloat` feature adds a double-double
+/// `TwoFloat` backend for values that need more than 53 bits.
+///
+/// # Layout
+///
+/// `Quantity<U, S>` is `#[repr(transparent)]` over `S`: a `Quantity<U>` has the size, alignment and ABI of `f64`, may
+/// be passed by value wherever an `f64` is expected across an FFI boundary, and `[Quantity<U>]` has the layout of
+/// `[f64]` (see [`slice_from_raw`](Quantity::slice_from_raw)). This is a stable guarantee, not an implementation
+/// detail.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Double))]
+#[repr(transparent)]
+pub struct Quantity<U: Unit, S = f64>(S, PhantomData<U>);
+
+impl<U: Unit, S: Copy> Quantity<U, S> {
+    /// Creates a new quantity with the given value.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    /// let d = Meters::new(3.0);
+    /// assert_eq!(d.value(), 3.0);
+    /// ```
+    #[inline]
+    pub const fn new(value: S) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the raw numeric value.
+    ///
+    /// ```rust
+    /// use qtty_core::time::Seconds;
+    /// let t = Seconds::new(2.5);
+    /// assert_eq!(t.value(), 2.5);
+    /// ```
+    #[inline]
+    pub const fn value(self) -> S {
+        self.0
+    }
+
+    /// Creates a quantity from a raw value; the same as [`new`](Self::new), spelled to read as `Meters::of(3.0)`.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    /// assert_eq!(Meters::of(3.0), Meters::new(3.0));
+    /// ```
+    #[inline]
+    pub const fn of(value: S) -> Self {
+        Self::new(value)
+    }
+
+    /// Returns the raw numeric value, discarding the unit; the same as [`value`](Self::value).
+    ///
+    /// Grep-friendly for audits of where a typed quantity leaves the type system.
+    ///
+    /// ```rust
+    /// use qtty_core::time::Seconds;
+    /// assert_eq!(Seconds::new(2.5).raw(), 2.5);
+    /// ```
+    #[inline]
+    pub const fn raw(self) -> S {
+        self.0
+    }
+
+    /// Borrows the stored value, for integrations whose traits hand out references tied to `&self`.
+    #[cfg(feature = "diesel")]
+    #[inline]
+    pub(crate) const fn value_ref(&self) -> &S {
+        &self.0
+    }
+}
 
 impl<U: Unit + Copy> Quantity<U> {
     /// A constant representing NaN for this quantity type.
@@ -46,28 +122,27 @@ impl<U: Unit + Copy> Quantity<U> {
     /// ```
     pub const NAN: Self = Self::new(f64::NAN);
 
-    /// Creates a new quantity with the given value.
+    /// The value's IEEE-754 bytes in little-endian order; the unit is not encoded.
     ///
     /// ```rust
     /// use qtty_core::length::Meters;
-    /// let d = Meters::new(3.0);
-    /// assert_eq!(d.value(), 3.0);
+    /// assert_eq!(Meters::new(1.0).to_le_bytes(), 1.0_f64.to_le_bytes());
     /// ```
     #[inline]
-    pub const fn new(value: f64) -> Self {
-        Self(value, PhantomData)
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
     }
 
-    /// Returns the raw numeric value.
+    /// Reads a value written by [`to_le_bytes`](Self::to_le_bytes) as a quantity in `U`.
     ///
     /// ```rust
     /// use qtty_core::time::Seconds;
-    /// let t = Seconds::new(2.5);
+    /// let t = Seconds::from_le_bytes(2.5_f64.to_le_bytes());
     /// assert_eq!(t.value(), 2.5);
     /// ```
     #[inline]
-    pub const fn value(self) -> f64 {
-        self.0
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self::new(f64::from_le_bytes(bytes))
     }
 
     /// Returns the absolute value.
@@ -82,8 +157,57 @@ impl<U: Unit + Copy> Quantity<U> {
         Self::new(self.0.abs())
     }
 
+    /// Applies `f` to the raw value, keeping the unit.
+    ///
+    /// Meant for calibrations and other corrections expressed on the number in this unit; `f` must not change what
+    /// unit the number is in.
+    ///
+    /// ```rust
+    /// use qtty_core::temperature::Kelvins;
+    ///
+    /// // A sensor calibration, linear in the reading.
+    /// let reading = Kelvins::new(290.0);
+    /// let corrected = reading.map(|k| 1.002 * k - 0.35);
+    /// assert!((corrected.value() - 290.23).abs() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn map(self, f: impl FnOnce(f64) -> f64) -> Self {
+        Self::new(f(self.0))
+    }
+
+    /// Like [`map`](Self::map), for a fallible `f`.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    ///
+    /// let checked_sqrt = |x: f64| if x >= 0.0 { Ok(x.sqrt()) } else { Err("negative") };
+    /// assert_eq!(Meters::new(4.0).try_map(checked_sqrt), Ok(Meters::new(2.0)));
+    /// assert_eq!(Meters::new(-1.0).try_map(checked_sqrt), Err("negative"));
+    /// ```
+    #[inline]
+    pub fn try_map<E>(self, f: impl FnOnce(f64) -> Result<f64, E>) -> Result<Self, E> {
+        f(self.0).map(Self::new)
+    }
+
+    /// Like [`map`](Self::map), for an `f` that may have no result.
+    ///
+    /// ```rust
+    /// use qtty_core::time::Seconds;
+    ///
+    /// let t = Seconds::new(90.0);
+    /// assert_eq!(t.checked_map(|s| s.is_finite().then_some(s / 2.0)), Some(Seconds::new(45.0)));
+    /// assert_eq!(Seconds::NAN.checked_map(|s| s.is_finite().then_some(s)), None);
+    /// ```
+    #[inline]
+    pub fn checked_map(self, f: impl FnOnce(f64) -> Option<f64>) -> Option<Self> {
+        f(self.0).map(Self::new)
+    }
+
     /// Converts this quantity to another unit of the same dimension.
     ///
+    /// The value is scaled by `U::RATIO / T::RATIO`, or by the reduced exact fraction when both units define
+    /// [`Unit::RATIO_NUM`] (a pure multiplication or division by an integer is then correctly rounded).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -112,9 +236,54 @@ impl<U: Unit + Copy> Quantity<U> {
     /// let m: Quantity<Meter> = km.to();
     /// assert_eq!(m.value(), 1000.0);
     /// ```
+    ///
+    /// Converting to a unit of another dimension does not compile:
+    ///
+    /// ```compile_fail
+    /// use qtty_core::length::Meters;
+    /// use qtty_core::time::Second;
+    ///
+    /// let _ = Meters::new(1.0).to::<Second>(); // cannot convert `Length` to `Time`
+    /// ```
     #[inline]
-    pub const fn to<T: Unit<Dim = U::Dim>>(self) -> Quantity<T> {
-        Quantity::<T>::new(self.0 * (U::RATIO / T::RATIO))
+    pub const fn to<T: Unit>(self) -> Quantity<T>
+    where
+        U::Dim: SameDimension<T::Dim>,
+    {
+        let value = match ExactFactor::<U, T>::VALUE {
+            Some(factor) => apply_exact(self.0, factor),
+            None => self.0 * (U::RATIO / T::RATIO),
+        };
+        Quantity::<T>::new(value)
+    }
+
+    /// Converts to `T` and reports the precision the conversion lost.
+    ///
+    /// Returns the converted quantity together with the residual `self - converted.to::<U>()`, in the source unit. The
+    /// residual is zero when the round trip is exact. Otherwise it is the part of `self` rounded away in `T`, which
+    /// matters when a small offset is expressed in a much larger unit. Calibration pipelines can log it to prove that
+    /// no conversion silently discarded signal.
+    ///
+    /// ```rust
+    /// use qtty_core::time::{JulianCentury, Seconds};
+    ///
+    /// let (_, residual) = Seconds::new(3_600.0).convert_with_residual::<qtty_core::time::Hour>();
+    /// assert_eq!(residual.value(), 0.0);
+    ///
+    /// let offset = Seconds::new(1e9 + 0.1);
+    /// let (centuries, residual) = offset.convert_with_residual::<JulianCentury>();
+    /// assert!(centuries.value() > 0.3);
+    /// assert!(residual.value() != 0.0 && residual.value().abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub const fn convert_with_residual<T: Unit>(self) -> (Quantity<T>, Self)
+    where
+        U::Dim: SameDimension<T::Dim>,
+        T::Dim: SameDimension<U::Dim>,
+    {
+        let converted = self.to::<T>();
+        let back = converted.to::<U>();
+        (converted, Self::new(self.0 - back.0))
     }
 
     /// Returns the minimum of this quantity and another.
@@ -185,6 +354,74 @@ impl<U: Unit + Copy> Quantity<U> {
     }
 }
 
+// The `repr(transparent)` guarantee above, checked at compile time for a plain and a composite unit.
+const _: () = {
+    use core::mem::{align_of, size_of};
+    assert!(size_of::<Quantity<crate::Unitless>>() == size_of::<f64>());
+    assert!(align_of::<Quantity<crate::Unitless>>() == align_of::<f64>());
+    assert!(size_of::<Quantity<Per<crate::Unitless, crate::Unitless>>>() == size_of::<f64>());
+    assert!(size_of::<[Quantity<crate::Unitless>; 3]>() == size_of::<[f64; 3]>());
+};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Zero-copy slice views
+// ─────────────────────────────────────────────────────────────────────────────
+
+// SAFETY (all four): `Quantity<U, S>` is `repr(transparent)` over `S`, so `[S]` and `[Quantity<U, S>]` have identical
+// layout and validity; the returned slice reuses the input's pointer, length and lifetime.
+#[allow(unsafe_code)]
+impl<U: Unit, S> Quantity<U, S> {
+    /// Views a slice of raw values as quantities in `U`, without copying.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    ///
+    /// let raw = [1.0, 2.0, 3.0];
+    /// let typed: &[Meters] = Meters::slice_from_raw(&raw);
+    /// assert_eq!(typed[2], Meters::new(3.0));
+    /// ```
+    #[inline]
+    pub const fn slice_from_raw(values: &[S]) -> &[Self] {
+        unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+    }
+
+    /// Mutable counterpart of [`slice_from_raw`](Self::slice_from_raw).
+    #[inline]
+    pub fn slice_from_raw_mut(values: &mut [S]) -> &mut [Self] {
+        unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+    }
+
+    /// Views a slice of quantities as their raw values, without copying.
+    ///
+    /// ```rust
+    /// use qtty_core::time::Seconds;
+    ///
+    /// let times = [Seconds::new(0.5), Seconds::new(1.5)];
+    /// let total: f64 = Seconds::slice_as_raw(&times).iter().sum();
+    /// assert_eq!(total, 2.0);
+    /// ```
+    #[inline]
+    pub const fn slice_as_raw(quantities: &[Self]) -> &[S] {
+        unsafe { core::slice::from_raw_parts(quantities.as_ptr().cast(), quantities.len()) }
+    }
+
+    /// Mutable counterpart of [`slice_as_raw`](Self::slice_as_raw), for kernels that update values in place.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// let mut angles = [Degrees::new(10.0), Degrees::new(20.0)];
+    /// for a in Degrees::slice_as_raw_mut(&mut angles) {
+    ///     *a *= 2.0;
+    /// }
+    /// assert_eq!(angles[1], Degrees::new(40.0));
+    /// ```
+    #[inline]
+    pub fn slice_as_raw_mut(quantities: &mut [Self]) -> &mut [S] {
+        unsafe { core::slice::from_raw_parts_mut(quantities.as_mut_ptr().cast(), quantities.len()) }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Operator implementations
 // ─────────────────────────────────────────────────────────────────────────────
@@ -276,6 +513,9 @@ impl<U: Unit> Rem<f64> for Quantity<U> {
     }
 }
 
+// The bare-`f64` comparison and conversion below let any float stand in for any unit; the `strict` feature drops
+// them.
+#[cfg(not(feature = "strict"))]
 impl<U: Unit> PartialEq<f64> for Quantity<U> {
     #[inline]
     fn eq(&self, other: &f64) -> bool {
@@ -291,6 +531,30 @@ impl<U: Unit> Neg for Quantity<U> {
     }
 }
 
+/// Zero of the unit, so structs holding quantities can `#[derive(Default)]`.
+///
+/// ```rust
+/// use qtty_core::length::Meters;
+/// use qtty_core::time::Seconds;
+///
+/// #[derive(Default)]
+/// struct Exposure {
+///     duration: Seconds,
+///     focus_offset: Meters,
+/// }
+///
+/// let e = Exposure::default();
+/// assert_eq!(e.duration.value(), 0.0);
+/// assert_eq!(e.focus_offset.value(), 0.0);
+/// ```
+impl<U: Unit, S: Default> Default for Quantity<U, S> {
+    #[inline]
+    fn default() -> Self {
+        Self(S::default(), PhantomData)
+    }
+}
+
+#[cfg(not(feature = "strict"))]
 impl<U: Unit> From<f64> for Quantity<U> {
     #[inline]
     fn from(value: f64) -> Self {
@@ -306,6 +570,216 @@ impl<N: Unit, D: Unit> Div<Quantity<D>> for Quantity<N> {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Grid snapping
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Which way [`Quantity::snap`] moves a value onto its grid.
+#[derive(Clone, Copy)]
+enum Snap {
+    Nearest,
+    Down,
+    Up,
+}
+
+impl<U: Unit> Quantity<U> {
+    /// Rounds to the nearest multiple of `step`, which may be given in any unit of the same dimension (halfway cases
+    /// round away from zero).
+    ///
+    /// The sign of `step` is ignored; a zero or non-finite step yields `NaN`.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::{Arcseconds, Degrees};
+    /// use qtty_core::time::{Hours, Minutes};
+    ///
+    /// let t = Hours::new(1.13).round_to(Minutes::new(15.0));
+    /// assert!((t.value() - 1.25).abs() < 1e-12);
+    /// let a = Degrees::new(10.000_4).round_to(Arcseconds::new(1.0));
+    /// assert!((a.value() - (10.0 + 1.0 / 3_600.0)).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn round_to<S: Unit<Dim = U::Dim>>(self, step: Quantity<S>) -> Self {
+        self.snap(step, Snap::Nearest)
+    }
+
+    /// Rounds down (towards `-∞`) to a multiple of `step`; see [`round_to`](Self::round_to).
+    ///
+    /// ```rust
+    /// use qtty_core::time::{Minutes, Seconds};
+    ///
+    /// assert_eq!(Seconds::new(-30.0).floor_to(Minutes::new(1.0)).value(), -60.0);
+    /// ```
+    #[inline]
+    pub fn floor_to<S: Unit<Dim = U::Dim>>(self, step: Quantity<S>) -> Self {
+        self.snap(step, Snap::Down)
+    }
+
+    /// Rounds up (towards `+∞`) to a multiple of `step`; see [`round_to`](Self::round_to).
+    ///
+    /// ```rust
+    /// use qtty_core::time::{Minutes, Seconds};
+    ///
+    /// assert_eq!(Seconds::new(61.0).ceil_to(Minutes::new(1.0)).value(), 120.0);
+    /// ```
+    #[inline]
+    pub fn ceil_to<S: Unit<Dim = U::Dim>>(self, step: Quantity<S>) -> Self {
+        self.snap(step, Snap::Up)
+    }
+
+    fn snap<S: Unit<Dim = U::Dim>>(self, step: Quantity<S>, mode: Snap) -> Self {
+        let step = step.to::<U>().value().abs();
+        if step == 0.0 || !step.is_finite() {
+            return Self::NAN;
+        }
+        let n = self.value() / step;
+        let n = match mode {
+            Snap::Nearest => math::round(n),
+            Snap::Down => math::floor(n),
+            Snap::Up => math::ceil(n),
+        };
+        Self::new(n * step)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Cross-unit comparison
+// ─────────────────────────────────────────────────────────────────────────────
+
+// `PartialEq`/`PartialOrd` are derived for same-unit comparison, so quantities of different units are compared through
+// these methods instead of operator impls.
+impl<U: Unit> Quantity<U> {
+    /// Compares with a quantity in another unit of the same dimension.
+    ///
+    /// When both units have exact rational ratios, `self` is converted into `V` exactly as [`to`](Self::to) does, so
+    /// `x.eq_across(x.to::<V>())` always holds; otherwise both sides are scaled to the canonical unit. Either way the
+    /// conversion rounds, so two quantities that are equal on paper may compare unequal (`1.1 h` against `66 min`),
+    /// and swapping the operands may change the answer; use [`eq_within`](Self::eq_within) when either side came out
+    /// of arithmetic.
+    ///
+    /// ```rust
+    /// use qtty_core::length::{Kilometers, Meters};
+    ///
+    /// assert!(Meters::new(1_000.0).eq_across(Kilometers::new(1.0)));
+    /// assert!(!Meters::new(999.0).eq_across(Kilometers::new(1.0)));
+    /// ```
+    #[inline]
+    pub fn eq_across<V: Unit>(self, other: Quantity<V>) -> bool
+    where
+        U::Dim: SameDimension<V::Dim>,
+    {
+        match ExactFactor::<U, V>::VALUE {
+            Some(factor) => apply_exact(self.value(), factor) == other.value(),
+            None => self.value() * U::RATIO == other.value() * V::RATIO,
+        }
+    }
+
+    /// Orders against a quantity in another unit of the same dimension, converting as [`eq_across`](Self::eq_across)
+    /// does.
+    ///
+    /// Returns `None` if either value is `NaN`; see [`eq_across`](Self::eq_across) for the rounding caveat.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// use qtty_core::time::{Hours, Minutes};
+    ///
+    /// assert_eq!(Minutes::new(90.0).partial_cmp_across(Hours::new(1.0)), Some(Ordering::Greater));
+    /// ```
+    #[inline]
+    pub fn partial_cmp_across<V: Unit>(self, other: Quantity<V>) -> Option<core::cmp::Ordering>
+    where
+        U::Dim: SameDimension<V::Dim>,
+    {
+        match ExactFactor::<U, V>::VALUE {
+            Some(factor) => apply_exact(self.value(), factor).partial_cmp(&other.value()),
+            None => (self.value() * U::RATIO).partial_cmp(&(other.value() * V::RATIO)),
+        }
+    }
+
+    /// Whether `other`, converted to this unit, lies within `tolerance` of `self` (inclusive).
+    ///
+    /// The tolerance may be given in any unit of the same dimension; its sign is ignored.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::{Arcseconds, Degrees, Radians};
+    ///
+    /// let a = Degrees::new(180.0);
+    /// assert!(a.eq_within(Radians::new(core::f64::consts::PI), Arcseconds::new(1e-6)));
+    /// assert!(!a.eq_within(Radians::new(3.14), Arcseconds::new(1.0)));
+    /// ```
+    #[inline]
+    pub fn eq_within<V: Unit, T: Unit>(self, other: Quantity<V>, tolerance: Quantity<T>) -> bool
+    where
+        V::Dim: SameDimension<U::Dim>,
+        T::Dim: SameDimension<U::Dim>,
+    {
+        (self.value() - other.to::<U>().value()).abs() <= tolerance.to::<U>().value().abs()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Constructors for Per<N, D>
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<N: Unit, D: Unit> Quantity<Per<N, D>> {
+    /// `numerator / denominator` in this compound unit, converting each side to `N` and `D` first.
+    ///
+    /// ```rust
+    /// use qtty_core::length::{Kilometers, Meter};
+    /// use qtty_core::time::{Hours, Second};
+    /// use qtty_core::velocity::Velocity;
+    ///
+    /// let v = Velocity::<Meter, Second>::per(Kilometers::new(36.0), Hours::new(1.0));
+    /// assert_eq!(v.value(), 10.0);
+    /// ```
+    #[inline]
+    pub const fn per<A: Unit, B: Unit>(numerator: Quantity<A>, denominator: Quantity<B>) -> Self
+    where
+        A::Dim: SameDimension<N::Dim>,
+        B::Dim: SameDimension<D::Dim>,
+    {
+        Self::new(numerator.to::<N>().value() / denominator.to::<D>().value())
+    }
+
+    /// `numerator / denominator`, both given as raw values in `N` and `D`.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degree;
+    /// use qtty_core::frequency::Frequency;
+    /// use qtty_core::time::Day;
+    ///
+    /// // 360° in 365.25 days.
+    /// let rate = Frequency::<Degree, Day>::rate(360.0, 365.25);
+    /// assert!((rate.value() - 0.985_626).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub const fn rate(numerator: f64, denominator: f64) -> Self {
+        Self::new(numerator / denominator)
+    }
+}
+
+impl<A: Unit, B: Unit, C: Unit> Quantity<Per<Per<A, B>, C>> {
+    /// Rewrites `(A/B)/C` as `A/(B·C)`, the same unit with a flat denominator; the value is unchanged.
+    ///
+    /// Dimensions are compared structurally, so this is also how a nested rate reaches units written with [`Prod`]
+    /// denominators, such as those in [`grav_param`](crate::grav_param).
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meter;
+    /// use qtty_core::time::{Second, Seconds};
+    /// use qtty_core::velocity::Velocity;
+    /// use qtty_core::{Per, Prod, Quantity};
+    ///
+    /// let g = Velocity::<Meter, Second>::new(9.81) / Seconds::new(1.0);
+    /// let flat: Quantity<Per<Meter, Prod<Second, Second>>> = g.flatten();
+    /// assert_eq!(flat.value(), 9.81);
+    /// assert_eq!(format!("{flat:#}"), "9.81 meter per second-second");
+    /// ```
+    #[inline]
+    pub const fn flatten(self) -> Quantity<Per<A, Prod<B, C>>> {
+        Quantity::new(self.value())
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Special methods for Per<U, U> (unitless ratios)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -321,14 +795,7 @@ impl<U: Unit> Quantity<Per<U, U>> {
     /// ```
     #[inline]
     pub fn asin(&self) -> f64 {
-        #[cfg(feature = "std")]
-        {
-            self.value().asin()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            libm::asin(self.value())
-        }
+        math::asin(self.value())
     }
 }
 
@@ -380,7 +847,7 @@ impl<'de, U: Unit> Deserialize<'de> for Quantity<U> {
 #[cfg(feature = "serde")]
 pub mod serde_with_unit {
     use super::*;
-    use serde::de::{self, Deserializer, MapAccess, Visitor};
+    use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, Visitor};
     use serde::ser::{SerializeStruct, Serializer};
 
     /// Serializes a `Quantity<U>` as a struct with `value` and `unit` fields.
@@ -400,10 +867,91 @@ pub mod serde_with_unit {
         state.end()
     }
 
+    /// JSON Schema of the tagged form, for `#[schemars(with = "qtty_core::serde_with_unit::TaggedSchema<U>")]`.
+    ///
+    /// Describes an object with a numeric `value` and an optional `unit` that must equal `U::SYMBOL`.
+    #[cfg(feature = "schemars")]
+    pub struct TaggedSchema<U>(core::marker::PhantomData<U>);
+
+    #[cfg(feature = "schemars")]
+    impl<U: Unit> schemars::JsonSchema for TaggedSchema<U> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> std::borrow::Cow<'static, str> {
+            "TaggedQuantity".into()
+        }
+
+        fn schema_id() -> std::borrow::Cow<'static, str> {
+            format!(
+                "qtty_core::serde_with_unit::TaggedSchema<{}>",
+                core::any::type_name::<U>()
+            )
+            .into()
+        }
+
+        fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+            schemars::json_schema!({
+                "type": "object",
+                "description": super::schema_description::<U>(),
+                "properties": {
+                    "value": { "type": "number" },
+                    "unit": { "const": U::SYMBOL },
+                },
+                "required": ["value"],
+            })
+        }
+    }
+
+    /// Seed that checks a `unit` string against `U::SYMBOL` without allocating (keeps `no_std` builds working).
+    struct UnitSymbol<U>(core::marker::PhantomData<U>);
+
+    struct UnitMismatch<'a> {
+        expected: &'static str,
+        found: &'a str,
+    }
+
+    impl core::fmt::Display for UnitMismatch<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(
+                f,
+                "unit mismatch: expected '{}', found '{}'",
+                self.expected, self.found
+            )
+        }
+    }
+
+    impl<'de, U: Unit> DeserializeSeed<'de> for UnitSymbol<U> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de, U: Unit> Visitor<'de> for UnitSymbol<U> {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a unit symbol string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+            if v == U::SYMBOL {
+                Ok(())
+            } else {
+                Err(E::custom(UnitMismatch {
+                    expected: U::SYMBOL,
+                    found: v,
+                }))
+            }
+        }
+    }
+
     /// Deserializes a `Quantity<U>` from a struct with `value` and optionally `unit` fields.
     ///
-    /// The `unit` field is validated if present but not required for backwards compatibility.
-    /// If provided and doesn't match `U::SYMBOL`, a warning could be logged in the future.
+    /// The `unit` field is not required (for backwards compatibility), but if present it must match `U::SYMBOL`.
     pub fn deserialize<'de, U, D>(deserializer: D) -> Result<Quantity<U>, D::Error>
     where
         U: Unit,
@@ -430,7 +978,7 @@ pub mod serde_with_unit {
                 V: MapAccess<'de>,
             {
                 let mut value: Option<f64> = None;
-                let mut unit: Option<String> = None;
+                let mut unit_seen = false;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -441,27 +989,17 @@ pub mod serde_with_unit {
                             value = Some(map.next_value()?);
                         }
                         Field::Unit => {
-                            if unit.is_some() {
+                            if unit_seen {
                                 return Err(de::Error::duplicate_field("unit"));
                             }
-                            unit = Some(map.next_value()?);
+                            // Validated if present; optional for backwards compatibility.
+                            map.next_value_seed(UnitSymbol::<U>(core::marker::PhantomData))?;
+                            unit_seen = true;
                         }
                     }
                 }
 
                 let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
-
-                // Validate unit if provided (optional for backwards compatibility)
-                if let Some(ref unit_str) = unit {
-                    if unit_str != U::SYMBOL {
-                        return Err(de::Error::custom(format!(
-                            "unit mismatch: expected '{}', found '{}'",
-                            U::SYMBOL,
-                            unit_str
-                        )));
-                    }
-                }
-
                 Ok(Quantity::new(value))
             }
         }
@@ -473,3 +1011,438 @@ pub mod serde_with_unit {
         )
     }
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Formatting
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Prints `Quantity<Meter>(1500.0 m)`: the unit type without module paths, then the value and symbol.
+impl<U: Unit, S: fmt::Debug> fmt::Debug for Quantity<U, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Quantity<")?;
+        write_type_name(f, core::any::type_name::<U>())?;
+        f.write_str(">(")?;
+        fmt::Debug::fmt(&self.0, f)?;
+        write!(f, "{})", Suffix::<U>::symbol())
+    }
+}
+
+/// Formats the value in scientific notation followed by the unit symbol (long name with `{:#e}`).
+///
+/// ```rust
+/// use qtty_core::length::Meters;
+///
+/// assert_eq!(format!("{:e}", Meters::new(1.5e11)), "1.5e11 m");
+/// assert_eq!(format!("{:.2E}", Meters::new(1_234.0)), "1.23E3 m");
+/// ```
+impl<U: Unit> fmt::LowerExp for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.0, f)?;
+        fmt::Display::fmt(&Suffix::<U>::for_formatter(f), f)
+    }
+}
+
+/// Upper-case counterpart of the [`LowerExp`](fmt::LowerExp) impl.
+impl<U: Unit> fmt::UpperExp for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.0, f)?;
+        fmt::Display::fmt(&Suffix::<U>::for_formatter(f), f)
+    }
+}
+
+/// Writes a `type_name` with every module path stripped (`qtty_core::unit::Per<a::Meter, b::Second>` →
+/// `Per<Meter, Second>`).
+fn write_type_name(f: &mut fmt::Formatter<'_>, mut name: &str) -> fmt::Result {
+    while !name.is_empty() {
+        let end = name
+            .find(['<', '>', ',', ' ', '(', ')', '[', ']', ';', '&'])
+            .unwrap_or(name.len());
+        let (path, rest) = name.split_at(end);
+        f.write_str(path.rsplit("::").next().unwrap_or(path))?;
+        let delimiter = rest.chars().next().map_or(0, char::len_utf8);
+        f.write_str(&rest[..delimiter])?;
+        name = &rest[delimiter..];
+    }
+    Ok(())
+}
+
+/// `" <symbol>"` (or `" <long name>"`), or nothing at all when the unit has an empty symbol.
+struct Suffix<U> {
+    long: bool,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> Suffix<U> {
+    fn symbol() -> Self {
+        Self {
+            long: false,
+            unit: PhantomData,
+        }
+    }
+
+    /// The long name under `{:#…}`, the symbol otherwise, as for `Display`.
+    fn for_formatter(f: &fmt::Formatter<'_>) -> Self {
+        Self {
+            long: f.alternate(),
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<U: Unit> fmt::Display for Suffix<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Writes a leading space before the first non-empty chunk.
+        struct Spaced<'a, 'b> {
+            f: &'a mut fmt::Formatter<'b>,
+            started: bool,
+        }
+
+        impl fmt::Write for Spaced<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                if !self.started && !s.is_empty() {
+                    self.f.write_str(" ")?;
+                    self.started = true;
+                }
+                self.f.write_str(s)
+            }
+        }
+
+        let mut out = Spaced { f, started: false };
+        fmt::Write::write_fmt(
+            &mut out,
+            format_args!("{}", UnitName::<U>(self.long, PhantomData)),
+        )
+    }
+}
+
+/// `U`'s symbol, or its long name when the flag is set, including the expanded form of composite units.
+struct UnitName<U>(bool, PhantomData<U>);
+
+impl<U: Unit> fmt::Display for UnitName<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 {
+            U::fmt_long_name(f)
+        } else {
+            U::fmt_symbol(f)
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// defmt support
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Formats as `<value> <symbol>`, like `Display`, without allocating.
+#[cfg(feature = "defmt")]
+impl<U: Unit> defmt::Format for Quantity<U> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        if U::SYMBOL.is_empty() {
+            // Composite (or unitless) symbols are assembled on the fly.
+            defmt::write!(
+                f,
+                "{=f64}{}",
+                self.0,
+                defmt::Display2Format(&Suffix::<U>::symbol())
+            );
+        } else {
+            defmt::write!(f, "{=f64} {=str}", self.0, U::SYMBOL);
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// bytemuck support
+// ─────────────────────────────────────────────────────────────────────────────
+
+// SAFETY: `Quantity` is `repr(transparent)` over `S` (the `PhantomData` is a 1-aligned ZST), so it has exactly `S`'s
+// layout and bit validity.
+#[cfg(feature = "bytemuck")]
+#[allow(unsafe_code)]
+unsafe impl<U: Unit, S: bytemuck::Zeroable> bytemuck::Zeroable for Quantity<U, S> {}
+
+// SAFETY: as above; `Unit: 'static + Copy` and `S: Pod` cover the remaining `Pod` requirements.
+#[cfg(feature = "bytemuck")]
+#[allow(unsafe_code)]
+unsafe impl<U: Unit, S: bytemuck::Pod> bytemuck::Pod for Quantity<U, S> {}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// num-traits support
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `0` in `U`; every unit shares the same additive identity.
+#[cfg(feature = "num-traits")]
+impl<U: Unit> num_traits::Zero for Quantity<U> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(0.0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+/// Reads the number as a value in `U`.
+#[cfg(feature = "num-traits")]
+impl<U: Unit> num_traits::FromPrimitive for Quantity<U> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::new(n as f64))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::new(n as f64))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::new(n))
+    }
+}
+
+/// Converts the value in `U`, with `f64`'s range checks for the integer targets.
+#[cfg(feature = "num-traits")]
+impl<U: Unit> num_traits::ToPrimitive for Quantity<U> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        num_traits::ToPrimitive::to_i64(&self.0)
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        num_traits::ToPrimitive::to_u64(&self.0)
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// JSON Schema support
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A bare JSON number, matching the default serde form; the description names the unit.
+///
+/// The schema is always inlined, so every unit gets its own description rather than sharing one `$defs` entry.
+#[cfg(feature = "schemars")]
+impl<U: Unit> schemars::JsonSchema for Quantity<U> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Quantity".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("qtty_core::Quantity<{}>", core::any::type_name::<U>()).into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "number",
+            "description": schema_description::<U>(),
+        })
+    }
+}
+
+/// `"Value in m (meter)"`, or `"Dimensionless value"` for an empty symbol.
+#[cfg(feature = "schemars")]
+fn schema_description<U: Unit>() -> String {
+    let symbol = UnitName::<U>(false, PhantomData).to_string();
+    if symbol.is_empty() {
+        "Dimensionless value".into()
+    } else {
+        format!("Value in {symbol} ({})", UnitName::<U>(true, PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::{Kilometer, Meter, Meters};
+    use crate::units::time::{JulianCentury, Second, Seconds};
+    use crate::Unitless;
+
+    #[test]
+    fn suffix() {
+        assert_eq!(
+            format!("{}", Suffix::<Per<Meter, Second>>::symbol()),
+            " m/s"
+        );
+        assert_eq!(format!("{}", Suffix::<Unitless>::symbol()), "");
+    }
+
+    #[test]
+    fn convert_with_residual_reports_rounding() {
+        let (km, residual) = Meters::new(1500.0).convert_with_residual::<Kilometer>();
+        assert_eq!(km.value(), 1.5);
+        assert_eq!(residual.value(), 0.0);
+
+        let start = Seconds::new(1e9 + 1e-7);
+        let (centuries, residual) = start.convert_with_residual::<JulianCentury>();
+        assert_eq!((centuries.to::<Second>() + residual).value(), start.value());
+        assert!(residual.value().abs() < 1e-6);
+    }
+
+    #[test]
+    fn debug_names_the_unit() {
+        assert_eq!(
+            format!("{:?}", Meters::new(1.5e11)),
+            "Quantity<Meter>(150000000000.0 m)"
+        );
+        assert_eq!(
+            format!("{:?}", Quantity::<Per<Kilometer, Second>>::new(2.0)),
+            "Quantity<Per<Kilometer, Second>>(2.0 Km/s)"
+        );
+        assert_eq!(
+            format!("{:?}", Quantity::<Unitless>::new(0.5)),
+            "Quantity<Unitless>(0.5)"
+        );
+        assert_eq!(
+            format!("{:.1?}", Seconds::new(1.25)),
+            "Quantity<Second>(1.2 s)"
+        );
+    }
+
+    #[test]
+    fn exponent_formatting() {
+        assert_eq!(format!("{:e}", Seconds::new(0.001)), "1e-3 s");
+        assert_eq!(format!("{:E}", Meters::new(2.5e-7)), "2.5E-7 m");
+        assert_eq!(format!("{:#e}", Meters::new(1e3)), "1e3 meter");
+        assert_eq!(
+            format!("{:e}", Quantity::<Per<Meter, Second>>::new(3e8)),
+            "3e8 m/s"
+        );
+        assert_eq!(format!("{:e}", Quantity::<Unitless>::new(1e-9)), "1e-9");
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema_names_the_unit() {
+        let schema = schemars::schema_for!(Meters);
+        assert_eq!(schema.get("type"), Some(&serde_json::json!("number")));
+        assert_eq!(
+            schema.get("description"),
+            Some(&serde_json::json!("Value in m (meter)"))
+        );
+
+        let schema = schemars::schema_for!(Quantity<Per<Kilometer, Second>>);
+        assert_eq!(
+            schema.get("description"),
+            Some(&serde_json::json!("Value in Km/s (kilometer per second)"))
+        );
+    }
+
+    #[cfg(all(feature = "schemars", feature = "serde"))]
+    #[test]
+    fn tagged_json_schema() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Config {
+            #[schemars(with = "serde_with_unit::TaggedSchema<Meter>")]
+            max_distance: Meters,
+            min_distance: Seconds,
+        }
+
+        let schema = schemars::schema_for!(Config);
+        let props = schema.get("properties").unwrap();
+        assert_eq!(
+            props["max_distance"]["properties"]["unit"],
+            serde_json::json!({ "const": "m" })
+        );
+        assert_eq!(
+            props["max_distance"]["required"],
+            serde_json::json!(["value"])
+        );
+        assert_eq!(props["min_distance"]["type"], serde_json::json!("number"));
+        assert_eq!(
+            props["min_distance"]["description"],
+            serde_json::json!("Value in s (second)")
+        );
+    }
+
+    #[test]
+    #[deny(improper_ctypes_definitions)]
+    fn ffi_safe_by_value() {
+        extern "C" fn halve(x: Meters) -> Meters {
+            x / 2.0
+        }
+        assert_eq!(halve(Meters::new(3.0)), Meters::new(1.5));
+    }
+
+    #[test]
+    fn le_bytes_round_trip() {
+        for v in [0.0, -0.0, 1.5e11, f64::MIN_POSITIVE, f64::INFINITY] {
+            let bytes = Meters::new(v).to_le_bytes();
+            assert_eq!(bytes, v.to_le_bytes());
+            assert_eq!(Meters::from_le_bytes(bytes).value().to_bits(), v.to_bits());
+        }
+        assert!(Meters::from_le_bytes(f64::NAN.to_le_bytes())
+            .value()
+            .is_nan());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_impls() {
+        use num_traits::{FromPrimitive, ToPrimitive, Zero};
+
+        fn sum<T: Zero + Copy>(xs: &[T]) -> T {
+            xs.iter().fold(T::zero(), |acc, &x| acc + x)
+        }
+        assert_eq!(sum(&[Meters::new(1.5), Meters::new(2.0)]), Meters::new(3.5));
+        assert!(Seconds::zero().is_zero());
+        assert_eq!(Meters::from_u32(7), Some(Meters::new(7.0)));
+        assert_eq!(Meters::new(-2.7).to_i32(), Some(-2));
+        assert_eq!(Meters::new(-2.7).to_u32(), None);
+        assert_eq!(Seconds::new(f64::NAN).to_i64(), None);
+    }
+
+    #[test]
+    fn default_is_zero() {
+        assert_eq!(Meters::default(), Meters::new(0.0));
+        assert!(Quantity::<Per<Kilometer, Second>>::default()
+            .value()
+            .is_sign_positive());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_casts() {
+        let raw = [1.0_f64, 2.0, 3.0];
+        let bytes: &[u8] = bytemuck::cast_slice(&raw);
+        let typed: &[Meters] = bytemuck::cast_slice(bytes);
+        assert_eq!(
+            typed,
+            [Meters::new(1.0), Meters::new(2.0), Meters::new(3.0)]
+        );
+        let zero: Seconds = bytemuck::Zeroable::zeroed();
+        assert_eq!(zero.value(), 0.0);
+        assert_eq!(
+            bytemuck::bytes_of(&typed[1]),
+            2.0_f64.to_ne_bytes().as_slice()
+        );
+    }
+
+    #[test]
+    fn slice_views_share_storage() {
+        let mut raw = [1.0, 2.0, 3.0];
+        let typed = Meters::slice_from_raw_mut(&mut raw);
+        typed[0] = Meters::new(-1.0);
+        assert_eq!(typed.len(), 3);
+        assert_eq!(raw[0], -1.0);
+
+        let empty: &[Seconds] = Seconds::slice_from_raw(&[]);
+        assert!(empty.is_empty());
+
+        let typed = [Meters::new(4.0), Meters::new(5.0)];
+        let back = Meters::slice_as_raw(&typed);
+        assert_eq!(back, [4.0, 5.0]);
+        assert_eq!(back.as_ptr(), typed.as_ptr().cast());
+    }
+}
@@ -0,0 +1,162 @@
+//! Flat conversion-ratio tables for upload to GPU buffers.
+//!
+//! Compute shaders cannot see the unit types, so they convert with plain ratios: `value * ratio[from] / ratio[to]`.
+//! A [`RatioTable`] collects the ratios of a chosen list of units into contiguous `f64` and `f32` arrays, built at
+//! compile time from the same [`Unit`](crate::Unit) definitions the CPU side uses, so the two cannot drift apart. The
+//! symbols and long names ride along for labelling and for checking shader-side indices against the CPU side.
+//!
+//! ```rust
+//! use qtty_core::length::{AstronomicalUnit, Kilometer, Meter};
+//! use qtty_core::ratio_table::RatioTable;
+//!
+//! static LENGTHS: RatioTable<3> = qtty_core::ratio_table![Meter, Kilometer, AstronomicalUnit];
+//!
+//! let ratios: &'static [f32] = LENGTHS.ratios_f32();
+//! assert_eq!(ratios, [1.0, 1_000.0, 149_597_870_700.0_f32]);
+//! assert_eq!(LENGTHS.index_of("Km"), Some(1));
+//!
+//! // On the device: 1 au in km.
+//! let (au, km) = (2, 1);
+//! let km_per_au = 1.0 * ratios[au] / ratios[km];
+//! assert!((km_per_au / 149_597_870.7 - 1.0).abs() < 1e-6);
+//! ```
+
+use crate::UnitInfo;
+
+/// Conversion ratios (in `f64` and `f32`) and symbol metadata for `N` units, in the order given.
+///
+/// Ratios are to the canonical unit of each unit's dimension unless the table has been
+/// [`rebased`](Self::rebased). Mixing dimensions in one table is allowed, but only ratios of units that share a
+/// dimension can be divided meaningfully.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RatioTable<const N: usize> {
+    units: [UnitInfo; N],
+    ratios: [f64; N],
+    ratios_f32: [f32; N],
+}
+
+impl<const N: usize> RatioTable<N> {
+    /// Builds the table from unit descriptions; usually called through [`ratio_table!`](crate::ratio_table!).
+    pub const fn new(units: [UnitInfo; N]) -> Self {
+        let mut ratios = [0.0; N];
+        let mut i = 0;
+        while i < N {
+            ratios[i] = units[i].ratio;
+            i += 1;
+        }
+        Self::with_ratios(units, ratios)
+    }
+
+    const fn with_ratios(units: [UnitInfo; N], ratios: [f64; N]) -> Self {
+        let mut ratios_f32 = [0.0; N];
+        let mut i = 0;
+        while i < N {
+            ratios_f32[i] = ratios[i] as f32;
+            i += 1;
+        }
+        Self {
+            units,
+            ratios,
+            ratios_f32,
+        }
+    }
+
+    /// The same table with every ratio divided by that of entry `base`, so `base` becomes `1.0`.
+    ///
+    /// Canonical ratios can be huge (a solar mass is `~2e33` grams), so `value * ratio[from]` overflows `f32` for
+    /// fairly ordinary values; rebasing on a unit near the working scale keeps that intermediate in range.
+    ///
+    /// # Panics
+    ///
+    /// If `base >= N` (at compile time when used in a `const`/`static`).
+    pub const fn rebased(&self, base: usize) -> Self {
+        let scale = self.ratios[base];
+        let mut ratios = self.ratios;
+        let mut i = 0;
+        while i < N {
+            ratios[i] /= scale;
+            i += 1;
+        }
+        Self::with_ratios(self.units, ratios)
+    }
+
+    /// The ratios as `f64`.
+    #[inline]
+    pub const fn ratios(&self) -> &[f64] {
+        &self.ratios
+    }
+
+    /// The ratios rounded to `f32` (about seven significant digits).
+    #[inline]
+    pub const fn ratios_f32(&self) -> &[f32] {
+        &self.ratios_f32
+    }
+
+    /// Symbol, long name and canonical ratio of each entry.
+    #[inline]
+    pub const fn units(&self) -> &[UnitInfo] {
+        &self.units
+    }
+
+    /// The symbols, in table order.
+    pub fn symbols(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.units.iter().map(|u| u.symbol)
+    }
+
+    /// Index of the entry with the given symbol.
+    pub fn index_of(&self, symbol: &str) -> Option<usize> {
+        self.units.iter().position(|u| u.symbol == symbol)
+    }
+}
+
+/// Builds a [`RatioTable`] from a list of unit types, usable in `const` and `static` items.
+///
+/// ```rust
+/// use qtty_core::time::{Day, Hour, Second};
+///
+/// const TIMES: qtty_core::ratio_table::RatioTable<3> = qtty_core::ratio_table![Second, Hour, Day];
+/// assert_eq!(TIMES.ratios(), [1.0, 3_600.0, 86_400.0]);
+/// ```
+#[macro_export]
+macro_rules! ratio_table {
+    ($($unit:ty),+ $(,)?) => {
+        $crate::ratio_table::RatioTable::new([$($crate::UnitInfo::of::<$unit>()),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::{Kilometer, Meter};
+    use crate::units::mass::{Gram, Kilogram, SolarMass};
+
+    #[test]
+    fn keeps_order_and_metadata() {
+        const T: RatioTable<2> = crate::ratio_table![Kilometer, Meter];
+        assert_eq!(T.ratios(), [1_000.0, 1.0]);
+        assert_eq!(T.symbols().collect::<Vec<_>>(), ["Km", "m"]);
+        assert_eq!(T.units()[0].long_name, "kilometer");
+        assert_eq!(T.index_of("m"), Some(1));
+        assert_eq!(T.index_of("ly"), None);
+    }
+
+    #[test]
+    fn rebasing_keeps_f32_intermediates_finite() {
+        const T: RatioTable<3> = crate::ratio_table![Gram, Kilogram, SolarMass];
+        // 1e6 solar masses, via grams, overflows f32.
+        assert!((1e6_f32 * T.ratios_f32()[2]).is_infinite());
+        let rebased = T.rebased(2);
+        assert_eq!(rebased.ratios()[2], 1.0);
+        let kg = 1e6_f32 * rebased.ratios_f32()[2] / rebased.ratios_f32()[1];
+        assert!((kg / 1.988_4e36 - 1.0).abs() < 1e-3, "{kg}");
+        assert_eq!(rebased.units(), T.units());
+    }
+
+    #[test]
+    fn f32_column_matches_f64() {
+        const T: RatioTable<2> = crate::ratio_table![Meter, Kilometer];
+        for (a, b) in T.ratios().iter().zip(T.ratios_f32()) {
+            assert_eq!(*a as f32, *b);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Argument reduction for angles far outside a single turn.
+//!
+//! Reducing `x` modulo `2π` with `x % TAU` divides by a *rounded* `τ`, so the error grows linearly with the number of
+//! turns removed: at `1e15` rad the result is already wrong in the first decimal. The routines here follow the
+//! Payne–Hanek scheme instead: the fraction of a turn is read off the product of the (exact) mantissa of `x` with a
+//! 128-bit window of the binary expansion of `1/(2π)`, so the reduced value keeps full `f64` precision for every
+//! finite input.
+
+use core::f64::consts::TAU;
+
+/// Low half of `τ` in double-double form (`TAU + TAU_LO ≈ 2π` to ~106 bits).
+const TAU_LO: f64 = 2.449_293_598_294_706_4e-16;
+
+/// `2^-53`.
+const TWO_POW_M53: f64 = 1.0 / 9_007_199_254_740_992.0;
+
+/// The first 1152 bits of `1/(2π)`; word `i` holds bits `64i + 1 ..= 64i + 64` after the binary point.
+///
+/// Enough for the largest finite `f64` (exponent 971 above its 53-bit mantissa) plus a 128-bit window.
+const INV_TAU_BITS: [u64; 18] = [
+    0x28BE_60DB_9391_054A,
+    0x7F09_D5F4_7D4D_3770,
+    0x36D8_A566_4F10_E410,
+    0x7F94_58EA_F7AE_F158,
+    0x6DC9_1B8E_9093_74B8,
+    0x0192_4BBA_8274_6487,
+    0x3F87_7AC7_2C4A_69CF,
+    0xBA20_8D7D_4BAE_D121,
+    0x3A67_1C09_AD17_DF90,
+    0x4E64_758E_60D4_CE7D,
+    0x2721_17E2_EF7E_4A0E,
+    0xC7FE_25FF_F781_6603,
+    0xFBCB_C462_D682_9B47,
+    0xDB4D_9FB3_C9F2_C26D,
+    0xD3D1_8FD9_A797_FA8B,
+    0x5D49_EEB1_FAF9_7C5E,
+    0xCF41_CE7D_E294_A4BA,
+    0x9AFE_D7EC_47E3_5742,
+];
+
+const fn inv_tau_word(i: i64) -> u128 {
+    if i < 0 || i >= INV_TAU_BITS.len() as i64 {
+        0
+    } else {
+        INV_TAU_BITS[i as usize] as u128
+    }
+}
+
+/// 128 bits of `1/(2π)` starting at bit `start` after the binary point (bits at or before the point are zero).
+const fn inv_tau_window(start: i64) -> u128 {
+    let q = (start - 1).div_euclid(64);
+    let r = (start - 1).rem_euclid(64) as u32;
+    let (a, b, c) = (inv_tau_word(q), inv_tau_word(q + 1), inv_tau_word(q + 2));
+    if r == 0 {
+        (a << 64) | b
+    } else {
+        (a << (64 + r)) | (b << r) | (c >> (64 - r))
+    }
+}
+
+/// Splits `a` into two halves whose products with another split value are exact (Dekker).
+const fn split(a: f64) -> (f64, f64) {
+    let c = 134_217_729.0 * a; // 2^27 + 1
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// `a * b` as an unevaluated sum `p + e` with `p = fl(a * b)` and `e` the exact rounding error.
+///
+/// Exact as long as neither the product nor the partial products overflow or underflow; otherwise the error term is
+/// zero.
+pub(crate) const fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    if !p.is_finite() {
+        return (p, 0.0);
+    }
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let e = ((ah * bh - p) + ah * bl + al * bh) + al * bl;
+    // Splitting an operand near f64::MAX overflows; fall back to the plain product.
+    (p, if e.is_finite() { e } else { 0.0 })
+}
+
+/// Fractional part of `x / (2π)` as a double-double, for finite `x ≥ τ`.
+const fn turn_fraction(x: f64) -> (f64, f64) {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1075;
+    let mantissa = ((bits & ((1 << 52) - 1)) | (1 << 52)) as u128;
+    // Bits of 1/(2π) at or above 2^-exponent only contribute whole turns; the wrapping product keeps exactly the
+    // fractional 128 bits of `mantissa * window`.
+    let f = mantissa.wrapping_mul(inv_tau_window(exponent + 1));
+    let hi = (f >> 75) as f64 * TWO_POW_M53;
+    let lo = ((f >> 22) & ((1 << 53) - 1)) as f64 * (TWO_POW_M53 * TWO_POW_M53);
+    (hi, lo)
+}
+
+/// Reduces the double-double radian value `hi + lo` into `[0, τ)`.
+///
+/// Non-finite input yields `NaN`.
+pub(crate) const fn wrap_radians(hi: f64, lo: f64) -> f64 {
+    if !hi.is_finite() || !lo.is_finite() {
+        return f64::NAN;
+    }
+    let a = hi.abs();
+    let r = if a < TAU {
+        let r = hi + lo;
+        if r < 0.0 {
+            r + TAU
+        } else if r >= TAU {
+            r - TAU
+        } else {
+            r
+        }
+    } else {
+        let (fh, fl) = turn_fraction(a);
+        let (mut th, tl) = if hi < 0.0 { (-fh, -fl) } else { (fh, fl) };
+        let tl = tl + lo * (1.0 / TAU);
+        if th + tl < 0.0 {
+            th += 1.0;
+        } else if th + tl >= 1.0 {
+            th -= 1.0;
+        }
+        let (p, e) = two_prod(th, TAU);
+        p + (e + th * TAU_LO + tl * TAU)
+    };
+    // Rounding can land exactly on τ (or a hair below zero) when the true value is within an ulp of a whole turn.
+    if r >= TAU || r < 0.0 {
+        0.0
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_matches_table() {
+        assert_eq!(inv_tau_window(1) >> 64, INV_TAU_BITS[0] as u128);
+        assert_eq!(inv_tau_window(65) >> 64, INV_TAU_BITS[1] as u128);
+        assert_eq!(inv_tau_window(-63), INV_TAU_BITS[0] as u128);
+        assert_eq!(
+            inv_tau_window(5) >> 124,
+            (INV_TAU_BITS[0] >> 56) as u128 & 0xf
+        );
+    }
+
+    #[test]
+    fn two_prod_is_exact() {
+        let (p, e) = two_prod(0.1, 1e17);
+        assert_eq!(p, 1e16);
+        assert_eq!(e, 0.555_111_512_312_578_3);
+    }
+
+    #[test]
+    fn reduces_huge_radians() {
+        // Reference values from a 3000-bit evaluation of x mod 2π.
+        let cases = [
+            (1e15, 2.109_698_117_070_112_6),
+            (-1e15, 4.173_487_190_109_474),
+            (1e22, 5.263_007_914_620_499),
+            (1e300, 4.099_312_823_027_354),
+            (123_456_789.0, 1.430_072_642_774_822_5),
+        ];
+        for (x, expected) in cases {
+            let r = wrap_radians(x, 0.0);
+            assert!(
+                (r - expected).abs() <= 4e-16 * TAU,
+                "{x}: {r} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn small_and_non_finite() {
+        assert_eq!(wrap_radians(1.0, 0.0), 1.0);
+        assert_eq!(wrap_radians(-1.0, 0.0), TAU - 1.0);
+        assert_eq!(wrap_radians(TAU, 0.0), 0.0);
+        assert!(wrap_radians(f64::INFINITY, 0.0).is_nan());
+        assert!(wrap_radians(f64::NAN, 0.0).is_nan());
+    }
+}
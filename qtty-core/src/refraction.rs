@@ -0,0 +1,160 @@
+//! Atmospheric refraction near the horizon.
+//!
+//! Refraction lifts every object above its geometric (true) altitude, by about 34′ at the horizon and less than 1′
+//! above 45°. [`apparent_altitude`] applies Sæmundsson's formula to a true altitude, and [`true_altitude`] removes
+//! Bennett's formula from an observed one (Meeus, *Astronomical Algorithms*, ch. 16). Both are calibrated for
+//! 1010 mbar and 10 °C and scaled by `(P / 1010 mbar) · (283 K / T)` for other conditions, which is good to a few
+//! tenths of an arcminute near the horizon under ordinary weather. Pressure and temperature may be given in any
+//! [`pressure`](crate::pressure) and [`temperature`](crate::temperature) unit.
+//!
+//! ```rust
+//! use qtty_core::angular::Degrees;
+//! use qtty_core::pressure::Hectopascals;
+//! use qtty_core::refraction::{apparent_altitude, true_altitude};
+//! use qtty_core::temperature::Kelvins;
+//!
+//! let (p, t) = (Hectopascals::new(1_010.0), Kelvins::from_celsius(10.0));
+//! // The Sun's upper limb on the geometric horizon already appears ~29′ up.
+//! let seen = apparent_altitude(Degrees::new(0.0), p, t);
+//! assert!((seen.value() * 60.0 - 28.9).abs() < 0.1);
+//! assert!(true_altitude(seen, p, t).value().abs() < 0.01);
+//! ```
+
+use crate::units::angular::{Arcminutes, Degree, Degrees};
+use crate::units::pressure::{Millibar, PressureUnit};
+use crate::units::temperature::{Kelvin, TemperatureUnit};
+use crate::Quantity;
+
+/// Lowest altitude the formulas are evaluated at; both diverge a few degrees below the horizon.
+const MIN_ALTITUDE: f64 = -1.0;
+
+/// `(P / 1010 mbar) · (283 K / T)`, the factor scaling the standard-condition refraction.
+fn weather_factor<P: PressureUnit, T: TemperatureUnit>(
+    pressure: Quantity<P>,
+    temperature: Quantity<T>,
+) -> f64 {
+    pressure.to::<Millibar>().value() / 1_010.0 * (283.15 / temperature.to::<Kelvin>().value())
+}
+
+/// Refraction for an object at true altitude `h` under standard conditions (Sæmundsson, Meeus eq. 16.4).
+///
+/// The constant term makes the refraction vanish at the zenith.
+fn saemundsson(h: Degrees) -> Arcminutes {
+    let h = h.value().max(MIN_ALTITUDE);
+    Arcminutes::new(1.02 / Degrees::new(h + 10.3 / (h + 5.11)).tan() + 0.001_927_9)
+}
+
+/// Refraction for an object seen at apparent altitude `h0` under standard conditions (Bennett, Meeus eq. 16.3).
+///
+/// The constant term makes the refraction vanish at the zenith.
+fn bennett(h0: Degrees) -> Arcminutes {
+    let h0 = h0.value().max(MIN_ALTITUDE);
+    Arcminutes::new(1.0 / Degrees::new(h0 + 7.31 / (h0 + 4.4)).tan() + 0.001_351_5)
+}
+
+/// The altitude at which an object at `true_alt` is seen, given the air pressure and temperature at the observer.
+///
+/// Altitudes below −1° are refracted as at −1°.
+pub fn apparent_altitude<P: PressureUnit, T: TemperatureUnit>(
+    true_alt: Degrees,
+    pressure: Quantity<P>,
+    temperature: Quantity<T>,
+) -> Degrees {
+    let r = saemundsson(true_alt) * weather_factor(pressure, temperature);
+    true_alt + r.to::<Degree>()
+}
+
+/// The true (geometric) altitude of an object seen at `apparent_alt`, given the air pressure and temperature at the
+/// observer. The inverse of [`apparent_altitude`] to within 0.07′ (4″).
+///
+/// Altitudes below −1° are refracted as at −1°.
+pub fn true_altitude<P: PressureUnit, T: TemperatureUnit>(
+    apparent_alt: Degrees,
+    pressure: Quantity<P>,
+    temperature: Quantity<T>,
+) -> Degrees {
+    let r = bennett(apparent_alt) * weather_factor(pressure, temperature);
+    apparent_alt - r.to::<Degree>()
+}
+
+/// The refraction `apparent − true` for an object at `true_alt`, as an angle.
+pub fn refraction<P: PressureUnit, T: TemperatureUnit>(
+    true_alt: Degrees,
+    pressure: Quantity<P>,
+    temperature: Quantity<T>,
+) -> Arcminutes {
+    saemundsson(true_alt) * weather_factor(pressure, temperature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::pressure::{Atmospheres, Millibars};
+    use crate::units::temperature::Kelvins;
+    use approx::assert_abs_diff_eq;
+
+    fn standard() -> (Millibars, Kelvins) {
+        (Millibars::new(1_010.0), Kelvins::from_celsius(10.0))
+    }
+
+    #[test]
+    fn meeus_example_16a() {
+        // Apparent altitude 0°30′ → refraction 28.754′ (Bennett), true altitude 0.0208°.
+        let (p, t) = standard();
+        let h = true_altitude(Degrees::new(0.5), p, t);
+        assert_abs_diff_eq!((0.5 - h.value()) * 60.0, 28.754, epsilon = 0.01);
+    }
+
+    #[test]
+    fn zenith_and_high_altitudes() {
+        let (p, t) = standard();
+        assert_abs_diff_eq!(
+            refraction(Degrees::new(90.0), p, t).value(),
+            0.0,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            true_altitude(Degrees::new(90.0), p, t).value(),
+            90.0,
+            epsilon = 1e-6
+        );
+        // About 1′ at 45°.
+        assert_abs_diff_eq!(
+            refraction(Degrees::new(45.0), p, t).value(),
+            1.01,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn formulas_invert_each_other() {
+        let (p, t) = standard();
+        for h in [-0.5, 0.0, 1.0, 5.0, 15.0, 40.0, 80.0] {
+            let seen = apparent_altitude(Degrees::new(h), p, t);
+            let back = true_altitude(seen, p, t);
+            assert_abs_diff_eq!(back.value() * 60.0, h * 60.0, epsilon = 0.07);
+        }
+    }
+
+    #[test]
+    fn weather_scaling() {
+        let (p, t) = standard();
+        let r0 = refraction(Degrees::new(2.0), p, t).value();
+        // Thinner, warmer air refracts less.
+        let thin = refraction(Degrees::new(2.0), Millibars::new(505.0), t).value();
+        assert_abs_diff_eq!(thin, r0 / 2.0, epsilon = 1e-12);
+        let warm = refraction(Degrees::new(2.0), p, Kelvins::from_celsius(30.0)).value();
+        assert!(warm < r0);
+        // Units only change the inputs, not the answer.
+        let atm = refraction(Degrees::new(2.0), Atmospheres::new(1_010.0 / 1_013.25), t).value();
+        assert_abs_diff_eq!(atm, r0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn far_below_the_horizon_stays_finite() {
+        let (p, t) = standard();
+        let r = refraction(Degrees::new(-5.11), p, t);
+        assert!(r.value().is_finite());
+        assert_eq!(r, refraction(Degrees::new(-1.0), p, t));
+    }
+}
@@ -0,0 +1,379 @@
+//! Runtime registry of the predefined units.
+//!
+//! Every named built-in unit is listed once, next to its definition, in a per-module table; derived dimensions also list
+//! their common composite aliases (`Per`/`Prod`) under the symbol they print with. The registry stitches those
+//! tables together so code that only has a symbol string (parsers, tagged serialization, FFI shims) can find the
+//! unit, its dimension, and its conversion ratio, and so code holding a unit *type* can find the matching entry by
+//! [`TypeId`](core::any::TypeId).
+//!
+//! ```rust
+//! use qtty_core::length::Kilometer;
+//! use qtty_core::registry;
+//!
+//! let km = registry::find_by_symbol("Km").unwrap();
+//! assert_eq!(km.dimension_name, "length");
+//! assert!(km.is::<Kilometer>());
+//!
+//! let au = registry::find_by_symbol("au").unwrap();
+//! assert_eq!(au.convert_to(1.0, km), Some(149_597_870.7));
+//! ```
+
+use crate::unit::{apply_exact, exact_factor};
+use crate::units::{
+    angular, angular_acceleration, area, density, flux, frequency, grav_param, length, mass, power,
+    pressure, temperature, time, velocity, volume,
+};
+use crate::{Unit, UnitInfo};
+use core::any::TypeId;
+use core::fmt;
+
+/// A registered unit: its [`UnitInfo`] plus the type identities needed to relate it to other units at runtime.
+#[derive(Clone, Copy, Debug)]
+pub struct RegisteredUnit {
+    /// Symbol, long name and ratio of the unit.
+    pub info: UnitInfo,
+    /// Lowercase name of the unit's dimension (e.g. `"length"`).
+    pub dimension_name: &'static str,
+    unit_type: fn() -> TypeId,
+    dimension_type: fn() -> TypeId,
+    #[cfg_attr(not(test), allow(dead_code))]
+    fmt_symbol: fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+}
+
+fn type_id_of<T: ?Sized + 'static>() -> TypeId {
+    TypeId::of::<T>()
+}
+
+impl RegisteredUnit {
+    /// Builds the registry entry for unit `U` in the dimension called `dimension_name`.
+    pub const fn of<U: Unit>(dimension_name: &'static str) -> Self
+    where
+        U::Dim: 'static,
+    {
+        Self {
+            info: UnitInfo::of::<U>(),
+            dimension_name,
+            unit_type: type_id_of::<U>,
+            dimension_type: type_id_of::<U::Dim>,
+            fmt_symbol: U::fmt_symbol,
+        }
+    }
+
+    /// Registers the entry under `symbol`, for composite units (`Per`, `Prod`) whose [`Unit::SYMBOL`] is empty.
+    ///
+    /// `symbol` must be what [`Unit::fmt_symbol`] prints; the empty long name is replaced by it as well.
+    pub const fn with_symbol(mut self, symbol: &'static str) -> Self {
+        self.info.symbol = symbol;
+        if self.info.long_name.is_empty() {
+            self.info.long_name = symbol;
+        }
+        self
+    }
+
+    /// The unit's symbol.
+    #[inline]
+    pub fn symbol(&self) -> &'static str {
+        self.info.symbol
+    }
+
+    /// [`TypeId`] of the unit marker type.
+    #[inline]
+    pub fn type_id(&self) -> TypeId {
+        (self.unit_type)()
+    }
+
+    /// [`TypeId`] of the unit's dimension.
+    #[inline]
+    pub fn dimension_id(&self) -> TypeId {
+        (self.dimension_type)()
+    }
+
+    /// Returns `true` if this entry describes unit `U`.
+    #[inline]
+    pub fn is<U: Unit>(&self) -> bool {
+        self.type_id() == TypeId::of::<U>()
+    }
+
+    /// Returns `true` if both units share a dimension.
+    #[inline]
+    pub fn same_dimension(&self, other: &RegisteredUnit) -> bool {
+        self.dimension_id() == other.dimension_id()
+    }
+
+    /// Converts `value` from this unit into `target`, or `None` if the dimensions differ.
+    ///
+    /// Uses the exact integer factor when both units define one, exactly as [`Quantity::to`](crate::Quantity::to)
+    /// does, so the result matches the typed conversion bit for bit.
+    #[inline]
+    pub fn convert_to(&self, value: f64, target: &RegisteredUnit) -> Option<f64> {
+        if !self.same_dimension(target) {
+            return None;
+        }
+        let (from, to) = (self.info, target.info);
+        Some(
+            match exact_factor(from.ratio_num, from.ratio_den, to.ratio_num, to.ratio_den) {
+                Some(factor) => apply_exact(value, factor),
+                None => value * (from.ratio / to.ratio),
+            },
+        )
+    }
+
+    /// Worst-case relative error of converting a value into `target` and back, or `None` if the dimensions differ.
+    ///
+    /// See [`UnitInfo::round_trip_error`].
+    #[inline]
+    pub fn conversion_error_to(&self, target: &RegisteredUnit) -> Option<f64> {
+        self.same_dimension(target)
+            .then(|| self.info.round_trip_error(&target.info))
+    }
+}
+
+static TABLES: &[&[RegisteredUnit]] = &[
+    angular::REGISTRY,
+    angular_acceleration::REGISTRY,
+    area::REGISTRY,
+    density::REGISTRY,
+    flux::REGISTRY,
+    flux::SPECTRAL_REGISTRY,
+    flux::nominal::REGISTRY,
+    frequency::REGISTRY,
+    grav_param::REGISTRY,
+    length::REGISTRY,
+    length::nominal::REGISTRY,
+    mass::REGISTRY,
+    power::REGISTRY,
+    pressure::REGISTRY,
+    temperature::REGISTRY,
+    time::REGISTRY,
+    velocity::REGISTRY,
+    volume::REGISTRY,
+];
+
+/// Iterates over every registered unit.
+pub fn iter() -> impl Iterator<Item = &'static RegisteredUnit> {
+    TABLES.iter().flat_map(|table| table.iter())
+}
+
+/// Looks up a unit by its exact [`Unit::SYMBOL`].
+pub fn find_by_symbol(symbol: &str) -> Option<&'static RegisteredUnit> {
+    iter().find(|u| u.info.symbol == symbol)
+}
+
+/// Looks up a unit by symbol, falling back to an ASCII case-insensitive match when exactly one unit matches that way.
+///
+/// Suited to hand-written input such as config files: `"km"` finds the `"Km"` entry, while `"MM"` stays unresolved
+/// because it could mean either `"Mm"` or `"mm"`.
+pub fn lookup(symbol: &str) -> Option<&'static RegisteredUnit> {
+    find_by_symbol(symbol).or_else(|| {
+        let mut matches = iter().filter(|u| u.info.symbol.eq_ignore_ascii_case(symbol));
+        let first = matches.next()?;
+        matches.next().is_none().then_some(first)
+    })
+}
+
+/// Metadata of the unit [`lookup`] resolves `symbol` to.
+///
+/// ```rust
+/// use qtty_core::registry;
+///
+/// assert_eq!(registry::lookup_unit("km").unwrap().ratio, 1_000.0);
+/// assert!(registry::lookup_unit("MM").is_none());
+/// ```
+pub fn lookup_unit(symbol: &str) -> Option<UnitInfo> {
+    lookup(symbol).map(|u| u.info)
+}
+
+/// Looks up the entry for unit type `U`, if it is registered.
+pub fn find<U: Unit>() -> Option<&'static RegisteredUnit> {
+    let id = TypeId::of::<U>();
+    iter().find(|u| u.type_id() == id)
+}
+
+/// Iterates over the registered units whose dimension is named `dimension_name`.
+pub fn in_dimension(dimension_name: &str) -> impl Iterator<Item = &'static RegisteredUnit> + '_ {
+    iter().filter(move |u| u.dimension_name == dimension_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Degree, Radian};
+    use crate::units::length::{nominal::SolarRadius, Kilometer, Meter};
+    use crate::units::time::{Day, Second};
+    use crate::Quantity;
+    use approx::assert_relative_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn symbols_are_unique() {
+        let all: Vec<_> = iter().collect();
+        for (i, a) in all.iter().enumerate() {
+            for b in &all[i + 1..] {
+                assert_ne!(a.symbol(), b.symbol(), "duplicate symbol");
+            }
+        }
+    }
+
+    #[test]
+    fn symbols_match_the_printed_symbol() {
+        struct Symbol(fn(&mut fmt::Formatter<'_>) -> fmt::Result);
+        impl fmt::Display for Symbol {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                (self.0)(f)
+            }
+        }
+
+        for unit in iter() {
+            assert!(!unit.symbol().is_empty());
+            assert_eq!(Symbol(unit.fmt_symbol).to_string(), unit.symbol());
+        }
+    }
+
+    #[test]
+    fn lookup_by_type_and_symbol_agree() {
+        let by_type = find::<Day>().unwrap();
+        let by_symbol = find_by_symbol("d").unwrap();
+        assert_eq!(by_type.type_id(), by_symbol.type_id());
+        assert_eq!(by_type.dimension_name, "time");
+        assert!(find::<SolarRadius>().is_some());
+    }
+
+    #[test]
+    fn unregistered_lookups_fail() {
+        assert!(find_by_symbol("furlong").is_none());
+        assert!(find::<crate::Unitless>().is_none());
+    }
+
+    #[test]
+    fn lookup_falls_back_to_unique_case_insensitive_match() {
+        assert_eq!(lookup("Km").unwrap().symbol(), "Km");
+        assert_eq!(lookup("km").unwrap().symbol(), "Km");
+        assert_eq!(lookup("Mm").unwrap().symbol(), "Mm");
+        assert!(lookup("MM").is_none());
+        assert_eq!(lookup_unit("AU").unwrap().symbol, "au");
+    }
+
+    #[test]
+    fn convert_checks_dimension() {
+        let d = find::<Day>().unwrap();
+        let s = find::<Second>().unwrap();
+        assert_relative_eq!(d.convert_to(1.0, s).unwrap(), 86_400.0);
+        assert!(d.convert_to(1.0, find::<Meter>().unwrap()).is_none());
+        assert!(find::<Degree>()
+            .unwrap()
+            .same_dimension(find_by_symbol("Rad").unwrap()));
+    }
+
+    #[test]
+    fn dimensions_are_consistent() {
+        for unit in iter() {
+            let first = in_dimension(unit.dimension_name).next().unwrap();
+            assert!(unit.same_dimension(first));
+        }
+        assert!(in_dimension("length").count() > 40);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Dimensional consistency audit
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ratios_are_finite_and_positive() {
+        for unit in iter() {
+            let ratio = unit.info.ratio;
+            assert!(
+                ratio.is_finite() && ratio > 0.0,
+                "{}: ratio {ratio}",
+                unit.symbol()
+            );
+        }
+    }
+
+    #[test]
+    fn exact_ratios_agree_with_float_ratios() {
+        for unit in iter().filter(|u| u.info.ratio_num != 0) {
+            let info = unit.info;
+            assert_ne!(info.ratio_den, 0, "{}: zero denominator", info.symbol);
+            let exact = info.ratio_num as f64 / info.ratio_den as f64;
+            assert!(
+                (exact - info.ratio).abs() <= 1e-15 * info.ratio,
+                "{}: RATIO {} but RATIO_NUM / RATIO_DEN = {}/{} = {exact}",
+                info.symbol,
+                info.ratio,
+                info.ratio_num,
+                info.ratio_den
+            );
+        }
+    }
+
+    #[test]
+    fn conversions_match_typed_conversions() {
+        let (day, second) = (find::<Day>().unwrap(), find::<Second>().unwrap());
+        let (deg, rad) = (find::<Degree>().unwrap(), find::<Radian>().unwrap());
+        for value in [0.1, 1.0 / 3.0, -7.25e9] {
+            let typed = Quantity::<Day>::new(value).to::<Second>().value();
+            assert_eq!(day.convert_to(value, second), Some(typed));
+            let typed = Quantity::<Second>::new(value).to::<Day>().value();
+            assert_eq!(second.convert_to(value, day), Some(typed));
+            let typed = Quantity::<Degree>::new(value).to::<Radian>().value();
+            assert_eq!(deg.convert_to(value, rad), Some(typed));
+        }
+    }
+
+    #[test]
+    fn round_trip_error_bounds() {
+        let (m, km) = (find::<Meter>().unwrap(), find::<Kilometer>().unwrap());
+        assert_eq!(m.conversion_error_to(m), Some(0.0));
+        assert_eq!(
+            m.conversion_error_to(km),
+            Some(Meter::conversion_error_to::<Kilometer>())
+        );
+        assert_eq!(m.conversion_error_to(find::<Second>().unwrap()), None);
+        for a in iter() {
+            for b in iter().filter(|b| a.same_dimension(b)) {
+                let bound = a.conversion_error_to(b).unwrap();
+                assert!(
+                    bound <= 4.5e-16,
+                    "{} <-> {}: {bound:e}",
+                    a.symbol(),
+                    b.symbol()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_round_trips_stay_within_the_published_bound(value in -1e12..1e12f64) {
+            for a in iter() {
+                for b in iter().filter(|b| a.same_dimension(b)) {
+                    let back = b.convert_to(a.convert_to(value, b).unwrap(), a).unwrap();
+                    let bound = a.conversion_error_to(b).unwrap();
+                    prop_assert!(
+                        (back - value).abs() <= bound * value.abs(),
+                        "{value} {} <-> {}: {back} exceeds {bound:e}",
+                        a.symbol(),
+                        b.symbol()
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn prop_same_dimension_pairs_round_trip(value in -1e12..1e12f64) {
+            for a in iter() {
+                for b in iter().filter(|b| a.same_dimension(b)) {
+                    let there = a.convert_to(value, b).unwrap();
+                    prop_assert!(there.is_finite(), "{} -> {}", a.symbol(), b.symbol());
+                    let back = b.convert_to(there, a).unwrap();
+                    prop_assert!(
+                        (back - value).abs() <= 1e-12 * value.abs(),
+                        "{value} {} -> {there} {} -> {back}",
+                        a.symbol(),
+                        b.symbol()
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Serde `with`-modules that pin the stored unit of a field.
+//!
+//! A plain `Quantity<U>` serializes as a bare number in `U`. When the wire format fixes a different unit (a database
+//! column in kilometres, a catalogue in milliarcseconds) but the tagged [`serde_with_unit`](crate::serde_with_unit)
+//! form is not an option, annotate the field with the module of the stored unit: the value is converted into that
+//! unit on serialization and back into the field's unit on deserialization.
+//!
+//! ```rust
+//! use qtty_core::length::Meters;
+//! use qtty_core::time::Seconds;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Pass {
+//!     #[serde(with = "qtty_core::serde_as::kilometers")]
+//!     range: Meters,
+//!     #[serde(with = "qtty_core::serde_as::minutes")]
+//!     duration: Seconds,
+//! }
+//!
+//! let pass = Pass { range: Meters::new(1500.0), duration: Seconds::new(90.0) };
+//! let json = serde_json::to_string(&pass).unwrap();
+//! assert_eq!(json, r#"{"range":1.5,"duration":1.5}"#);
+//!
+//! let back: Pass = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.range.value(), 1500.0);
+//! ```
+//!
+//! Modules for other units are generated with [`serde_as_unit!`](crate::serde_as_unit):
+//!
+//! ```rust
+//! use qtty_core::length::Meters;
+//! use qtty_core::length::nominal::SolarRadius;
+//!
+//! qtty_core::serde_as_unit!(pub solar_radii, SolarRadius);
+//!
+//! #[derive(serde::Serialize)]
+//! struct Star {
+//!     #[serde(with = "solar_radii")]
+//!     radius: Meters,
+//! }
+//! # fn main() {}
+//! ```
+
+use crate::dimension::SameDimension;
+use crate::{Quantity, Unit};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[doc(hidden)]
+pub use serde::{Deserializer as __Deserializer, Serializer as __Serializer};
+
+/// Serializes `quantity` as a bare number in the stored unit `T`.
+pub fn serialize_as<T, U, S>(quantity: &Quantity<U>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Unit,
+    U: Unit,
+    U::Dim: SameDimension<T::Dim>,
+    S: Serializer,
+{
+    quantity.to::<T>().value().serialize(serializer)
+}
+
+/// Deserializes a bare number in the stored unit `T` into a `Quantity<U>`.
+pub fn deserialize_as<'de, T, U, D>(deserializer: D) -> Result<Quantity<U>, D::Error>
+where
+    T: Unit,
+    U: Unit,
+    T::Dim: SameDimension<U::Dim>,
+    D: Deserializer<'de>,
+{
+    let value = f64::deserialize(deserializer)?;
+    Ok(Quantity::<T>::new(value).to::<U>())
+}
+
+/// Defines a serde `with`-module storing quantities of the unit's dimension as bare numbers in that unit.
+///
+/// ```text
+/// serde_as_unit!(pub kilometers, Kilometer);
+/// ```
+#[macro_export]
+macro_rules! serde_as_unit {
+    ($(#[$meta:meta])* $vis:vis $module:ident, $unit:ty $(,)?) => {
+        $(#[$meta])*
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Serializes the quantity as a bare number in the module's unit.
+            pub fn serialize<U, S>(
+                quantity: &$crate::Quantity<U>,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                U: $crate::Unit,
+                U::Dim: $crate::SameDimension<<$unit as $crate::Unit>::Dim>,
+                S: $crate::serde_as::__Serializer,
+            {
+                $crate::serde_as::serialize_as::<$unit, U, S>(quantity, serializer)
+            }
+
+            /// Deserializes a bare number in the module's unit.
+            pub fn deserialize<'de, U, D>(
+                deserializer: D,
+            ) -> ::core::result::Result<$crate::Quantity<U>, D::Error>
+            where
+                U: $crate::Unit,
+                <$unit as $crate::Unit>::Dim: $crate::SameDimension<U::Dim>,
+                D: $crate::serde_as::__Deserializer<'de>,
+            {
+                $crate::serde_as::deserialize_as::<$unit, U, D>(deserializer)
+            }
+        }
+    };
+}
+
+use crate::angular::{Arcsecond, Degree, HourAngle, MicroArcsecond, MilliArcsecond, Radian};
+use crate::length::{AstronomicalUnit, Kilometer, LightYear, Meter, Parsec};
+use crate::mass::{Kilogram, SolarMass};
+use crate::time::{Day, Hour, JulianYear, Minute, Second};
+use crate::Per;
+
+serde_as_unit!(
+    /// Lengths stored in metres.
+    pub meters, Meter
+);
+serde_as_unit!(
+    /// Lengths stored in kilometres.
+    pub kilometers, Kilometer
+);
+serde_as_unit!(
+    /// Lengths stored in astronomical units.
+    pub astronomical_units, AstronomicalUnit
+);
+serde_as_unit!(
+    /// Lengths stored in light-years.
+    pub light_years, LightYear
+);
+serde_as_unit!(
+    /// Lengths stored in parsecs.
+    pub parsecs, Parsec
+);
+serde_as_unit!(
+    /// Durations stored in seconds.
+    pub seconds, Second
+);
+serde_as_unit!(
+    /// Durations stored in minutes.
+    pub minutes, Minute
+);
+serde_as_unit!(
+    /// Durations stored in hours.
+    pub hours, Hour
+);
+serde_as_unit!(
+    /// Durations stored in days.
+    pub days, Day
+);
+serde_as_unit!(
+    /// Durations stored in Julian years.
+    pub julian_years, JulianYear
+);
+serde_as_unit!(
+    /// Angles stored in degrees.
+    pub degrees, Degree
+);
+serde_as_unit!(
+    /// Angles stored in radians.
+    pub radians, Radian
+);
+serde_as_unit!(
+    /// Angles stored in hours of right ascension.
+    pub hour_angles, HourAngle
+);
+serde_as_unit!(
+    /// Angles stored in arcseconds.
+    pub arcseconds, Arcsecond
+);
+serde_as_unit!(
+    /// Angles stored in milliarcseconds.
+    pub milliarcseconds, MilliArcsecond
+);
+serde_as_unit!(
+    /// Angles stored in microarcseconds.
+    pub microarcseconds, MicroArcsecond
+);
+serde_as_unit!(
+    /// Masses stored in kilograms.
+    pub kilograms, Kilogram
+);
+serde_as_unit!(
+    /// Masses stored in solar masses.
+    pub solar_masses, SolarMass
+);
+serde_as_unit!(
+    /// Velocities stored in metres per second.
+    pub m_per_s, Per<Meter, Second>
+);
+serde_as_unit!(
+    /// Velocities stored in kilometres per second.
+    pub km_per_s, Per<Kilometer, Second>
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::angular::Degrees;
+    use crate::length::{Kilometer, Meters};
+    use crate::time::Second;
+    use crate::velocity::Velocity;
+    use approx::assert_relative_eq;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Star {
+        #[serde(with = "super::milliarcseconds")]
+        parallax: Degrees,
+        #[serde(with = "super::m_per_s")]
+        radial_velocity: Velocity<Kilometer, Second>,
+        #[serde(with = "super::kilometers")]
+        radius: Meters,
+    }
+
+    #[test]
+    fn converts_into_and_out_of_the_stored_unit() {
+        let star = Star {
+            parallax: Degrees::new(1.0 / 3_600_000.0),
+            radial_velocity: Velocity::new(-12.5),
+            radius: Meters::new(6.957e8),
+        };
+        let json = serde_json::to_value(&star).unwrap();
+        assert_relative_eq!(json["parallax"].as_f64().unwrap(), 1.0);
+        assert_relative_eq!(json["radial_velocity"].as_f64().unwrap(), -12_500.0);
+        assert_relative_eq!(json["radius"].as_f64().unwrap(), 695_700.0);
+
+        let back: Star = serde_json::from_value(json).unwrap();
+        assert_relative_eq!(back.parallax.value(), star.parallax.value());
+        assert_relative_eq!(back.radial_velocity.value(), -12.5);
+        assert_relative_eq!(back.radius.value(), 6.957e8);
+    }
+
+    #[test]
+    fn rejects_non_numbers() {
+        let err = serde_json::from_str::<Star>(
+            r#"{"parallax":"1 mas","radial_velocity":0.0,"radius":1.0}"#,
+        );
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,981 @@
+//! Dimension-checked polynomial and harmonic series for ephemeris-style expressions.
+//!
+//! Fundamental arguments, precession angles and sidereal time are all polynomials in time, usually Julian centuries
+//! since J2000.0, with coefficients quoted in `″`, `″/cy`, `″/cy²`, …. A [`Polynomial<In, Out, N>`] holds `N` such
+//! coefficients; each one is added with [`Polynomial::term`], whose argument must have the dimension of
+//! `Out / In^k`, so a coefficient in the wrong slot fails to compile. Coefficients may be given in any unit of the
+//! right dimension and are converted on the way in.
+//!
+//! ```rust
+//! use qtty_core::angular::{Arcsecond, Arcseconds};
+//! use qtty_core::series::Polynomial;
+//! use qtty_core::time::{JulianCenturies, JulianCentury};
+//! use qtty_core::{Per, Prod, Quantity};
+//!
+//! type Cy = JulianCentury;
+//! // IAU 1976 precession angle ζ_A = 2306.2181″ T + 0.30188″ T² + 0.017998″ T³.
+//! let zeta = Polynomial::<Cy, Arcsecond, 1>::constant(Arcseconds::new(0.0))
+//!     .term(Quantity::<Per<Arcsecond, Cy>>::new(2306.2181))
+//!     .term(Quantity::<Per<Arcsecond, Prod<Cy, Cy>>>::new(0.30188))
+//!     .term(Quantity::<Per<Arcsecond, Prod<Prod<Cy, Cy>, Cy>>>::new(0.017998));
+//!
+//! let z = zeta.eval(JulianCenturies::new(0.5));
+//! assert!((z.value() - 1153.186_7).abs() < 1e-3);
+//! ```
+//!
+//! A coefficient whose dimension does not match its degree is rejected:
+//!
+//! ```compile_fail
+//! use qtty_core::angular::{Arcsecond, Arcseconds};
+//! use qtty_core::series::Polynomial;
+//! use qtty_core::time::JulianCentury;
+//!
+//! // The linear coefficient must be an angle per time, not an angle.
+//! let p = Polynomial::<JulianCentury, Arcsecond, 1>::constant(Arcseconds::new(0.0))
+//!     .term(Arcseconds::new(2306.2181));
+//! ```
+//!
+//! Periodic corrections (nutation, aberration, the TDB − TT offset) are sums of sines instead; a [`HarmonicSeries`]
+//! borrows a table of [`HarmonicTerm`]s, each with an amplitude in `Out`, a phase and an angular rate per `In`:
+//!
+//! ```rust
+//! use qtty_core::angular::{Arcsecond, Arcseconds, Degree, Degrees};
+//! use qtty_core::series::{HarmonicSeries, HarmonicTerm};
+//! use qtty_core::time::{JulianCenturies, JulianCentury};
+//! use qtty_core::{Per, Quantity};
+//!
+//! type DegPerCy = Per<Degree, JulianCentury>;
+//! // Leading terms of the nutation in longitude: −17.20″ sin Ω − 1.32″ sin 2L.
+//! const DPSI: [HarmonicTerm<JulianCentury, Arcsecond>; 2] = [
+//!     HarmonicTerm::new(Arcseconds::new(-17.20), Degrees::new(125.044_52), Quantity::<DegPerCy>::new(-1_934.136_261)),
+//!     HarmonicTerm::new(Arcseconds::new(-1.32), Degrees::new(560.933), Quantity::<DegPerCy>::new(72_001.539_6)),
+//! ];
+//! let dpsi = HarmonicSeries::new(&DPSI).eval(JulianCenturies::new(-0.127_296_372));
+//! // 1987-04-10: the two terms give −4.12″; Meeus's full series gives −3.788″.
+//! assert!((dpsi.value() + 4.12).abs() < 0.01);
+//! ```
+//!
+//! Tabulated series (an ephemeris sampled daily, a light curve) are resampled onto new times with
+//! [`resample_linear`], [`resample_nearest`] or [`resample_previous`]; the knots and the result stay typed:
+//!
+//! ```rust
+//! use qtty_core::length::AstronomicalUnits;
+//! use qtty_core::series::resample_linear;
+//! use qtty_core::time::Days;
+//!
+//! let t = [0.0, 1.0, 2.0].map(Days::new);
+//! let r = [1.00, 1.02, 1.01].map(AstronomicalUnits::new);
+//! let hourly: Vec<AstronomicalUnits> = resample_linear(&t, &r, &[Days::new(0.25), Days::new(1.5)]).collect();
+//! assert!((hourly[0].value() - 1.005).abs() < 1e-12);
+//! assert!((hourly[1].value() - 1.015).abs() < 1e-12);
+//! ```
+
+use crate::units::angular::{Angular, Degree, Degrees};
+use crate::{DivDim, Per, Prod, Quantity, SameDimension, Unit};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A polynomial `c₀ + c₁·t + … + c_{N−1}·t^{N−1}` mapping quantities in `In` to quantities in `Out`.
+///
+/// Coefficient `k` is stored as a plain `f64` in the unit `Out / In^k`.
+pub struct Polynomial<In: Unit, Out: Unit, const N: usize> {
+    coeffs: [f64; N],
+    _units: PhantomData<(In, Out)>,
+}
+
+impl<In: Unit, Out: Unit, const N: usize> Polynomial<In, Out, N> {
+    /// Builds a polynomial from raw coefficients, `coeffs[k]` being expressed in `Out / In^k`.
+    #[inline]
+    pub const fn from_coefficients(coeffs: [f64; N]) -> Self {
+        Self {
+            coeffs,
+            _units: PhantomData,
+        }
+    }
+
+    /// The raw coefficients, `coeffs[k]` in `Out / In^k`.
+    #[inline]
+    pub const fn coefficients(&self) -> [f64; N] {
+        self.coeffs
+    }
+
+    /// Evaluates the polynomial at `t` (converted to `In` first) by Horner's scheme.
+    pub fn eval<T: Unit>(&self, t: Quantity<T>) -> Quantity<Out>
+    where
+        T::Dim: SameDimension<In::Dim>,
+    {
+        let t = t.to::<In>().value();
+        let value = self.coeffs.iter().rev().fold(0.0, |acc, c| acc * t + c);
+        Quantity::new(value)
+    }
+}
+
+impl<In: Unit, Out: Unit> Polynomial<In, Out, 1> {
+    /// A constant polynomial; extend it with [`term`](Polynomial::term).
+    #[inline]
+    pub const fn constant(c0: Quantity<Out>) -> Self {
+        Self::from_coefficients([c0.value()])
+    }
+}
+
+/// Generates `term` for each degree: `$n` coefficients so far, the new one in `Out / $den`.
+macro_rules! polynomial_terms {
+    ($($n:literal => [$($i:literal),*] $den:ty;)*) => {$(
+        impl<In: Unit, Out: Unit> Polynomial<In, Out, $n> {
+            #[doc = concat!("Appends the degree-", stringify!($n), " coefficient, in any unit of dimension `Out / ", stringify!($den), "`.")]
+            #[inline]
+            pub const fn term<C: Unit>(self, c: Quantity<C>) -> Polynomial<In, Out, { $n + 1 }>
+            where
+                C::Dim: SameDimension<<Per<Out, $den> as Unit>::Dim>,
+            {
+                let next = c.to::<Per<Out, $den>>().value();
+                Polynomial::from_coefficients([$(self.coeffs[$i],)* next])
+            }
+        }
+    )*};
+}
+
+polynomial_terms! {
+    1 => [0] In;
+    2 => [0, 1] Prod<In, In>;
+    3 => [0, 1, 2] Prod<Prod<In, In>, In>;
+    4 => [0, 1, 2, 3] Prod<Prod<Prod<In, In>, In>, In>;
+    5 => [0, 1, 2, 3, 4] Prod<Prod<Prod<Prod<In, In>, In>, In>, In>;
+}
+
+/// One term `A · sin(φ + ω·t)` of a [`HarmonicSeries`]: amplitude in `Out`, phase `φ`, rate `ω` per `In`.
+///
+/// Cosine terms are sine terms with the phase advanced by 90°.
+pub struct HarmonicTerm<In: Unit, Out: Unit> {
+    amplitude: f64,
+    /// Degrees.
+    phase: f64,
+    /// Degrees per `In`.
+    frequency: f64,
+    _units: PhantomData<(In, Out)>,
+}
+
+impl<In: Unit, Out: Unit> HarmonicTerm<In, Out> {
+    /// A term with the given amplitude, phase at `t = 0`, and angular rate in any unit of `angle / In`.
+    #[inline]
+    pub const fn new<F: Unit>(
+        amplitude: Quantity<Out>,
+        phase: Degrees,
+        frequency: Quantity<F>,
+    ) -> Self
+    where
+        F::Dim: SameDimension<DivDim<Angular, In::Dim>>,
+    {
+        Self {
+            amplitude: amplitude.value(),
+            phase: phase.value(),
+            frequency: frequency.to::<Per<Degree, In>>().value(),
+            _units: PhantomData,
+        }
+    }
+
+    /// The amplitude `A`.
+    #[inline]
+    pub const fn amplitude(&self) -> Quantity<Out> {
+        Quantity::new(self.amplitude)
+    }
+
+    /// The phase `φ` at `t = 0`.
+    #[inline]
+    pub const fn phase(&self) -> Degrees {
+        Degrees::new(self.phase)
+    }
+
+    /// The angular rate `ω`.
+    #[inline]
+    pub const fn frequency(&self) -> Quantity<Per<Degree, In>> {
+        Quantity::new(self.frequency)
+    }
+
+    /// The term's value at `t`, in units of `In`.
+    #[inline]
+    fn at(&self, t: f64) -> f64 {
+        self.amplitude * Degrees::new(self.phase + self.frequency * t).sin()
+    }
+}
+
+/// A sum of [`HarmonicTerm`]s over a borrowed table.
+pub struct HarmonicSeries<'a, In: Unit, Out: Unit> {
+    terms: &'a [HarmonicTerm<In, Out>],
+}
+
+impl<'a, In: Unit, Out: Unit> HarmonicSeries<'a, In, Out> {
+    /// Wraps a table of terms.
+    #[inline]
+    pub const fn new(terms: &'a [HarmonicTerm<In, Out>]) -> Self {
+        Self { terms }
+    }
+
+    /// The terms of the series.
+    #[inline]
+    pub const fn terms(&self) -> &'a [HarmonicTerm<In, Out>] {
+        self.terms
+    }
+
+    /// Evaluates `Σ Aᵢ · sin(φᵢ + ωᵢ·t)` at `t` (converted to `In` first).
+    pub fn eval<T: Unit>(&self, t: Quantity<T>) -> Quantity<Out>
+    where
+        T::Dim: SameDimension<In::Dim>,
+    {
+        let t = t.to::<In>().value();
+        Quantity::new(self.terms.iter().map(|term| term.at(t)).sum())
+    }
+}
+
+/// Locates `t` among ascending `times`: the index of the knot at or before it and the fraction of the way to the
+/// next one, in `[0, 1]`. `None` outside `[times[0], times[n − 1]]` (or for NaN).
+fn bracket(times: &[f64], t: f64) -> Option<(usize, f64)> {
+    let (first, last) = (*times.first()?, *times.last()?);
+    if !(first <= t && t <= last) {
+        return None;
+    }
+    let after = times.partition_point(|&k| k <= t);
+    if after == times.len() {
+        // `t` is the last knot.
+        return Some((times.len() - 1, 0.0));
+    }
+    let (lo, hi) = (times[after - 1], times[after]);
+    Some((after - 1, (t - lo) / (hi - lo)))
+}
+
+/// Checks the knot slices and maps each new time to [`bracket`]'s result.
+fn resample<'a, T: Unit, U: Unit>(
+    times: &'a [Quantity<T>],
+    values: &'a [Quantity<U>],
+    new_times: &'a [Quantity<T>],
+    pick: impl Fn(usize, f64) -> f64 + 'a,
+) -> impl ExactSizeIterator<Item = Quantity<U>> + 'a {
+    assert_eq!(
+        times.len(),
+        values.len(),
+        "resample: `times` and `values` differ in length"
+    );
+    let times = Quantity::slice_as_raw(times);
+    new_times.iter().map(move |t| {
+        Quantity::new(match bracket(times, t.value()) {
+            Some((i, frac)) => pick(i, frac),
+            None => f64::NAN,
+        })
+    })
+}
+
+/// Linearly interpolates the samples `values[i]` taken at `times[i]` onto `new_times`.
+///
+/// `times` must be ascending. Times outside `[times[0], times[n − 1]]` give NaN rather than an extrapolation.
+///
+/// # Panics
+///
+/// If `times` and `values` have different lengths.
+pub fn resample_linear<'a, T: Unit, U: Unit>(
+    times: &'a [Quantity<T>],
+    values: &'a [Quantity<U>],
+    new_times: &'a [Quantity<T>],
+) -> impl ExactSizeIterator<Item = Quantity<U>> + 'a {
+    resample(times, values, new_times, move |i, frac| {
+        let a = values[i].value();
+        if frac == 0.0 {
+            a
+        } else {
+            a + frac * (values[i + 1].value() - a)
+        }
+    })
+}
+
+/// Resamples onto `new_times` by taking the value of the nearest knot; a time halfway between two knots takes the
+/// earlier one.
+///
+/// Same requirements and out-of-range behaviour as [`resample_linear`].
+pub fn resample_nearest<'a, T: Unit, U: Unit>(
+    times: &'a [Quantity<T>],
+    values: &'a [Quantity<U>],
+    new_times: &'a [Quantity<T>],
+) -> impl ExactSizeIterator<Item = Quantity<U>> + 'a {
+    resample(times, values, new_times, move |i, frac| {
+        values[if frac > 0.5 { i + 1 } else { i }].value()
+    })
+}
+
+/// Resamples onto `new_times` by holding the value of the last knot at or before each time (zero-order hold).
+///
+/// Same requirements and out-of-range behaviour as [`resample_linear`].
+pub fn resample_previous<'a, T: Unit, U: Unit>(
+    times: &'a [Quantity<T>],
+    values: &'a [Quantity<U>],
+    new_times: &'a [Quantity<T>],
+) -> impl ExactSizeIterator<Item = Quantity<U>> + 'a {
+    resample(times, values, new_times, move |i, _| values[i].value())
+}
+
+/// Rate of change of samples taken every `dt`: central differences inside, one-sided differences at the two ends
+/// (the same scheme as `numpy.gradient`).
+///
+/// The result has one rate per sample, in `U` per `T`. Fewer than two samples give an empty vector.
+///
+/// ```rust
+/// use qtty_core::length::{Kilometer, Kilometers};
+/// use qtty_core::series::derivative;
+/// use qtty_core::time::{Minutes, Second};
+/// use qtty_core::velocity::Velocity;
+///
+/// let range = [0.0, 60.0, 240.0].map(Kilometers::new);
+/// let rates = derivative(&range, Minutes::new(1.0));
+/// let v: Velocity<Kilometer, Second> = rates[1].to();
+/// assert!((v.value() - 2.0).abs() < 1e-12);
+/// ```
+///
+/// Only available with the `std` feature.
+#[cfg(feature = "std")]
+pub fn derivative<U: Unit, T: Unit>(
+    samples: &[Quantity<U>],
+    dt: Quantity<T>,
+) -> Vec<Quantity<Per<U, T>>> {
+    let y = Quantity::slice_as_raw(samples);
+    let h = dt.value();
+    let n = y.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    (0..n)
+        .map(|i| {
+            let rate = if i == 0 {
+                (y[1] - y[0]) / h
+            } else if i == n - 1 {
+                (y[n - 1] - y[n - 2]) / h
+            } else {
+                (y[i + 1] - y[i - 1]) / (2.0 * h)
+            };
+            Quantity::new(rate)
+        })
+        .collect()
+}
+
+/// Integral of rates sampled every `dt`, by the trapezoid rule.
+///
+/// Fewer than two samples integrate to zero.
+///
+/// ```rust
+/// use qtty_core::length::Kilometer;
+/// use qtty_core::series::integrate;
+/// use qtty_core::time::Seconds;
+/// use qtty_core::velocity::Velocity;
+///
+/// let v = [1.0, 2.0, 3.0].map(Velocity::<Kilometer, qtty_core::time::Second>::new);
+/// let distance = integrate(&v, Seconds::new(10.0));
+/// assert_eq!(distance.value(), 40.0);
+/// ```
+pub fn integrate<U: Unit, T: Unit>(rates: &[Quantity<Per<U, T>>], dt: Quantity<T>) -> Quantity<U> {
+    let y = Quantity::slice_as_raw(rates);
+    let n = y.len();
+    if n < 2 {
+        return Quantity::new(0.0);
+    }
+    let inner: f64 = y[1..n - 1].iter().sum();
+    Quantity::new(dt.value() * (0.5 * (y[0] + y[n - 1]) + inner))
+}
+
+/// Integral of rates sampled every `dt`, by the composite Simpson rule.
+///
+/// Exact for cubics. Simpson's 1/3 rule covers pairs of intervals; with an odd number of intervals the last three
+/// use the 3/8 rule instead, and a single interval falls back to the trapezoid rule. Fewer than two samples integrate
+/// to zero.
+pub fn integrate_simpson<U: Unit, T: Unit>(
+    rates: &[Quantity<Per<U, T>>],
+    dt: Quantity<T>,
+) -> Quantity<U> {
+    let y = Quantity::slice_as_raw(rates);
+    let h = dt.value();
+    let intervals = y.len().saturating_sub(1);
+    if intervals < 2 {
+        return integrate(rates, dt);
+    }
+    let (pairs, tail) = if intervals.is_multiple_of(2) {
+        (intervals, 0)
+    } else {
+        (intervals - 3, 3)
+    };
+    let mut sum = 0.0;
+    for k in (0..pairs).step_by(2) {
+        sum += h / 3.0 * (y[k] + 4.0 * y[k + 1] + y[k + 2]);
+    }
+    if tail == 3 {
+        let k = pairs;
+        sum += 3.0 * h / 8.0 * (y[k] + 3.0 * y[k + 1] + 3.0 * y[k + 2] + y[k + 3]);
+    }
+    Quantity::new(sum)
+}
+
+/// A piecewise-cubic curve through typed knots, with a continuous first derivative.
+///
+/// Each segment is the cubic Hermite polynomial fixed by the values and slopes at its two ends. The slopes come from
+/// the caller in [`hermite`](Self::hermite), which is how ephemerides that tabulate position and velocity are
+/// interpolated, or from the data in [`natural`](Self::natural), the natural cubic spline (continuous second
+/// derivative, zero at both ends). [`eval`](Self::eval) and [`derivative`](Self::derivative) return NaN outside the
+/// knot range.
+///
+/// ```rust
+/// use qtty_core::length::{Kilometer, Kilometers};
+/// use qtty_core::series::Spline;
+/// use qtty_core::time::{Days, Second};
+/// use qtty_core::velocity::Velocity;
+///
+/// // Positions of a body moving at a steady 1 km/s, tabulated with their velocities.
+/// let t = [0.0, 1.0, 2.0].map(Days::new);
+/// let x = [0.0, 86_400.0, 172_800.0].map(Kilometers::new);
+/// let v = [Velocity::<Kilometer, Second>::new(1.0); 3];
+/// let spline = Spline::hermite(&t, &x, &v);
+///
+/// assert!((spline.eval(Days::new(0.5)).value() - 43_200.0).abs() < 1e-9);
+/// let speed: Velocity<Kilometer, Second> = spline.derivative(Days::new(1.5)).to();
+/// assert!((speed.value() - 1.0).abs() < 1e-12);
+/// ```
+///
+/// Only available with the `std` feature, as the knots are copied into the spline.
+#[cfg(feature = "std")]
+pub struct Spline<In: Unit, Out: Unit> {
+    times: Vec<f64>,
+    values: Vec<f64>,
+    /// `d values / d times` at each knot, in `Out / In`.
+    slopes: Vec<f64>,
+    _units: PhantomData<(In, Out)>,
+}
+
+#[cfg(feature = "std")]
+impl<In: Unit, Out: Unit> Spline<In, Out> {
+    /// Cubic Hermite spline through `values` at `times` with the given slopes, in any unit of `Out / In`.
+    ///
+    /// # Panics
+    ///
+    /// If the slices differ in length, hold fewer than two knots, or `times` is not strictly increasing.
+    pub fn hermite<R: Unit>(
+        times: &[Quantity<In>],
+        values: &[Quantity<Out>],
+        slopes: &[Quantity<R>],
+    ) -> Self
+    where
+        R::Dim: SameDimension<<Per<Out, In> as Unit>::Dim>,
+    {
+        assert_eq!(
+            values.len(),
+            slopes.len(),
+            "Spline: `values` and `slopes` differ in length"
+        );
+        let slopes = slopes.iter().map(|m| m.to::<Per<Out, In>>().value());
+        Self::from_parts(times, values, slopes.collect())
+    }
+
+    /// Natural cubic spline through `values` at `times`.
+    ///
+    /// # Panics
+    ///
+    /// As for [`hermite`](Self::hermite).
+    pub fn natural(times: &[Quantity<In>], values: &[Quantity<Out>]) -> Self {
+        let t = Quantity::slice_as_raw(times);
+        let y = Quantity::slice_as_raw(values);
+        let n = t.len().min(y.len());
+        // Second derivatives `M` at the knots from the tridiagonal system
+        // `h₋ M₋ + 2 (h₋ + h₊) M + h₊ M₊ = 6 (Δ₊ − Δ₋)`, with `M = 0` at both ends (Thomas algorithm).
+        let mut m = vec![0.0; n];
+        if n > 2 {
+            let mut diag = vec![0.0; n];
+            let mut rhs = vec![0.0; n];
+            for i in 1..n - 1 {
+                let (h0, h1) = (t[i] - t[i - 1], t[i + 1] - t[i]);
+                diag[i] = 2.0 * (h0 + h1);
+                rhs[i] = 6.0 * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+                if i > 1 {
+                    let w = h0 / diag[i - 1];
+                    diag[i] -= w * h0;
+                    rhs[i] -= w * rhs[i - 1];
+                }
+            }
+            for i in (1..n - 1).rev() {
+                let h1 = t[i + 1] - t[i];
+                m[i] = (rhs[i] - h1 * m[i + 1]) / diag[i];
+            }
+        }
+        let slopes = (0..n)
+            .map(|i| {
+                if i + 1 < n {
+                    let h = t[i + 1] - t[i];
+                    (y[i + 1] - y[i]) / h - h * (2.0 * m[i] + m[i + 1]) / 6.0
+                } else {
+                    let h = t[i] - t[i - 1];
+                    (y[i] - y[i - 1]) / h + h * (m[i - 1] + 2.0 * m[i]) / 6.0
+                }
+            })
+            .collect();
+        Self::from_parts(times, values, slopes)
+    }
+
+    fn from_parts(times: &[Quantity<In>], values: &[Quantity<Out>], slopes: Vec<f64>) -> Self {
+        assert_eq!(
+            times.len(),
+            values.len(),
+            "Spline: `times` and `values` differ in length"
+        );
+        assert!(times.len() >= 2, "Spline: at least two knots are required");
+        let times = Quantity::slice_as_raw(times);
+        assert!(
+            times.windows(2).all(|w| w[0] < w[1]),
+            "Spline: `times` must be strictly increasing"
+        );
+        Self {
+            times: times.to_vec(),
+            values: Quantity::slice_as_raw(values).to_vec(),
+            slopes,
+            _units: PhantomData,
+        }
+    }
+
+    /// The segment containing `t` (in `In`) and the normalized position `s ∈ [0, 1]` within it.
+    fn segment(&self, t: f64) -> Option<(usize, f64, f64)> {
+        let (i, s) = bracket(&self.times, t)?;
+        let (i, s) = if i + 1 == self.times.len() {
+            (i - 1, 1.0)
+        } else {
+            (i, s)
+        };
+        Some((i, s, self.times[i + 1] - self.times[i]))
+    }
+
+    /// The interpolated value at `t` (converted to `In` first).
+    pub fn eval<T: Unit>(&self, t: Quantity<T>) -> Quantity<Out>
+    where
+        T::Dim: SameDimension<In::Dim>,
+    {
+        let Some((i, s, h)) = self.segment(t.to::<In>().value()) else {
+            return Quantity::new(f64::NAN);
+        };
+        let (s2, s3) = (s * s, s * s * s);
+        let value = (2.0 * s3 - 3.0 * s2 + 1.0) * self.values[i]
+            + (s3 - 2.0 * s2 + s) * h * self.slopes[i]
+            + (3.0 * s2 - 2.0 * s3) * self.values[i + 1]
+            + (s3 - s2) * h * self.slopes[i + 1];
+        Quantity::new(value)
+    }
+
+    /// The rate of change at `t` (converted to `In` first), in `Out` per `In`.
+    pub fn derivative<T: Unit>(&self, t: Quantity<T>) -> Quantity<Per<Out, In>>
+    where
+        T::Dim: SameDimension<In::Dim>,
+    {
+        let Some((i, s, h)) = self.segment(t.to::<In>().value()) else {
+            return Quantity::new(f64::NAN);
+        };
+        let s2 = s * s;
+        let rate = 6.0 * (s2 - s) * (self.values[i] - self.values[i + 1]) / h
+            + (3.0 * s2 - 4.0 * s + 1.0) * self.slopes[i]
+            + (3.0 * s2 - 2.0 * s) * self.slopes[i + 1];
+        Quantity::new(rate)
+    }
+
+    /// The knot times.
+    #[inline]
+    pub fn times(&self) -> &[Quantity<In>] {
+        Quantity::slice_from_raw(&self.times)
+    }
+
+    /// The knot values.
+    #[inline]
+    pub fn values(&self) -> &[Quantity<Out>] {
+        Quantity::slice_from_raw(&self.values)
+    }
+}
+
+// Manual impls: deriving would bound the unit markers as well.
+
+impl<In: Unit, Out: Unit, const N: usize> Clone for Polynomial<In, Out, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<In: Unit, Out: Unit, const N: usize> Copy for Polynomial<In, Out, N> {}
+
+impl<In: Unit, Out: Unit, const N: usize> PartialEq for Polynomial<In, Out, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<In: Unit, Out: Unit, const N: usize> fmt::Debug for Polynomial<In, Out, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Polynomial")
+            .field("input", &In::SYMBOL)
+            .field("output", &Out::SYMBOL)
+            .field("coeffs", &self.coeffs)
+            .finish()
+    }
+}
+
+impl<In: Unit, Out: Unit> Clone for HarmonicTerm<In, Out> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<In: Unit, Out: Unit> Copy for HarmonicTerm<In, Out> {}
+
+impl<In: Unit, Out: Unit> PartialEq for HarmonicTerm<In, Out> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        (self.amplitude, self.phase, self.frequency)
+            == (other.amplitude, other.phase, other.frequency)
+    }
+}
+
+impl<In: Unit, Out: Unit> fmt::Debug for HarmonicTerm<In, Out> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HarmonicTerm")
+            .field("amplitude", &self.amplitude)
+            .field("phase", &self.phase)
+            .field("frequency", &self.frequency)
+            .finish()
+    }
+}
+
+impl<In: Unit, Out: Unit> Clone for HarmonicSeries<'_, In, Out> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<In: Unit, Out: Unit> Copy for HarmonicSeries<'_, In, Out> {}
+
+impl<In: Unit, Out: Unit> fmt::Debug for HarmonicSeries<'_, In, Out> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HarmonicSeries")
+            .field("terms", &self.terms)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<In: Unit, Out: Unit> Clone for Spline<In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            times: self.times.clone(),
+            values: self.values.clone(),
+            slopes: self.slopes.clone(),
+            _units: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<In: Unit, Out: Unit> PartialEq for Spline<In, Out> {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.times, &self.values, &self.slopes) == (&other.times, &other.values, &other.slopes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<In: Unit, Out: Unit> fmt::Debug for Spline<In, Out> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spline")
+            .field("input", &In::SYMBOL)
+            .field("output", &Out::SYMBOL)
+            .field("knots", &self.times.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Arcsecond, Arcseconds, Degree, Degrees};
+    use crate::units::time::{Day, Days, JulianCenturies, JulianCentury};
+    use approx::assert_abs_diff_eq;
+
+    type Cy = JulianCentury;
+
+    #[test]
+    fn horner_matches_direct_sum() {
+        let p = Polynomial::<Cy, Arcsecond, 4>::from_coefficients([1.0, 2.0, 3.0, 4.0]);
+        let t = 0.7;
+        let direct = 1.0 + 2.0 * t + 3.0 * t * t + 4.0 * t * t * t;
+        assert_abs_diff_eq!(
+            p.eval(JulianCenturies::new(t)).value(),
+            direct,
+            epsilon = 1e-12
+        );
+        assert_eq!(
+            Polynomial::<Cy, Arcsecond, 0>::from_coefficients([])
+                .eval(JulianCenturies::new(t))
+                .value(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn terms_convert_into_canonical_slots() {
+        // Mean longitude of the Sun (Meeus 25.2): L₀ = 280.46646° + 36000.76983° T + 0.0003032° T².
+        let l0 = Polynomial::<Cy, Degree, 1>::constant(Degrees::new(280.466_46))
+            .term(Quantity::<Per<Degree, Cy>>::new(36_000.769_83))
+            .term(Quantity::<Per<Arcsecond, Prod<Cy, Cy>>>::new(
+                0.000_303_2 * 3_600.0,
+            ));
+        assert_abs_diff_eq!(l0.coefficients()[2], 0.000_303_2, epsilon = 1e-15);
+
+        // 1992-10-13 0h TD: T = −0.072 183 436, L₀ = 201.807 19° (mod 360).
+        let l = l0.eval(JulianCenturies::new(-0.072_183_436));
+        assert_abs_diff_eq!(l.wrap_pos().value(), 201.807_19, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn eval_converts_the_argument() {
+        type ArcsecPerCy = Per<Arcsecond, Cy>;
+        let p = Polynomial::<Cy, Arcsecond, 1>::constant(Arcseconds::new(10.0)).term(Quantity::<
+            ArcsecPerCy,
+        >::new(
+            36_525.0
+        ));
+        // One day is 1/36525 century → +1″.
+        assert_abs_diff_eq!(p.eval(Days::new(1.0)).value(), 11.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn copy_eq_debug() {
+        let p = Polynomial::<Cy, Arcsecond, 2>::from_coefficients([1.0, 2.0]);
+        let q = p;
+        assert_eq!(p, q);
+        assert_eq!(
+            format!("{:?}", p),
+            "Polynomial { input: \"JC\", output: \"Arcs\", coeffs: [1.0, 2.0] }"
+        );
+    }
+
+    #[test]
+    fn harmonic_series_sums_sines() {
+        type DegPerCy = Per<Degree, Cy>;
+        let terms = [
+            HarmonicTerm::<Cy, Arcsecond>::new(
+                Arcseconds::new(2.0),
+                Degrees::new(30.0),
+                Quantity::<DegPerCy>::new(90.0),
+            ),
+            // A cosine term: sin(φ + 90°) = cos φ.
+            HarmonicTerm::new(
+                Arcseconds::new(-1.0),
+                Degrees::new(90.0),
+                Quantity::<DegPerCy>::new(0.0),
+            ),
+        ];
+        let series = HarmonicSeries::new(&terms);
+        // t = 1 cy: 2 sin 120° − 1.
+        assert_abs_diff_eq!(
+            series.eval(JulianCenturies::new(1.0)).value(),
+            2.0 * 120f64.to_radians().sin() - 1.0,
+            epsilon = 1e-12
+        );
+        assert_eq!(series.terms().len(), 2);
+        assert_eq!(
+            HarmonicSeries::<Cy, Arcsecond>::new(&[])
+                .eval(Days::new(3.0))
+                .value(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn harmonic_frequency_converts() {
+        // 1°/day is 36525°/cy.
+        let term = HarmonicTerm::<Cy, Arcsecond>::new(
+            Arcseconds::new(1.0),
+            Degrees::new(0.0),
+            Quantity::<Per<Degree, Day>>::new(1.0),
+        );
+        assert_abs_diff_eq!(term.frequency().value(), 36_525.0, epsilon = 1e-9);
+        assert_eq!(term.phase(), Degrees::new(0.0));
+        assert_eq!(term.amplitude(), Arcseconds::new(1.0));
+        let series = HarmonicSeries::new(core::slice::from_ref(&term));
+        assert_abs_diff_eq!(series.eval(Days::new(90.0)).value(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn resample_linear_hits_knots_and_midpoints() {
+        let t = [0.0, 1.0, 3.0].map(Days::new);
+        let v = [10.0, 20.0, 0.0].map(Arcseconds::new);
+        let at = [0.0, 0.5, 1.0, 2.0, 3.0].map(Days::new);
+        let out: Vec<f64> = resample_linear(&t, &v, &at).map(|q| q.value()).collect();
+        assert_eq!(out, [10.0, 15.0, 20.0, 10.0, 0.0]);
+    }
+
+    #[test]
+    fn resample_outside_range_is_nan() {
+        let t = [0.0, 1.0].map(Days::new);
+        let v = [1.0, 2.0].map(Degrees::new);
+        let at = [-0.1, 1.1, f64::NAN].map(Days::new);
+        assert!(resample_linear(&t, &v, &at).all(|q| q.value().is_nan()));
+        assert!(resample_nearest(&t, &v, &at).all(|q| q.value().is_nan()));
+        assert!(resample_linear(&[], &[] as &[Degrees], &at).all(|q| q.value().is_nan()));
+
+        let single = resample_linear(&t[..1], &v[..1], &[Days::new(0.0)]).next();
+        assert_eq!(single, Some(Degrees::new(1.0)));
+    }
+
+    #[test]
+    fn resample_nearest_and_previous() {
+        let t = [0.0, 1.0, 2.0].map(Days::new);
+        let v = [1.0, 2.0, 3.0].map(Degrees::new);
+        let at = [0.4, 0.5, 0.6, 1.99, 2.0].map(Days::new);
+        let nearest: Vec<f64> = resample_nearest(&t, &v, &at).map(|q| q.value()).collect();
+        assert_eq!(nearest, [1.0, 1.0, 2.0, 3.0, 3.0]);
+        let held: Vec<f64> = resample_previous(&t, &v, &at).map(|q| q.value()).collect();
+        assert_eq!(held, [1.0, 1.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "differ in length")]
+    fn resample_checks_lengths() {
+        let t = [0.0, 1.0].map(Days::new);
+        let _ = resample_linear(&t, &[Degrees::new(1.0)], &t);
+    }
+
+    #[test]
+    fn trapezoid_and_simpson() {
+        type Rate = Per<Degree, Day>;
+        // ∫₀² 3t² dt = 8: Simpson is exact for every sample count, the trapezoid overshoots.
+        for n in [3, 4, 5, 8] {
+            let h = 2.0 / (n - 1) as f64;
+            let r: Vec<Quantity<Rate>> = (0..n)
+                .map(|k| Quantity::new(3.0 * (k as f64 * h).powi(2)))
+                .collect();
+            let simpson = integrate_simpson(&r, Days::new(h));
+            assert_abs_diff_eq!(simpson.value(), 8.0, epsilon = 1e-12);
+            assert!(integrate(&r, Days::new(h)).value() > 8.0);
+        }
+        let two = [1.0, 3.0].map(Quantity::<Rate>::new);
+        assert_eq!(integrate_simpson(&two, Days::new(0.5)), Degrees::new(1.0));
+        assert_eq!(integrate(&two[..1], Days::new(0.5)), Degrees::new(0.0));
+        assert_eq!(
+            integrate::<Degree, Day>(&[], Days::new(0.5)),
+            Degrees::new(0.0)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn derivative_matches_numpy_gradient() {
+        let x = [1.0, 2.0, 4.0, 7.0, 11.0].map(Arcseconds::new);
+        let rates = derivative(&x, Days::new(2.0));
+        let raw: Vec<f64> = rates.iter().map(|r| r.value()).collect();
+        assert_eq!(raw, [0.5, 0.75, 1.25, 1.75, 2.0]);
+        let per_cy: Quantity<Per<Arcsecond, Cy>> = rates[0].to();
+        assert_abs_diff_eq!(per_cy.value(), 0.5 * 36_525.0, epsilon = 1e-9);
+        assert!(derivative(&x[..1], Days::new(1.0)).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    mod spline {
+        use super::*;
+        use crate::units::length::{Kilometer, Kilometers};
+        use crate::units::time::{Hour, Second};
+        use crate::units::velocity::Velocity;
+
+        #[test]
+        fn hermite_reproduces_a_cubic() {
+            // x(t) = t³ − 2t, x'(t) = 3t² − 2.
+            let t = [-1.0, 0.5, 2.0].map(Days::new);
+            let x = t.map(|t| Kilometers::new(t.value().powi(3) - 2.0 * t.value()));
+            let v = t.map(|t| Quantity::<Per<Kilometer, Day>>::new(3.0 * t.value().powi(2) - 2.0));
+            let spline = Spline::hermite(&t, &x, &v);
+            for k in 0..=30 {
+                let tk = -1.0 + 0.1 * k as f64;
+                let at = Days::new(tk);
+                assert_abs_diff_eq!(
+                    spline.eval(at).value(),
+                    tk.powi(3) - 2.0 * tk,
+                    epsilon = 1e-12
+                );
+                assert_abs_diff_eq!(
+                    spline.derivative(at).value(),
+                    3.0 * tk * tk - 2.0,
+                    epsilon = 1e-12
+                );
+            }
+        }
+
+        #[test]
+        fn hermite_converts_slopes_and_times() {
+            let t = [0.0, 1.0].map(Days::new);
+            let x = [0.0, 86_400.0].map(Kilometers::new);
+            let v = [Velocity::<Kilometer, Second>::new(1.0); 2];
+            let spline = Spline::hermite(&t, &x, &v);
+            assert_abs_diff_eq!(
+                spline.eval(Quantity::<Hour>::new(12.0)).value(),
+                43_200.0,
+                epsilon = 1e-9
+            );
+            assert_abs_diff_eq!(
+                spline.derivative(Days::new(0.3)).value(),
+                86_400.0,
+                epsilon = 1e-9
+            );
+            assert_eq!(spline.times(), &t);
+            assert_eq!(spline.values(), &x);
+        }
+
+        #[test]
+        fn natural_spline_is_exact_on_lines_and_close_on_sines() {
+            let t = [0.0, 1.0, 3.0, 4.0].map(Days::new);
+            let x = t.map(|t| Degrees::new(2.0 * t.value() + 1.0));
+            let line = Spline::natural(&t, &x);
+            assert_abs_diff_eq!(line.eval(Days::new(2.5)).value(), 6.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(
+                line.derivative(Days::new(3.5)).value(),
+                2.0,
+                epsilon = 1e-12
+            );
+
+            let t: Vec<Days> = (0..=40).map(|k| Days::new(k as f64 * 0.1)).collect();
+            let x: Vec<Degrees> = t.iter().map(|t| Degrees::new(t.value().sin())).collect();
+            let spline = Spline::natural(&t, &x);
+            assert_abs_diff_eq!(
+                spline.eval(Days::new(1.234)).value(),
+                1.234f64.sin(),
+                epsilon = 1e-5
+            );
+            assert_abs_diff_eq!(
+                spline.derivative(Days::new(2.05)).value(),
+                2.05f64.cos(),
+                epsilon = 1e-3
+            );
+            // The ends are still the knots.
+            assert_abs_diff_eq!(
+                spline.eval(Days::new(4.0)).value(),
+                4.0f64.sin(),
+                epsilon = 1e-15
+            );
+        }
+
+        #[test]
+        fn outside_the_knots_is_nan() {
+            let t = [0.0, 1.0].map(Days::new);
+            let spline = Spline::natural(&t, &[Degrees::new(0.0), Degrees::new(1.0)]);
+            assert!(spline.eval(Days::new(1.5)).value().is_nan());
+            assert!(spline.derivative(Days::new(-0.5)).value().is_nan());
+            assert_eq!(spline.clone(), spline);
+            assert_eq!(
+                format!("{spline:?}"),
+                "Spline { input: \"d\", output: \"Deg\", knots: 2 }"
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "strictly increasing")]
+        fn rejects_unsorted_knots() {
+            let t = [0.0, 2.0, 1.0].map(Days::new);
+            let _ = Spline::natural(&t, &[Degrees::new(0.0); 3]);
+        }
+    }
+}
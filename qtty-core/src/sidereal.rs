@@ -0,0 +1,114 @@
+//! Greenwich sidereal time from typed Julian centuries.
+//!
+//! [`gmst`] evaluates the IAU 1982 expression for Greenwich Mean Sidereal Time (Meeus, *Astronomical Algorithms*,
+//! eq. 12.4) and [`gast`] adds the equation of the equinoxes from a four-term nutation series, good to a few
+//! hundredths of a second of time. The argument is the number of Julian centuries of UT1 since J2000.0; `UTC` is within 0.9 s of
+//! UT1 and is usually close enough.
+//!
+//! ```rust
+//! use qtty_core::angular::HourAngle;
+//! use qtty_core::sidereal::gmst;
+//! use qtty_core::time::{Days, JulianCentury};
+//!
+//! // 1987-04-10 0h UT (JD 2446895.5): GMST = 13h 10m 46.3668s.
+//! let t = Days::new(2_446_895.5 - 2_451_545.0).to::<JulianCentury>();
+//! let hours = gmst(t).to::<HourAngle>();
+//! assert!((hours.value() - (13.0 + 10.0 / 60.0 + 46.3668 / 3600.0)).abs() < 1e-8);
+//! ```
+
+use crate::math;
+use crate::units::angular::{Arcseconds, Degree, Degrees};
+use crate::units::time::{Day, JulianCenturies};
+
+/// Greenwich Mean Sidereal Time at `t` Julian centuries (UT1) since J2000.0, in `[0°, 360°)`.
+///
+/// `θ₀ = 280.460 618 37° + 360.985 647 366 29° · d + 0.000 387 933° · T² − T³ / 38 710 000`, with `d` the days since
+/// J2000.0.
+pub fn gmst(t: JulianCenturies) -> Degrees {
+    let d = t.to::<Day>().value();
+    let t = t.value();
+    // Split 360.985…·d so the whole turns in 360·d never enter the sum.
+    let theta = 280.460_618_37
+        + 360.0 * (d - math::floor(d))
+        + 0.985_647_366_29 * d
+        + 0.000_387_933 * t * t
+        - t * t * t / 38_710_000.0;
+    Degrees::new(theta).wrap_pos()
+}
+
+/// The equation of the equinoxes `Δψ · cos ε` at `t` Julian centuries since J2000.0.
+///
+/// Uses the four largest nutation terms and the IAU 1980 mean obliquity; the result is within ~0.5″ of the full
+/// series.
+pub fn equation_of_equinoxes(t: JulianCenturies) -> Arcseconds {
+    let t = t.value();
+    let sun = Degrees::new(2.0 * (280.466_5 + 36_000.769_8 * t));
+    let moon = Degrees::new(2.0 * (218.316_5 + 481_267.881_3 * t));
+    let node =
+        Degrees::new(125.044_52 - 1_934.136_261 * t + 0.002_070_8 * t * t + t * t * t / 450_000.0);
+    let node2 = Degrees::new(2.0 * node.value());
+
+    let dpsi = -17.20 * node.sin() - 1.32 * sun.sin() - 0.23 * moon.sin() + 0.21 * node2.sin();
+    let deps = 9.20 * node.cos() + 0.57 * sun.cos() + 0.10 * moon.cos() - 0.09 * node2.cos();
+    let eps0 = 84_381.448 - 46.815_0 * t - 0.000_59 * t * t + 0.001_813 * t * t * t;
+    let eps = Arcseconds::new(eps0 + deps).to::<Degree>();
+    Arcseconds::new(dpsi * eps.cos())
+}
+
+/// Greenwich Apparent Sidereal Time: [`gmst`] plus the [`equation_of_equinoxes`], in `[0°, 360°)`.
+pub fn gast(t: JulianCenturies) -> Degrees {
+    (gmst(t) + equation_of_equinoxes(t).to::<Degree>()).wrap_pos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::HourAngle;
+    use crate::units::time::{Days, JulianCentury};
+    use approx::assert_abs_diff_eq;
+
+    fn centuries(jd: f64) -> JulianCenturies {
+        Days::new(jd - 2_451_545.0).to::<JulianCentury>()
+    }
+
+    fn hms(h: f64, m: f64, s: f64) -> f64 {
+        h + m / 60.0 + s / 3_600.0
+    }
+
+    #[test]
+    fn meeus_example_12a() {
+        let t = centuries(2_446_895.5);
+        assert_abs_diff_eq!(
+            gmst(t).to::<HourAngle>().value(),
+            hms(13.0, 10.0, 46.3668),
+            epsilon = 1e-8
+        );
+        // Meeus: Δψ = −3.788″, ε = 23°26′36.85″ → GAST = 13h 10m 46.1351s.
+        assert_abs_diff_eq!(equation_of_equinoxes(t).value(), -3.476, epsilon = 0.5);
+        assert_abs_diff_eq!(
+            gast(t).to::<HourAngle>().value(),
+            hms(13.0, 10.0, 46.1351),
+            epsilon = 0.05 / 3_600.0
+        );
+    }
+
+    #[test]
+    fn meeus_example_12b() {
+        assert_abs_diff_eq!(
+            gmst(centuries(2_446_896.306_25)).value(),
+            128.737_873_4,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn at_j2000() {
+        assert_abs_diff_eq!(
+            gmst(JulianCenturies::new(0.0)).value(),
+            280.460_618_37,
+            epsilon = 1e-12
+        );
+        let later = gmst(JulianCenturies::new(0.5));
+        assert!((0.0..360.0).contains(&later.value()));
+    }
+}
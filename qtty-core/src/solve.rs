@@ -0,0 +1,468 @@
+//! Root finding over quantity-valued functions.
+//!
+//! Rise and set times, meridian transits and node crossings all come down to finding where some function of time
+//! crosses zero: an altitude minus the horizon dip, an hour angle, an ecliptic latitude. [`find_root`] runs Brent's
+//! method on such a function with its argument, value and tolerance all typed, so the answer comes back as a time (or
+//! whatever the argument is) in the caller's unit.
+//!
+//! ```rust
+//! use qtty_core::angular::Degrees;
+//! use qtty_core::solve::find_root;
+//! use qtty_core::time::{Days, Seconds};
+//!
+//! // An altitude that climbs 15° per hour from −10°: it crosses the horizon 40 minutes in.
+//! let altitude = |t: Days| Degrees::new(-10.0 + 360.0 * t.value());
+//! let rise = find_root(altitude, Days::new(0.0)..=Days::new(0.25), Seconds::new(0.1)).unwrap();
+//! assert!((rise.value() * 1_440.0 - 40.0).abs() < 1e-3);
+//! ```
+//!
+//! When the crossings are not known in advance, [`scan_events`] samples the function across a range at a fixed step
+//! and refines every sign change it sees with [`find_root`]:
+//!
+//! ```rust
+//! use qtty_core::angular::{Degrees, Radians};
+//! use qtty_core::solve::{scan_events, Direction};
+//! use qtty_core::time::{Days, Hours, Minutes};
+//!
+//! // A toy altitude curve with one rise and one set per day.
+//! let altitude = |t: Days| Degrees::new(30.0 * Radians::new(t.value() * core::f64::consts::TAU).sin() - 5.0);
+//! let events: Vec<_> = scan_events(altitude, Days::new(0.0)..=Days::new(2.0), Hours::new(1.0), Minutes::new(0.01))
+//!     .collect::<Result<_, _>>()
+//!     .unwrap();
+//! assert_eq!(events.len(), 4);
+//! assert_eq!(events[0].direction, Direction::Rising);
+//! assert_eq!(events[1].direction, Direction::Falling);
+//! ```
+
+use crate::{Quantity, SameDimension, Unit};
+use core::fmt::{self, Display, Formatter};
+use core::ops::RangeInclusive;
+
+/// Iteration limit for [`find_root`]; Brent's method needs far fewer for any bracket representable in `f64`.
+const MAX_ITERATIONS: usize = 200;
+
+/// Error returned by [`find_root`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RootError {
+    /// The function has the same sign at both ends of the bracket.
+    NotBracketed,
+    /// The function returned NaN, or a bracket end is not finite.
+    NotFinite,
+    /// No convergence within the iteration limit.
+    NoConvergence,
+}
+
+impl Display for RootError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotBracketed => "the function has the same sign at both ends of the bracket",
+            Self::NotFinite => "the function or the bracket is not finite",
+            Self::NoConvergence => "root finding did not converge",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RootError {}
+
+/// Finds a zero of `f` inside `bracket` by Brent's method, to within `tol` of the true root.
+///
+/// The signs of `f` at the two ends of the bracket must differ (or one of them be zero). Brent's method combines
+/// bisection with secant and inverse quadratic steps: it converges superlinearly on smooth functions and never does
+/// worse than bisection. The tolerance may be given in any unit of the argument's dimension.
+///
+/// # Errors
+///
+/// [`RootError::NotBracketed`] if `f` has the same sign at both ends, [`RootError::NotFinite`] if `f` returns NaN or
+/// the bracket is not finite, and [`RootError::NoConvergence`] if the iteration limit is reached.
+pub fn find_root<In, Out, Tol, F>(
+    f: F,
+    bracket: RangeInclusive<Quantity<In>>,
+    tol: Quantity<Tol>,
+) -> Result<Quantity<In>, RootError>
+where
+    In: Unit,
+    Out: Unit,
+    Tol: Unit,
+    Tol::Dim: SameDimension<In::Dim>,
+    F: Fn(Quantity<In>) -> Quantity<Out>,
+{
+    let eval = |x: f64| -> Result<f64, RootError> {
+        let y = f(Quantity::new(x)).value();
+        if y.is_nan() {
+            Err(RootError::NotFinite)
+        } else {
+            Ok(y)
+        }
+    };
+    let (mut a, mut b) = (bracket.start().value(), bracket.end().value());
+    if !(a.is_finite() && b.is_finite()) {
+        return Err(RootError::NotFinite);
+    }
+    let tol = tol.to::<In>().value().abs();
+    let (mut fa, mut fb) = (eval(a)?, eval(b)?);
+    if fa == 0.0 {
+        return Ok(Quantity::new(a));
+    }
+    if fb == 0.0 {
+        return Ok(Quantity::new(b));
+    }
+    if (fa > 0.0) == (fb > 0.0) {
+        return Err(RootError::NotBracketed);
+    }
+
+    // Brent (1973), as laid out in Numerical Recipes' `zbrent`: `b` is the best estimate, `c` the point on the other
+    // side of the root, `a` the previous `b`.
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (0.0, 0.0);
+    for _ in 0..MAX_ITERATIONS {
+        if (fb > 0.0) == (fc > 0.0) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0.0 {
+            return Ok(Quantity::new(b));
+        }
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * xm * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let min1 = 3.0 * xm * q - (tol1 * q).abs();
+            let min2 = (e * q).abs();
+            if 2.0 * p < min1.min(min2) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { tol1.copysign(xm) };
+        fb = eval(b)?;
+    }
+    Err(RootError::NoConvergence)
+}
+
+/// Which way a function crosses zero at an [`Event`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From zero or below to above zero (a rise).
+    Rising,
+    /// From above zero to zero or below (a set).
+    Falling,
+}
+
+/// A zero crossing found by [`scan_events`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Event<U: Unit> {
+    /// Where the function crosses zero, refined to the requested tolerance.
+    pub at: Quantity<U>,
+    /// Whether the function rises or falls through zero there.
+    pub direction: Direction,
+}
+
+/// Samples `f` over `range` every `step` and yields each zero crossing, refined with [`find_root`] to within `tol`.
+///
+/// Samples are taken at `start + k·step` and always at the end of the range. A sign change between two neighbouring
+/// samples is one event. A value of exactly zero counts as not positive: passing through zero at a sample is one
+/// event, touching zero from below is none, and touching it from above is a fall and a rise at the same instant.
+/// Crossings closer together than `step` can cancel out and be missed, so pick a step below the shortest interval
+/// between events.
+///
+/// The iterator is lazy and yields events in order. It yields an error and then stops if `f` returns NaN or a root
+/// cannot be refined.
+///
+/// # Panics
+///
+/// If `step` is not positive and finite.
+pub fn scan_events<In, Out, Step, Tol, F>(
+    f: F,
+    range: RangeInclusive<Quantity<In>>,
+    step: Quantity<Step>,
+    tol: Quantity<Tol>,
+) -> ScanEvents<In, Out, F>
+where
+    In: Unit,
+    Out: Unit,
+    Step: Unit,
+    Tol: Unit,
+    Step::Dim: SameDimension<In::Dim>,
+    Tol::Dim: SameDimension<In::Dim>,
+    F: Fn(Quantity<In>) -> Quantity<Out>,
+{
+    let step = step.to::<In>().value();
+    assert!(
+        step > 0.0 && step.is_finite(),
+        "scan_events: `step` must be positive and finite"
+    );
+    let (start, end) = (*range.start(), *range.end());
+    ScanEvents {
+        start: start.value(),
+        end: end.value(),
+        step,
+        tol: tol.to::<In>(),
+        k: 0,
+        prev: None,
+        done: false,
+        f,
+    }
+}
+
+/// Iterator returned by [`scan_events`].
+pub struct ScanEvents<In: Unit, Out: Unit, F> {
+    f: F,
+    start: f64,
+    end: f64,
+    step: f64,
+    tol: Quantity<In>,
+    /// Index of the next sample.
+    k: u64,
+    /// The last sample taken and the function's value there.
+    prev: Option<(f64, Quantity<Out>)>,
+    done: bool,
+}
+
+impl<In, Out, F> Iterator for ScanEvents<In, Out, F>
+where
+    In: Unit,
+    Out: Unit,
+    F: Fn(Quantity<In>) -> Quantity<Out>,
+{
+    type Item = Result<Event<In>, RootError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let t = (self.start + self.k as f64 * self.step).min(self.end);
+            self.k += 1;
+            if t >= self.end {
+                self.done = true;
+            }
+            let y = (self.f)(Quantity::new(t));
+            if y.value().is_nan() {
+                self.done = true;
+                return Some(Err(RootError::NotFinite));
+            }
+            let Some((t0, y0)) = self.prev.replace((t, y)) else {
+                continue;
+            };
+            let (up0, up) = (y0.value() > 0.0, y.value() > 0.0);
+            if up0 == up {
+                continue;
+            }
+            let root = find_root(&self.f, Quantity::new(t0)..=Quantity::new(t), self.tol);
+            let direction = if up {
+                Direction::Rising
+            } else {
+                Direction::Falling
+            };
+            return Some(match root {
+                Ok(at) => Ok(Event { at, direction }),
+                Err(err) => {
+                    self.done = true;
+                    Err(err)
+                }
+            });
+        }
+        None
+    }
+}
+
+impl<In: Unit, Out: Unit, F> fmt::Debug for ScanEvents<In, Out, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScanEvents")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("step", &self.step)
+            .field("next_sample", &self.k)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Degrees, Radian, Radians};
+    use crate::units::time::{Day, Days, Hour, Second, Seconds};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn finds_cosine_zero() {
+        let root = find_root(
+            |x: Radians| Quantity::<Day>::new(x.cos()),
+            Radians::new(0.0)..=Radians::new(3.0),
+            Quantity::<Radian>::new(1e-12),
+        )
+        .unwrap();
+        assert_abs_diff_eq!(root.value(), core::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn tolerance_in_another_unit() {
+        // A crossing at 6.5 h, found to within 1 s from a bracket in days.
+        let f = |t: Days| Degrees::new((t.to::<Hour>().value() - 6.5).powi(3));
+        let root = find_root(f, Days::new(0.0)..=Days::new(1.0), Seconds::new(1.0)).unwrap();
+        assert!((root.to::<Second>().value() - 6.5 * 3_600.0).abs() <= 1.0);
+    }
+
+    #[test]
+    fn reversed_bracket_and_exact_ends() {
+        let f = |x: Days| Degrees::new(x.value() - 2.0);
+        let root = find_root(f, Days::new(5.0)..=Days::new(0.0), Days::new(1e-10)).unwrap();
+        assert_abs_diff_eq!(root.value(), 2.0, epsilon = 1e-10);
+        let root = find_root(f, Days::new(2.0)..=Days::new(3.0), Days::new(1e-3));
+        assert_eq!(root, Ok(Days::new(2.0)));
+    }
+
+    #[test]
+    fn errors() {
+        let f = |x: Days| Degrees::new(x.value() * x.value() + 1.0);
+        assert_eq!(
+            find_root(f, Days::new(-1.0)..=Days::new(1.0), Days::new(1e-6)),
+            Err(RootError::NotBracketed)
+        );
+        let nan = |_: Days| Degrees::new(f64::NAN);
+        assert_eq!(
+            find_root(nan, Days::new(-1.0)..=Days::new(1.0), Days::new(1e-6)),
+            Err(RootError::NotFinite)
+        );
+        let g = |x: Days| Degrees::new(x.value());
+        assert_eq!(
+            find_root(
+                g,
+                Days::new(-1.0)..=Days::new(f64::INFINITY),
+                Days::new(1e-6)
+            ),
+            Err(RootError::NotFinite)
+        );
+        assert_eq!(
+            RootError::NotBracketed.to_string(),
+            "the function has the same sign at both ends of the bracket"
+        );
+    }
+
+    #[test]
+    fn scan_finds_every_crossing_in_order() {
+        // sin(t) over [0.5, 10] rad-days: zeros at π, 2π and 3π.
+        let f = |t: Days| Degrees::new(t.value().sin());
+        let events: Vec<Event<Day>> = scan_events(
+            f,
+            Days::new(0.5)..=Days::new(10.0),
+            Quantity::<Hour>::new(6.0),
+            Seconds::new(0.01),
+        )
+        .collect::<Result<_, _>>()
+        .unwrap();
+        let directions: Vec<Direction> = events.iter().map(|e| e.direction).collect();
+        assert_eq!(
+            directions,
+            [Direction::Falling, Direction::Rising, Direction::Falling]
+        );
+        for (k, e) in events.iter().enumerate() {
+            let expected = (k + 1) as f64 * core::f64::consts::PI;
+            assert!((e.at.value() - expected).abs() * 86_400.0 < 0.01);
+        }
+    }
+
+    #[test]
+    fn scan_handles_exact_zeros_and_uneven_end() {
+        // Zero exactly on the sample at t = 1, range end not a multiple of the step.
+        let f = |t: Days| Degrees::new(t.value() - 1.0);
+        let events: Vec<_> = scan_events(
+            f,
+            Days::new(0.0)..=Days::new(1.7),
+            Days::new(0.5),
+            Days::new(1e-9),
+        )
+        .collect();
+        assert_eq!(
+            events,
+            [Ok(Event {
+                at: Days::new(1.0),
+                direction: Direction::Rising
+            })]
+        );
+        // The last sample is the range end.
+        let g = |t: Days| Degrees::new(t.value() - 1.65);
+        let events: Vec<_> = scan_events(
+            g,
+            Days::new(0.0)..=Days::new(1.7),
+            Days::new(0.5),
+            Days::new(1e-9),
+        )
+        .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            scan_events(
+                g,
+                Days::new(0.0)..=Days::new(0.0),
+                Days::new(0.5),
+                Days::new(1e-9)
+            )
+            .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn scan_stops_on_nan() {
+        let f = |t: Days| {
+            Degrees::new(if t.value() > 2.0 {
+                f64::NAN
+            } else {
+                t.value() - 0.25
+            })
+        };
+        let events: Vec<_> = scan_events(
+            f,
+            Days::new(0.0)..=Days::new(5.0),
+            Days::new(1.0),
+            Days::new(1e-6),
+        )
+        .collect();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_ok());
+        assert_eq!(events[1], Err(RootError::NotFinite));
+    }
+
+    #[test]
+    #[should_panic(expected = "positive")]
+    fn scan_rejects_non_positive_step() {
+        let f = |t: Days| Degrees::new(t.value());
+        let _ = scan_events(
+            f,
+            Days::new(0.0)..=Days::new(1.0),
+            Days::new(0.0),
+            Days::new(1e-6),
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Database column support for [`Quantity`]: `sqlx` and `diesel` map `Quantity<U>` to a `DOUBLE PRECISION` column.
+//!
+//! The stored number is the value in `U` itself, not in the dimension's canonical unit, so a column read back as
+//! `Kilometers` must have been written as `Kilometers`. Name the unit in the column (`distance_km`) or convert to the
+//! canonical unit (`.to::<Meter>()`) before writing when several units share a table.
+
+use crate::{Quantity, Unit};
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use super::*;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::{PgHasArrayType, PgTypeInfo};
+    use sqlx::{Database, Decode, Encode, Type};
+
+    impl<U: Unit, DB: Database> Type<DB> for Quantity<U>
+    where
+        f64: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <f64 as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <f64 as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, U: Unit, DB: Database> Encode<'q, DB> for Quantity<U>
+    where
+        f64: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.value().encode_by_ref(buf)
+        }
+
+        fn size_hint(&self) -> usize {
+            self.value().size_hint()
+        }
+    }
+
+    impl<'r, U: Unit, DB: Database> Decode<'r, DB> for Quantity<U>
+    where
+        f64: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            f64::decode(value).map(Quantity::new)
+        }
+    }
+
+    impl<U: Unit> PgHasArrayType for Quantity<U> {
+        fn array_type_info() -> PgTypeInfo {
+            f64::array_type_info()
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use super::*;
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::Double;
+
+    // `AsExpression` and `FromSqlRow` are derived on `Quantity` itself.
+
+    impl<U: Unit, DB: Backend> ToSql<Double, DB> for Quantity<U>
+    where
+        f64: ToSql<Double, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.value_ref().to_sql(out)
+        }
+    }
+
+    impl<U: Unit, DB: Backend> FromSql<Double, DB> for Quantity<U>
+    where
+        f64: FromSql<Double, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            f64::from_sql(bytes).map(Quantity::new)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    #[allow(unused_imports)]
+    use crate::units::length::{Kilometers, Meters};
+
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn sqlx_maps_to_double_precision() {
+        use sqlx::encode::IsNull;
+        use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, Postgres};
+        use sqlx::{Decode, Encode, Type, TypeInfo};
+
+        let info = <Meters as Type<Postgres>>::type_info();
+        assert_eq!(info.name(), "FLOAT8");
+        assert!(<Kilometers as Type<Postgres>>::compatible(&info));
+        assert_eq!(Meters::array_type_info().name(), "FLOAT8[]");
+
+        let mut typed = PgArgumentBuffer::default();
+        let mut raw = PgArgumentBuffer::default();
+        let null = Meters::new(1.5).encode_by_ref(&mut typed).unwrap();
+        assert!(matches!(null, IsNull::No));
+        let _ = 1.5_f64.encode_by_ref(&mut raw).unwrap();
+        assert_eq!(&typed[..], &raw[..]);
+
+        fn decodes<'r, T: Decode<'r, Postgres>>() {}
+        decodes::<Kilometers>();
+    }
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn diesel_maps_to_double() {
+        use diesel::deserialize::{FromSql, FromSqlRow};
+        use diesel::expression::AsExpression;
+        use diesel::pg::Pg;
+        use diesel::serialize::ToSql;
+        use diesel::sql_types::{Double, Nullable};
+
+        fn column<T>()
+        where
+            T: AsExpression<Double>
+                + AsExpression<Nullable<Double>>
+                + ToSql<Double, Pg>
+                + FromSql<Double, Pg>
+                + FromSqlRow<Double, Pg>,
+        {
+        }
+        column::<Meters>();
+        column::<Kilometers>();
+    }
+}
@@ -0,0 +1,419 @@
+//! Unit-checked statistics over samples of quantities.
+//!
+//! A [`Histogram<U>`] bins samples of any unit of `U`'s dimension against bin edges stored in `U`, and reports its bin
+//! edges and centres back as quantities, so magnitude, angle or velocity distributions never pass through bare
+//! numbers. The estimators ([`weighted_mean`], [`median`], [`median_abs_deviation`], [`sigma_clip`]) likewise take
+//! and return quantities.
+//!
+//! ```rust
+//! use qtty_core::angular::{Arcminutes, Degrees};
+//! use qtty_core::stats::Histogram;
+//!
+//! let mut separations = Histogram::from_range(Degrees::new(0.0)..Degrees::new(1.0), 4);
+//! separations.add(Degrees::new(0.1));
+//! separations.add(Arcminutes::new(20.0));
+//! separations.extend([0.3, 0.9, 1.5].map(Degrees::new));
+//!
+//! assert_eq!(separations.counts(), [1, 2, 0, 1]);
+//! assert_eq!(separations.overflow(), 1);
+//! assert_eq!(separations.centers().nth(1), Some(Degrees::new(0.375)));
+//! ```
+//!
+//! Robust photometry: a cosmic-ray hit is rejected by sigma clipping but drags the plain mean.
+//!
+//! ```rust
+//! use qtty_core::stats::{median_abs_deviation, sigma_clip};
+//! use qtty_core::time::Seconds;
+//!
+//! let delays = [10.1, 9.9, 10.0, 10.2, 9.8, 55.0].map(Seconds::new);
+//! let clipped = sigma_clip(&delays, 2.0).unwrap();
+//! assert_eq!(clipped.kept, [true, true, true, true, true, false]);
+//! assert!((clipped.mean.value() - 10.0).abs() < 1e-12);
+//! assert!((median_abs_deviation(&delays).unwrap().value() - 0.15).abs() < 1e-12);
+//! ```
+//!
+//! Only available with the `std` feature.
+
+use crate::{Quantity, SameDimension, Unit};
+use core::ops::Range;
+
+/// Counts of samples falling between consecutive bin edges in unit `U`.
+///
+/// Bin `i` covers `[edges[i], edges[i + 1])`; the last bin also includes its upper edge, as in NumPy. Samples below
+/// the first edge or above the last are tallied in [`underflow`](Self::underflow) and
+/// [`overflow`](Self::overflow); `NaN` samples are dropped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram<U: Unit> {
+    edges: Vec<Quantity<U>>,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+}
+
+impl<U: Unit> Histogram<U> {
+    /// An empty histogram with `n_bins` equal-width bins spanning `range`.
+    ///
+    /// # Panics
+    ///
+    /// If `n_bins` is zero, or `range` is empty or not finite.
+    pub fn from_range(range: Range<Quantity<U>>, n_bins: usize) -> Self {
+        let (lo, hi) = (range.start.value(), range.end.value());
+        assert!(n_bins > 0, "Histogram: `n_bins` must be positive");
+        assert!(
+            lo.is_finite() && hi.is_finite() && lo < hi,
+            "Histogram: `range` must be finite and non-empty"
+        );
+        let width = (hi - lo) / n_bins as f64;
+        let edges = (0..=n_bins)
+            .map(|i| {
+                // Pin the last edge so the upper bound is exactly `range.end`.
+                Quantity::new(if i == n_bins {
+                    hi
+                } else {
+                    lo + width * i as f64
+                })
+            })
+            .collect();
+        Self::with_edges(edges)
+    }
+
+    /// An empty histogram with the given bin edges, in any unit of `U`'s dimension.
+    ///
+    /// # Panics
+    ///
+    /// If fewer than two edges are given, or they are not finite and strictly increasing.
+    pub fn from_edges<V: Unit>(edges: &[Quantity<V>]) -> Self
+    where
+        V::Dim: SameDimension<U::Dim>,
+    {
+        assert!(
+            edges.len() >= 2,
+            "Histogram: at least two bin edges are required"
+        );
+        let edges: Vec<Quantity<U>> = edges.iter().map(|e| e.to::<U>()).collect();
+        assert!(
+            edges.iter().all(|e| e.value().is_finite())
+                && edges.windows(2).all(|w| w[0].value() < w[1].value()),
+            "Histogram: bin edges must be finite and strictly increasing"
+        );
+        Self::with_edges(edges)
+    }
+
+    fn with_edges(edges: Vec<Quantity<U>>) -> Self {
+        Self {
+            counts: vec![0; edges.len() - 1],
+            edges,
+            underflow: 0,
+            overflow: 0,
+        }
+    }
+
+    /// The index of the bin containing `sample`, or `None` if it lies outside the edges or is `NaN`.
+    pub fn bin_of<V: Unit>(&self, sample: Quantity<V>) -> Option<usize>
+    where
+        V::Dim: SameDimension<U::Dim>,
+    {
+        let x = sample.to::<U>().value();
+        let (first, last) = (self.edges[0].value(), self.edges[self.counts.len()].value());
+        if !(first..=last).contains(&x) {
+            return None;
+        }
+        // Number of edges at or below `x`, minus one; the upper edge folds into the last bin.
+        let i = self.edges.partition_point(|e| e.value() <= x) - 1;
+        Some(i.min(self.counts.len() - 1))
+    }
+
+    /// Counts one sample, in any unit of `U`'s dimension.
+    pub fn add<V: Unit>(&mut self, sample: Quantity<V>)
+    where
+        V::Dim: SameDimension<U::Dim>,
+    {
+        let sample = sample.to::<U>();
+        match self.bin_of(sample) {
+            Some(i) => self.counts[i] += 1,
+            None if sample.value() < self.edges[0].value() => self.underflow += 1,
+            None if sample.value().is_nan() => {}
+            None => self.overflow += 1,
+        }
+    }
+
+    /// The number of bins.
+    pub fn n_bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The `n_bins + 1` bin edges, in increasing order.
+    pub fn edges(&self) -> &[Quantity<U>] {
+        &self.edges
+    }
+
+    /// The per-bin sample counts.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// The midpoint of each bin.
+    pub fn centers(&self) -> impl ExactSizeIterator<Item = Quantity<U>> + '_ {
+        self.edges
+            .windows(2)
+            .map(|w| Quantity::new(0.5 * (w[0].value() + w[1].value())))
+    }
+
+    /// The width of each bin.
+    pub fn widths(&self) -> impl ExactSizeIterator<Item = Quantity<U>> + '_ {
+        self.edges
+            .windows(2)
+            .map(|w| Quantity::new(w[1].value() - w[0].value()))
+    }
+
+    /// Samples below the first edge.
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Samples above the last edge.
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Samples counted in some bin (excluding underflow and overflow).
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Resets every count to zero, keeping the bin edges.
+    pub fn clear(&mut self) {
+        self.counts.iter_mut().for_each(|c| *c = 0);
+        self.underflow = 0;
+        self.overflow = 0;
+    }
+}
+
+impl<U: Unit, V: Unit> Extend<Quantity<V>> for Histogram<U>
+where
+    V::Dim: SameDimension<U::Dim>,
+{
+    fn extend<I: IntoIterator<Item = Quantity<V>>>(&mut self, samples: I) {
+        for sample in samples {
+            self.add(sample);
+        }
+    }
+}
+
+/// Scale factor turning a [`median_abs_deviation`] into a standard-deviation estimate for normally distributed data.
+pub const MAD_TO_SIGMA: f64 = 1.482_602_218_505_602;
+
+/// `Σ wᵢ xᵢ / Σ wᵢ`, or `None` when the weights sum to zero (including when `values` is empty).
+///
+/// Weights are plain numbers, typically inverse variances `1 / σᵢ²`.
+///
+/// # Panics
+///
+/// If `values` and `weights` differ in length.
+pub fn weighted_mean<U: Unit>(values: &[Quantity<U>], weights: &[f64]) -> Option<Quantity<U>> {
+    assert_eq!(
+        values.len(),
+        weights.len(),
+        "weighted_mean: `values` and `weights` differ in length"
+    );
+    let x = Quantity::slice_as_raw(values);
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 {
+        return None;
+    }
+    let sum: f64 = x.iter().zip(weights).map(|(x, w)| x * w).sum();
+    Some(Quantity::new(sum / total))
+}
+
+/// The median of the non-`NaN` values, or `None` if there are none.
+///
+/// An even count averages the two middle values.
+pub fn median<U: Unit>(values: &[Quantity<U>]) -> Option<Quantity<U>> {
+    let mut x: Vec<f64> = Quantity::slice_as_raw(values)
+        .iter()
+        .copied()
+        .filter(|x| !x.is_nan())
+        .collect();
+    median_in_place(&mut x).map(Quantity::new)
+}
+
+/// The median absolute deviation `median(|xᵢ − median(x)|)` of the non-`NaN` values, or `None` if there are none.
+///
+/// Multiply by [`MAD_TO_SIGMA`] for a standard-deviation estimate that a few outliers cannot inflate.
+pub fn median_abs_deviation<U: Unit>(values: &[Quantity<U>]) -> Option<Quantity<U>> {
+    let mut x: Vec<f64> = Quantity::slice_as_raw(values)
+        .iter()
+        .copied()
+        .filter(|x| !x.is_nan())
+        .collect();
+    let center = median_in_place(&mut x)?;
+    x.iter_mut().for_each(|x| *x = (*x - center).abs());
+    median_in_place(&mut x).map(Quantity::new)
+}
+
+fn median_in_place(x: &mut [f64]) -> Option<f64> {
+    let n = x.len();
+    if n == 0 {
+        return None;
+    }
+    x.sort_unstable_by(f64::total_cmp);
+    Some(if n % 2 == 1 {
+        x[n / 2]
+    } else {
+        0.5 * (x[n / 2 - 1] + x[n / 2])
+    })
+}
+
+/// Statistics of the samples surviving [`sigma_clip`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigmaClipped<U: Unit> {
+    /// Mean of the kept samples.
+    pub mean: Quantity<U>,
+    /// Median of the kept samples.
+    pub median: Quantity<U>,
+    /// Population standard deviation of the kept samples.
+    pub std_dev: Quantity<U>,
+    /// `true` for each input sample that was kept, in input order.
+    pub kept: Vec<bool>,
+}
+
+impl<U: Unit> SigmaClipped<U> {
+    /// The number of samples kept.
+    pub fn n_kept(&self) -> usize {
+        self.kept.iter().filter(|&&k| k).count()
+    }
+}
+
+/// Iteratively rejects samples more than `k` standard deviations from the median, until none are rejected.
+///
+/// Each pass takes the median and population standard deviation of the samples still kept and drops those with
+/// `|x − median| > k·σ`. `NaN` samples are never kept. Returns `None` if no sample is kept to begin with.
+pub fn sigma_clip<U: Unit>(values: &[Quantity<U>], k: f64) -> Option<SigmaClipped<U>> {
+    let x = Quantity::slice_as_raw(values);
+    let mut kept: Vec<bool> = x.iter().map(|x| !x.is_nan()).collect();
+    let mut scratch = Vec::with_capacity(x.len());
+    loop {
+        scratch.clear();
+        scratch.extend(x.iter().zip(&kept).filter(|(_, &k)| k).map(|(x, _)| *x));
+        let n = scratch.len() as f64;
+        let mean = scratch.iter().sum::<f64>() / n;
+        let std_dev = (scratch.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+        let median = median_in_place(&mut scratch)?;
+
+        let mut rejected = false;
+        for (x, k_i) in x.iter().zip(kept.iter_mut()) {
+            if *k_i && (x - median).abs() > k * std_dev {
+                *k_i = false;
+                rejected = true;
+            }
+        }
+        if !rejected {
+            return Some(SigmaClipped {
+                mean: Quantity::new(mean),
+                median: Quantity::new(median),
+                std_dev: Quantity::new(std_dev),
+                kept,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length::{Kilometers, Meter, Meters};
+    use crate::time::{Minutes, Seconds};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bins_are_half_open_except_the_last() {
+        let mut h = Histogram::from_range(Meters::new(0.0)..Meters::new(3.0), 3);
+        h.extend([0.0, 0.999, 1.0, 2.5, 3.0].map(Meters::new));
+        assert_eq!(h.counts(), [2, 1, 2]);
+        assert_eq!(h.total(), 5);
+        assert_eq!((h.underflow(), h.overflow()), (0, 0));
+
+        h.extend([-0.1, 3.1, f64::NAN].map(Meters::new));
+        assert_eq!((h.underflow(), h.overflow()), (1, 1));
+        assert_eq!(h.total(), 5);
+
+        h.clear();
+        assert_eq!(h.counts(), [0, 0, 0]);
+        assert_eq!(h.underflow(), 0);
+    }
+
+    #[test]
+    fn converts_samples_and_edges() {
+        let mut h = Histogram::<Meter>::from_edges(&[0.0, 0.5, 2.0].map(Kilometers::new));
+        assert_eq!(h.edges()[1], Meters::new(500.0));
+        h.add(Kilometers::new(1.0));
+        h.add(Meters::new(100.0));
+        assert_eq!(h.counts(), [1, 1]);
+        assert_eq!(h.bin_of(Kilometers::new(2.0)), Some(1));
+        assert_eq!(h.bin_of(Meters::new(-1.0)), None);
+    }
+
+    #[test]
+    fn reports_centers_and_widths() {
+        let h = Histogram::from_range(Seconds::new(0.0)..Seconds::new(120.0), 4);
+        assert_eq!(h.n_bins(), 4);
+        let centers: Vec<f64> = h.centers().map(|c| c.value()).collect();
+        assert_eq!(centers, [15.0, 45.0, 75.0, 105.0]);
+        assert!(h.widths().all(|w| w == Seconds::new(30.0)));
+        assert_eq!(h.edges().last(), Some(&Minutes::new(2.0).to()));
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn rejects_unsorted_edges() {
+        Histogram::<Meter>::from_edges(&[0.0, 2.0, 1.0].map(Meters::new));
+    }
+
+    #[test]
+    #[should_panic(expected = "non-empty")]
+    fn rejects_empty_range() {
+        Histogram::from_range(Meters::new(1.0)..Meters::new(1.0), 4);
+    }
+
+    #[test]
+    fn weighted_mean_uses_weights() {
+        let x = [10.0, 12.0].map(Meters::new);
+        assert_eq!(weighted_mean(&x, &[3.0, 1.0]), Some(Meters::new(10.5)));
+        assert_eq!(weighted_mean(&x, &[0.0, 0.0]), None);
+        assert_eq!(weighted_mean::<Meter>(&[], &[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "differ in length")]
+    fn weighted_mean_rejects_mismatched_weights() {
+        weighted_mean(&[Meters::new(1.0)], &[]);
+    }
+
+    #[test]
+    fn median_and_mad_skip_nan() {
+        let x = [3.0, f64::NAN, 1.0, 2.0, 10.0].map(Seconds::new);
+        assert_eq!(median(&x), Some(Seconds::new(2.5)));
+        assert_eq!(median_abs_deviation(&x), Some(Seconds::new(1.0)));
+        assert_eq!(median(&[Seconds::new(f64::NAN)]), None);
+        assert_eq!(median_abs_deviation::<Meter>(&[]), None);
+    }
+
+    #[test]
+    fn sigma_clip_iterates_to_convergence() {
+        // The 30 hides the 14 on the first pass; once it is gone the 14 stands out too.
+        let x = [10.0, 11.0, 9.0, 10.0, 11.0, 9.0, 10.0, 14.0, 30.0, f64::NAN].map(Meters::new);
+        let clipped = sigma_clip(&x, 2.0).unwrap();
+        assert_eq!(
+            clipped.kept,
+            [true, true, true, true, true, true, true, false, false, false]
+        );
+        assert_eq!(clipped.n_kept(), 7);
+        assert_relative_eq!(clipped.mean.value(), 10.0);
+        assert_eq!(clipped.median, Meters::new(10.0));
+        assert_relative_eq!(clipped.std_dev.value(), (4.0f64 / 7.0).sqrt());
+
+        let flat = sigma_clip(&[Meters::new(1.0); 3], 3.0).unwrap();
+        assert_eq!(flat.n_kept(), 3);
+        assert!(sigma_clip::<Meter>(&[], 3.0).is_none());
+    }
+}
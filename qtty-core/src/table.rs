@@ -0,0 +1,439 @@
+//! Unit-aware ingestion of table and CSV columns.
+//!
+//! Catalogs usually record the unit of a column in its header (`"radial_velocity [km/s]"`, `"parallax (mas)"`) and
+//! store bare numbers in the cells. A [`Column<U>`] reads the header once, checks that its unit has the same
+//! dimension as the requested unit `U`, and then converts every raw cell into a `Quantity<U>`, either directly with
+//! [`Column::convert`] or as a serde [`DeserializeSeed`] while deserializing a row.
+//!
+//! Header units are resolved through the [`registry`] module: single symbols (`pc`, `Mas`), quotients and
+//! products of them (`km/s`, `g/cm^3`, `kg·m²/s`). Symbols are matched exactly first, then case-insensitively when
+//! that is unambiguous, so `km` and `mas` find `Km` and `Mas` while `mm` and `Mm` stay distinct. A header unit that
+//! spells `U`'s own symbol is always accepted, even when `U` is not in the registry.
+//!
+//! ```rust
+//! use qtty_core::length::Meter;
+//! use qtty_core::table::Column;
+//! use qtty_core::time::Second;
+//! use qtty_core::Per;
+//!
+//! let rv = Column::<Per<Meter, Second>>::from_header("radial_velocity [km/s]").unwrap();
+//! assert_eq!(rv.name(), "radial_velocity");
+//! assert_eq!(rv.convert(12.5).value(), 12_500.0);
+//!
+//! // A length column cannot feed a velocity.
+//! assert!(Column::<Per<Meter, Second>>::from_header("distance [pc]").is_err());
+//! ```
+
+use crate::registry::{self, RegisteredUnit};
+use crate::unit::SymbolTerms;
+use crate::{Quantity, Unit};
+use core::any::TypeId;
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use serde::de::{DeserializeSeed, Deserializer};
+use serde::Deserialize;
+
+/// Error returned when a column header cannot be mapped onto the requested unit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColumnError {
+    /// The header carries no `[unit]` or `(unit)` annotation.
+    MissingUnit {
+        /// The full header text.
+        header: String,
+    },
+    /// A symbol in the header unit is not in the registry, or the unit expression is malformed.
+    UnknownUnit {
+        /// The offending symbol or expression.
+        symbol: String,
+    },
+    /// The header unit has a different dimension from the requested unit.
+    Incompatible {
+        /// The header unit.
+        found: String,
+        /// The requested unit's symbol.
+        expected: String,
+    },
+}
+
+impl Display for ColumnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUnit { header } => {
+                write!(f, "column header `{}` has no [unit] annotation", header)
+            }
+            Self::UnknownUnit { symbol } => write!(f, "unknown unit `{}`", symbol),
+            Self::Incompatible { found, expected } => {
+                write!(f, "unit `{}` cannot be converted to `{}`", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColumnError {}
+
+/// A column header split into its name and optional unit annotation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnHeader<'a> {
+    /// The header text before the unit annotation, trimmed.
+    pub name: &'a str,
+    /// The text inside a trailing `[...]` or `(...)`, trimmed.
+    pub unit: Option<&'a str>,
+}
+
+impl<'a> ColumnHeader<'a> {
+    /// Splits `"name [unit]"` or `"name (unit)"`; any other header has no unit.
+    ///
+    /// ```rust
+    /// use qtty_core::table::ColumnHeader;
+    ///
+    /// let h = ColumnHeader::parse("pm_ra (mas/yr)");
+    /// assert_eq!((h.name, h.unit), ("pm_ra", Some("mas/yr")));
+    /// assert_eq!(ColumnHeader::parse("id").unit, None);
+    /// ```
+    pub fn parse(header: &'a str) -> Self {
+        let trimmed = header.trim();
+        for (open, close) in [('[', ']'), ('(', ')')] {
+            if let Some(body) = trimmed.strip_suffix(close) {
+                if let Some(start) = body.rfind(open) {
+                    return Self {
+                        name: body[..start].trim(),
+                        unit: Some(body[start + open.len_utf8()..].trim()),
+                    };
+                }
+            }
+        }
+        Self {
+            name: trimmed,
+            unit: None,
+        }
+    }
+}
+
+/// A typed view of a table column whose header declares its unit.
+///
+/// Cells are converted with a single factor computed from the header, so reading a column costs one multiplication
+/// per value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column<U: Unit> {
+    name: String,
+    unit: String,
+    factor: f64,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit> Column<U> {
+    /// Builds the column from a header such as `"radial_velocity [km/s]"`.
+    pub fn from_header(header: &str) -> Result<Self, ColumnError> {
+        let parsed = ColumnHeader::parse(header);
+        let unit = parsed.unit.ok_or_else(|| ColumnError::MissingUnit {
+            header: header.to_owned(),
+        })?;
+        Ok(Self {
+            name: parsed.name.to_owned(),
+            unit: unit.to_owned(),
+            factor: unit_factor::<U>(unit)?,
+            _unit: PhantomData,
+        })
+    }
+
+    /// The column name, without its unit annotation.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The unit written in the header.
+    pub fn header_unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Factor that turns a raw cell value into a value in `U`.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Converts a raw cell value into the requested unit.
+    #[inline]
+    pub fn convert(&self, raw: f64) -> Quantity<U> {
+        Quantity::new(raw * self.factor)
+    }
+}
+
+impl<'de, U: Unit> DeserializeSeed<'de> for &Column<U> {
+    type Value = Quantity<U>;
+
+    /// Deserializes a raw number and converts it into `U`.
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Quantity<U>, D::Error> {
+        f64::deserialize(deserializer).map(|raw| self.convert(raw))
+    }
+}
+
+/// Factor converting values written in the unit `symbol` into values in `U`.
+///
+/// ```rust
+/// use qtty_core::table::unit_factor;
+/// use qtty_core::density::KilogramPerCubicMeter;
+///
+/// assert!((unit_factor::<KilogramPerCubicMeter>("g/cm^3").unwrap() - 1_000.0).abs() < 1e-9);
+/// ```
+pub fn unit_factor<U: Unit>(symbol: &str) -> Result<f64, ColumnError> {
+    let symbol = symbol.trim();
+    let expected = UnitSymbol::<U>(PhantomData).to_string();
+    if symbol == expected {
+        return Ok(1.0);
+    }
+
+    let found = match lookup(symbol) {
+        Some(unit) => Signature::new().with(unit, 1),
+        None => {
+            let terms = parse_terms(symbol).ok_or_else(|| ColumnError::UnknownUnit {
+                symbol: symbol.to_owned(),
+            })?;
+            Signature::resolve(terms).map_err(|s| ColumnError::UnknownUnit {
+                symbol: s.to_owned(),
+            })?
+        }
+    };
+
+    let mut terms = SymbolTerms::new();
+    U::symbol_terms(&mut terms, 1);
+    let incompatible = || ColumnError::Incompatible {
+        found: symbol.to_owned(),
+        expected: expected.clone(),
+    };
+    if terms.is_truncated() {
+        return Err(incompatible());
+    }
+    let wanted = Signature::resolve(terms.iter()).map_err(|_| incompatible())?;
+    if found.dims != wanted.dims {
+        return Err(incompatible());
+    }
+    Ok(found.ratio / U::RATIO)
+}
+
+/// Looks a symbol up exactly, then case-insensitively if exactly one registered unit matches.
+fn lookup(symbol: &str) -> Option<&'static RegisteredUnit> {
+    registry::find_by_symbol(symbol).or_else(|| {
+        let mut matches = registry::iter().filter(|u| u.symbol().eq_ignore_ascii_case(symbol));
+        let first = matches.next()?;
+        matches.next().is_none().then_some(first)
+    })
+}
+
+/// Splits `a·b/c^2` (also `*`, spaces, `/(c d)`, `²`, `³`) into `(symbol, exponent)` factors.
+fn parse_terms(expr: &str) -> Option<Vec<(&str, i32)>> {
+    let mut terms = Vec::new();
+    for (i, segment) in expr.split('/').enumerate() {
+        let sign = if i == 0 { 1 } else { -1 };
+        let segment = segment.trim();
+        let segment = segment
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(segment);
+        let mut factors = segment
+            .split(|c: char| c == '·' || c == '*' || c.is_whitespace())
+            .filter(|f| !f.is_empty())
+            .peekable();
+        factors.peek()?;
+        for factor in factors {
+            let (symbol, exponent) = if let Some((symbol, exp)) = factor.split_once('^') {
+                (symbol, exp.parse::<i32>().ok()?)
+            } else if let Some(symbol) = factor.strip_suffix('²') {
+                (symbol, 2)
+            } else if let Some(symbol) = factor.strip_suffix('³') {
+                (symbol, 3)
+            } else {
+                (factor, 1)
+            };
+            // A bare `1` only appears as the numerator of `1/s`.
+            if symbol == "1" && i == 0 && exponent == 1 {
+                continue;
+            }
+            if symbol.is_empty() {
+                return None;
+            }
+            terms.push((symbol, sign * exponent));
+        }
+    }
+    Some(terms)
+}
+
+/// Dimension exponents (by dimension [`TypeId`]) and overall ratio of a unit expression.
+struct Signature {
+    dims: Vec<(TypeId, i32)>,
+    ratio: f64,
+}
+
+impl Signature {
+    fn new() -> Self {
+        Self {
+            dims: Vec::new(),
+            ratio: 1.0,
+        }
+    }
+
+    fn resolve<'a>(terms: impl IntoIterator<Item = (&'a str, i32)>) -> Result<Self, &'a str> {
+        terms
+            .into_iter()
+            .try_fold(Self::new(), |sig, (symbol, exponent)| {
+                lookup(symbol)
+                    .map(|unit| sig.with(unit, exponent))
+                    .ok_or(symbol)
+            })
+    }
+
+    fn with(mut self, unit: &RegisteredUnit, exponent: i32) -> Self {
+        self.ratio *= unit.info.ratio.powi(exponent);
+        for (id, power) in dimension_factors(unit) {
+            let power = power * exponent;
+            match self.dims.iter_mut().find(|(d, _)| *d == id) {
+                Some(entry) => entry.1 += power,
+                None => self.dims.push((id, power)),
+            }
+        }
+        self.dims.retain(|&(_, e)| e != 0);
+        self.dims.sort();
+        self
+    }
+}
+
+/// Dimensions of `unit`, split into the factors of its symbol when it is written as a product or quotient of
+/// registered units (`Km/s`, `W/m^2`), so that it compares equal to the same expression parsed term by term.
+fn dimension_factors(unit: &RegisteredUnit) -> Vec<(TypeId, i32)> {
+    let whole = || vec![(unit.dimension_id(), 1)];
+    let Some(terms) = parse_terms(unit.symbol()) else {
+        return whole();
+    };
+    if terms.len() == 1 && terms[0].1 == 1 {
+        return whole();
+    }
+    terms
+        .into_iter()
+        .map(|(symbol, exponent)| lookup(symbol).map(|u| (u.dimension_id(), exponent)))
+        .collect::<Option<_>>()
+        .unwrap_or_else(whole)
+}
+
+/// Displays `U`'s symbol, including the expanded form of composite units.
+struct UnitSymbol<U>(PhantomData<U>);
+
+impl<U: Unit> Display for UnitSymbol<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        U::fmt_symbol(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::MilliArcsecond;
+    use crate::units::area::SquareKilometer;
+    use crate::units::density::KilogramPerCubicMeter;
+    use crate::units::flux::Jansky;
+    use crate::units::length::{Kilometer, Meter, Parsec};
+    use crate::units::time::{Second, Year};
+    use crate::units::velocity::MilePerHour;
+    use crate::Per;
+    use approx::assert_relative_eq;
+
+    type KmPerSecond = Per<Kilometer, Second>;
+
+    #[test]
+    fn header_split() {
+        let h = ColumnHeader::parse("  radial_velocity [km/s] ");
+        assert_eq!(h.name, "radial_velocity");
+        assert_eq!(h.unit, Some("km/s"));
+        let h = ColumnHeader::parse("flux (Jy)");
+        assert_eq!((h.name, h.unit), ("flux", Some("Jy")));
+        assert_eq!(ColumnHeader::parse("name").unit, None);
+        assert_eq!(ColumnHeader::parse("x []").unit, Some(""));
+    }
+
+    #[test]
+    fn composite_headers_convert() {
+        let rv = Column::<Per<Meter, Second>>::from_header("rv [km/s]").unwrap();
+        assert_eq!(rv.header_unit(), "km/s");
+        assert_relative_eq!(rv.convert(2.0).value(), 2_000.0);
+
+        let pm = Column::<Per<MilliArcsecond, Year>>::from_header("pm_ra (mas/yr)").unwrap();
+        assert_relative_eq!(pm.factor(), 1.0);
+
+        assert_relative_eq!(
+            unit_factor::<KilogramPerCubicMeter>("g / cm^3").unwrap(),
+            1_000.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(unit_factor::<SquareKilometer>("m²").unwrap(), 1e-6);
+        assert_relative_eq!(
+            unit_factor::<Per<crate::Unitless, Second>>("1/s").unwrap(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn registered_composites_match_their_factors() {
+        assert_relative_eq!(unit_factor::<Per<Meter, Second>>("Km/s").unwrap(), 1_000.0);
+        assert_relative_eq!(
+            unit_factor::<Per<Meter, Second>>("mi/h").unwrap(),
+            MilePerHour::RATIO,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            unit_factor::<MilePerHour>("Km/h").unwrap(),
+            1_000.0 / 3_600.0 / MilePerHour::RATIO,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn own_symbol_is_accepted_even_if_unregistered() {
+        assert_eq!(unit_factor::<Per<Parsec, Second>>("pc/s").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn case_insensitive_only_when_unambiguous() {
+        assert_relative_eq!(unit_factor::<Meter>("km").unwrap(), 1_000.0);
+        assert_relative_eq!(unit_factor::<Meter>("mm").unwrap(), 1e-3);
+        assert_relative_eq!(unit_factor::<Meter>("Mm").unwrap(), 1e6);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Column::<Parsec>::from_header("distance"),
+            Err(ColumnError::MissingUnit {
+                header: "distance".into()
+            })
+        );
+        assert_eq!(
+            unit_factor::<Meter>("furlong"),
+            Err(ColumnError::UnknownUnit {
+                symbol: "furlong".into()
+            })
+        );
+        assert!(matches!(
+            unit_factor::<Meter>("km/"),
+            Err(ColumnError::UnknownUnit { .. })
+        ));
+        let err = Column::<KmPerSecond>::from_header("d [pc]").unwrap_err();
+        assert_eq!(err.to_string(), "unit `pc` cannot be converted to `Km/s`");
+        assert!(matches!(
+            unit_factor::<Jansky>("W/m^2"),
+            Err(ColumnError::Incompatible { .. })
+        ));
+    }
+
+    #[test]
+    fn spectral_flux_density_headers_convert() {
+        assert_relative_eq!(unit_factor::<Jansky>("mJy").unwrap(), 1e-3);
+        assert_relative_eq!(unit_factor::<Jansky>("µJy").unwrap(), 1e-6);
+    }
+
+    #[test]
+    fn deserialize_seed_converts_cells() {
+        let column = Column::<Meter>::from_header("r [Km]").unwrap();
+        let mut de = serde_json::Deserializer::from_str("1.5");
+        let q = (&column).deserialize(&mut de).unwrap();
+        assert_relative_eq!(q.value(), 1_500.0);
+    }
+}
@@ -0,0 +1,369 @@
+//! Proptest strategies and invariant checks for typed quantities.
+//!
+//! Enabled by the `test-util` feature (which implies `std`). Downstream crates can generate typed inputs directly in
+//! their own property tests instead of mapping raw `f64` generators by hand:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use qtty_core::length::Kilometer;
+//! use qtty_core::testutil::{any_degrees, any_length, check_wrap_invariants};
+//!
+//! proptest!(|(d in any_length::<Kilometer>(), a in any_degrees())| {
+//!     prop_assert!(d.value().is_finite());
+//!     check_wrap_invariants(a)?;
+//! });
+//! ```
+//!
+//! Every strategy yields finite values only. The default spans are wide enough to exercise wrapping and conversion
+//! paths while keeping round trips through any predefined unit well inside the `f64` range.
+//!
+//! The [`assert_quantity_eq!`](crate::assert_quantity_eq) and
+//! [`assert_quantity_relative_eq!`](crate::assert_quantity_relative_eq) macros compare quantities of possibly
+//! different units of one dimension, and print both sides with their symbols on failure:
+//!
+//! ```rust
+//! use qtty_core::angular::{Arcseconds, Degrees};
+//! use qtty_core::{assert_quantity_eq, assert_quantity_relative_eq};
+//!
+//! assert_quantity_eq!(Degrees::new(1.0), Arcseconds::new(3600.0004), tol = Arcseconds::new(1e-3));
+//! assert_quantity_relative_eq!(Degrees::new(1.0), Arcseconds::new(3600.0));
+//! ```
+
+use crate::dimension::SameDimension;
+use crate::units::angular::{AngularUnit, Degree, Degrees, Radian, Radians};
+use crate::units::length::LengthUnit;
+use crate::units::mass::MassUnit;
+use crate::units::time::TimeUnit;
+use crate::{Quantity, Unit};
+use core::fmt;
+use core::ops::Range;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+/// Magnitude bound used by [`any_quantity`], in the target unit.
+pub const DEFAULT_MAGNITUDE: f64 = 1e12;
+
+/// Number of full turns either side of zero covered by [`any_angle`].
+pub const DEFAULT_TURNS: f64 = 1_000.0;
+
+/// Quantities of unit `U` whose value lies in `range`.
+pub fn quantity_in<U: Unit>(range: Range<f64>) -> impl Strategy<Value = Quantity<U>> {
+    range.prop_map(Quantity::new)
+}
+
+/// Finite quantities of unit `U` in `(-DEFAULT_MAGNITUDE, DEFAULT_MAGNITUDE)`.
+pub fn any_quantity<U: Unit>() -> impl Strategy<Value = Quantity<U>> {
+    quantity_in(-DEFAULT_MAGNITUDE..DEFAULT_MAGNITUDE)
+}
+
+/// Angles of unit `U` spanning [`DEFAULT_TURNS`] revolutions in each direction.
+pub fn any_angle<U: AngularUnit>() -> impl Strategy<Value = Quantity<U>> {
+    let span = DEFAULT_TURNS * U::FULL_TURN;
+    quantity_in(-span..span)
+}
+
+/// Degrees spanning [`DEFAULT_TURNS`] revolutions in each direction.
+pub fn any_degrees() -> impl Strategy<Value = Degrees> {
+    any_angle::<Degree>()
+}
+
+/// Radians spanning [`DEFAULT_TURNS`] revolutions in each direction.
+pub fn any_radians() -> impl Strategy<Value = Radians> {
+    any_angle::<Radian>()
+}
+
+/// Finite lengths in unit `U` (see [`any_quantity`]).
+pub fn any_length<U: LengthUnit>() -> impl Strategy<Value = Quantity<U>> {
+    any_quantity::<U>()
+}
+
+/// Finite durations in unit `U` (see [`any_quantity`]).
+pub fn any_time<U: TimeUnit>() -> impl Strategy<Value = Quantity<U>> {
+    any_quantity::<U>()
+}
+
+/// Finite masses in unit `U` (see [`any_quantity`]).
+pub fn any_mass<U: MassUnit>() -> impl Strategy<Value = Quantity<U>> {
+    any_quantity::<U>()
+}
+
+/// Checks the documented range and periodicity guarantees of the angular wrapping helpers for one angle.
+///
+/// Intended for use inside `proptest!` bodies (`check_wrap_invariants(angle)?`). Verifies that
+/// - `wrap_pos` lands in `[0, FULL_TURN)`, `wrap_signed` in `(-HALF_TURN, HALF_TURN]`, `wrap_signed_lo` in
+///   `[-HALF_TURN, HALF_TURN)` and `wrap_quarter_fold` in `[-QUARTER_TURN, QUARTER_TURN]`;
+/// - `wrap_pos`, `wrap_signed` and `wrap_signed_lo` differ from the input by whole turns only;
+/// - wrapping is idempotent.
+pub fn check_wrap_invariants<U: AngularUnit + Copy>(
+    angle: Quantity<U>,
+) -> Result<(), TestCaseError> {
+    let full = U::FULL_TURN;
+    let half = U::HALF_TURN;
+    let quarter = U::QUARTER_TURN;
+    // Reduction of an input spanning many turns loses a few ULP of the input magnitude.
+    let tol = 1e-9 * full.max(angle.value().abs());
+
+    let pos = angle.wrap_pos().value();
+    prop_assert!(
+        (0.0..full).contains(&pos),
+        "wrap_pos({:?}) = {}",
+        angle.value(),
+        pos
+    );
+
+    let signed = angle.wrap_signed().value();
+    prop_assert!(
+        signed > -half && signed <= half,
+        "wrap_signed({:?}) = {}",
+        angle.value(),
+        signed
+    );
+
+    let lo = angle.wrap_signed_lo().value();
+    prop_assert!(
+        (-half..half).contains(&lo),
+        "wrap_signed_lo({:?}) = {}",
+        angle.value(),
+        lo
+    );
+
+    let fold = angle.wrap_quarter_fold().value();
+    prop_assert!(
+        (-quarter..=quarter).contains(&fold),
+        "wrap_quarter_fold({:?}) = {}",
+        angle.value(),
+        fold
+    );
+
+    for wrapped in [pos, signed, lo] {
+        let turns = (angle.value() - wrapped) / full;
+        prop_assert!(
+            (turns - turns.round()).abs() * full <= tol,
+            "{} is not {:?} shifted by whole turns",
+            wrapped,
+            angle.value()
+        );
+    }
+
+    prop_assert_eq!(Quantity::<U>::new(pos).wrap_pos().value(), pos);
+    prop_assert_eq!(Quantity::<U>::new(signed).wrap_signed().value(), signed);
+    Ok(())
+}
+
+/// Asserts that two quantities of one dimension differ by at most `tol`.
+///
+/// `right` and `tol` may be in any unit of the dimension of `left`; both are converted into the unit of `left` before
+/// comparing. On failure the message shows both sides with their symbols, `right` converted, and the difference. An
+/// optional format string and arguments are appended to the message, as with [`assert_eq!`].
+///
+/// ```rust
+/// use qtty_core::assert_quantity_eq;
+/// use qtty_core::length::{Kilometers, Meters};
+///
+/// assert_quantity_eq!(Kilometers::new(1.5), Meters::new(1500.2), tol = Meters::new(0.5));
+/// ```
+///
+/// ```rust,should_panic
+/// # use qtty_core::assert_quantity_eq;
+/// # use qtty_core::length::{Kilometers, Meters};
+/// assert_quantity_eq!(Kilometers::new(1.5), Meters::new(1501.0), tol = Meters::new(0.5), "pass {}", 3);
+/// ```
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr, tol = $tol:expr $(,)?) => {
+        $crate::testutil::assert_quantity_within($left, $right, $tol, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, tol = $tol:expr, $($arg:tt)+) => {
+        $crate::testutil::assert_quantity_within(
+            $left,
+            $right,
+            $tol,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Asserts that two quantities of one dimension agree to a relative tolerance.
+///
+/// `right` is converted into the unit of `left`, then `|left - right| <= max_relative * max(|left|, |right|)` must
+/// hold. `max_relative` defaults to [`f64::EPSILON`]. An optional format string and arguments are appended to the
+/// failure message.
+///
+/// ```rust
+/// use qtty_core::assert_quantity_relative_eq;
+/// use qtty_core::time::{Days, Hours};
+///
+/// assert_quantity_relative_eq!(Days::new(1.0), Hours::new(24.0));
+/// assert_quantity_relative_eq!(Days::new(1.0), Hours::new(24.000001), max_relative = 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_quantity_relative_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_quantity_relative_eq!($left, $right, max_relative = ::core::primitive::f64::EPSILON)
+    };
+    ($left:expr, $right:expr, max_relative = $max:expr $(,)?) => {
+        $crate::testutil::assert_quantity_relative($left, $right, $max, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, max_relative = $max:expr, $($arg:tt)+) => {
+        $crate::testutil::assert_quantity_relative(
+            $left,
+            $right,
+            $max,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Implementation of [`assert_quantity_eq!`](crate::assert_quantity_eq).
+#[track_caller]
+pub fn assert_quantity_within<A, B, T>(
+    left: Quantity<A>,
+    right: Quantity<B>,
+    tol: Quantity<T>,
+    message: Option<fmt::Arguments<'_>>,
+) where
+    A: Unit,
+    B: Unit,
+    T: Unit,
+    B::Dim: SameDimension<A::Dim>,
+    T::Dim: SameDimension<A::Dim>,
+{
+    let converted = right.to::<A>();
+    let diff = (left - converted).abs();
+    let tol_in_left = tol.to::<A>();
+    if diff.value() <= tol_in_left.value() {
+        return;
+    }
+    fail(
+        format_args!("|left - right| = {diff:?} > tol = {tol:?}"),
+        &left,
+        &right,
+        &converted,
+        message,
+    );
+}
+
+/// Implementation of [`assert_quantity_relative_eq!`](crate::assert_quantity_relative_eq).
+#[track_caller]
+pub fn assert_quantity_relative<A, B>(
+    left: Quantity<A>,
+    right: Quantity<B>,
+    max_relative: f64,
+    message: Option<fmt::Arguments<'_>>,
+) where
+    A: Unit,
+    B: Unit,
+    B::Dim: SameDimension<A::Dim>,
+{
+    let converted = right.to::<A>();
+    let diff = (left.value() - converted.value()).abs();
+    let scale = left.value().abs().max(converted.value().abs());
+    if diff <= max_relative * scale {
+        return;
+    }
+    fail(
+        format_args!(
+            "relative difference {:e} > max_relative = {max_relative:e}",
+            diff / scale
+        ),
+        &left,
+        &right,
+        &converted,
+        message,
+    );
+}
+
+#[track_caller]
+fn fail<A: Unit, B: Unit>(
+    reason: fmt::Arguments<'_>,
+    left: &Quantity<A>,
+    right: &Quantity<B>,
+    converted: &Quantity<A>,
+    message: Option<fmt::Arguments<'_>>,
+) -> ! {
+    match message {
+        Some(message) => panic!(
+            "assertion `left ≈ right` failed: {message}\n {reason}\n  left: {left:?}\n right: {right:?} = {converted:?}"
+        ),
+        None => panic!(
+            "assertion `left ≈ right` failed\n {reason}\n  left: {left:?}\n right: {right:?} = {converted:?}"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Arcsecond, Arcseconds, HourAngle};
+    use crate::units::length::{AstronomicalUnit, Meter, Meters};
+    use crate::units::mass::Kilogram;
+    use crate::units::time::{Day, Days, Hours, Seconds};
+
+    proptest! {
+        #[test]
+        fn prop_wrap_invariants_degrees(a in any_degrees()) {
+            check_wrap_invariants(a)?;
+        }
+
+        #[test]
+        fn prop_wrap_invariants_radians(a in any_radians()) {
+            check_wrap_invariants(a)?;
+        }
+
+        #[test]
+        fn prop_wrap_invariants_other_units(h in any_angle::<HourAngle>(), s in any_angle::<Arcsecond>()) {
+            check_wrap_invariants(h)?;
+            check_wrap_invariants(s)?;
+        }
+
+        #[test]
+        fn prop_strategies_are_finite_and_bounded(
+            l in any_length::<AstronomicalUnit>(),
+            t in any_time::<Day>(),
+            m in any_mass::<Kilogram>(),
+        ) {
+            for v in [l.value(), t.value(), m.value()] {
+                prop_assert!(v.is_finite() && v.abs() < DEFAULT_MAGNITUDE);
+            }
+            prop_assert!(l.to::<Meter>().value().is_finite());
+        }
+
+        #[test]
+        fn prop_quantity_in_respects_range(q in quantity_in::<Meter>(-2.0..3.0)) {
+            prop_assert!((-2.0..3.0).contains(&q.value()));
+        }
+    }
+
+    #[test]
+    fn quantity_asserts_accept_mixed_units() {
+        assert_quantity_eq!(Days::new(1.0), Hours::new(24.001), tol = Seconds::new(4.0));
+        assert_quantity_relative_eq!(Arcseconds::new(3600.0), Degrees::new(1.0));
+        assert_quantity_relative_eq!(Meters::new(0.0), Meters::new(0.0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "right: Quantity<Hour>(24.01 h) = Quantity<Day>(1.0004166666666667 d)"
+    )]
+    fn quantity_eq_reports_symbols() {
+        assert_quantity_eq!(Days::new(1.0), Hours::new(24.01), tol = Seconds::new(4.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed: epoch 7")]
+    fn quantity_relative_eq_appends_message() {
+        assert_quantity_relative_eq!(
+            Meters::new(1.0),
+            Meters::new(1.1),
+            max_relative = 1e-3,
+            "epoch {}",
+            7
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "max_relative")]
+    fn quantity_relative_eq_rejects_nan() {
+        assert_quantity_relative_eq!(Meters::new(f64::NAN), Meters::new(1.0));
+    }
+}
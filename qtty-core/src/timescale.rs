@@ -0,0 +1,522 @@
+//! Epochs tagged with their time scale (TAI, TT, TDB, UTC).
+//!
+//! An [`Epoch<S>`] is an instant on time scale `S`, stored as a two-part Julian Date (whole days plus the fraction of
+//! the day) so that sub-microsecond offsets survive next to a JD of ~2.4 million. Epochs only subtract from epochs on
+//! the same scale; moving between scales goes through explicit conversions:
+//!
+//! | From → To | Offset |
+//! |-----------|--------|
+//! | TAI ↔ TT  | `TT − TAI = 32.184 s` (exact, [`TT_MINUS_TAI`]) |
+//! | TT ↔ TDB  | `TDB − TT ≈ 1.657 ms · sin g + 14 µs · sin 2g` (periodic, ≤ 1.7 ms; [`tdb_minus_tt`]) |
+//! | UTC ↔ TAI | `TAI − UTC` from a caller-supplied [`LeapSecondTable`] |
+//!
+//! The leap-second table is borrowed, so callers choose its source: the compiled-in snapshot from
+//! [`leap_seconds::builtin`](crate::leap_seconds::builtin), a newer IERS file parsed at runtime, or a hand-written slice
+//! of [`LeapSecond`] entries.
+//!
+//! ```rust
+//! use qtty_core::time::{Days, Seconds};
+//! use qtty_core::timescale::{Epoch, LeapSecond, LeapSecondTable, Tt};
+//!
+//! const LEAPS: [LeapSecond; 2] = [
+//!     LeapSecond::new(Days::new(50_630.0), Seconds::new(31.0)), // 1997-07-01
+//!     LeapSecond::new(Days::new(51_179.0), Seconds::new(32.0)), // 1999-01-01
+//! ];
+//! let table = LeapSecondTable::new(&LEAPS);
+//!
+//! let utc = Epoch::<Tt>::J2000.to_tai().to_utc(&table).unwrap();
+//! // J2000.0 (TT) is 11:58:55.816 UTC on 2000-01-01.
+//! let since_midnight = utc - Epoch::from_julian_date(Days::new(2_451_544.5));
+//! assert!((since_midnight.value() - 43_135.816).abs() < 1e-6);
+//! ```
+//!
+//! Mixing scales is a type error:
+//!
+//! ```compile_fail
+//! use qtty_core::timescale::{Epoch, Tt};
+//!
+//! let tt = Epoch::<Tt>::J2000;
+//! let _ = tt - tt.to_tai(); // expected `Epoch<Tt>`, found `Epoch<Tai>`
+//! ```
+
+use crate::math;
+use crate::units::angular::Degrees;
+use crate::units::time::{
+    Day, Days, JulianCenturies, JulianCentury, Second, Seconds, TimeUnit, SECONDS_PER_DAY,
+};
+use crate::Quantity;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Julian Date of the J2000.0 epoch (2000-01-01 12:00 TT).
+pub const J2000_JD: f64 = 2_451_545.0;
+
+/// Offset between the Julian Date and the Modified Julian Date (`MJD = JD − 2_400_000.5`).
+pub const MJD_OFFSET: f64 = 2_400_000.5;
+
+/// `TT − TAI`, fixed by definition.
+pub const TT_MINUS_TAI: Seconds = Seconds::new(32.184);
+
+/// A time scale that [`Epoch`] can be tagged with.
+pub trait TimeScale {
+    /// Short name used for display (`"TT"`, `"UTC"`, …).
+    const NAME: &'static str;
+}
+
+/// International Atomic Time.
+pub enum Tai {}
+impl TimeScale for Tai {
+    const NAME: &'static str = "TAI";
+}
+
+/// Terrestrial Time.
+pub enum Tt {}
+impl TimeScale for Tt {
+    const NAME: &'static str = "TT";
+}
+
+/// Barycentric Dynamical Time.
+pub enum Tdb {}
+impl TimeScale for Tdb {
+    const NAME: &'static str = "TDB";
+}
+
+/// Coordinated Universal Time.
+///
+/// Instants inside a positive leap second (23:59:60) have no distinct UTC Julian Date; they map onto the following
+/// midnight.
+pub enum Utc {}
+impl TimeScale for Utc {
+    const NAME: &'static str = "UTC";
+}
+
+/// An instant on time scale `S`, held as a two-part Julian Date.
+pub struct Epoch<S: TimeScale> {
+    /// Whole Julian days.
+    day: f64,
+    /// Fraction of the day, in `[0, 1)`.
+    frac: f64,
+    _scale: PhantomData<S>,
+}
+
+impl<S: TimeScale> Epoch<S> {
+    const fn from_raw(day: f64, frac: f64) -> Self {
+        Self {
+            day,
+            frac,
+            _scale: PhantomData,
+        }
+    }
+
+    /// Builds an epoch from `jd1 + jd2`, splitting the sum into whole days and a day fraction without losing the
+    /// low-order bits of either part.
+    pub fn from_jd_parts(jd1: Days, jd2: Days) -> Self {
+        let (a, b) = (jd1.value(), jd2.value());
+        let (wa, wb) = (math::floor(a), math::floor(b));
+        let rest = (a - wa) + (b - wb);
+        let wr = math::floor(rest);
+        Self::from_raw(wa + wb + wr, rest - wr)
+    }
+
+    /// Builds an epoch from a single Julian Date.
+    #[inline]
+    pub fn from_julian_date(jd: Days) -> Self {
+        Self::from_jd_parts(jd, Days::new(0.0))
+    }
+
+    /// Builds an epoch from a Modified Julian Date.
+    #[inline]
+    pub fn from_mjd(mjd: Days) -> Self {
+        Self::from_jd_parts(Days::new(MJD_OFFSET), mjd)
+    }
+
+    /// The Julian Date, rounded to a single `f64`.
+    #[inline]
+    pub fn julian_date(self) -> Days {
+        Days::new(self.day + self.frac)
+    }
+
+    /// The Julian Date as `(whole days, day fraction)`, with the fraction in `[0, 1)`.
+    #[inline]
+    pub const fn jd_parts(self) -> (Days, Days) {
+        (Days::new(self.day), Days::new(self.frac))
+    }
+
+    /// The Modified Julian Date.
+    #[inline]
+    pub fn mjd(self) -> Days {
+        Days::new((self.day - MJD_OFFSET) + self.frac)
+    }
+
+    /// Julian centuries elapsed since J2000.0 on this epoch's scale, the usual argument of ephemeris polynomials.
+    #[inline]
+    pub fn centuries_since_j2000(self) -> JulianCenturies {
+        Days::new((self.day - J2000_JD) + self.frac).to::<JulianCentury>()
+    }
+
+    /// Shifts the epoch by `offset` and re-tags it with scale `T`.
+    fn shift<T: TimeScale>(self, offset: Seconds) -> Epoch<T> {
+        Epoch::from_jd_parts(
+            Days::new(self.day),
+            Days::new(self.frac + offset.value() / SECONDS_PER_DAY),
+        )
+    }
+}
+
+impl Epoch<Tt> {
+    /// J2000.0: 2000-01-01 12:00:00 TT (JD 2451545.0).
+    pub const J2000: Self = Self::from_raw(J2000_JD, 0.0);
+
+    /// The same instant in TAI.
+    #[inline]
+    pub fn to_tai(self) -> Epoch<Tai> {
+        self.shift(-TT_MINUS_TAI)
+    }
+
+    /// The same instant in TDB, using [`tdb_minus_tt`].
+    #[inline]
+    pub fn to_tdb(self) -> Epoch<Tdb> {
+        self.shift(tdb_minus_tt(self))
+    }
+}
+
+impl Epoch<Tai> {
+    /// The same instant in TT.
+    #[inline]
+    pub fn to_tt(self) -> Epoch<Tt> {
+        self.shift(TT_MINUS_TAI)
+    }
+
+    /// The same instant in UTC, or `None` if it precedes the first entry of `table`.
+    pub fn to_utc(self, table: &LeapSecondTable<'_>) -> Option<Epoch<Utc>> {
+        // Look up TAI − UTC at an approximate UTC instant first, then again at the corrected one so that instants
+        // just after a step use the new offset.
+        let guess: Epoch<Utc> = self.shift(-table.tai_minus_utc(self.mjd())?);
+        let offset = table.tai_minus_utc(guess.mjd())?;
+        Some(self.shift(-offset))
+    }
+}
+
+impl Epoch<Tdb> {
+    /// The same instant in TT (the periodic term is evaluated at the TDB epoch, an error far below a nanosecond).
+    #[inline]
+    pub fn to_tt(self) -> Epoch<Tt> {
+        let tt: Epoch<Tt> = self.shift(Seconds::new(0.0));
+        self.shift(-tdb_minus_tt(tt))
+    }
+}
+
+impl Epoch<Utc> {
+    /// The same instant in TAI, or `None` if it precedes the first entry of `table`.
+    #[inline]
+    pub fn to_tai(self, table: &LeapSecondTable<'_>) -> Option<Epoch<Tai>> {
+        Some(self.shift(table.tai_minus_utc(self.mjd())?))
+    }
+}
+
+/// `TDB − TT` at a TT epoch, from the two leading periodic terms (Fairhead & Bretagnon), good to ~10 µs.
+///
+/// `g = 357.53° + 0.985 600 28° · (JD − 2451545)` is the Earth's mean anomaly.
+pub fn tdb_minus_tt(epoch: Epoch<Tt>) -> Seconds {
+    let days = (epoch.day - J2000_JD) + epoch.frac;
+    let g = Degrees::new(357.53 + 0.985_600_28 * days);
+    let g2 = Degrees::new(2.0 * g.value());
+    Seconds::new(0.001_657 * g.sin() + 0.000_014 * g2.sin())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Leap seconds
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One step of the `TAI − UTC` staircase: from `mjd` (UTC, at 0h) onwards, `TAI − UTC = tai_minus_utc`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LeapSecond {
+    mjd: f64,
+    tai_minus_utc: f64,
+}
+
+impl LeapSecond {
+    /// A step taking effect at the start of UTC day `mjd`.
+    #[inline]
+    pub const fn new(mjd: Days, tai_minus_utc: Seconds) -> Self {
+        Self {
+            mjd: mjd.value(),
+            tai_minus_utc: tai_minus_utc.value(),
+        }
+    }
+
+    /// The Modified Julian Date (UTC) from which this offset applies.
+    #[inline]
+    pub const fn mjd(&self) -> Days {
+        Days::new(self.mjd)
+    }
+
+    /// `TAI − UTC` from [`Self::mjd`] onwards.
+    #[inline]
+    pub const fn tai_minus_utc(&self) -> Seconds {
+        Seconds::new(self.tai_minus_utc)
+    }
+}
+
+/// A borrowed `TAI − UTC` table, sorted by ascending date.
+#[derive(Clone, Copy, Debug)]
+pub struct LeapSecondTable<'a> {
+    entries: &'a [LeapSecond],
+}
+
+impl<'a> LeapSecondTable<'a> {
+    /// Wraps `entries`, which must be sorted by ascending [`LeapSecond::mjd`].
+    #[inline]
+    pub const fn new(entries: &'a [LeapSecond]) -> Self {
+        Self { entries }
+    }
+
+    /// The table entries.
+    #[inline]
+    pub const fn entries(&self) -> &'a [LeapSecond] {
+        self.entries
+    }
+
+    /// `TAI − UTC` in force at UTC date `mjd`, or `None` before the first entry.
+    pub fn tai_minus_utc(&self, mjd: Days) -> Option<Seconds> {
+        let mjd = mjd.value();
+        let n = self.entries.partition_point(|e| e.mjd <= mjd);
+        n.checked_sub(1).map(|i| self.entries[i].tai_minus_utc())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Trait impls
+// ─────────────────────────────────────────────────────────────────────────────
+
+// Manual impls: deriving would require the (uninhabited) scale tags to implement the traits themselves.
+
+impl<S: TimeScale> Clone for Epoch<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: TimeScale> Copy for Epoch<S> {}
+
+impl<S: TimeScale> PartialEq for Epoch<S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.day == other.day && self.frac == other.frac
+    }
+}
+
+impl<S: TimeScale> PartialOrd for Epoch<S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.day.partial_cmp(&other.day) {
+            Some(Ordering::Equal) => self.frac.partial_cmp(&other.frac),
+            ord => ord,
+        }
+    }
+}
+
+impl<S: TimeScale> fmt::Debug for Epoch<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Epoch")
+            .field("scale", &S::NAME)
+            .field("day", &self.day)
+            .field("frac", &self.frac)
+            .finish()
+    }
+}
+
+impl<S: TimeScale> fmt::Display for Epoch<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JD ")?;
+        fmt::Display::fmt(&self.julian_date().value(), f)?;
+        write!(f, " {}", S::NAME)
+    }
+}
+
+impl<S: TimeScale, U: TimeUnit> Add<Quantity<U>> for Epoch<S> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Quantity<U>) -> Self {
+        self.shift(rhs.to::<Second>())
+    }
+}
+
+impl<S: TimeScale, U: TimeUnit> Sub<Quantity<U>> for Epoch<S> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Quantity<U>) -> Self {
+        self.shift(-rhs.to::<Second>())
+    }
+}
+
+impl<S: TimeScale, U: TimeUnit> AddAssign<Quantity<U>> for Epoch<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Quantity<U>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S: TimeScale, U: TimeUnit> SubAssign<Quantity<U>> for Epoch<S> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Quantity<U>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<S: TimeScale> Sub for Epoch<S> {
+    type Output = Seconds;
+    /// Elapsed time between two epochs on the same scale.
+    #[inline]
+    fn sub(self, rhs: Self) -> Seconds {
+        Days::new((self.day - rhs.day) + (self.frac - rhs.frac)).to::<Second>()
+    }
+}
+
+impl<S: TimeScale> From<Epoch<S>> for Quantity<Day> {
+    /// The Julian Date of the epoch.
+    #[inline]
+    fn from(epoch: Epoch<S>) -> Self {
+        epoch.julian_date()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::time::{Microseconds, Nanoseconds};
+    use approx::assert_abs_diff_eq;
+    use proptest::prelude::*;
+
+    const LEAPS: [LeapSecond; 4] = [
+        LeapSecond::new(Days::new(41_317.0), Seconds::new(10.0)), // 1972-01-01
+        LeapSecond::new(Days::new(51_179.0), Seconds::new(32.0)), // 1999-01-01
+        LeapSecond::new(Days::new(57_204.0), Seconds::new(36.0)), // 2015-07-01
+        LeapSecond::new(Days::new(57_754.0), Seconds::new(37.0)), // 2017-01-01
+    ];
+
+    fn table() -> LeapSecondTable<'static> {
+        LeapSecondTable::new(&LEAPS)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Epoch representation
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn parts_are_normalised() {
+        let e = Epoch::<Tt>::from_jd_parts(Days::new(2_451_544.75), Days::new(0.5));
+        let (day, frac) = e.jd_parts();
+        assert_eq!((day.value(), frac.value()), (2_451_545.0, 0.25));
+        assert_eq!(e.julian_date().value(), 2_451_545.25);
+        assert_eq!(Epoch::<Tt>::from_mjd(Days::new(51_544.5)), Epoch::J2000);
+        assert_eq!(Epoch::<Tt>::J2000.mjd().value(), 51_544.5);
+    }
+
+    #[test]
+    fn keeps_sub_microsecond_offsets() {
+        let t0 = Epoch::<Tai>::from_julian_date(Days::new(2_460_000.5));
+        let t1 = t0 + Nanoseconds::new(250.0);
+        assert_abs_diff_eq!((t1 - t0).value(), 250e-9, epsilon = 1e-10);
+        let mut t = t0;
+        t -= Microseconds::new(1.0);
+        assert!(t < t0);
+        assert_abs_diff_eq!((t0 - t).value(), 1e-6, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn centuries_since_j2000() {
+        let e = Epoch::<Tt>::J2000 + Days::new(36_525.0);
+        assert_eq!(e.centuries_since_j2000().value(), 1.0);
+        assert_eq!(Epoch::<Tt>::J2000.centuries_since_j2000().value(), 0.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Epoch::<Tt>::J2000.to_string(), "JD 2451545 TT");
+        assert_eq!(
+            format!("{:.3}", Epoch::<Tai>::from_mjd(Days::new(0.25))),
+            "JD 2400000.750 TAI"
+        );
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Scale conversions
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn tt_tai_roundtrip() {
+        let tai = Epoch::<Tt>::J2000.to_tai();
+        let shift = Epoch::<Tai>::from_julian_date(Days::new(J2000_JD)) - tai;
+        assert_abs_diff_eq!(shift.value(), 32.184, epsilon = 1e-9);
+        assert_abs_diff_eq!((tai.to_tt() - Epoch::J2000).value(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn tdb_offset_is_periodic_and_small() {
+        // Near perihelion (early January) g ≈ 0 and the offset is close to zero; it peaks around April and October.
+        let jan = tdb_minus_tt(Epoch::J2000);
+        assert!(jan.value().abs() < 1e-4, "{jan:?}");
+        let apr = tdb_minus_tt(Epoch::J2000 + Days::new(91.0));
+        assert!((apr.value() - 0.001_65).abs() < 5e-5, "{apr:?}");
+        let tdb = (Epoch::J2000 + Days::new(91.0)).to_tdb();
+        let back = tdb.to_tt() - (Epoch::J2000 + Days::new(91.0));
+        assert_abs_diff_eq!(back.value(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn utc_tai_offsets() {
+        let table = table();
+        let utc = Epoch::<Utc>::from_mjd(Days::new(57_754.5)); // 2017-01-01 12:00 UTC
+        let tai = utc.to_tai(&table).unwrap();
+        assert_abs_diff_eq!(
+            (tai.mjd() - utc.mjd()).value() * SECONDS_PER_DAY,
+            37.0,
+            epsilon = 1e-5
+        );
+        assert_eq!(tai.to_utc(&table).unwrap(), utc);
+
+        let before = Epoch::<Utc>::from_mjd(Days::new(57_753.5));
+        let tai = before.to_tai(&table).unwrap();
+        assert_abs_diff_eq!(
+            (tai.mjd() - before.mjd()).value() * SECONDS_PER_DAY,
+            36.0,
+            epsilon = 1e-5
+        );
+
+        assert!(Epoch::<Utc>::from_mjd(Days::new(41_316.0))
+            .to_tai(&table)
+            .is_none());
+        assert_eq!(
+            table.tai_minus_utc(Days::new(1e6)),
+            Some(Seconds::new(37.0))
+        );
+    }
+
+    #[test]
+    fn utc_just_after_a_leap_second() {
+        let table = table();
+        // Half a second either side of the 2017-01-01 step: the later instant must use 37 s, the earlier one 36 s,
+        // even though both fall after midnight in TAI.
+        for utc in [
+            Epoch::<Utc>::from_mjd(Days::new(57_754.0)) + Seconds::new(0.5),
+            Epoch::<Utc>::from_mjd(Days::new(57_754.0)) - Seconds::new(0.5),
+        ] {
+            let back = utc.to_tai(&table).unwrap().to_utc(&table).unwrap();
+            assert_abs_diff_eq!((back - utc).value(), 0.0, epsilon = 1e-6);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_utc_roundtrip(mjd in 41_317.0..70_000.0f64) {
+            let table = table();
+            let utc = Epoch::<Utc>::from_mjd(Days::new(mjd));
+            let back = utc.to_tai(&table).unwrap().to_utc(&table).unwrap();
+            prop_assert!((back - utc).value().abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Transforms between celestial coordinate frames, on typed angles.
+//!
+//! [`equatorial_to_horizontal`] and [`horizontal_to_equatorial`] relate an object's hour angle and declination to
+//! its altitude and azimuth as seen from a site at a given latitude. Conventions:
+//!
+//! - azimuth is measured from **north through east**, in `[0°, 360°)` (Meeus counts from the south; add 180°);
+//! - hour angle is positive west of the meridian and returned in `(-12h, +12h]`;
+//! - altitude and declination are in `[-90°, +90°]`.
+//!
+//! Declinations and latitudes may be passed as plain [`Degrees`] or as the range-checked
+//! [`Declination`](crate::coordinates::Declination) and [`Latitude`](crate::coordinates::Latitude); hour angles as
+//! [`HourAngles`] or any angle convertible to them.
+//!
+//! ```rust
+//! use qtty_core::angular::{Degrees, HourAngles};
+//! use qtty_core::transform::equatorial_to_horizontal;
+//!
+//! // An object on the meridian, 10° south of the zenith at latitude 40° N.
+//! let h = equatorial_to_horizontal(HourAngles::new(0.0), Degrees::new(30.0), Degrees::new(40.0));
+//! assert!((h.altitude.value() - 80.0).abs() < 1e-12);
+//! assert!((h.azimuth.value() - 180.0).abs() < 1e-12);
+//! ```
+//!
+//! [`ecliptic_to_equatorial`] and [`equatorial_to_ecliptic`] rotate about the equinox direction by the obliquity of
+//! the ecliptic, which is a parameter so that mean, true or fixed-epoch values can all be used; [`mean_obliquity`]
+//! gives the IAU 2006 mean value. Right ascension and ecliptic longitude are returned in `[0h, 24h)` and `[0°, 360°)`.
+//!
+//! ```rust
+//! use qtty_core::angular::Degrees;
+//! use qtty_core::time::JulianCenturies;
+//! use qtty_core::transform::{ecliptic_to_equatorial, equatorial_to_ecliptic, mean_obliquity};
+//!
+//! let eps = mean_obliquity(JulianCenturies::new(0.0));
+//! let eq = ecliptic_to_equatorial(Degrees::new(90.0), Degrees::new(0.0), eps);
+//! // The summer solstice point: RA 6h, declination equal to the obliquity.
+//! assert!((eq.right_ascension.value() - 6.0).abs() < 1e-12);
+//! assert!((eq.declination - eps).value().abs() < 1e-12);
+//! let ecl = equatorial_to_ecliptic(eq.right_ascension, eq.declination, eps);
+//! assert!((ecl.longitude.value() - 90.0).abs() < 1e-9);
+//! ```
+
+use crate::math;
+use crate::series::Polynomial;
+use crate::units::angular::{Arcsecond, Degree, Degrees, HourAngle, HourAngles, Radians};
+use crate::units::time::{JulianCenturies, JulianCentury};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[inline]
+pub(crate) fn asin(x: f64) -> Radians {
+    // Rounding can push |x| a hair past 1 at the poles and the zenith.
+    let x = x.clamp(-1.0, 1.0);
+    Radians::new(math::asin(x))
+}
+
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> Radians {
+    Radians::new(math::atan2(y, x))
+}
+
+/// A direction in the local horizontal frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Horizontal {
+    /// Angle above the horizon, in `[-90°, +90°]`.
+    pub altitude: Degrees,
+    /// Angle from north through east, in `[0°, 360°)`.
+    pub azimuth: Degrees,
+}
+
+/// A direction in the local equatorial frame (hour angle and declination).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LocalEquatorial {
+    /// Hour angle, positive west of the meridian, in `(-12h, +12h]`.
+    pub hour_angle: HourAngles,
+    /// Declination, in `[-90°, +90°]`.
+    pub declination: Degrees,
+}
+
+/// Altitude and azimuth of an object at `hour_angle` and `declination`, seen from `latitude`.
+///
+/// `sin a = sin φ sin δ + cos φ cos δ cos H`, with the azimuth from the matching `atan2`. At the zenith (and the
+/// poles) the azimuth is undefined and comes out as whatever `atan2(0, 0)` gives for the rounding at hand.
+pub fn equatorial_to_horizontal(
+    hour_angle: impl Into<HourAngles>,
+    declination: impl Into<Degrees>,
+    latitude: impl Into<Degrees>,
+) -> Horizontal {
+    let h = hour_angle.into().to::<Degree>();
+    let (sin_d, cos_d) = declination.into().sin_cos();
+    let (sin_p, cos_p) = latitude.into().sin_cos();
+    let (sin_h, cos_h) = h.sin_cos();
+    let altitude = asin(sin_p * sin_d + cos_p * cos_d * cos_h);
+    let azimuth = atan2(-cos_d * sin_h, sin_d * cos_p - cos_d * sin_p * cos_h);
+    Horizontal {
+        altitude: altitude.to(),
+        azimuth: azimuth.to::<Degree>().wrap_pos(),
+    }
+}
+
+/// Hour angle and declination of an object at `altitude` and `azimuth` (from north through east), seen from
+/// `latitude`. The inverse of [`equatorial_to_horizontal`].
+pub fn horizontal_to_equatorial(
+    altitude: impl Into<Degrees>,
+    azimuth: impl Into<Degrees>,
+    latitude: impl Into<Degrees>,
+) -> LocalEquatorial {
+    let (sin_a, cos_a) = altitude.into().sin_cos();
+    let (sin_z, cos_z) = azimuth.into().sin_cos();
+    let (sin_p, cos_p) = latitude.into().sin_cos();
+    let declination = asin(sin_p * sin_a + cos_p * cos_a * cos_z);
+    let hour_angle = atan2(-cos_a * sin_z, sin_a * cos_p - cos_a * sin_p * cos_z);
+    LocalEquatorial {
+        hour_angle: hour_angle.to::<HourAngle>().wrap_signed(),
+        declination: declination.to(),
+    }
+}
+
+/// A direction in ecliptic coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ecliptic {
+    /// Ecliptic longitude, in `[0°, 360°)`.
+    pub longitude: Degrees,
+    /// Ecliptic latitude, in `[-90°, +90°]`.
+    pub latitude: Degrees,
+}
+
+/// A direction in equatorial coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Equatorial {
+    /// Right ascension, in `[0h, 24h)`.
+    pub right_ascension: HourAngles,
+    /// Declination, in `[-90°, +90°]`.
+    pub declination: Degrees,
+}
+
+/// IAU 2006 mean obliquity of the ecliptic `ε_A`, in arcseconds per power of `T` (Capitaine et al. 2003).
+const OBLIQUITY_2006: Polynomial<JulianCentury, Arcsecond, 6> = Polynomial::from_coefficients([
+    84_381.406,
+    -46.836_769,
+    -0.000_183_1,
+    0.002_003_40,
+    -0.000_000_576,
+    -0.000_000_043_4,
+]);
+
+/// Mean obliquity of the ecliptic (IAU 2006) at `t` Julian centuries (TT) since J2000.0.
+pub fn mean_obliquity(t: JulianCenturies) -> Degrees {
+    OBLIQUITY_2006.eval(t).to()
+}
+
+/// Right ascension and declination of the direction at ecliptic `longitude` and `latitude`, for the given
+/// `obliquity`.
+pub fn ecliptic_to_equatorial(
+    longitude: impl Into<Degrees>,
+    latitude: impl Into<Degrees>,
+    obliquity: impl Into<Degrees>,
+) -> Equatorial {
+    let (sin_l, cos_l) = longitude.into().sin_cos();
+    let (sin_b, cos_b) = latitude.into().sin_cos();
+    let (sin_e, cos_e) = obliquity.into().sin_cos();
+    let ra = atan2(sin_l * cos_b * cos_e - sin_b * sin_e, cos_l * cos_b);
+    let dec = asin(sin_b * cos_e + cos_b * sin_e * sin_l);
+    Equatorial {
+        right_ascension: ra.to::<HourAngle>().wrap_pos(),
+        declination: dec.to(),
+    }
+}
+
+/// Ecliptic longitude and latitude of the direction at `right_ascension` and `declination`, for the given
+/// `obliquity`. The inverse of [`ecliptic_to_equatorial`].
+pub fn equatorial_to_ecliptic(
+    right_ascension: impl Into<HourAngles>,
+    declination: impl Into<Degrees>,
+    obliquity: impl Into<Degrees>,
+) -> Ecliptic {
+    let (sin_a, cos_a) = right_ascension.into().sin_cos();
+    let (sin_d, cos_d) = declination.into().sin_cos();
+    let (sin_e, cos_e) = obliquity.into().sin_cos();
+    let lon = atan2(sin_a * cos_d * cos_e + sin_d * sin_e, cos_a * cos_d);
+    let lat = asin(sin_d * cos_e - cos_d * sin_e * sin_a);
+    Ecliptic {
+        longitude: lon.to::<Degree>().wrap_pos(),
+        latitude: lat.to(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::{Declination, Latitude};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn meeus_example_13b() {
+        // Venus from the USNO, 1987-04-10 19:21 UT: H = 64.352133°, δ = −6°43′11.61″, φ = +38°55′17″.
+        let lat = Latitude::new(Degrees::from_dms(38, 55, 17.0)).unwrap();
+        let dec = Declination::from_dms(-6, 43, 11.61).unwrap();
+        let h = equatorial_to_horizontal(Degrees::new(64.352_133), dec, lat);
+        assert_abs_diff_eq!(h.altitude.value(), 15.124_9, epsilon = 1e-4);
+        // Meeus: A = 68.0337° from the south.
+        assert_abs_diff_eq!(h.azimuth.value(), 248.033_7, epsilon = 1e-4);
+
+        let back = horizontal_to_equatorial(h.altitude, h.azimuth, lat);
+        assert_abs_diff_eq!(
+            back.hour_angle.to::<Degree>().value(),
+            64.352_133,
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            back.declination.value(),
+            dec.degrees().value(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn cardinal_directions() {
+        let lat = Degrees::new(0.0);
+        // On the equator, a star at δ = 0 rises due east and sets due west.
+        let rising = equatorial_to_horizontal(HourAngles::new(-6.0), Degrees::new(0.0), lat);
+        assert_abs_diff_eq!(rising.altitude.value(), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(rising.azimuth.value(), 90.0, epsilon = 1e-12);
+        let setting = equatorial_to_horizontal(HourAngles::new(6.0), Degrees::new(0.0), lat);
+        assert_abs_diff_eq!(setting.azimuth.value(), 270.0, epsilon = 1e-12);
+        // The celestial pole sits due north at an altitude equal to the latitude.
+        let pole =
+            equatorial_to_horizontal(HourAngles::new(3.0), Degrees::new(90.0), Degrees::new(52.0));
+        assert_abs_diff_eq!(pole.altitude.value(), 52.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(pole.azimuth.value(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn round_trips_over_the_sky() {
+        for lat in [-70.0, -20.0, 0.0, 35.0, 65.0] {
+            for ha in [-11.0, -6.5, -1.0, 0.5, 4.0, 9.0] {
+                for dec in [-60.0, -10.0, 0.0, 25.0, 80.0] {
+                    let hz = equatorial_to_horizontal(
+                        HourAngles::new(ha),
+                        Degrees::new(dec),
+                        Degrees::new(lat),
+                    );
+                    assert!((0.0..360.0).contains(&hz.azimuth.value()));
+                    let eq = horizontal_to_equatorial(hz.altitude, hz.azimuth, Degrees::new(lat));
+                    assert_abs_diff_eq!(eq.hour_angle.value(), ha, epsilon = 1e-9);
+                    assert_abs_diff_eq!(eq.declination.value(), dec, epsilon = 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn meeus_example_13a() {
+        // Pollux: α = 7h 45m 18.946s, δ = +28° 01′ 34.26″, ε = 23.439 291 1°.
+        let ra = crate::coordinates::RightAscension::from_hms(7, 45, 18.946).unwrap();
+        let dec = Declination::from_dms(28, 1, 34.26).unwrap();
+        let eps = Degrees::new(23.439_291_1);
+        let ecl = equatorial_to_ecliptic(ra, dec, eps);
+        assert_abs_diff_eq!(ecl.longitude.value(), 113.215_630, epsilon = 1e-6);
+        assert_abs_diff_eq!(ecl.latitude.value(), 6.684_170, epsilon = 1e-6);
+
+        let eq = ecliptic_to_equatorial(ecl.longitude, ecl.latitude, eps);
+        assert_abs_diff_eq!(
+            eq.right_ascension.value(),
+            ra.hours().value(),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            eq.declination.value(),
+            dec.degrees().value(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn ecliptic_round_trips_and_ranges() {
+        let eps = Degrees::new(23.44);
+        for lon in [0.0, 45.0, 179.0, 200.0, 359.5] {
+            for lat in [-89.0, -30.0, 0.0, 12.0, 89.0] {
+                let eq = ecliptic_to_equatorial(Degrees::new(lon), Degrees::new(lat), eps);
+                assert!((0.0..24.0).contains(&eq.right_ascension.value()));
+                let back = equatorial_to_ecliptic(eq.right_ascension, eq.declination, eps);
+                let dlon = back.longitude.signed_separation(Degrees::new(lon));
+                assert_abs_diff_eq!(dlon.value(), 0.0, epsilon = 1e-9);
+                assert_abs_diff_eq!(back.latitude.value(), lat, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn obliquity_2006() {
+        assert_abs_diff_eq!(
+            mean_obliquity(JulianCenturies::new(0.0))
+                .to::<Arcsecond>()
+                .value(),
+            84_381.406,
+            epsilon = 1e-9
+        );
+        // Decreasing by ~47″ per century.
+        let drift =
+            mean_obliquity(JulianCenturies::new(1.0)) - mean_obliquity(JulianCenturies::new(0.0));
+        assert_abs_diff_eq!(drift.to::<Arcsecond>().value(), -46.835, epsilon = 1e-3);
+    }
+}
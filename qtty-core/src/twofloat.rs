@@ -0,0 +1,494 @@
+//! Double-double ("twofold `f64`") storage for quantities that outgrow 53 bits.
+//!
+//! A [`TwoFloat`] is the unevaluated sum `hi + lo` of two `f64`s with `|lo| ≤ ulp(hi) / 2`, which carries about 106
+//! bits (~32 significant digits). That is the usual remedy for long time baselines: a Julian Date near `2.46e6` days
+//! only resolves ~40 µs in a plain `f64`, but well below a picosecond as a `TwoFloat`.
+//!
+//! `Quantity<U, TwoFloat>` supports addition, subtraction, scaling and unit conversion. It does not mix implicitly
+//! with the `f64` backend: widen with [`Quantity::to_twofloat`] and narrow with [`Quantity::to_f64`] (or the
+//! equivalent `From` impls).
+//!
+//! ```rust
+//! use qtty_core::time::{Day, Days, Second, Seconds};
+//!
+//! let jd = Days::new(2_460_000.5).to_twofloat();
+//! let later = jd + Seconds::new(1e-6).to_twofloat().to::<Day>();
+//! let dt = (later - jd).to::<Second>().to_f64();
+//! assert!((dt.value() - 1e-6).abs() < 1e-18);
+//!
+//! // The same sum in f64 cannot even represent the microsecond.
+//! let f = Days::new(2_460_000.5);
+//! let lost = ((f + Seconds::new(1e-6).to::<Day>()) - f).to::<Second>();
+//! assert!((lost.value() - 1e-6).abs() > 1e-7);
+//! ```
+
+use crate::dimension::SameDimension;
+use crate::quantity::Quantity;
+use crate::reduce::two_prod;
+use crate::unit::{ExactFactor, Unit};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// `a + b` as `s + e` with `s = fl(a + b)` and `e` the exact rounding error (Knuth).
+const fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Like [`two_sum`], assuming `|a| ≥ |b|` (Dekker).
+const fn quick_two_sum(a: f64, b: f64) -> TwoFloat {
+    let s = a + b;
+    if !s.is_finite() {
+        return TwoFloat { hi: s, lo: 0.0 };
+    }
+    TwoFloat {
+        hi: s,
+        lo: b - (s - a),
+    }
+}
+
+/// A double-double number: the unevaluated sum of two non-overlapping `f64`s.
+///
+/// Comparison is lexicographic on `(hi, lo)`, which orders normalised values correctly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct TwoFloat {
+    hi: f64,
+    lo: f64,
+}
+
+impl TwoFloat {
+    /// Zero.
+    pub const ZERO: Self = Self { hi: 0.0, lo: 0.0 };
+
+    /// The exact sum `hi + lo`, renormalised so that `lo` is below half an ulp of the leading part.
+    #[inline]
+    pub const fn new(hi: f64, lo: f64) -> Self {
+        let (s, e) = two_sum(hi, lo);
+        if s.is_finite() {
+            Self { hi: s, lo: e }
+        } else {
+            Self { hi: s, lo: 0.0 }
+        }
+    }
+
+    /// Widens an `f64` (exact).
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+
+    /// The leading component, which is also the nearest `f64`.
+    #[inline]
+    pub const fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// The trailing correction.
+    #[inline]
+    pub const fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Rounds to the nearest `f64`.
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        self.hi
+    }
+
+    /// Absolute value.
+    #[inline]
+    pub const fn abs(self) -> Self {
+        if self.hi < 0.0 || (self.hi == 0.0 && self.lo < 0.0) {
+            Self {
+                hi: -self.hi,
+                lo: -self.lo,
+            }
+        } else {
+            self
+        }
+    }
+
+    /// `true` if neither component is `NaN` or infinite.
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.hi.is_finite() && self.lo.is_finite()
+    }
+
+    const fn add_dd(self, rhs: Self) -> Self {
+        let (s1, s2) = two_sum(self.hi, rhs.hi);
+        let (t1, t2) = two_sum(self.lo, rhs.lo);
+        let r = quick_two_sum(s1, s2 + t1);
+        quick_two_sum(r.hi, r.lo + t2)
+    }
+
+    const fn add_f64(self, rhs: f64) -> Self {
+        let (s1, s2) = two_sum(self.hi, rhs);
+        quick_two_sum(s1, s2 + self.lo)
+    }
+
+    const fn sub_dd(self, rhs: Self) -> Self {
+        self.add_dd(Self {
+            hi: -rhs.hi,
+            lo: -rhs.lo,
+        })
+    }
+
+    const fn sub_f64(self, rhs: f64) -> Self {
+        self.add_f64(-rhs)
+    }
+
+    const fn mul_dd(self, rhs: Self) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs.hi);
+        quick_two_sum(p1, p2 + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+
+    const fn mul_f64(self, rhs: f64) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs);
+        quick_two_sum(p1, p2 + self.lo * rhs)
+    }
+
+    const fn div_f64(self, rhs: f64) -> Self {
+        let q1 = self.hi / rhs;
+        if !q1.is_finite() {
+            return Self { hi: q1, lo: 0.0 };
+        }
+        let (p1, p2) = two_prod(q1, rhs);
+        let (s, e) = two_sum(self.hi, -p1);
+        let q2 = (s + (e + self.lo - p2)) / rhs;
+        quick_two_sum(q1, q2)
+    }
+
+    const fn div_dd(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        if !q1.is_finite() {
+            return Self { hi: q1, lo: 0.0 };
+        }
+        // One Newton-style correction step on the remainder.
+        let r = self.add_dd(rhs.mul_f64(-q1));
+        let q2 = r.hi / rhs.hi;
+        let r = r.add_dd(rhs.mul_f64(-q2));
+        let q3 = r.hi / rhs.hi;
+        quick_two_sum(q1, q2).add_f64(q3)
+    }
+}
+
+impl From<f64> for TwoFloat {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl From<TwoFloat> for f64 {
+    #[inline]
+    fn from(value: TwoFloat) -> Self {
+        value.to_f64()
+    }
+}
+
+macro_rules! twofloat_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $dd:ident, $f64:ident) => {
+        impl $trait for TwoFloat {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                self.$dd(rhs)
+            }
+        }
+
+        impl $trait<f64> for TwoFloat {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: f64) -> Self {
+                self.$f64(rhs)
+            }
+        }
+
+        impl $assign_trait for TwoFloat {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$dd(rhs);
+            }
+        }
+
+        impl $assign_trait<f64> for TwoFloat {
+            #[inline]
+            fn $assign_method(&mut self, rhs: f64) {
+                *self = self.$f64(rhs);
+            }
+        }
+    };
+}
+
+twofloat_binop!(Add, add, AddAssign, add_assign, add_dd, add_f64);
+twofloat_binop!(Sub, sub, SubAssign, sub_assign, sub_dd, sub_f64);
+twofloat_binop!(Mul, mul, MulAssign, mul_assign, mul_dd, mul_f64);
+twofloat_binop!(Div, div, DivAssign, div_assign, div_dd, div_f64);
+
+impl Neg for TwoFloat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Sum for TwoFloat {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Quantity<U, TwoFloat>
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<U: Unit> Quantity<U> {
+    /// Widens to the double-double backend (exact).
+    ///
+    /// ```rust
+    /// use qtty_core::time::Days;
+    ///
+    /// let jd = Days::new(2_451_545.0).to_twofloat();
+    /// assert_eq!(jd.to_f64().value(), 2_451_545.0);
+    /// ```
+    #[inline]
+    pub const fn to_twofloat(self) -> Quantity<U, TwoFloat> {
+        Quantity::new(TwoFloat::from_f64(self.value()))
+    }
+}
+
+impl<U: Unit> Quantity<U, TwoFloat> {
+    /// Rounds to the nearest `f64` quantity.
+    #[inline]
+    pub const fn to_f64(self) -> Quantity<U> {
+        Quantity::new(self.value().to_f64())
+    }
+
+    /// Absolute value.
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self::new(self.value().abs())
+    }
+
+    /// Converts to another unit of the same dimension, carrying the full double-double precision.
+    ///
+    /// Exact rational ratios ([`Unit::RATIO_NUM`]) are applied as an integer multiplication and division; otherwise
+    /// the value is scaled by `U::RATIO` and divided by `T::RATIO`, so the only rounding is that of the declared
+    /// ratios themselves.
+    #[inline]
+    pub const fn to<T: Unit>(self) -> Quantity<T, TwoFloat>
+    where
+        U::Dim: SameDimension<T::Dim>,
+    {
+        let v = self.value();
+        let value = match ExactFactor::<U, T>::VALUE {
+            Some((num, den)) => v.mul_f64(num).div_f64(den),
+            None => v.mul_f64(U::RATIO).div_f64(T::RATIO),
+        };
+        Quantity::new(value)
+    }
+}
+
+impl<U: Unit> From<Quantity<U>> for Quantity<U, TwoFloat> {
+    #[inline]
+    fn from(quantity: Quantity<U>) -> Self {
+        quantity.to_twofloat()
+    }
+}
+
+impl<U: Unit> From<Quantity<U, TwoFloat>> for Quantity<U> {
+    #[inline]
+    fn from(quantity: Quantity<U, TwoFloat>) -> Self {
+        quantity.to_f64()
+    }
+}
+
+impl<U: Unit> Add for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value() + rhs.value())
+    }
+}
+
+impl<U: Unit> Sub for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value() - rhs.value())
+    }
+}
+
+impl<U: Unit> AddAssign for Quantity<U, TwoFloat> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<U: Unit> SubAssign for Quantity<U, TwoFloat> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<U: Unit> Neg for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.value())
+    }
+}
+
+impl<U: Unit> Mul<f64> for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.value() * rhs)
+    }
+}
+
+impl<U: Unit> Mul<TwoFloat> for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: TwoFloat) -> Self {
+        Self::new(self.value() * rhs)
+    }
+}
+
+impl<U: Unit> Div<f64> for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.value() / rhs)
+    }
+}
+
+impl<U: Unit> Div<TwoFloat> for Quantity<U, TwoFloat> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: TwoFloat) -> Self {
+        Self::new(self.value() / rhs)
+    }
+}
+
+impl<U: Unit> Sum for Quantity<U, TwoFloat> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(Quantity::value).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Arcsecond, Degrees};
+    use crate::units::length::{AstronomicalUnit, Kilometers, Meter};
+    use crate::units::time::{Day, Days, Second, Seconds};
+    use proptest::prelude::*;
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // TwoFloat arithmetic
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn new_normalises() {
+        let x = TwoFloat::new(1.0, 1e-20);
+        assert_eq!((x.hi(), x.lo()), (1.0, 1e-20));
+        let y = TwoFloat::new(1e-20, 1.0);
+        assert_eq!((y.hi(), y.lo()), (1.0, 1e-20));
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn keeps_bits_f64_drops() {
+        let x = TwoFloat::from(1.0) + 1e-20;
+        assert_eq!((x - 1.0).to_f64(), 1e-20);
+        let p = TwoFloat::from(0.1) * 1e17;
+        assert_eq!((p.hi(), p.lo()), (1e16, 0.555_111_512_312_578_3));
+    }
+
+    #[test]
+    fn division_roundtrip() {
+        let third = TwoFloat::from(1.0) / 3.0;
+        let back = third * 3.0 - 1.0;
+        assert!(back.to_f64().abs() < 1e-31);
+        let q = TwoFloat::new(2.0, 1e-17) / TwoFloat::new(3.0, -1e-17);
+        let back = q * TwoFloat::new(3.0, -1e-17) - TwoFloat::new(2.0, 1e-17);
+        assert!(back.to_f64().abs() < 1e-31);
+    }
+
+    #[test]
+    fn ordering_and_sign() {
+        let a = TwoFloat::new(1.0, -1e-20);
+        let b = TwoFloat::from(1.0);
+        assert!(a < b);
+        assert_eq!((-a).abs(), a);
+        assert_eq!(TwoFloat::new(-0.0, -1e-30).abs(), TwoFloat::new(0.0, 1e-30));
+        assert!(!TwoFloat::from(f64::INFINITY).is_finite());
+        assert!((TwoFloat::from(1e308) * 10.0).hi().is_infinite());
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Quantity backend
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn julian_date_keeps_microseconds() {
+        let jd = Days::new(2_460_000.5).to_twofloat();
+        let step = Seconds::new(1e-6).to_twofloat().to::<Day>();
+        let mut t = jd;
+        for _ in 0..1_000 {
+            t += step;
+        }
+        let elapsed = (t - jd).to::<Second>().to_f64().value();
+        assert!((elapsed - 1e-3).abs() < 1e-15, "{elapsed}");
+    }
+
+    #[test]
+    fn exact_ratio_conversion() {
+        let d = Degrees::new(1.0 / 3.0).to_twofloat().to::<Arcsecond>();
+        // 1/3 (as f64) × 3600, with the product's low bits retained.
+        assert_eq!(d.to_f64().value(), 1_200.0);
+        assert!(d.value().lo() != 0.0);
+    }
+
+    #[test]
+    fn float_ratio_conversion_roundtrip() {
+        let au = Kilometers::new(149_597_870.7)
+            .to_twofloat()
+            .to::<AstronomicalUnit>();
+        assert!((au.to_f64().value() - 1.0).abs() < 1e-15);
+        let m = au.to::<Meter>();
+        assert_eq!(m.to_f64().value(), 149_597_870_700.0);
+    }
+
+    #[test]
+    fn interop_and_scaling() {
+        let a: Quantity<Second, TwoFloat> = Seconds::new(1.5).into();
+        let b: Seconds = (a * 2.0 / TwoFloat::from(3.0)).into();
+        assert_eq!(b.value(), 1.0);
+        let total: Quantity<Second, TwoFloat> = [a, -a, a].into_iter().sum();
+        assert_eq!(total, a);
+        assert_eq!((-a).abs(), a);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_add_sub_roundtrip(a in -1e12..1e12f64, b in -1e-6..1e-6f64) {
+            let x = TwoFloat::from(a) + b;
+            prop_assert_eq!((x - a).to_f64(), b);
+        }
+
+        #[test]
+        fn prop_mul_div_roundtrip(a in -1e12..1e12f64, b in 1e-6..1e6f64) {
+            let x = TwoFloat::from(a) / b * b - a;
+            prop_assert!(x.to_f64().abs() <= 1e-28 * a.abs().max(1.0));
+        }
+    }
+}
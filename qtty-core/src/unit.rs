@@ -1,6 +1,6 @@
 //! Unit types and traits.
 
-use crate::dimension::{Dimension, Dimensionless, DivDim};
+use crate::dimension::{Dimension, DimensionId, Dimensionless, DivDim, MulDim, SameDimension};
 use crate::Quantity;
 use core::fmt::{Debug, Display, Formatter, Result};
 use core::marker::PhantomData;
@@ -13,23 +13,469 @@ use core::marker::PhantomData;
 ///
 /// * `SYMBOL` is the printable string (e.g. `"m"` or `"km"`).
 ///
+/// * `LONG_NAME` is the human-readable name (e.g. `"kilometer"`), printed by the alternate form `{:#}`.
+///
 /// * `Dim` ties the unit to its underlying [`Dimension`].
 ///
+/// * `RATIO_NUM` / `RATIO_DEN` optionally give `RATIO` as an exact integer fraction (e.g. `1 / 3600` for the
+///   arcsecond). When both sides of a conversion provide one, [`Quantity::to`] scales by the reduced fraction instead
+///   of `RATIO / RATIO`, so `Arcseconds → Degrees` is a single correctly-rounded division by `3600`.
+///
 /// # Invariants
 ///
 /// - Implementations should be zero-sized marker types (this crate's built-in units are unit structs with no fields).
 /// - `RATIO` should be finite and non-zero.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a unit",
+    note = "unit types derive it with `#[derive(Unit)]` and a `#[unit(symbol = ..., dimension = ..., ratio = ...)]` attribute"
+)]
 pub trait Unit: Copy + PartialEq + Debug + 'static {
     /// Unit-to-canonical conversion factor.
     const RATIO: f64;
 
+    /// Numerator of [`Self::RATIO`] as an exact fraction, or `0` when the ratio has no exact integer form.
+    const RATIO_NUM: u64 = 0;
+
+    /// Denominator of [`Self::RATIO`] as an exact fraction (ignored when [`Self::RATIO_NUM`] is `0`).
+    const RATIO_DEN: u64 = 1;
+
     /// Dimension to which this unit belongs.
     type Dim: Dimension;
 
     /// Printable symbol, shown by [`core::fmt::Display`].
     const SYMBOL: &'static str;
+
+    /// Human-readable unit name, shown by the alternate [`core::fmt::Display`] form (`{:#}`).
+    ///
+    /// Defaults to [`Self::SYMBOL`] for hand-written implementations.
+    const LONG_NAME: &'static str = Self::SYMBOL;
+
+    /// `siunitx` unit macro for LaTeX output (e.g. `r"\kilo\metre"`); empty when there is no standard spelling.
+    const SIUNITX: &'static str = "";
+
+    /// Runtime identity of this unit's dimension, for code that dispatches on units it only knows dynamically.
+    ///
+    /// ```rust
+    /// use qtty_core::length::{Kilometer, Length};
+    /// use qtty_core::time::Second;
+    /// use qtty_core::{DimensionId, Unit};
+    ///
+    /// assert_eq!(Kilometer::dimension_id(), DimensionId::of::<Length>());
+    /// assert_ne!(Kilometer::dimension_id(), Second::dimension_id());
+    /// ```
+    #[inline]
+    fn dimension_id() -> DimensionId
+    where
+        Self::Dim: 'static,
+    {
+        DimensionId::of::<Self::Dim>()
+    }
+
+    /// Writes this unit's symbol to `f`.
+    ///
+    /// Simple units write [`Self::SYMBOL`]. Composite units such as [`Per`] override this to format their whole
+    /// expression tree, so nested compositions print as e.g. `m/s^2`.
+    fn fmt_symbol(f: &mut Formatter<'_>) -> Result {
+        f.write_str(Self::SYMBOL)
+    }
+
+    /// Writes this unit's long name to `f` (see [`Self::LONG_NAME`]); composites join their parts with `per`.
+    fn fmt_long_name(f: &mut Formatter<'_>) -> Result {
+        f.write_str(Self::LONG_NAME)
+    }
+
+    /// Writes this unit in `siunitx` notation, prefixed by `\per` when it appears in a denominator.
+    ///
+    /// Units without a [`Self::SIUNITX`] spelling fall back to their literal symbol.
+    fn fmt_siunitx(f: &mut Formatter<'_>, inverse: bool) -> Result {
+        let unit = if Self::SIUNITX.is_empty() {
+            Self::SYMBOL
+        } else {
+            Self::SIUNITX
+        };
+        if unit.is_empty() {
+            return Ok(());
+        }
+        if inverse {
+            f.write_str("\\per")?;
+        }
+        f.write_str(unit)
+    }
+
+    /// Appends this unit's symbol factors to `terms` with the given exponent sign.
+    ///
+    /// Used by composite units to flatten nested expressions before formatting.
+    #[doc(hidden)]
+    fn symbol_terms(terms: &mut SymbolTerms, exponent: i32) {
+        terms.push(Self::SYMBOL, exponent);
+    }
+
+    /// Worst-case relative error `|y − x| / |x|` of the round trip `y = x.to::<T>().to::<Self>()`.
+    ///
+    /// Zero when neither direction rounds (identical ratios, or an exact factor of `1`). See
+    /// [`UnitInfo::round_trip_error`] for how the bound is derived and when it holds.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::{Degree, Radian};
+    /// use qtty_core::length::{Kilometer, Meter};
+    /// use qtty_core::Unit;
+    ///
+    /// assert_eq!(Meter::conversion_error_to::<Meter>(), 0.0);
+    /// // One rounding each way through the exact factor 1000.
+    /// assert_eq!(Meter::conversion_error_to::<Kilometer>(), f64::EPSILON / (1.0 - f64::EPSILON));
+    /// // π/180 has no exact fraction: the factors themselves are rounded too.
+    /// assert!(Degree::conversion_error_to::<Radian>() > Meter::conversion_error_to::<Kilometer>());
+    /// assert!(Degree::conversion_error_to::<Radian>() < 4.5e-16);
+    /// ```
+    fn conversion_error_to<T: Unit>() -> f64
+    where
+        Self::Dim: SameDimension<T::Dim>,
+    {
+        UnitInfo::of::<Self>().round_trip_error(&UnitInfo::of::<T>())
+    }
 }
 
+/// Runtime description of a unit: its symbol, long name and conversion ratio.
+///
+/// Built at compile time from any [`Unit`] via [`UnitInfo::of`], so tables of units can be stored in `const`/`static`
+/// items and inspected without knowing the unit types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct UnitInfo {
+    /// The unit's [`Unit::SYMBOL`].
+    pub symbol: &'static str,
+    /// The unit's [`Unit::LONG_NAME`].
+    pub long_name: &'static str,
+    /// The unit's [`Unit::RATIO`] to the canonical unit of its dimension.
+    pub ratio: f64,
+    /// The unit's [`Unit::RATIO_NUM`] (`0` when the ratio has no exact integer form).
+    pub ratio_num: u64,
+    /// The unit's [`Unit::RATIO_DEN`].
+    pub ratio_den: u64,
+}
+
+impl UnitInfo {
+    /// Describes unit `U`.
+    pub const fn of<U: Unit>() -> Self {
+        Self {
+            symbol: U::SYMBOL,
+            long_name: U::LONG_NAME,
+            ratio: U::RATIO,
+            ratio_num: U::RATIO_NUM,
+            ratio_den: U::RATIO_DEN,
+        }
+    }
+
+    /// Number of `f64` roundings in converting a value from this unit to `target`, mirroring [`Quantity::to`].
+    ///
+    /// An exact factor `num / den` costs one rounding when either term is `1` and two otherwise; the `RATIO`
+    /// quotient costs one rounding for the factor and one for the product, unless the ratios are equal.
+    pub const fn conversion_roundings(&self, target: &UnitInfo) -> u32 {
+        match exact_factor(
+            self.ratio_num,
+            self.ratio_den,
+            target.ratio_num,
+            target.ratio_den,
+        ) {
+            Some((num, den)) if num == 1.0 && den == 1.0 => 0,
+            Some((num, den)) if num == 1.0 || den == 1.0 => 1,
+            Some(_) => 2,
+            None if self.ratio == target.ratio => 0,
+            None => 2,
+        }
+    }
+
+    /// Worst-case relative error of converting a value from this unit to `other` and back.
+    ///
+    /// With `n` roundings over both directions ([`conversion_roundings`](Self::conversion_roundings)), each of
+    /// relative size at most `u = 2⁻⁵³`, the standard bound is `γₙ = n·u / (1 − n·u)`. Every built-in pair needs at
+    /// most four roundings, so no round trip loses more than about `4.4e-16` of the value, i.e. two ulps.
+    /// The bound assumes the converted value neither overflows nor falls into the subnormal range.
+    ///
+    /// Usable in `const` items to tabulate the guarantees of a set of units:
+    ///
+    /// ```rust
+    /// use qtty_core::length::{AstronomicalUnit, Kilometer, Parsec};
+    /// use qtty_core::UnitInfo;
+    ///
+    /// const KM: UnitInfo = UnitInfo::of::<Kilometer>();
+    /// const WORST: [f64; 2] = [
+    ///     KM.round_trip_error(&UnitInfo::of::<AstronomicalUnit>()),
+    ///     KM.round_trip_error(&UnitInfo::of::<Parsec>()),
+    /// ];
+    /// assert!(WORST.iter().all(|&e| e <= 4.5e-16));
+    /// ```
+    pub const fn round_trip_error(&self, other: &UnitInfo) -> f64 {
+        let n = (self.conversion_roundings(other) + other.conversion_roundings(self)) as f64;
+        let nu = n * (f64::EPSILON / 2.0);
+        nu / (1.0 - nu)
+    }
+}
+
+/// Maximum number of distinct factors tracked when formatting a composite symbol.
+const MAX_SYMBOL_TERMS: usize = 16;
+
+/// Flattened factors of a composite unit symbol (`symbol^exponent` pairs).
+///
+/// Numerator and denominator factors are kept apart: repeated factors on the same side are merged into an exponent
+/// (`s·s` → `s^2`), but factors on opposite sides are not cancelled, so `Per<Meter, Meter>` still prints `m/m`.
+///
+/// A composite with more than `MAX_SYMBOL_TERMS` distinct factors marks the terms as truncated; `Per` and `Prod`
+/// then print their two operands unsimplified instead (`(A)/(B)`, `(A)·(B)`).
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct SymbolTerms {
+    terms: [(&'static str, i32); MAX_SYMBOL_TERMS],
+    len: usize,
+    truncated: bool,
+}
+
+impl SymbolTerms {
+    pub(crate) const fn new() -> Self {
+        Self {
+            terms: [("", 0); MAX_SYMBOL_TERMS],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Adds `symbol^exponent`, merging with an existing factor of the same symbol on the same side.
+    pub fn push(&mut self, symbol: &'static str, exponent: i32) {
+        if symbol.is_empty() || exponent == 0 {
+            return;
+        }
+        for term in &mut self.terms[..self.len] {
+            if term.0 == symbol && (term.1 > 0) == (exponent > 0) {
+                term.1 += exponent;
+                return;
+            }
+        }
+        if self.len < MAX_SYMBOL_TERMS {
+            self.terms[self.len] = (symbol, exponent);
+            self.len += 1;
+        } else {
+            self.truncated = true;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `true` if some factors did not fit and were dropped.
+    pub(crate) fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Writes `(A)<op>(B)` for a composite too large to flatten.
+    fn fmt_unsimplified<A: Unit, B: Unit>(f: &mut Formatter<'_>, op: &str) -> Result {
+        f.write_str("(")?;
+        A::fmt_symbol(f)?;
+        write!(f, "){}(", op)?;
+        B::fmt_symbol(f)?;
+        f.write_str(")")
+    }
+
+    /// The collected `(symbol, exponent)` factors.
+    #[cfg(feature = "table")]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'static str, i32)> + '_ {
+        self.terms[..self.len].iter().copied()
+    }
+
+    fn fmt_side(&self, f: &mut Formatter<'_>, numerator: bool) -> Result {
+        let mut first = true;
+        for &(symbol, exponent) in &self.terms[..self.len] {
+            if (exponent > 0) != numerator {
+                continue;
+            }
+            if !first {
+                f.write_str("·")?;
+            }
+            first = false;
+            f.write_str(symbol)?;
+            let power = exponent.abs();
+            if power != 1 {
+                write!(f, "^{}", power)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn count(&self, numerator: bool) -> usize {
+        self.terms[..self.len]
+            .iter()
+            .filter(|t| (t.1 > 0) == numerator)
+            .count()
+    }
+}
+
+impl Display for SymbolTerms {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let num = self.count(true);
+        let den = self.count(false);
+        if self.is_empty() {
+            return Ok(());
+        }
+        if num == 0 {
+            f.write_str("1")?;
+        } else {
+            self.fmt_side(f, true)?;
+        }
+        if den == 0 {
+            return Ok(());
+        }
+        f.write_str("/")?;
+        if den > 1 {
+            f.write_str("(")?;
+            self.fmt_side(f, false)?;
+            f.write_str(")")
+        } else {
+            self.fmt_side(f, false)
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Exact rational ratios
+// ─────────────────────────────────────────────────────────────────────────────
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Reduced product `(an/ad)·(bn/bd)`, or `(0, 1)` ("no exact ratio") if a factor is missing or the product overflows.
+pub(crate) const fn rational_mul(an: u64, ad: u64, bn: u64, bd: u64) -> (u64, u64) {
+    if an == 0 || ad == 0 || bn == 0 || bd == 0 {
+        return (0, 1);
+    }
+    // Cross-reduce first so that products stay small.
+    let g1 = gcd(an, bd);
+    let g2 = gcd(bn, ad);
+    match (
+        (an / g1).checked_mul(bn / g2),
+        (ad / g2).checked_mul(bd / g1),
+    ) {
+        (Some(num), Some(den)) => (num, den),
+        _ => (0, 1),
+    }
+}
+
+/// Largest integer up to which every integer is exactly representable as an `f64`.
+const MAX_EXACT_F64_INT: u64 = 1 << 53;
+
+/// Whether `n` survives the round trip through `f64`. Every integer up to 2^53 does, and so do larger ones with
+/// enough trailing zero bits, such as the powers of ten up to `1e19` that SI prefixes produce.
+const fn is_exact_f64(n: u64) -> bool {
+    n <= MAX_EXACT_F64_INT || {
+        let f = n as f64;
+        // `u64::MAX as f64` rounds up to 2^64, which would saturate back to `u64::MAX`.
+        f < 18_446_744_073_709_551_616.0 && f as u64 == n
+    }
+}
+
+/// Exact factor `(from_num/from_den) / (to_num/to_den)` as `(num, den)`, when both ratios are rational and the
+/// reduced fraction's terms are exactly representable as `f64`.
+pub(crate) const fn exact_factor(
+    from_num: u64,
+    from_den: u64,
+    to_num: u64,
+    to_den: u64,
+) -> Option<(f64, f64)> {
+    let (num, den) = rational_mul(from_num, from_den, to_den, to_num);
+    if num == 0 || !is_exact_f64(num) || !is_exact_f64(den) {
+        None
+    } else {
+        Some((num as f64, den as f64))
+    }
+}
+
+/// Scales `value` by an exact factor from [`exact_factor`], with a single rounding when either term is `1`.
+#[inline]
+pub(crate) const fn apply_exact(value: f64, (num, den): (f64, f64)) -> f64 {
+    if den == 1.0 {
+        value * num
+    } else if num == 1.0 {
+        value / den
+    } else {
+        value * num / den
+    }
+}
+
+/// Exact factor `U::RATIO / T::RATIO` (see [`exact_factor`]).
+pub(crate) struct ExactFactor<U, T>(PhantomData<(U, T)>);
+
+impl<U: Unit, T: Unit> ExactFactor<U, T> {
+    pub(crate) const VALUE: Option<(f64, f64)> =
+        exact_factor(U::RATIO_NUM, U::RATIO_DEN, T::RATIO_NUM, T::RATIO_DEN);
+}
+
+/// Compile-time assertion that units `A` and `B` share a dimension.
+///
+/// Evaluate it in a `const` item to document (and enforce) that two unit types are interchangeable; a mismatch is a
+/// compile error naming both dimensions.
+///
+/// ```rust
+/// use qtty_core::length::{Kilometer, Parsec};
+///
+/// const _: () = qtty_core::same_dimension::<Kilometer, Parsec>();
+/// ```
+///
+/// ```compile_fail
+/// use qtty_core::length::Kilometer;
+/// use qtty_core::time::Second;
+///
+/// const _: () = qtty_core::same_dimension::<Kilometer, Second>();
+/// ```
+#[inline]
+pub const fn same_dimension<A: Unit, B: Unit>()
+where
+    A::Dim: SameDimension<B::Dim>,
+{
+}
+
+/// Compile-time assertion that unit `U` measures dimension `D`.
+///
+/// ```rust
+/// use qtty_core::length::{Length, Parsec};
+///
+/// const _: () = qtty_core::is_dimension::<Parsec, Length>();
+/// ```
+///
+/// ```compile_fail
+/// use qtty_core::length::Length;
+/// use qtty_core::time::Second;
+///
+/// const _: () = qtty_core::is_dimension::<Second, Length>();
+/// ```
+#[inline]
+pub const fn is_dimension<U: Unit, D: Dimension>()
+where
+    U::Dim: SameDimension<D>,
+{
+}
+
+/// Bound for a unit that shares its dimension with unit `B`: `A: SameDimensionAs<B>` reads better than
+/// `A: Unit<Dim = B::Dim>` in generic signatures, and implies it, so conversions between the two type-check.
+///
+/// ```rust
+/// use qtty_core::{Quantity, SameDimensionAs, Unit};
+/// use qtty_core::length::{Kilometer, Meters};
+///
+/// fn total<A: SameDimensionAs<B>, B: Unit>(parts: &[Quantity<A>]) -> Quantity<B> {
+///     parts.iter().fold(Quantity::new(0.0), |acc, p| acc + p.to::<B>())
+/// }
+///
+/// let km = total::<_, Kilometer>(&[Meters::new(500.0), Meters::new(1_500.0)]);
+/// assert_eq!(km.value(), 2.0);
+/// ```
+pub trait SameDimensionAs<B: Unit>: Unit<Dim = <B as Unit>::Dim> {}
+impl<A: Unit<Dim = B::Dim>, B: Unit> SameDimensionAs<B> for A {}
+
 /// Unit representing the division of two other units.
 ///
 /// `Per<N, D>` corresponds to `N / D` and carries both the
@@ -42,13 +488,107 @@ pub struct Per<N: Unit, D: Unit>(PhantomData<(N, D)>);
 
 impl<N: Unit, D: Unit> Unit for Per<N, D> {
     const RATIO: f64 = N::RATIO / D::RATIO;
+    const RATIO_NUM: u64 = rational_mul(N::RATIO_NUM, N::RATIO_DEN, D::RATIO_DEN, D::RATIO_NUM).0;
+    const RATIO_DEN: u64 = rational_mul(N::RATIO_NUM, N::RATIO_DEN, D::RATIO_DEN, D::RATIO_NUM).1;
     type Dim = DivDim<N::Dim, D::Dim>;
     const SYMBOL: &'static str = "";
+
+    fn fmt_symbol(f: &mut Formatter<'_>) -> Result {
+        let mut terms = SymbolTerms::new();
+        Self::symbol_terms(&mut terms, 1);
+        if terms.is_truncated() {
+            return SymbolTerms::fmt_unsimplified::<N, D>(f, "/");
+        }
+        Display::fmt(&terms, f)
+    }
+
+    fn fmt_long_name(f: &mut Formatter<'_>) -> Result {
+        N::fmt_long_name(f)?;
+        f.write_str(" per ")?;
+        // A quotient in the denominator needs grouping: `m/(Km/s)` is "meter per (kilometer per second)".
+        let mut terms = SymbolTerms::new();
+        D::symbol_terms(&mut terms, 1);
+        if terms.count(false) > 0 {
+            f.write_str("(")?;
+            D::fmt_long_name(f)?;
+            f.write_str(")")
+        } else {
+            D::fmt_long_name(f)
+        }
+    }
+
+    fn fmt_siunitx(f: &mut Formatter<'_>, inverse: bool) -> Result {
+        N::fmt_siunitx(f, inverse)?;
+        D::fmt_siunitx(f, !inverse)
+    }
+
+    fn symbol_terms(terms: &mut SymbolTerms, exponent: i32) {
+        N::symbol_terms(terms, exponent);
+        D::symbol_terms(terms, -exponent);
+    }
 }
 
 impl<N: Unit, D: Unit> Display for Quantity<Per<N, D>> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{} {}/{}", self.value(), N::SYMBOL, D::SYMBOL)
+        Display::fmt(&self.value(), f)?;
+        f.write_str(" ")?;
+        if f.alternate() {
+            <Per<N, D>>::fmt_long_name(f)
+        } else {
+            <Per<N, D>>::fmt_symbol(f)
+        }
+    }
+}
+
+/// Unit representing the product of two other units.
+///
+/// `Prod<A, B>` corresponds to `A·B`, e.g. `Prod<Meter, Meter>` is the square metre. Its ratio is the product of the
+/// factors' ratios, and its symbol merges repeated factors into exponents (`m^2`, `Km·h`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Prod<A: Unit, B: Unit>(PhantomData<(A, B)>);
+
+impl<A: Unit, B: Unit> Unit for Prod<A, B> {
+    const RATIO: f64 = A::RATIO * B::RATIO;
+    const RATIO_NUM: u64 = rational_mul(A::RATIO_NUM, A::RATIO_DEN, B::RATIO_NUM, B::RATIO_DEN).0;
+    const RATIO_DEN: u64 = rational_mul(A::RATIO_NUM, A::RATIO_DEN, B::RATIO_NUM, B::RATIO_DEN).1;
+    type Dim = MulDim<A::Dim, B::Dim>;
+    const SYMBOL: &'static str = "";
+
+    fn fmt_symbol(f: &mut Formatter<'_>) -> Result {
+        let mut terms = SymbolTerms::new();
+        Self::symbol_terms(&mut terms, 1);
+        if terms.is_truncated() {
+            return SymbolTerms::fmt_unsimplified::<A, B>(f, "·");
+        }
+        Display::fmt(&terms, f)
+    }
+
+    fn fmt_long_name(f: &mut Formatter<'_>) -> Result {
+        A::fmt_long_name(f)?;
+        f.write_str("-")?;
+        B::fmt_long_name(f)
+    }
+
+    fn fmt_siunitx(f: &mut Formatter<'_>, inverse: bool) -> Result {
+        A::fmt_siunitx(f, inverse)?;
+        B::fmt_siunitx(f, inverse)
+    }
+
+    fn symbol_terms(terms: &mut SymbolTerms, exponent: i32) {
+        A::symbol_terms(terms, exponent);
+        B::symbol_terms(terms, exponent);
+    }
+}
+
+impl<A: Unit, B: Unit> Display for Quantity<Prod<A, B>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.value(), f)?;
+        f.write_str(" ")?;
+        if f.alternate() {
+            <Prod<A, B>>::fmt_long_name(f)
+        } else {
+            <Prod<A, B>>::fmt_symbol(f)
+        }
     }
 }
 
@@ -66,20 +606,25 @@ pub struct Unitless;
 
 impl Unit for Unitless {
     const RATIO: f64 = 1.0;
+    const RATIO_NUM: u64 = 1;
     type Dim = Dimensionless;
     const SYMBOL: &'static str = "";
 }
 
 impl Display for Quantity<Unitless> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value())
+        Display::fmt(&self.value(), f)
     }
 }
 
 /// Trait for simplifying composite unit types.
 ///
 /// This allows reducing complex unit expressions to simpler forms,
-/// such as `Per<U, U>` to `Unitless` or `Per<N, Per<N, D>>` to `D`.
+/// such as `Per<U, U>` to `Unitless`, `Per<N, Per<N, D>>` to `D` or `Per<Prod<A, B>, B>` to `A`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no simpler form",
+    note = "`simplify()` is implemented for `Per<U, U>`, `Per<N, Per<N, D>>` and `Per<Prod<A, B>, B>` quantities"
+)]
 pub trait Simplify {
     /// The simplified unit type.
     type Out: Unit;
@@ -97,6 +642,7 @@ impl<U: Unit> Simplify for Quantity<Per<U, U>> {
     /// let unitless: Quantity<Unitless> = ratio.simplify();
     /// assert!((unitless.value() - 0.5).abs() < 1e-12);
     /// ```
+    #[inline]
     fn simplify(self) -> Quantity<Unitless> {
         Quantity::new(self.value())
     }
@@ -104,7 +650,24 @@ impl<U: Unit> Simplify for Quantity<Per<U, U>> {
 
 impl<N: Unit, D: Unit> Simplify for Quantity<Per<N, Per<N, D>>> {
     type Out = D;
+    #[inline]
     fn simplify(self) -> Quantity<D> {
         Quantity::new(self.value())
     }
 }
+
+impl<A: Unit, B: Unit> Simplify for Quantity<Per<Prod<A, B>, B>> {
+    type Out = A;
+    /// ```rust
+    /// use qtty_core::area::SquareMeters;
+    /// use qtty_core::length::{Meter, Meters};
+    /// use qtty_core::{Quantity, Simplify};
+    ///
+    /// let width: Quantity<Meter> = (SquareMeters::new(12.0) / Meters::new(3.0)).simplify();
+    /// assert_eq!(width.value(), 4.0);
+    /// ```
+    #[inline]
+    fn simplify(self) -> Quantity<A> {
+        Quantity::new(self.value())
+    }
+}
@@ -10,12 +10,16 @@
 //!   `Degree::RATIO == 1.0`, and all other angular units express how many *degrees* correspond to one of that unit.
 //!   For example, `Radian::RATIO == 180.0 / PI` because 1 radian = 180/π degrees.
 //! * **Associated constants:** The `AngularUnit` trait exposes precomputed constants (`FULL_TURN`, `HALF_TURN`,
-//!   `QUARTED_TURN`) expressed *in the receiving unit* for ergonomic range‑wrapping. These are derived from `τ`
+//!   `QUARTER_TURN`) expressed *in the receiving unit* for ergonomic range‑wrapping. These are derived from `τ`
 //!   radians and then converted to the target unit to avoid cumulative error from chained conversions.
 //! * **Trigonometry:** `sin`, `cos`, `tan`, and `sin_cos` methods are provided on angular quantities; they convert to
 //!   radians internally and then call the corresponding `f64` intrinsic.
 //! * **Wrapping helpers:** Utility methods to wrap any angle into common ranges — `[0, 360)` (or unit equivalent),
-//!   `(-180, 180]`, and the latitude‑style quarter fold `[-90, 90]`.
+//!   `(-180, 180]`, and the latitude‑style quarter fold `[-90, 90]`. They are `const fn`, as are the polynomial
+//!   [`Quantity::sin_const`] / [`Quantity::cos_const`], so typed angle tables can be built at compile time.
+//!   [`Quantity::wrap_pos_precise`] and [`Quantity::mul_wrap_pos`] keep full precision for angles of many
+//!   thousands of turns (Payne–Hanek reduction for radians, exact `%` for whole-number turns). [`WrapMode`]
+//!   selects among the ranges at run time through [`Quantity::wrap`].
 //!
 //! ## Edge cases
 //!
@@ -49,25 +53,90 @@
 //! assert_eq!(a.value(), 10.0);
 //! ```
 
-use crate::{Dimension, Quantity, Unit};
+use crate::math;
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
 use core::f64::consts::TAU;
+use core::fmt;
 use qtty_derive::Unit;
 
+/// Euclidean remainder in `[0, modulus)`, usable in `const` contexts.
+///
+/// `%` on floats is an exact IEEE remainder (the same operation as `fmod`), so this needs neither `std` nor `libm`.
+/// Unlike `f64::rem_euclid`, a tiny negative remainder whose shift by `modulus` rounds up to `modulus` itself comes
+/// back as zero, so the result never reaches the upper bound.
 #[inline]
-fn rem_euclid(x: f64, modulus: f64) -> f64 {
-    #[cfg(feature = "std")]
-    {
-        x.rem_euclid(modulus)
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        let r = crate::libm::fmod(x, modulus);
-        if r < 0.0 {
-            r + modulus
-        } else {
-            r
+const fn rem_euclid(x: f64, modulus: f64) -> f64 {
+    let r = x % modulus;
+    if r >= 0.0 {
+        return r;
+    }
+    let shifted = r + modulus;
+    if shifted >= modulus {
+        0.0
+    } else {
+        shifted
+    }
+}
+
+/// One turn of `U` when it is a whole number small enough for `%` to reduce by it exactly.
+///
+/// Taken from the exact degree ratio when the unit declares one, so that e.g. microarcseconds use `1_296_000_000_000`
+/// rather than a `FULL_TURN` rounded on its way through radians.
+const fn exact_turn<U: AngularUnit>() -> Option<f64> {
+    const MAX_EXACT: f64 = 9_007_199_254_740_992.0; // 2^53
+    if U::RATIO_NUM != 0 {
+        let scaled = 360 * U::RATIO_DEN as u128;
+        let num = U::RATIO_NUM as u128;
+        if scaled.is_multiple_of(num) && ((scaled / num) as f64) <= MAX_EXACT {
+            return Some((scaled / num) as f64);
         }
     }
+    let turn = Radians::new(TAU).to::<U>().value();
+    if turn % 1.0 == 0.0 && turn <= MAX_EXACT {
+        Some(turn)
+    } else {
+        None
+    }
+}
+
+/// `sin(x)` for `x` in `[-π/2, π/2]`, via its Taylor series up to `x^23` (truncation error below `1e-20`).
+const fn sin_poly(x: f64) -> f64 {
+    const COEFFS: [f64; 12] = {
+        let mut c = [0.0; 12];
+        let mut k = 0;
+        let mut fact = 1.0;
+        while k < 12 {
+            let n = 2 * k + 1;
+            if k > 0 {
+                fact *= ((n - 1) * n) as f64;
+            }
+            c[k] = if k % 2 == 0 { 1.0 / fact } else { -1.0 / fact };
+            k += 1;
+        }
+        c
+    };
+    let x2 = x * x;
+    let mut acc = COEFFS[11];
+    let mut i = 11;
+    while i > 0 {
+        i -= 1;
+        acc = acc * x2 + COEFFS[i];
+    }
+    acc * x
+}
+
+/// `sin(x)` for `x` in `[-π, π]`, folded into `[-π/2, π/2]` using `sin(π - x) = sin(x)`.
+const fn sin_reduced(x: f64) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, PI};
+    let y = if x > FRAC_PI_2 {
+        PI - x
+    } else if x < -FRAC_PI_2 {
+        -PI - x
+    } else {
+        x
+    };
+    sin_poly(y)
 }
 
 /// Dimension tag for angular measures (e.g., degrees, radians, arcseconds).
@@ -80,15 +149,18 @@ impl Dimension for Angular {}
 /// They are computed via a compile-time conversion from `TAU` radians (i.e., a full revolution) and then scaled.
 /// This keeps all fractions derived from the same base value.
 ///
-/// > **Naming note:** The historical spelling `QUARTED_TURN` is retained for backward compatibility. It represents a
-/// > quarter turn (90°).
+/// > **Naming note:** `QUARTED_TURN`, the original misspelling of [`Self::QUARTER_TURN`], is kept as a deprecated
+/// > alias so existing code keeps compiling.
 pub trait AngularUnit: Unit<Dim = Angular> {
     /// One full revolution (τ radians / 360°) expressed in this unit.
     const FULL_TURN: f64;
     /// Half a revolution (π radians / 180°) expressed in this unit.
     const HALF_TURN: f64;
     /// A quarter revolution (π/2 radians / 90°) expressed in this unit.
-    const QUARTED_TURN: f64;
+    const QUARTER_TURN: f64;
+    /// Deprecated spelling of [`Self::QUARTER_TURN`].
+    #[deprecated(note = "use `QUARTER_TURN`")]
+    const QUARTED_TURN: f64 = Self::QUARTER_TURN;
 }
 impl<T: Unit<Dim = Angular>> AngularUnit for T {
     /// One full revolution (360°) expressed in T unit.
@@ -96,7 +168,33 @@ impl<T: Unit<Dim = Angular>> AngularUnit for T {
     /// Half a revolution (180°) expressed in T unit.
     const HALF_TURN: f64 = Radians::new(TAU).to::<T>().value() * 0.5;
     /// Quarter revolution (90°) expressed in T unit.
-    const QUARTED_TURN: f64 = Radians::new(TAU).to::<T>().value() * 0.25;
+    const QUARTER_TURN: f64 = Radians::new(TAU).to::<T>().value() * 0.25;
+}
+
+/// Range convention for [`Quantity::wrap`] and [`Quantity::is_wrapped`].
+///
+/// Each variant names one of the `wrap_*` helpers, so a convention can be stored in configuration or passed as an
+/// argument instead of being fixed by which method the caller happens to call.
+///
+/// ```rust
+/// use qtty_core::angular::{Degrees, WrapMode};
+///
+/// let longitude = Degrees::new(190.0);
+/// assert_eq!(longitude.wrap(WrapMode::Positive).value(), 190.0);
+/// assert_eq!(longitude.wrap(WrapMode::SignedUpperInc).value(), -170.0);
+/// assert!(!longitude.is_wrapped(WrapMode::SignedUpperInc));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WrapMode {
+    /// `[0, FULL_TURN)`, as [`Quantity::wrap_pos`].
+    Positive,
+    /// `(-HALF_TURN, HALF_TURN]`, as [`Quantity::wrap_signed`].
+    SignedUpperInc,
+    /// `[-HALF_TURN, HALF_TURN)`, as [`Quantity::wrap_signed_lo`].
+    SignedLowerInc,
+    /// `[-QUARTER_TURN, QUARTER_TURN]` by reflection, as [`Quantity::wrap_quarter_fold`].
+    QuarterFold,
 }
 
 impl<U: AngularUnit + Copy> Quantity<U> {
@@ -109,22 +207,17 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     /// Half a revolution (180°) expressed as `Quantity<U>`.
     pub const HALF_TURN: Quantity<U> = Quantity::<U>::new(U::HALF_TURN);
     /// Quarter revolution (90°) expressed as `Quantity<U>`.
-    pub const QUARTED_TURN: Quantity<U> = Quantity::<U>::new(U::QUARTED_TURN);
+    pub const QUARTER_TURN: Quantity<U> = Quantity::<U>::new(U::QUARTER_TURN);
+    /// Deprecated spelling of [`Self::QUARTER_TURN`].
+    #[deprecated(note = "use `QUARTER_TURN`")]
+    pub const QUARTED_TURN: Quantity<U> = Self::QUARTER_TURN;
 
     /// Sine of the angle.
     ///
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
     pub fn sin(&self) -> f64 {
-        let x = self.to::<Radian>().value();
-        #[cfg(feature = "std")]
-        {
-            x.sin()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            crate::libm::sin(x)
-        }
+        math::sin(self.to::<Radian>().value())
     }
 
     /// Cosine of the angle.
@@ -132,15 +225,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
     pub fn cos(&self) -> f64 {
-        let x = self.to::<Radian>().value();
-        #[cfg(feature = "std")]
-        {
-            x.cos()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            crate::libm::cos(x)
-        }
+        math::cos(self.to::<Radian>().value())
     }
 
     /// Tangent of the angle.
@@ -148,15 +233,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
     pub fn tan(&self) -> f64 {
-        let x = self.to::<Radian>().value();
-        #[cfg(feature = "std")]
-        {
-            x.tan()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            crate::libm::tan(x)
-        }
+        math::tan(self.to::<Radian>().value())
     }
 
     /// Simultaneously compute sine and cosine.
@@ -165,14 +242,107 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     #[inline]
     pub fn sin_cos(&self) -> (f64, f64) {
         let x = self.to::<Radian>().value();
-        #[cfg(feature = "std")]
-        {
-            x.sin_cos()
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            (crate::libm::sin(x), crate::libm::cos(x))
-        }
+        (math::sin(x), math::cos(x))
+    }
+
+    /// Haversine, `hav θ = sin²(θ/2) = (1 − cos θ)/2`.
+    ///
+    /// Written as a square of a sine, so it keeps full relative precision for small angles, where `1 − cos θ`
+    /// cancels.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// // Great-circle distance between two points given in latitude/longitude.
+    /// let (lat1, lon1) = (Degrees::new(51.5), Degrees::new(-0.13));
+    /// let (lat2, lon2) = (Degrees::new(48.86), Degrees::new(2.35));
+    /// let h = (lat2 - lat1).hav() + lat1.cos() * lat2.cos() * (lon2 - lon1).hav();
+    /// let d = Degrees::ahav(h);
+    /// assert!((d.value() - 3.08).abs() < 0.01);
+    /// ```
+    #[inline]
+    pub fn hav(&self) -> f64 {
+        let s = Radians::new(0.5 * self.to::<Radian>().value()).sin();
+        s * s
+    }
+
+    /// Archaversine: the angle in `[0, HALF_TURN]` whose haversine is `h`, `2·asin(√h)`.
+    ///
+    /// `h` is clamped to `[0, 1]` first, so rounding just outside the range (as happens in great-circle sums for
+    /// nearly antipodal points) does not produce `NaN`.
+    #[inline]
+    pub fn ahav(h: f64) -> Self {
+        let root = h.clamp(0.0, 1.0);
+        let theta = 2.0 * math::asin(math::sqrt(root));
+        Radians::new(theta).to::<U>()
+    }
+
+    /// Sine of the angle, evaluable in `const` contexts.
+    ///
+    /// Uses a polynomial after range reduction; agrees with [`Self::sin`] to within a few ULP for moderate angles.
+    /// Accuracy degrades for very large magnitudes (beyond ~10⁶ turns) because the reduction is done in `f64`.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// // A compile-time table of sines at 15° steps.
+    /// const TABLE: [f64; 7] = {
+    ///     let mut t = [0.0; 7];
+    ///     let mut i = 0;
+    ///     while i < 7 {
+    ///         t[i] = Degrees::new(15.0 * i as f64).sin_const();
+    ///         i += 1;
+    ///     }
+    ///     t
+    /// };
+    /// assert!((TABLE[2] - 0.5).abs() < 1e-15);
+    /// assert!((TABLE[6] - 1.0).abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub const fn sin_const(&self) -> f64 {
+        // Reduce in the native unit first so the radian conversion only sees (-π, π].
+        sin_reduced(self.wrap_signed().to::<Radian>().value())
+    }
+
+    /// Cosine of the angle, evaluable in `const` contexts (see [`Self::sin_const`]).
+    #[inline]
+    pub const fn cos_const(&self) -> f64 {
+        // cos(x) = sin(π/2 - |x|) for x in [-π, π].
+        let x = self.wrap_signed().to::<Radian>().value();
+        sin_poly(core::f64::consts::FRAC_PI_2 - x.abs())
+    }
+
+    /// The angle in radians as a bare `f64`: one multiplication by the unit's radian scale, with no wrapping.
+    ///
+    /// Intended for the small angles of astrometric corrections (aberration, parallax, proper motion), where the
+    /// radian value feeds straight into first-order formulas.
+    #[inline]
+    pub const fn to_radians_small(self) -> f64 {
+        self.to::<Radian>().value()
+    }
+
+    /// `sin θ ≈ θ`, with `θ` in radians.
+    ///
+    /// The relative error is below `θ²/6`: under `f64` precision up to about 5 mas, `4e-12` at 1″ and `1.4e-8` at
+    /// 1′.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::MilliArcseconds;
+    ///
+    /// let x = MilliArcseconds::new(5.0);
+    /// assert!((x.sin_small() / x.sin() - 1.0).abs() <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub const fn sin_small(self) -> f64 {
+        self.to_radians_small()
+    }
+
+    /// `tan θ ≈ θ`, with `θ` in radians.
+    ///
+    /// The relative error is about `θ²/3`, twice that of [`Self::sin_small`]: `8e-12` at 1″ and `2.8e-8` at 1′.
+    #[inline]
+    pub const fn tan_small(self) -> f64 {
+        self.to_radians_small()
     }
 
     /// Sign of the *raw numeric* in this unit (same semantics as `f64::signum()`).
@@ -185,7 +355,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     ///
     /// Shorthand for [`Self::wrap_pos`].
     #[inline]
-    pub fn normalize(self) -> Self {
+    pub const fn normalize(self) -> Self {
         self.wrap_pos()
     }
 
@@ -193,7 +363,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     ///
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
-    pub fn wrap_pos(self) -> Self {
+    pub const fn wrap_pos(self) -> Self {
         Self::new(rem_euclid(self.value(), U::FULL_TURN))
     }
 
@@ -203,7 +373,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     ///
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
-    pub fn wrap_signed(self) -> Self {
+    pub const fn wrap_signed(self) -> Self {
         let full = U::FULL_TURN;
         let half = 0.5 * full;
         let x = self.value();
@@ -218,7 +388,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     ///
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
-    pub fn wrap_signed_lo(self) -> Self {
+    pub const fn wrap_signed_lo(self) -> Self {
         let mut y = self.wrap_signed().value(); // now in (-half, half]
         let half = 0.5 * U::FULL_TURN;
         if y >= half {
@@ -234,7 +404,7 @@ impl<U: AngularUnit + Copy> Quantity<U> {
     ///
     /// IEEE‑754 note: `NaN`/`±∞` inputs generally produce `NaN`.
     #[inline]
-    pub fn wrap_quarter_fold(self) -> Self {
+    pub const fn wrap_quarter_fold(self) -> Self {
         let full = U::FULL_TURN;
         let half = 0.5 * full;
         let quarter = 0.25 * full;
@@ -243,23 +413,145 @@ impl<U: AngularUnit + Copy> Quantity<U> {
         Self::new(quarter - (y - half).abs())
     }
 
+    /// Wrap into the range selected by `mode`.
+    #[inline]
+    pub const fn wrap(self, mode: WrapMode) -> Self {
+        match mode {
+            WrapMode::Positive => self.wrap_pos(),
+            WrapMode::SignedUpperInc => self.wrap_signed(),
+            WrapMode::SignedLowerInc => self.wrap_signed_lo(),
+            WrapMode::QuarterFold => self.wrap_quarter_fold(),
+        }
+    }
+
+    /// Whether the value already lies in the range of `mode`, boundaries included or excluded as `mode` specifies.
+    ///
+    /// `NaN` is never wrapped.
+    #[inline]
+    pub const fn is_wrapped(self, mode: WrapMode) -> bool {
+        let x = self.value();
+        let half = U::HALF_TURN;
+        match mode {
+            WrapMode::Positive => x >= 0.0 && x < U::FULL_TURN,
+            WrapMode::SignedUpperInc => x > -half && x <= half,
+            WrapMode::SignedLowerInc => x >= -half && x < half,
+            WrapMode::QuarterFold => x >= -U::QUARTER_TURN && x <= U::QUARTER_TURN,
+        }
+    }
+
+    /// Wrap into `[0, FULL_TURN)` without losing precision on very large magnitudes.
+    ///
+    /// For units whose full turn is a whole number (degrees, arcseconds, hour angle, gradians, …) `%` is already exact
+    /// and this matches [`Self::wrap_pos`]. Radian-based units go through a Payne–Hanek reduction against a 1152-bit
+    /// expansion of `1/(2π)` instead of dividing by the rounded `τ`, so the result is correct to the last bits even
+    /// for inputs like `1e15` rad, where [`Self::wrap_pos`] is already off in the first decimal.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Radians;
+    ///
+    /// let r = Radians::new(1e15).wrap_pos_precise();
+    /// assert!((r.value() - 2.109_698_117_070_112_6).abs() < 1e-15);
+    /// ```
+    ///
+    /// IEEE‑754 note: `NaN`/`±∞` inputs produce `NaN`.
+    #[inline]
+    pub const fn wrap_pos_precise(self) -> Self {
+        Self::reduce_pos(self.value(), 0.0)
+    }
+
+    /// Wrap into `(-HALF_TURN, HALF_TURN]` with the same precision as [`Self::wrap_pos_precise`].
+    #[inline]
+    pub const fn wrap_signed_precise(self) -> Self {
+        let y = self.wrap_pos_precise().value();
+        let full = match exact_turn::<U>() {
+            Some(turn) => turn,
+            None => U::FULL_TURN,
+        };
+        Self::new(if y > 0.5 * full { y - full } else { y })
+    }
+
+    /// `self * factor` wrapped into `[0, FULL_TURN)`, reducing the exact product rather than its rounded value.
+    ///
+    /// Secular terms such as `rate * t` in ephemeris polynomials grow to many thousands of turns over a few centuries;
+    /// rounding the product to `f64` before wrapping throws away the low bits that carry the sub-mas part of the
+    /// angle. Here the rounding error of the product is kept alongside it (a double-double) through the reduction.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// // Mean motion of the Moon's longitude (°/century) over ten thousand centuries.
+    /// let rate = Degrees::new(481_267.881_234_21);
+    /// let precise = rate.mul_wrap_pos(1e4).value();
+    /// let naive = (rate * 1e4).wrap_pos().value();
+    /// assert!((precise - 92.342_099_845_409_4).abs() < 1e-10);
+    /// assert!((naive - precise).abs() > 1e-7);
+    /// ```
+    #[inline]
+    pub const fn mul_wrap_pos(self, factor: f64) -> Self {
+        let (p, e) = crate::reduce::two_prod(self.value(), factor);
+        Self::reduce_pos(p, e)
+    }
+
+    /// Wraps the double-double value `hi + lo` (in `U`) into `[0, FULL_TURN)`.
+    const fn reduce_pos(hi: f64, lo: f64) -> Self {
+        match exact_turn::<U>() {
+            Some(turn) => Self::new(rem_euclid(rem_euclid(hi, turn) + lo, turn)),
+            None => {
+                // The scale to radians is exactly 1.0 for `Radian` itself; other radian multiples keep the rounding
+                // of their declared ratio, as they do in every conversion.
+                let k = Quantity::<U>::new(1.0).to::<Radian>().value();
+                let (h, l) = crate::reduce::two_prod(hi, k);
+                let r = crate::reduce::wrap_radians(h, l + lo * k);
+                let y = Radians::new(r).to::<U>().value();
+                Self::new(if y >= U::FULL_TURN { 0.0 } else { y })
+            }
+        }
+    }
+
     /// Signed smallest angular separation in `(-HALF_TURN, HALF_TURN]`.
     #[inline]
-    pub fn signed_separation(self, other: Self) -> Self {
-        (self - other).wrap_signed()
+    pub const fn signed_separation(self, other: Self) -> Self {
+        Self::new(self.value() - other.value()).wrap_signed()
     }
 
     /// Absolute smallest angular separation (magnitude only).
     #[inline]
-    pub fn abs_separation(self, other: Self) -> Self {
+    pub const fn abs_separation(self, other: Self) -> Self {
         let sep = self.signed_separation(other);
         Self::new(sep.value().abs())
     }
+
+    /// Angle travelled going from `self` to `other` in the direction of increasing angle, in `[0, FULL_TURN)`.
+    ///
+    /// Whether that is a clockwise or counterclockwise rotation depends on the angle's convention: counterclockwise
+    /// for mathematical angles, clockwise (seen from above) for azimuths measured north through east. Unlike
+    /// [`Self::signed_separation`], the direction is fixed, so the result can exceed a half turn.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// let from = Degrees::new(350.0);
+    /// let to = Degrees::new(10.0);
+    /// assert_eq!(from.increasing_separation(to).value(), 20.0);
+    /// assert_eq!(from.decreasing_separation(to).value(), 340.0);
+    /// ```
+    #[inline]
+    pub const fn increasing_separation(self, other: Self) -> Self {
+        Self::new(other.value() - self.value()).wrap_pos()
+    }
+
+    /// Angle travelled going from `self` to `other` in the direction of decreasing angle, in `[0, FULL_TURN)`.
+    ///
+    /// The complement of [`Self::increasing_separation`]: the two sum to `FULL_TURN` unless the angles coincide.
+    #[inline]
+    pub const fn decreasing_separation(self, other: Self) -> Self {
+        Self::new(self.value() - other.value()).wrap_pos()
+    }
 }
 
 /// Degree.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Deg", dimension = Angular, ratio = 1.0)]
+#[unit(symbol = "Deg", dimension = Angular, ratio_num = 1, siunitx = r"\degree")]
 pub struct Degree;
 /// Type alias shorthand for [`Degree`].
 pub type Deg = Degree;
@@ -270,7 +562,7 @@ pub const DEG: Degrees = Degrees::new(1.0);
 
 /// Radian.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Rad", dimension = Angular, ratio = 180.0 / core::f64::consts::PI)]
+#[unit(symbol = "Rad", dimension = Angular, ratio = 180.0 / core::f64::consts::PI, siunitx = r"\radian")]
 pub struct Radian;
 /// Type alias shorthand for [`Radian`].
 pub type Rad = Radian;
@@ -281,7 +573,7 @@ pub const RAD: Radians = Radians::new(1.0);
 
 /// Milliradian (`1/1000` radian).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mrad", dimension = Angular, ratio = (180.0 / core::f64::consts::PI) / 1_000.0)]
+#[unit(symbol = "mrad", dimension = Angular, ratio = (180.0 / core::f64::consts::PI) / 1_000.0, siunitx = r"\milli\radian")]
 pub struct Milliradian;
 /// Type alias shorthand for [`Milliradian`].
 pub type Mrad = Milliradian;
@@ -292,7 +584,7 @@ pub const MRAD: Milliradians = Milliradians::new(1.0);
 
 /// Arcminute (`1/60` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Arcm", dimension = Angular, ratio = 1.0 / 60.0)]
+#[unit(symbol = "Arcm", dimension = Angular, ratio_num = 1, ratio_den = 60, siunitx = r"\arcminute")]
 pub struct Arcminute;
 /// Alias for [`Arcminute`] (minute of angle, MOA).
 pub type MOA = Arcminute;
@@ -305,7 +597,7 @@ pub const ARCM: Arcminutes = Arcminutes::new(1.0);
 
 /// Arcsecond (`1/3600` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Arcs", dimension = Angular, ratio = 1.0 / 3600.0)]
+#[unit(symbol = "Arcs", dimension = Angular, ratio_num = 1, ratio_den = 3_600, siunitx = r"\arcsecond")]
 pub struct Arcsecond;
 /// Type alias shorthand for [`Arcsecond`].
 pub type Arcs = Arcsecond;
@@ -316,7 +608,7 @@ pub const ARCS: Arcseconds = Arcseconds::new(1.0);
 
 /// Milliarcsecond (`1/3_600_000` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Mas", dimension = Angular, ratio = 1.0 / 3_600_000.0)]
+#[unit(symbol = "Mas", dimension = Angular, ratio_num = 1, ratio_den = 3_600_000, long_name = "milliarcsecond", siunitx = r"\milli\arcsecond")]
 pub struct MilliArcsecond;
 /// Type alias shorthand for [`MilliArcsecond`].
 pub type Mas = MilliArcsecond;
@@ -327,7 +619,7 @@ pub const MAS: MilliArcseconds = MilliArcseconds::new(1.0);
 
 /// Microarcsecond (`1/3_600_000_000` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "μas", dimension = Angular, ratio = 1.0 / 3_600_000_000.0)]
+#[unit(symbol = "μas", dimension = Angular, ratio_num = 1, ratio_den = 3_600_000_000, long_name = "microarcsecond", siunitx = r"\micro\arcsecond")]
 pub struct MicroArcsecond;
 /// Type alias shorthand for [`MicroArcsecond`].
 pub type Uas = MicroArcsecond;
@@ -338,7 +630,7 @@ pub const UAS: MicroArcseconds = MicroArcseconds::new(1.0);
 
 /// Gradian (also called gon; `1/400` of a full turn = `0.9` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gon", dimension = Angular, ratio = 0.9)]
+#[unit(symbol = "Gon", dimension = Angular, ratio_num = 9, ratio_den = 10)]
 pub struct Gradian;
 /// Type alias shorthand for [`Gradian`].
 pub type Gon = Gradian;
@@ -349,7 +641,7 @@ pub const GON: Gradians = Gradians::new(1.0);
 
 /// Turn (full revolution; `360` degrees).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Turn", dimension = Angular, ratio = 360.0)]
+#[unit(symbol = "Turn", dimension = Angular, ratio_num = 360)]
 pub struct Turn;
 /// Convenience alias for a turn quantity.
 pub type Turns = Quantity<Turn>;
@@ -358,7 +650,7 @@ pub const TURN: Turns = Turns::new(1.0);
 
 /// Hour angle hour (`15` degrees).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Hms", dimension = Angular, ratio = 15.0)]
+#[unit(symbol = "Hms", dimension = Angular, ratio_num = 15)]
 pub struct HourAngle;
 /// Type alias shorthand for [`HourAngle`].
 pub type Hms = HourAngle;
@@ -377,6 +669,7 @@ impl HourAngles {
     /// let ra = HourAngles::from_hms(5, 30, 0.0); // 5h30m == 5.5h
     /// assert_eq!(ra.value(), 5.5);
     /// ```
+    #[inline]
     pub const fn from_hms(hours: i32, minutes: u32, seconds: f64) -> Self {
         let sign = if hours < 0 { -1.0 } else { 1.0 };
         let h_abs = if hours < 0 { -hours } else { hours } as f64;
@@ -398,6 +691,7 @@ impl Degrees {
     /// let lat = Degrees::from_dms(-33, 52, 0.0); // −33°52′00″
     /// assert!(lat.value() < 0.0);
     /// ```
+    #[inline]
     pub const fn from_dms(deg: i32, min: u32, sec: f64) -> Self {
         let sign = if deg < 0 { -1.0 } else { 1.0 };
         let d_abs = if deg < 0 { -deg } else { deg } as f64;
@@ -410,6 +704,7 @@ impl Degrees {
     /// Construct from explicit sign and magnitude components.
     ///
     /// `sign` should be −1, 0, or +1 (0 treated as +1 unless all components are zero).
+    #[inline]
     pub const fn from_dms_sign(sign: i8, deg: u32, min: u32, sec: f64) -> Self {
         let s = if sign < 0 { -1.0 } else { 1.0 };
         let total = (deg as f64) + (min as f64) / 60.0 + (sec / 3600.0);
@@ -417,6 +712,155 @@ impl Degrees {
     }
 }
 
+impl HourAngles {
+    /// Construct from explicit sign and magnitude **HMS** components, so angles between `-1ʰ` and `0ʰ` keep their
+    /// sign; `sign` follows [`Degrees::from_dms_sign`].
+    ///
+    /// ```rust
+    /// use qtty_core::angular::HourAngles;
+    /// assert_eq!(HourAngles::from_hms_sign(-1, 0, 30, 0.0).value(), -0.5);
+    /// ```
+    #[inline]
+    pub const fn from_hms_sign(sign: i8, hours: u32, minutes: u32, seconds: f64) -> Self {
+        let s = if sign < 0 { -1.0 } else { 1.0 };
+        let total = (hours as f64) + (minutes as f64) / 60.0 + (seconds / 3600.0);
+        Self::new(s * total)
+    }
+}
+
+/// Splits `value` into `(sign, whole, minutes, seconds)` with minutes `< 60` and seconds in `[0, 60)`.
+///
+/// The split works on whole seconds first, so the carries are exact; the whole part saturates at `u32::MAX`.
+const fn sexagesimal_parts(value: f64) -> (i8, u32, u32, f64) {
+    let magnitude = if value < 0.0 { -value } else { value } * 3_600.0;
+    let whole = magnitude as u64;
+    let seconds = (whole % 60) as f64 + (magnitude - whole as f64);
+    let minutes = ((whole / 60) % 60) as u32;
+    let units = whole / 3_600;
+    let units = if units > u32::MAX as u64 {
+        u32::MAX
+    } else {
+        units as u32
+    };
+    (if value < 0.0 { -1 } else { 1 }, units, minutes, seconds)
+}
+
+impl<U: AngularUnit + Copy> Quantity<U> {
+    /// Splits the angle into `(sign, degrees, arcminutes, arcseconds)`, the inverse of [`Degrees::from_dms_sign`].
+    ///
+    /// `sign` is `-1` or `+1` and applies to the whole angle; arcminutes are `< 60` and arcseconds in `[0, 60)`.
+    /// No rounding is applied (see [`format_angle`](Self::format_angle) for display).
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// assert_eq!(Degrees::new(-0.5).dms_parts(), (-1, 0, 30, 0.0));
+    /// let (sign, d, m, s) = Degrees::from_dms_sign(1, 12, 34, 56.5).dms_parts();
+    /// assert_eq!((sign, d, m), (1, 12, 34));
+    /// assert!((s - 56.5).abs() < 1e-9);
+    /// ```
+    #[inline]
+    pub const fn dms_parts(self) -> (i8, u32, u32, f64) {
+        sexagesimal_parts(self.to::<Degree>().value())
+    }
+
+    /// Splits the angle into `(sign, hours, minutes, seconds)` of hour angle, the inverse of
+    /// [`HourAngles::from_hms_sign`]; conventions as in [`dms_parts`](Self::dms_parts).
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// assert_eq!(Degrees::new(97.5).hms_parts(), (1, 6, 30, 0.0));
+    /// ```
+    #[inline]
+    pub const fn hms_parts(self) -> (i8, u32, u32, f64) {
+        sexagesimal_parts(self.to::<HourAngle>().value())
+    }
+}
+
+/// Entry point for composing an angle from sexagesimal parts; see [`AngleBuilder`].
+///
+/// ```rust
+/// use qtty_core::angular::{Angle, Degrees, Radians};
+///
+/// let a: Degrees = Angle::builder().degrees(12).arcminutes(34).arcseconds(56.0).build();
+/// assert!((a.value() - 12.582_222_222).abs() < 1e-9);
+/// let south: Radians = Angle::builder().degrees(0).arcminutes(30).negative().build();
+/// assert!(south.value() < 0.0);
+/// ```
+pub enum Angle {}
+
+impl Angle {
+    /// Starts an empty (zero) angle.
+    #[inline]
+    pub const fn builder() -> AngleBuilder {
+        AngleBuilder {
+            total: Degrees::new(0.0),
+        }
+    }
+}
+
+/// Accumulates degrees, arcminutes and arcseconds (or hour-angle parts) into a single angle.
+///
+/// Parts are added with their own sign. Negative sexagesimal angles such as `-0° 30′` are written as positive parts
+/// followed by [`negative`](Self::negative), which avoids the lost sign of a `-0` degree field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[must_use = "call `build()` to obtain the quantity"]
+pub struct AngleBuilder {
+    total: Degrees,
+}
+
+impl AngleBuilder {
+    /// Adds degrees.
+    #[inline]
+    pub fn degrees(self, degrees: impl Into<f64>) -> Self {
+        self.add(Degrees::new(degrees.into()))
+    }
+
+    /// Adds arcminutes (`1/60°`).
+    #[inline]
+    pub fn arcminutes(self, arcminutes: impl Into<f64>) -> Self {
+        self.add(Quantity::<Arcminute>::new(arcminutes.into()))
+    }
+
+    /// Adds arcseconds (`1/3600°`).
+    #[inline]
+    pub fn arcseconds(self, arcseconds: impl Into<f64>) -> Self {
+        self.add(Quantity::<Arcsecond>::new(arcseconds.into()))
+    }
+
+    /// Adds hour-angle hours (`15°`).
+    #[inline]
+    pub fn hours(self, hours: impl Into<f64>) -> Self {
+        self.add(HourAngles::new(hours.into()))
+    }
+
+    /// Adds radians.
+    #[inline]
+    pub fn radians(self, radians: impl Into<f64>) -> Self {
+        self.add(Radians::new(radians.into()))
+    }
+
+    /// Negates the angle accumulated so far.
+    #[inline]
+    pub fn negative(self) -> Self {
+        Self { total: -self.total }
+    }
+
+    /// Returns the angle in the requested unit (usually inferred, e.g. `let a: Degrees = ….build()`).
+    #[inline]
+    pub fn build<U: AngularUnit>(self) -> Quantity<U> {
+        self.total.to::<U>()
+    }
+
+    #[inline]
+    fn add<U: AngularUnit>(self, part: Quantity<U>) -> Self {
+        Self {
+            total: self.total + part.to::<Degree>(),
+        }
+    }
+}
+
 // Generate all bidirectional From implementations between angular units
 crate::impl_unit_conversions!(
     Degree,
@@ -431,6 +875,238 @@ crate::impl_unit_conversions!(
     HourAngle
 );
 
+/// Removes full-turn jumps from a sequence of angles, like `numpy.unwrap`.
+///
+/// Each output differs from the previous one by the smallest signed step to the next input (see
+/// [`Quantity::signed_separation`]), so a run that crosses `0°`/`360°` continues past it instead of jumping back by a
+/// full turn. The first angle is passed through unchanged. Steps of exactly half a turn are taken as positive.
+///
+/// ```rust
+/// use qtty_core::angular::{unwrap_angles, Degrees};
+///
+/// let azimuth = [350.0, 355.0, 2.0, 8.0, 3.0].map(Degrees::new);
+/// let unwrapped: Vec<f64> = unwrap_angles(azimuth).map(|a| a.value()).collect();
+/// assert_eq!(unwrapped, [350.0, 355.0, 362.0, 368.0, 363.0]);
+/// ```
+pub fn unwrap_angles<U, I>(angles: I) -> UnwrapAngles<U, I::IntoIter>
+where
+    U: AngularUnit + Copy,
+    I: IntoIterator<Item = Quantity<U>>,
+{
+    UnwrapAngles {
+        inner: angles.into_iter(),
+        state: None,
+    }
+}
+
+/// Iterator returned by [`unwrap_angles`].
+#[derive(Clone, Debug)]
+pub struct UnwrapAngles<U: Unit, I> {
+    inner: I,
+    state: Option<UnwrappedAngle<U>>,
+}
+
+impl<U, I> Iterator for UnwrapAngles<U, I>
+where
+    U: AngularUnit + Copy,
+    I: Iterator<Item = Quantity<U>>,
+{
+    type Item = Quantity<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let raw = self.inner.next()?;
+        Some(match &mut self.state {
+            Some(state) => state.update(raw),
+            None => self.state.insert(UnwrappedAngle::new(raw)).total(),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Total rotation accumulated from a stream of wrapped angle readings, for cable-wrap tracking.
+///
+/// Each [`update`](Self::update) adds the smallest signed step from the previous reading, the same rule as
+/// [`unwrap_angles`], so readings must be taken often enough that the axis moves less than half a turn between them.
+///
+/// ```rust
+/// use qtty_core::angular::{Degree, Degrees, UnwrappedAngle};
+///
+/// let mut azimuth = UnwrappedAngle::<Degree>::new(Degrees::new(300.0));
+/// for reading in [20.0, 100.0, 190.0, 280.0, 10.0] {
+///     azimuth.update(Degrees::new(reading));
+/// }
+/// assert_eq!(azimuth.total().value(), 730.0);
+/// assert_eq!(azimuth.turns(), 2);
+/// assert_eq!(azimuth.wrapped().value(), 10.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnwrappedAngle<U: Unit> {
+    last: Quantity<U>,
+    total: Quantity<U>,
+}
+
+impl<U: AngularUnit + Copy> UnwrappedAngle<U> {
+    /// Starts accumulating at `start`, taken as the total rotation so far.
+    #[inline]
+    pub const fn new(start: Quantity<U>) -> Self {
+        Self {
+            last: start,
+            total: start,
+        }
+    }
+
+    /// Records a new reading and returns the updated total.
+    #[inline]
+    pub fn update(&mut self, reading: Quantity<U>) -> Quantity<U> {
+        self.total += reading.signed_separation(self.last);
+        self.last = reading;
+        self.total
+    }
+
+    /// Total rotation, without wrapping.
+    #[inline]
+    pub const fn total(&self) -> Quantity<U> {
+        self.total
+    }
+
+    /// Whole turns contained in [`Self::total`], rounded towards negative infinity.
+    #[inline]
+    pub fn turns(&self) -> i64 {
+        let k = (self.total - self.wrapped()).value() / U::FULL_TURN;
+        // `k` is a whole number up to rounding; round to nearest.
+        if k >= 0.0 {
+            (k + 0.5) as i64
+        } else {
+            (k - 0.5) as i64
+        }
+    }
+
+    /// [`Self::total`] wrapped into `[0, FULL_TURN)`.
+    #[inline]
+    pub const fn wrapped(&self) -> Quantity<U> {
+        self.total.wrap_pos()
+    }
+}
+
+/// Rounding step for [`Quantity::format_angle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AnglePrecision {
+    /// Whole degrees: `13°`.
+    Degree,
+    /// Whole arcminutes: `12°35′`.
+    Arcminute,
+    /// Whole arcseconds: `12°34′57″`.
+    Arcsecond,
+    /// Milliarcseconds: `12°34′56.789″`.
+    Mas,
+}
+
+impl AnglePrecision {
+    const fn steps_per_degree(self) -> u64 {
+        match self {
+            Self::Degree => 1,
+            Self::Arcminute => 60,
+            Self::Arcsecond => 3_600,
+            Self::Mas => 3_600_000,
+        }
+    }
+}
+
+impl<U: AngularUnit + Copy> Quantity<U> {
+    /// Formats the angle as sexagesimal degrees rounded to `precision`.
+    ///
+    /// The angle is rounded once, as a whole number of `precision` steps, so carries propagate through every field:
+    /// `10°59′59.9995″` at [`AnglePrecision::Mas`] prints as `11°00′00.000″`, never `10°59′60.000″`. Minutes and
+    /// seconds are zero-padded to two digits; an angle that rounds to zero has no sign. Non-finite angles, and angles
+    /// too large to count in `precision` steps (beyond about `5e12°` at [`AnglePrecision::Mas`]), print as plain
+    /// decimal degrees such as `inf°` or `1e300°`.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::{AnglePrecision, Degrees};
+    ///
+    /// let a = Degrees::from_dms(12, 34, 56.789);
+    /// assert_eq!(a.format_angle(AnglePrecision::Mas).to_string(), "12°34′56.789″");
+    /// assert_eq!(a.format_angle(AnglePrecision::Arcsecond).to_string(), "12°34′57″");
+    /// assert_eq!(a.format_angle(AnglePrecision::Arcminute).to_string(), "12°35′");
+    ///
+    /// let carry = Degrees::from_dms(-1, 59, 59.999_6);
+    /// assert_eq!(carry.format_angle(AnglePrecision::Mas).to_string(), "-2°00′00.000″");
+    /// ```
+    #[inline]
+    pub const fn format_angle(self, precision: AnglePrecision) -> FormattedAngle {
+        FormattedAngle {
+            degrees: self.to::<Degree>().value(),
+            precision,
+        }
+    }
+}
+
+/// Sexagesimal display adapter returned by [`Quantity::format_angle`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FormattedAngle {
+    degrees: f64,
+    precision: AnglePrecision,
+}
+
+impl fmt::Display for FormattedAngle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let per_degree = self.precision.steps_per_degree();
+        let rounded = math::round(self.degrees.abs() * per_degree as f64);
+        // `u64::MAX as f64` rounds up to 2^64, the first step count that no longer fits.
+        if !rounded.is_finite() || rounded >= u64::MAX as f64 {
+            return write!(f, "{}°", self.degrees);
+        }
+        let steps = rounded as u64;
+        if self.degrees.is_sign_negative() && steps != 0 {
+            f.write_str("-")?;
+        }
+        let rest = steps % per_degree;
+        write!(f, "{}°", steps / per_degree)?;
+        match self.precision {
+            AnglePrecision::Degree => Ok(()),
+            AnglePrecision::Arcminute => write!(f, "{:02}′", rest),
+            AnglePrecision::Arcsecond => write!(f, "{:02}′{:02}″", rest / 60, rest % 60),
+            AnglePrecision::Mas => write!(
+                f,
+                "{:02}′{:02}.{:03}″",
+                rest / 60_000,
+                rest / 1_000 % 60,
+                rest % 1_000
+            ),
+        }
+    }
+}
+
+/// Preferred units for [`Quantity::display_auto`] on angular quantities.
+impl AutoScale for Angular {
+    const DISPLAY_UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<MicroArcsecond>(),
+        UnitInfo::of::<MilliArcsecond>(),
+        UnitInfo::of::<Arcsecond>(),
+        UnitInfo::of::<Arcminute>(),
+        UnitInfo::of::<Degree>(),
+    ];
+}
+
+/// Registry entries for the angular units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "angle";
+    Degree,
+    Radian,
+    Milliradian,
+    Arcminute,
+    Arcsecond,
+    MilliArcsecond,
+    MicroArcsecond,
+    Gradian,
+    Turn,
+    HourAngle
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -442,6 +1118,28 @@ mod tests {
     // Angular unit constants
     // ─────────────────────────────────────────────────────────────────────────────
 
+    #[test]
+    fn sexagesimal_parts_round_trip() {
+        for value in [-181.25, -0.75, -0.0, 0.0, 1e-9, 45.508_333, 359.999_999] {
+            let (sign, d, m, s) = Degrees::new(value).dms_parts();
+            assert!(m < 60 && (0.0..60.0).contains(&s));
+            assert_relative_eq!(
+                Degrees::from_dms_sign(sign, d, m, s).value(),
+                value,
+                max_relative = 1e-12
+            );
+            let (sign, h, m, s) = Degrees::new(value).hms_parts();
+            assert_relative_eq!(
+                HourAngles::from_hms_sign(sign, h, m, s)
+                    .to::<Degree>()
+                    .value(),
+                value,
+                max_relative = 1e-12
+            );
+        }
+        assert_eq!(Degrees::new(f64::INFINITY).dms_parts().1, u32::MAX);
+    }
+
     #[test]
     fn test_full_turn() {
         assert_abs_diff_eq!(Radian::FULL_TURN, TAU, epsilon = 1e-12);
@@ -458,16 +1156,23 @@ mod tests {
 
     #[test]
     fn test_quarter_turn() {
-        assert_abs_diff_eq!(Radian::QUARTED_TURN, PI / 2.0, epsilon = 1e-12);
-        assert_eq!(Degree::QUARTED_TURN, 90.0);
-        assert_eq!(Arcsecond::QUARTED_TURN, 324_000.0);
+        assert_abs_diff_eq!(Radian::QUARTER_TURN, PI / 2.0, epsilon = 1e-12);
+        assert_eq!(Degree::QUARTER_TURN, 90.0);
+        assert_eq!(Arcsecond::QUARTER_TURN, 324_000.0);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn quarted_turn_alias() {
+        assert_eq!(Radian::QUARTED_TURN, Radian::QUARTER_TURN);
+        assert_eq!(Degrees::QUARTED_TURN, Degrees::QUARTER_TURN);
     }
 
     #[test]
     fn test_quantity_constants() {
         assert_eq!(Degrees::FULL_TURN.value(), 360.0);
         assert_eq!(Degrees::HALF_TURN.value(), 180.0);
-        assert_eq!(Degrees::QUARTED_TURN.value(), 90.0);
+        assert_eq!(Degrees::QUARTER_TURN.value(), 90.0);
         assert_eq!(Degrees::TAU.value(), 360.0);
     }
 
@@ -657,6 +1362,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrap_pos_tiny_negative_stays_below_full_turn() {
+        // `-1e-17 + 360.0` rounds to exactly `360.0`; the result must still be in `[0, FULL_TURN)`.
+        assert_eq!(Degrees::new(-1e-17).wrap_pos().value(), 0.0);
+        assert_eq!(Radians::new(-1e-17).wrap_pos().value(), 0.0);
+        assert_eq!(Turns::new(-1e-17).wrap_pos().value(), 0.0);
+        const WRAPPED: Degrees = Degrees::new(-1e-17).wrap_pos();
+        assert!(WRAPPED.value() < Degree::FULL_TURN);
+    }
+
     #[test]
     fn normalize_is_wrap_pos() {
         let angle = Degrees::new(450.0);
@@ -869,6 +1584,29 @@ mod tests {
         assert_abs_diff_eq!(deg.value(), 90.0, epsilon = 1e-12);
     }
 
+    #[test]
+    fn angle_builder_matches_from_dms() {
+        let built: Degrees = Angle::builder()
+            .degrees(33)
+            .arcminutes(52)
+            .arcseconds(4.5)
+            .negative()
+            .build();
+        assert_abs_diff_eq!(
+            built.value(),
+            Degrees::from_dms(-33, 52, 4.5).value(),
+            epsilon = 1e-12
+        );
+
+        let half: Degrees = Angle::builder().arcminutes(30).negative().build();
+        assert_abs_diff_eq!(half.value(), -0.5, epsilon = 1e-12);
+
+        let ra: HourAngles = Angle::builder().hours(5).degrees(7.5).build();
+        assert_abs_diff_eq!(ra.value(), 5.5, epsilon = 1e-12);
+        let rad: Radians = Angle::builder().radians(PI).build();
+        assert_abs_diff_eq!(rad.value(), PI, epsilon = 1e-12);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Display formatting
     // ─────────────────────────────────────────────────────────────────────────────
@@ -885,6 +1623,24 @@ mod tests {
         assert_eq!(format!("{}", r), "1 Rad");
     }
 
+    #[test]
+    fn display_honors_precision_and_width() {
+        let d = Degrees::new(12.34567);
+        assert_eq!(format!("{:.2}", d), "12.35 Deg");
+        assert_eq!(format!("{:>8.1}", d), "    12.3 Deg");
+        assert_eq!(format!("{:+}", d), "+12.34567 Deg");
+    }
+
+    #[test]
+    fn display_alternate_uses_long_name() {
+        assert_eq!(format!("{:#}", Degrees::new(45.5)), "45.5 degree");
+        assert_eq!(
+            format!("{:#.1}", MilliArcseconds::new(2.26)),
+            "2.3 milliarcsecond"
+        );
+        assert_eq!(HourAngle::LONG_NAME, "hour angle");
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Unit constants
     // ─────────────────────────────────────────────────────────────────────────────
@@ -922,6 +1678,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrap_mode_matches_helpers() {
+        let modes = [
+            WrapMode::Positive,
+            WrapMode::SignedUpperInc,
+            WrapMode::SignedLowerInc,
+            WrapMode::QuarterFold,
+        ];
+        for v in [-540.0, -180.0, -90.0, 0.0, 90.0, 180.0, 200.0, 360.0, 725.5] {
+            let d = Degrees::new(v);
+            let expected = [
+                d.wrap_pos(),
+                d.wrap_signed(),
+                d.wrap_signed_lo(),
+                d.wrap_quarter_fold(),
+            ];
+            for (mode, want) in modes.iter().zip(expected) {
+                let got = d.wrap(*mode);
+                assert_eq!(got, want, "{v} {mode:?}");
+                assert!(got.is_wrapped(*mode), "{v} {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn is_wrapped_boundaries() {
+        let half = Degrees::new(180.0);
+        assert!(half.is_wrapped(WrapMode::SignedUpperInc));
+        assert!(!half.is_wrapped(WrapMode::SignedLowerInc));
+        assert!((-half).is_wrapped(WrapMode::SignedLowerInc));
+        assert!(!(-half).is_wrapped(WrapMode::SignedUpperInc));
+        assert!(!Degrees::new(360.0).is_wrapped(WrapMode::Positive));
+        assert!(Degrees::new(-90.0).is_wrapped(WrapMode::QuarterFold));
+        assert!(!Degrees::NAN.is_wrapped(WrapMode::Positive));
+    }
+
+    #[test]
+    fn directed_separations() {
+        let a = Degrees::new(30.0);
+        let b = Degrees::new(300.0);
+        assert_eq!(a.increasing_separation(b).value(), 270.0);
+        assert_eq!(a.decreasing_separation(b).value(), 90.0);
+        assert_eq!(a.increasing_separation(a).value(), 0.0);
+        assert_eq!(
+            a.decreasing_separation(a + Degrees::new(720.0)).value(),
+            0.0
+        );
+
+        // A rounding-sized negative difference must not come back as a full turn.
+        let next = f64::from_bits(0.1_f64.to_bits() + 1);
+        assert_eq!(rem_euclid(0.1 - next, TAU), 0.0);
+        let tiny = Radians::new(next).increasing_separation(Radians::new(0.1));
+        assert!(tiny.value() < TAU);
+        assert!(tiny.is_wrapped(WrapMode::Positive));
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // High-precision reduction
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn exact_turns() {
+        assert_eq!(exact_turn::<Degree>(), Some(360.0));
+        assert_eq!(exact_turn::<MicroArcsecond>(), Some(1_296_000_000_000.0));
+        assert_eq!(exact_turn::<HourAngle>(), Some(24.0));
+        assert_eq!(exact_turn::<Gradian>(), Some(400.0));
+        assert_eq!(exact_turn::<Radian>(), None);
+        assert_eq!(exact_turn::<Milliradian>(), None);
+    }
+
+    #[test]
+    fn wrap_pos_precise_large_radians() {
+        // Reference from a 3000-bit evaluation; `%` with the rounded τ is off by ~0.04 rad here.
+        let r = Radians::new(1e15).wrap_pos_precise().value();
+        assert_abs_diff_eq!(r, 2.109_698_117_070_112_6, epsilon = 1e-15);
+        assert!((Radians::new(1e15).wrap_pos().value() - r).abs() > 1e-2);
+        let r = Radians::new(-1e15).wrap_signed_precise().value();
+        assert_abs_diff_eq!(r, 4.173_487_190_109_474 - TAU, epsilon = 1e-15);
+        // Milliradians carry the rounding of their own radian scale (~1e-17 relative), which dominates here.
+        let r = Milliradians::new(1e9).wrap_pos_precise().value();
+        assert_abs_diff_eq!(r, 5_925.621_140_093_852, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn wrap_pos_precise_whole_turn_units() {
+        assert_eq!(Degrees::new(1e15).wrap_pos_precise().value(), 280.0);
+        assert_eq!(Degrees::new(-1e15).wrap_signed_precise().value(), 80.0);
+        assert_eq!(Degrees::new(360.0).wrap_pos_precise().value(), 0.0);
+        assert_eq!(
+            HourAngles::new(-25.0).wrap_pos_precise().value(),
+            HourAngles::new(-25.0).wrap_pos().value()
+        );
+        assert!(Degrees::NAN.wrap_pos_precise().value().is_nan());
+        assert!(Radians::new(f64::INFINITY)
+            .wrap_pos_precise()
+            .value()
+            .is_nan());
+    }
+
+    #[test]
+    fn mul_wrap_pos_keeps_product_bits() {
+        // 0.1 (as f64) × 1e17 = 1e16 + 0.555…; rounding the product first would give exactly 280°.
+        let r = Degrees::new(0.1).mul_wrap_pos(1e17).value();
+        assert_abs_diff_eq!(r, 280.555_111_512_312_6, epsilon = 1e-12);
+        let r = Radians::new(8_399.684_731_7).mul_wrap_pos(1e7).value();
+        assert_abs_diff_eq!(r, 3.994_567_861_187_473, epsilon = 1e-14);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // New unit conversions and tests
     // ─────────────────────────────────────────────────────────────────────────────
@@ -1153,11 +2017,148 @@ mod tests {
         );
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Unwrapping
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn unwrap_angles_follows_both_directions() {
+        let raw = [10.0, 350.0, 340.0, 5.0, 100.0, 190.0, 280.0, 10.0].map(Degrees::new);
+        let out: Vec<f64> = unwrap_angles(raw).map(|a| a.value()).collect();
+        assert_eq!(out, [10.0, -10.0, -20.0, 5.0, 100.0, 190.0, 280.0, 370.0]);
+    }
+
+    #[test]
+    fn unwrap_angles_radians_and_edge_cases() {
+        assert_eq!(unwrap_angles(core::iter::empty::<Degrees>()).count(), 0);
+
+        let raw = [3.0, -3.0, 3.1].map(Radians::new);
+        let out: Vec<Radians> = unwrap_angles(raw).collect();
+        assert_abs_diff_eq!(out[1].value(), TAU - 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(out[2].value(), 3.1, epsilon = 1e-12);
+        assert_eq!(unwrap_angles(raw).size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn unwrapped_angle_counts_turns_both_ways() {
+        let mut a = UnwrappedAngle::<Degree>::new(Degrees::new(10.0));
+        for r in [300.0, 200.0, 100.0, 0.0, 260.0, 170.0] {
+            a.update(Degrees::new(r));
+        }
+        assert_abs_diff_eq!(a.total().value(), -550.0, epsilon = 1e-12);
+        assert_eq!(a.turns(), -2);
+        assert_abs_diff_eq!(a.wrapped().value(), 170.0, epsilon = 1e-12);
+
+        let fresh = UnwrappedAngle::new(Radians::new(0.5));
+        assert_eq!(fresh.turns(), 0);
+        assert_eq!(fresh.total(), Radians::new(0.5));
+    }
+
+    #[test]
+    fn small_angle_error_bounds() {
+        for (angle, bound) in [
+            (Arcseconds::new(1.0), 4e-12),
+            (Arcseconds::new(60.0), 1.5e-8),
+            (Arcseconds::new(0.000_5), 1e-16),
+        ] {
+            assert_eq!(angle.to_radians_small(), angle.to::<Radian>().value());
+            assert!((angle.sin_small() / angle.sin() - 1.0).abs() <= bound);
+            assert!((angle.tan_small() / angle.tan() - 1.0).abs() <= 2.0 * bound);
+        }
+    }
+
+    #[test]
+    fn haversine() {
+        assert_abs_diff_eq!(Degrees::new(60.0).hav(), 0.25, epsilon = 1e-15);
+        assert_abs_diff_eq!(Degrees::new(180.0).hav(), 1.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(Degrees::ahav(0.25).value(), 60.0, epsilon = 1e-12);
+        // Small angles keep their relative precision.
+        let tiny = Arcseconds::new(1e-3);
+        let r = tiny.to::<Radian>().value();
+        assert_relative_eq!(tiny.hav(), r * r / 4.0, max_relative = 1e-12);
+        assert_relative_eq!(
+            Arcseconds::ahav(tiny.hav()).value(),
+            1e-3,
+            max_relative = 1e-12
+        );
+        // Out-of-range inputs are clamped.
+        assert_eq!(Degrees::ahav(1.0 + 1e-15).value(), 180.0);
+        assert_eq!(Degrees::ahav(-1e-18).value(), 0.0);
+    }
+
+    #[test]
+    fn format_angle_rounding_and_carry() {
+        let fmt = |d: f64, p| Degrees::new(d).format_angle(p).to_string();
+        assert_eq!(fmt(12.5, AnglePrecision::Degree), "13°");
+        assert_eq!(fmt(0.999_99, AnglePrecision::Arcminute), "1°00′");
+        // 59.9995″ carries into the next degree; 59.9994″ does not.
+        let up = 10.0 + (59.0 * 60.0 + 59.999_5) / 3_600.0;
+        assert_eq!(fmt(up, AnglePrecision::Mas), "11°00′00.000″");
+        assert_eq!(fmt(up, AnglePrecision::Arcsecond), "11°00′00″");
+        let down = 10.0 + (59.0 * 60.0 + 59.999_4) / 3_600.0;
+        assert_eq!(fmt(down, AnglePrecision::Mas), "10°59′59.999″");
+        assert_eq!(fmt(-1e-9, AnglePrecision::Mas), "0°00′00.000″");
+        assert_eq!(fmt(-0.5, AnglePrecision::Arcsecond), "-0°30′00″");
+        assert_eq!(fmt(f64::NAN, AnglePrecision::Mas), "NaN°");
+        assert_eq!(fmt(f64::NEG_INFINITY, AnglePrecision::Degree), "-inf°");
+        // Step counts past `u64` print in decimal degrees instead of saturating.
+        assert_eq!(fmt(1e300, AnglePrecision::Degree), format!("{}°", 1e300));
+        assert_eq!(fmt(-1e13, AnglePrecision::Mas), "-10000000000000°");
+        assert_eq!(
+            fmt(1e13, AnglePrecision::Arcsecond),
+            "10000000000000°00′00″"
+        );
+        // Other units are expressed in degrees first.
+        let hours = HourAngles::new(1.5).format_angle(AnglePrecision::Arcminute);
+        assert_eq!(hours.to_string(), "22°30′");
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Const evaluation
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn wrapping_is_const() {
+        const WRAPPED: Degrees = Degrees::new(370.0).wrap_signed();
+        const POS: Radians = Radians::new(-1.0).wrap_pos();
+        const SEP: Degrees = Degrees::new(350.0).abs_separation(Degrees::new(10.0));
+        assert_eq!(WRAPPED.value(), 10.0);
+        assert_abs_diff_eq!(POS.value(), TAU - 1.0, epsilon = 1e-15);
+        assert_eq!(SEP.value(), 20.0);
+    }
+
+    #[test]
+    fn sin_cos_const_known_values() {
+        const S30: f64 = Degrees::new(30.0).sin_const();
+        const C60: f64 = Degrees::new(60.0).cos_const();
+        assert_abs_diff_eq!(S30, 0.5, epsilon = 1e-15);
+        assert_abs_diff_eq!(C60, 0.5, epsilon = 1e-15);
+        assert_abs_diff_eq!(Degrees::new(-90.0).sin_const(), -1.0, epsilon = 1e-15);
+        assert_abs_diff_eq!(Degrees::new(180.0).cos_const(), -1.0, epsilon = 1e-15);
+        assert!(Degrees::NAN.sin_const().is_nan());
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Property-based tests
     // ─────────────────────────────────────────────────────────────────────────────
 
     proptest! {
+        #[test]
+        fn prop_sin_cos_const_match_runtime(a in -1e4..1e4f64) {
+            // Compare against the runtime functions on the same reduced angle; the unreduced runtime path loses
+            // precision in the degree→radian conversion for large inputs.
+            let d = Degrees::new(a);
+            let r = d.wrap_signed();
+            prop_assert!((d.sin_const() - r.sin()).abs() < 1e-15);
+            prop_assert!((d.cos_const() - r.cos()).abs() < 1e-15);
+            prop_assert!((d.sin_const() - d.sin()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn prop_rem_euclid_matches_std(x in -1e9..1e9f64, m in 1e-3..1e3f64) {
+            prop_assert_eq!(rem_euclid(x, m), x.rem_euclid(m));
+        }
+
         #[test]
         fn prop_wrap_pos_range(angle in -1e6..1e6f64) {
             let wrapped = Degrees::new(angle).wrap_pos();
@@ -1165,6 +2166,19 @@ mod tests {
             prop_assert!(wrapped.value() < 360.0);
         }
 
+        #[test]
+        fn prop_wrap_pos_precise_matches_for_moderate_angles(angle in -1e4..1e4f64) {
+            let precise = Radians::new(angle).wrap_pos_precise().value();
+            prop_assert!((0.0..TAU).contains(&precise));
+            let naive = Radians::new(angle).wrap_pos().value();
+            let diff = (precise - naive).abs();
+            prop_assert!(diff < 1e-12 || (TAU - diff) < 1e-12);
+            prop_assert_eq!(
+                Degrees::new(angle).wrap_pos_precise().value(),
+                Degrees::new(angle).wrap_pos().value()
+            );
+        }
+
         #[test]
         fn prop_wrap_signed_range(angle in -1e6..1e6f64) {
             let wrapped = Degrees::new(angle).wrap_signed();
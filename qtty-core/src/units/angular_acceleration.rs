@@ -0,0 +1,111 @@
+//! Angular acceleration unit aliases (`Angular / Time²`).
+//!
+//! Angular accelerations are nested [`Per`] types, a [`Frequency`] divided by a time, so the generic
+//! `Quantity<Per<N, D>>` arithmetic already covers the slew-profile relations: dividing a rate by a time gives an
+//! acceleration, and multiplying an acceleration by a time in its own unit gives a rate back.
+//!
+//! ```rust
+//! use qtty_core::angular_acceleration::DegreesPerSecondSquared;
+//! use qtty_core::frequency::Frequency;
+//! use qtty_core::angular::Degree;
+//! use qtty_core::time::{Second, Seconds};
+//!
+//! // A mount that reaches 2°/s in 4 s accelerates at 0.5°/s².
+//! let accel: DegreesPerSecondSquared = Frequency::<Degree, Second>::new(2.0) / Seconds::new(4.0);
+//! assert_eq!(accel.value(), 0.5);
+//! let rate: Frequency<Degree, Second> = accel * Seconds::new(4.0);
+//! assert_eq!(rate.value(), 2.0);
+//! assert_eq!(format!("{accel}"), "0.5 Deg/s^2");
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::units::angular::{AngularUnit, Degree, Radian};
+use crate::units::frequency::{Frequency, FrequencyDim};
+use crate::units::time::{Second, Time, TimeUnit};
+use crate::{DivDim, Per, Quantity, Unit};
+
+/// Dimension alias for angular acceleration (`Angular / Time / Time`).
+pub type AngularAccelerationDim = DivDim<FrequencyDim, Time>;
+
+/// Marker trait for any unit with angular acceleration dimension.
+pub trait AngularAccelerationUnit: Unit<Dim = AngularAccelerationDim> {}
+impl<T: Unit<Dim = AngularAccelerationDim>> AngularAccelerationUnit for T {}
+
+/// An angular acceleration parameterized by angular and time units (`A / T / T`).
+pub type AngularAcceleration<A, T> = Quantity<Per<Per<A, T>, T>>;
+
+/// Degree per second squared (`°/s²`).
+pub type DegreePerSecondSquared = Per<Per<Degree, Second>, Second>;
+/// A quantity measured in degrees per second squared.
+pub type DegreesPerSecondSquared = Quantity<DegreePerSecondSquared>;
+
+/// Radian per second squared (`rad/s²`), the SI unit of angular acceleration.
+pub type RadianPerSecondSquared = Per<Per<Radian, Second>, Second>;
+/// A quantity measured in radians per second squared.
+pub type RadiansPerSecondSquared = Quantity<RadianPerSecondSquared>;
+
+impl<A: AngularUnit, T: TimeUnit> AngularAcceleration<A, T> {
+    /// Rate reached after accelerating from rest for `dt`, `ω = α·t`; `dt` may be in any time unit.
+    #[inline]
+    pub const fn rate_after<U: TimeUnit>(self, dt: Quantity<U>) -> Frequency<A, T> {
+        Quantity::new(self.value() * dt.to::<T>().value())
+    }
+
+    /// Angle covered while accelerating from rest for `dt`, `θ = α·t²/2`; `dt` may be in any time unit.
+    ///
+    /// ```rust
+    /// use qtty_core::angular_acceleration::DegreesPerSecondSquared;
+    /// use qtty_core::time::Milliseconds;
+    ///
+    /// let ramp = DegreesPerSecondSquared::new(2.0).angle_after(Milliseconds::new(1_500.0));
+    /// assert!((ramp.value() - 2.25).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub const fn angle_after<U: TimeUnit>(self, dt: Quantity<U>) -> Quantity<A> {
+        let t = dt.to::<T>().value();
+        Quantity::new(0.5 * self.value() * t * t)
+    }
+}
+
+/// Registry entries for the angular acceleration units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "angular acceleration";
+    RadianPerSecondSquared = "Rad/s^2",
+    DegreePerSecondSquared = "Deg/s^2"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::angular::Degrees;
+    use crate::units::time::{Minute, Seconds};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn degrees_to_radians() {
+        let a = DegreesPerSecondSquared::new(180.0).to::<RadianPerSecondSquared>();
+        assert_relative_eq!(a.value(), core::f64::consts::PI, max_relative = 1e-15);
+    }
+
+    #[test]
+    fn time_unit_enters_squared() {
+        let per_min2 = DegreesPerSecondSquared::new(1.0).to::<Per<Per<Degree, Minute>, Minute>>();
+        assert_eq!(per_min2.value(), 3_600.0);
+    }
+
+    #[test]
+    fn slew_profile() {
+        let accel = DegreesPerSecondSquared::new(0.5);
+        assert_eq!(accel.rate_after(Seconds::new(4.0)).value(), 2.0);
+        assert_eq!(accel.angle_after(Seconds::new(4.0)), Degrees::new(4.0));
+        let rate: Frequency<Degree, Second> = Seconds::new(4.0) * accel;
+        assert_eq!(rate.value(), 2.0);
+    }
+
+    #[test]
+    fn display() {
+        let a = RadiansPerSecondSquared::new(0.25);
+        assert_eq!(format!("{a}"), "0.25 Rad/s^2");
+        assert_eq!(format!("{a:#}"), "0.25 radian per second per second");
+    }
+}
@@ -0,0 +1,134 @@
+//! Area units (`Length · Length`).
+//!
+//! Areas are products of two length units ([`Prod`]), so any pair of predefined lengths already forms an area unit and
+//! converts to every other one through the length ratios. The canonical scaling unit is the square metre
+//! (`SquareMeter::RATIO == 1.0`). The hectare is provided as a named unit.
+//!
+//! ```rust
+//! use qtty_core::area::{Hectare, SquareMeter};
+//! use qtty_core::length::Meters;
+//!
+//! let field = Meters::new(250.0).squared();
+//! assert_eq!(field.value(), 62_500.0);
+//! assert_eq!(field.to::<Hectare>().value(), 6.25);
+//! assert_eq!(format!("{}", field.to::<SquareMeter>()), "62500 m^2");
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::units::length::{Kilometer, Length, Meter};
+use crate::{MulDim, Prod, Quantity, Unit};
+use qtty_derive::Unit;
+
+/// Dimension alias for areas (`Length · Length`).
+pub type Area = MulDim<Length, Length>;
+
+/// Marker trait for any unit whose dimension is [`Area`].
+pub trait AreaUnit: Unit<Dim = Area> {}
+impl<T: Unit<Dim = Area>> AreaUnit for T {}
+
+/// Square metre (`m^2`).
+pub type SquareMeter = Prod<Meter, Meter>;
+/// A quantity measured in square metres.
+pub type SquareMeters = Quantity<SquareMeter>;
+
+/// Square kilometre (`Km^2`, `1e6 m^2`).
+pub type SquareKilometer = Prod<Kilometer, Kilometer>;
+/// A quantity measured in square kilometres.
+pub type SquareKilometers = Quantity<SquareKilometer>;
+
+/// Hectare (`1e4 m^2`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "ha", dimension = Area, ratio = 1e4, siunitx = r"\hectare")]
+pub struct Hectare;
+/// A quantity measured in hectares.
+pub type Hectares = Quantity<Hectare>;
+/// One hectare.
+pub const HA: Hectares = Hectares::new(1.0);
+
+impl<U: Unit> Quantity<U> {
+    /// Squares a quantity, producing `U · U` (an area for lengths, a squared time for times, …).
+    ///
+    /// ```rust
+    /// use qtty_core::area::SquareKilometers;
+    /// use qtty_core::length::Kilometers;
+    ///
+    /// let a: SquareKilometers = Kilometers::new(3.0).squared();
+    /// assert_eq!(a.value(), 9.0);
+    /// ```
+    #[inline]
+    pub const fn squared(self) -> Quantity<Prod<U, U>> {
+        Quantity::new(self.value() * self.value())
+    }
+}
+
+/// Registry entries for the area units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "area";
+    SquareMeter = "m^2",
+    SquareKilometer = "Km^2",
+    Hectare
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::{Kilometers, Meters};
+    use approx::assert_relative_eq;
+    use proptest::prelude::*;
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Conversions
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn square_km_to_hectares() {
+        let a = SquareKilometers::new(1.0);
+        assert_relative_eq!(a.to::<Hectare>().value(), 100.0);
+        assert_relative_eq!(a.to::<SquareMeter>().value(), 1e6);
+    }
+
+    #[test]
+    fn mixed_length_products_convert() {
+        let strip: Quantity<Prod<Kilometer, Meter>> = Quantity::new(2.0);
+        assert_relative_eq!(strip.to::<SquareMeter>().value(), 2_000.0);
+    }
+
+    #[test]
+    fn squared_keeps_unit() {
+        let a = Kilometers::new(2.0).squared();
+        assert_eq!(a.value(), 4.0);
+        assert_relative_eq!(a.to::<SquareMeter>().value(), 4e6);
+    }
+
+    #[test]
+    fn area_over_length_simplifies() {
+        use crate::Simplify;
+        let side: Meters = (SquareMeters::new(20.0) / Meters::new(4.0)).simplify();
+        assert_eq!(side.value(), 5.0);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Formatting
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn display_uses_exponents() {
+        assert_eq!(format!("{}", SquareMeters::new(2.0)), "2 m^2");
+        assert_eq!(format!("{}", SquareKilometers::new(1.5)), "1.5 Km^2");
+        assert_eq!(format!("{:#}", SquareMeters::new(1.0)), "1 meter-meter");
+        assert_eq!(format!("{}", Hectares::new(3.0)), "3 ha");
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Property-based tests
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    proptest! {
+        #[test]
+        fn prop_squared_matches_meters(x in -1e6..1e6f64) {
+            let km = Kilometers::new(x).squared().to::<SquareMeter>().value();
+            let m = Kilometers::new(x).to::<Meter>().squared().value();
+            prop_assert!((km - m).abs() <= 1e-12 * m.abs());
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Density units (`Mass / Volume`).
+//!
+//! Dividing any mass by any volume already yields a [`Density`] quantity (`Quantity<Per<M, V>>`), which converts to
+//! the aliases below through the mass and length ratios. The canonical scaling unit is the gram per cubic metre, since
+//! the gram is the canonical mass unit.
+//!
+//! ```rust
+//! use qtty_core::density::{mean_density, GramPerCubicCentimeter};
+//! use qtty_core::length::Kilometers;
+//! use qtty_core::mass::Kilograms;
+//!
+//! // Earth: 5.972e24 kg, mean radius 6371 km → ≈ 5.51 g/cm³.
+//! let rho = mean_density(Kilograms::new(5.972e24), Kilometers::new(6_371.0));
+//! assert!((rho.to::<GramPerCubicCentimeter>().value() - 5.51).abs() < 0.01);
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::units::length::nominal::SolarRadius;
+use crate::units::length::{Centimeter, LengthUnit, Meter};
+use crate::units::mass::{Gram, Kilogram, Mass, MassUnit, SolarMass};
+use crate::units::volume::{CubicMeter, Volume};
+use crate::{DivDim, Per, Prod, Quantity, Unit};
+use core::f64::consts::PI;
+use qtty_derive::Unit;
+
+/// Dimension alias for density (`Mass / Volume`).
+pub type Density = DivDim<Mass, Volume>;
+
+/// Marker trait for any unit whose dimension is [`Density`].
+pub trait DensityUnit: Unit<Dim = Density> {}
+impl<T: Unit<Dim = Density>> DensityUnit for T {}
+
+/// Kilogram per cubic metre (`kg/m^3`), the SI unit of density.
+pub type KilogramPerCubicMeter = Per<Kilogram, CubicMeter>;
+/// A quantity measured in kilograms per cubic metre.
+pub type KilogramsPerCubicMeter = Quantity<KilogramPerCubicMeter>;
+
+/// Gram per cubic centimetre (`g/cm^3`, `1000 kg/m^3`).
+pub type GramPerCubicCentimeter = Per<Gram, Prod<Prod<Centimeter, Centimeter>, Centimeter>>;
+/// A quantity measured in grams per cubic centimetre.
+pub type GramsPerCubicCentimeter = Quantity<GramPerCubicCentimeter>;
+
+/// Mean solar density: one nominal solar mass spread over a sphere of one nominal solar radius (≈ 1.41 g/cm³).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(
+    symbol = "ρ☉",
+    dimension = Density,
+    ratio = SolarMass::RATIO / (4.0 / 3.0 * PI * SolarRadius::RATIO * SolarRadius::RATIO * SolarRadius::RATIO),
+    long_name = "solar density"
+)]
+pub struct SolarDensity;
+/// A quantity measured in mean solar densities.
+pub type SolarDensities = Quantity<SolarDensity>;
+
+/// Mean density of a uniform sphere of the given mass and radius, `ρ = M / (4/3 π r³)`.
+#[inline]
+pub fn mean_density<M: MassUnit, L: LengthUnit>(
+    mass: Quantity<M>,
+    radius: Quantity<L>,
+) -> KilogramsPerCubicMeter {
+    let r = radius.to::<Meter>().value();
+    Quantity::new(mass.to::<Kilogram>().value() / (4.0 / 3.0 * PI * r * r * r))
+}
+
+/// Registry entries for the density units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "density";
+    KilogramPerCubicMeter = "kg/m^3",
+    GramPerCubicCentimeter = "g/cm^3",
+    SolarDensity
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::nominal::SolarRadiuses;
+    use crate::units::length::{Kilometers, Meters};
+    use crate::units::mass::{Kilograms, SolarMasses};
+    use crate::units::volume::{CubicMeters, Liters};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn g_per_cm3_to_kg_per_m3() {
+        let water = GramsPerCubicCentimeter::new(1.0);
+        assert_relative_eq!(
+            water.to::<KilogramPerCubicMeter>().value(),
+            1_000.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn mass_over_volume_is_density() {
+        let rho = Kilograms::new(2.0) / Liters::new(1.0);
+        assert_relative_eq!(
+            rho.to::<KilogramPerCubicMeter>().value(),
+            2_000.0,
+            max_relative = 1e-12
+        );
+        let rho = Kilograms::new(10.0) / Meters::new(2.0).cubed();
+        assert_relative_eq!(
+            rho.to::<GramPerCubicCentimeter>().value(),
+            1.25e-3,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn density_times_volume_is_mass() {
+        let m: Kilograms = KilogramsPerCubicMeter::new(7_850.0) * CubicMeters::new(2.0);
+        assert_relative_eq!(m.value(), 15_700.0);
+    }
+
+    #[test]
+    fn solar_density() {
+        assert_relative_eq!(
+            SolarDensities::new(1.0)
+                .to::<GramPerCubicCentimeter>()
+                .value(),
+            1.41,
+            max_relative = 1e-2
+        );
+        let sun = mean_density(SolarMasses::new(1.0), SolarRadiuses::new(1.0));
+        assert_relative_eq!(sun.to::<SolarDensity>().value(), 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn jupiter_mean_density() {
+        // 1.898e27 kg, mean radius 69 911 km → 1.326 g/cm³.
+        let rho = mean_density(Kilograms::new(1.898e27), Kilometers::new(69_911.0));
+        assert_relative_eq!(
+            rho.to::<GramPerCubicCentimeter>().value(),
+            1.326,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", KilogramsPerCubicMeter::new(1.0)), "1 kg/m^3");
+        assert_eq!(
+            format!("{}", GramsPerCubicCentimeter::new(5.5)),
+            "5.5 g/cm^3"
+        );
+    }
+}
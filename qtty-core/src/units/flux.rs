@@ -0,0 +1,237 @@
+//! Flux (`Power / Area`) and spectral flux density units for photometry.
+//!
+//! - [`Flux`] is the bolometric energy flux, with [`WattPerSquareMeter`] as its canonical scaling unit and the CGS
+//!   [`ErgPerSecondPerSquareCentimeter`].
+//! - The IAU nominal solar irradiance lives in [`nominal`].
+//! - [`SpectralFluxDensity`] is flux per unit frequency (`W·m⁻²·Hz⁻¹`), with the radio-astronomy [`Jansky`]
+//!   (`1 Jy = 1e-26 W·m⁻²·Hz⁻¹`). Since `Hz⁻¹ = s`, the dimension is modelled as `Flux · Time`.
+//!
+//! The inverse-square law relating luminosity, distance and flux is provided by [`flux_at`],
+//! [`luminosity_from_flux`] and [`luminosity_distance`]:
+//!
+//! ```rust
+//! use qtty_core::flux::flux_at;
+//! use qtty_core::length::Parsecs;
+//! use qtty_core::power::SolarLuminosities;
+//!
+//! // The Sun seen from 10 pc (the absolute-magnitude reference distance).
+//! let f = flux_at(SolarLuminosities::new(1.0), Parsecs::new(10.0));
+//! assert!((f.value() - 3.2e-10).abs() < 1e-11);
+//! ```
+
+use crate::math;
+use crate::registry::RegisteredUnit;
+use crate::units::area::{Area, SquareMeter};
+use crate::units::length::{LengthUnit, Meter, Meters};
+use crate::units::power::{Power, PowerUnit, Watt, Watts};
+use crate::units::time::Time;
+use crate::{DivDim, MulDim, Per, Quantity, Unit};
+use core::f64::consts::PI;
+use qtty_derive::Unit;
+
+/// Dimension alias for energy flux (`Power / Area`).
+pub type Flux = DivDim<Power, Area>;
+
+/// Marker trait for any unit whose dimension is [`Flux`].
+pub trait FluxUnit: Unit<Dim = Flux> {}
+impl<T: Unit<Dim = Flux>> FluxUnit for T {}
+
+/// Watt per square metre (`W/m^2`), the SI unit of flux.
+pub type WattPerSquareMeter = Per<Watt, SquareMeter>;
+/// A quantity measured in watts per square metre.
+pub type WattsPerSquareMeter = Quantity<WattPerSquareMeter>;
+
+/// Erg per second per square centimetre (`1e-3 W/m^2`), the customary CGS flux unit.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "erg/s/cm^2", dimension = Flux, ratio = 1e-3)]
+pub struct ErgPerSecondPerSquareCentimeter;
+/// A quantity measured in erg per second per square centimetre.
+pub type ErgsPerSecondPerSquareCentimeter = Quantity<ErgPerSecondPerSquareCentimeter>;
+
+/// Dimension alias for spectral flux density (`Flux / Frequency`, i.e. `Flux · Time`).
+pub type SpectralFluxDensity = MulDim<Flux, Time>;
+
+/// Marker trait for any unit whose dimension is [`SpectralFluxDensity`].
+pub trait SpectralFluxDensityUnit: Unit<Dim = SpectralFluxDensity> {}
+impl<T: Unit<Dim = SpectralFluxDensity>> SpectralFluxDensityUnit for T {}
+
+/// Watt per square metre per hertz, the SI unit of spectral flux density.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "W/m^2/Hz", dimension = SpectralFluxDensity, ratio = 1.0, long_name = "watt per square meter per hertz")]
+pub struct WattPerSquareMeterPerHertz;
+/// A quantity measured in watts per square metre per hertz.
+pub type WattsPerSquareMeterPerHertz = Quantity<WattPerSquareMeterPerHertz>;
+
+/// Jansky (`1e-26 W·m⁻²·Hz⁻¹`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "Jy", dimension = SpectralFluxDensity, ratio = 1e-26)]
+pub struct Jansky;
+/// A quantity measured in janskys.
+pub type Janskys = Quantity<Jansky>;
+/// One jansky.
+pub const JY: Janskys = Janskys::new(1.0);
+
+/// Millijansky (`1e-29 W·m⁻²·Hz⁻¹`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "mJy", dimension = SpectralFluxDensity, ratio = 1e-29)]
+pub struct MilliJansky;
+/// A quantity measured in millijanskys.
+pub type MilliJanskys = Quantity<MilliJansky>;
+
+/// Microjansky (`1e-32 W·m⁻²·Hz⁻¹`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "µJy", dimension = SpectralFluxDensity, ratio = 1e-32)]
+pub struct MicroJansky;
+/// A quantity measured in microjanskys.
+pub type MicroJanskys = Quantity<MicroJansky>;
+
+crate::impl_unit_conversions!(Jansky, MilliJansky, MicroJansky, WattPerSquareMeterPerHertz);
+
+/// IAU 2015 Resolution B3 nominal solar irradiance.
+pub mod nominal {
+    use super::*;
+
+    /// Nominal total solar irradiance `S☉ᴺ = 1361 W/m²`, the mean flux received at 1 au.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+    #[unit(symbol = "S☉", dimension = Flux, ratio = 1_361.0, long_name = "nominal solar irradiance")]
+    pub struct SolarIrradiance;
+    /// A quantity measured in nominal solar irradiances.
+    pub type SolarIrradiances = Quantity<SolarIrradiance>;
+    /// One nominal solar irradiance.
+    pub const S_SUN: SolarIrradiances = SolarIrradiances::new(1.0);
+
+    /// Registry entries for the nominal flux units (see [`crate::registry`]).
+    pub(crate) static REGISTRY: &[RegisteredUnit] =
+        crate::macros::registry_table!("flux"; SolarIrradiance);
+}
+
+/// Flux received at `distance` from an isotropic source of the given luminosity, `F = L / (4π d²)`.
+#[inline]
+pub fn flux_at<P: PowerUnit, L: LengthUnit>(
+    luminosity: Quantity<P>,
+    distance: Quantity<L>,
+) -> WattsPerSquareMeter {
+    let d = distance.to::<Meter>().value();
+    Quantity::new(luminosity.to::<Watt>().value() / (4.0 * PI * d * d))
+}
+
+/// Luminosity of an isotropic source producing `flux` at `distance`, `L = 4π d² F`; the inverse of [`flux_at`].
+///
+/// ```rust
+/// use qtty_core::flux::{luminosity_from_flux, WattsPerSquareMeter};
+/// use qtty_core::length::AstronomicalUnits;
+/// use qtty_core::power::SolarLuminosity;
+///
+/// // The solar constant, 1361 W/m² at 1 au.
+/// let l = luminosity_from_flux(WattsPerSquareMeter::new(1361.0), AstronomicalUnits::new(1.0));
+/// assert!((l.to::<SolarLuminosity>().value() - 1.0).abs() < 1e-3);
+/// ```
+#[inline]
+pub fn luminosity_from_flux<F: FluxUnit, L: LengthUnit>(
+    flux: Quantity<F>,
+    distance: Quantity<L>,
+) -> Watts {
+    let d = distance.to::<Meter>().value();
+    Watts::new(4.0 * PI * d * d * flux.to::<WattPerSquareMeter>().value())
+}
+
+/// Distance at which an isotropic source of the given luminosity produces `flux`, `d = √(L / 4πF)`.
+#[inline]
+pub fn luminosity_distance<P: PowerUnit, F: FluxUnit>(
+    luminosity: Quantity<P>,
+    flux: Quantity<F>,
+) -> Meters {
+    let l = luminosity.to::<Watt>().value();
+    Meters::new(math::sqrt(
+        l / (4.0 * PI * flux.to::<WattPerSquareMeter>().value()),
+    ))
+}
+
+/// Registry entries for the flux units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "flux";
+    WattPerSquareMeter = "W/m^2",
+    ErgPerSecondPerSquareCentimeter
+);
+
+/// Registry entries for the spectral flux density units (see [`crate::registry`]).
+pub(crate) static SPECTRAL_REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "spectral flux density";
+    WattPerSquareMeterPerHertz,
+    Jansky,
+    MilliJansky,
+    MicroJansky
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::{AstronomicalUnit, AstronomicalUnits, Parsec, Parsecs};
+    use crate::units::power::{SolarLuminosities, SolarLuminosity};
+    use approx::assert_relative_eq;
+    use proptest::prelude::*;
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Unit conversions
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn cgs_flux_to_si() {
+        let f = ErgsPerSecondPerSquareCentimeter::new(1.0);
+        assert_relative_eq!(f.to::<WattPerSquareMeter>().value(), 1e-3);
+    }
+
+    #[test]
+    fn jansky_ladder() {
+        assert_relative_eq!(Janskys::new(1.0).to::<MilliJansky>().value(), 1e3);
+        assert_relative_eq!(MilliJanskys::new(1.0).to::<MicroJansky>().value(), 1e3);
+        let si: WattsPerSquareMeterPerHertz = Janskys::new(2.0).into();
+        assert_relative_eq!(si.value(), 2e-26);
+    }
+
+    #[test]
+    fn power_over_area_is_flux() {
+        use crate::units::area::SquareMeters;
+        let f: WattsPerSquareMeter = Watts::new(10.0) / SquareMeters::new(4.0);
+        assert_relative_eq!(f.to::<ErgPerSecondPerSquareCentimeter>().value(), 2_500.0);
+        assert_eq!(format!("{}", f), "2.5 W/m^2");
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Inverse-square law
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn solar_constant() {
+        let f = flux_at(SolarLuminosities::new(1.0), AstronomicalUnits::new(1.0));
+        assert_relative_eq!(f.value(), 1_361.0, max_relative = 1e-3);
+        assert_relative_eq!(
+            f.to::<nominal::SolarIrradiance>().value(),
+            1.0,
+            max_relative = 1e-3
+        );
+        assert_eq!(nominal::S_SUN.to::<WattPerSquareMeter>().value(), 1_361.0);
+    }
+
+    #[test]
+    fn distance_from_solar_constant() {
+        let d = luminosity_distance(
+            SolarLuminosities::new(1.0),
+            WattsPerSquareMeter::new(1_361.0),
+        );
+        assert_relative_eq!(d.to::<AstronomicalUnit>().value(), 1.0, max_relative = 1e-3);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_inverse_square_roundtrip(l in 1e-6..1e12f64, d in 1e-3..1e9f64) {
+            let lum = SolarLuminosities::new(l);
+            let dist = Parsecs::new(d);
+            let f = flux_at(lum, dist);
+            let l_back = luminosity_from_flux(f, dist).to::<SolarLuminosity>().value();
+            prop_assert!((l_back - l).abs() <= 1e-12 * l);
+            let d_back = luminosity_distance(lum, f).to::<Parsec>().value();
+            prop_assert!((d_back - d).abs() <= 1e-12 * d);
+        }
+    }
+}
@@ -12,8 +12,10 @@
 //! assert!((f_rad.value() - core::f64::consts::PI).abs() < 1e-12);
 //! ```
 
-use crate::units::angular::Angular;
-use crate::units::time::Time;
+use crate::registry::RegisteredUnit;
+use crate::units::angular::{Angular, AngularUnit, Degree, Radian};
+use crate::units::length::LengthUnit;
+use crate::units::time::{Day, Second, Time, TimeUnit};
 use crate::{DivDim, Per, Quantity, Unit};
 
 /// Dimension alias for angular frequency (`Angular / Time`).
@@ -37,11 +39,73 @@ impl<T: Unit<Dim = FrequencyDim>> FrequencyUnit for T {}
 /// ```
 pub type Frequency<N, D> = Quantity<Per<N, D>>;
 
+/// Degrees per day, the customary unit for orbital mean motion.
+pub type DegreesPerDay = Frequency<Degree, Day>;
+
+impl<A: AngularUnit, T: TimeUnit> Frequency<A, T> {
+    /// Linear speed of a point at distance `radius` from the rotation axis, `v = ω·r`.
+    ///
+    /// The rate is converted to radians first, so the result is in `radius`'s length unit per this rate's time unit.
+    /// See [`Velocity::angular_velocity`](crate::velocity::Velocity::angular_velocity) for the inverse.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degree;
+    /// use qtty_core::frequency::Frequency;
+    /// use qtty_core::length::Meters;
+    /// use qtty_core::time::Second;
+    ///
+    /// // A 2 m gear rim turning at 0.5 °/s.
+    /// let rim = Frequency::<Degree, Second>::new(0.5).tangential_velocity(Meters::new(2.0));
+    /// assert!((rim.value() - 0.5_f64.to_radians() * 2.0).abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub const fn tangential_velocity<L: LengthUnit>(
+        self,
+        radius: Quantity<L>,
+    ) -> Quantity<Per<L, T>> {
+        let omega = self.to::<Per<Radian, T>>().value();
+        Quantity::new(omega * radius.value())
+    }
+}
+
+/// Angular rate between consecutive samples taken every `dt`, as typed frequencies.
+///
+/// Each window of two samples yields the smallest signed step between them (so a jump from `359°` to `1°` reads as
+/// `+2°`, as in [`unwrap_angles`](crate::angular::unwrap_angles)) divided by `dt`. `n` samples give `n − 1` rates; the
+/// rate for `samples[i]..samples[i + 1]` is the `i`-th item.
+///
+/// ```rust
+/// use qtty_core::angular::Degrees;
+/// use qtty_core::frequency::angular_velocity;
+/// use qtty_core::time::Seconds;
+///
+/// let azimuth = [358.0, 359.5, 1.0, 2.0].map(Degrees::new);
+/// let rates: Vec<f64> = angular_velocity(&azimuth, Seconds::new(0.5)).map(|w| w.value()).collect();
+/// assert_eq!(rates, [3.0, 3.0, 2.0]);
+/// ```
+pub fn angular_velocity<A: AngularUnit + Copy, T: TimeUnit>(
+    samples: &[Quantity<A>],
+    dt: Quantity<T>,
+) -> impl ExactSizeIterator<Item = Frequency<A, T>> + '_ {
+    samples
+        .windows(2)
+        .map(move |w| w[1].signed_separation(w[0]) / dt)
+}
+
+/// Registry entries for the common angular frequency aliases (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "frequency";
+    Per<Radian, Second> = "Rad/s",
+    Per<Degree, Second> = "Deg/s",
+    Per<Degree, Day> = "Deg/d"
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::units::angular::{Degree, Degrees, MilliArcsecond, Radian};
-    use crate::units::time::{Day, Days, Year};
+    use crate::units::length::{Kilometers, Meter, Meters};
+    use crate::units::time::{Day, Days, Hour, Second, SiderealDay, Year};
     use crate::Per;
     use approx::{assert_abs_diff_eq, assert_relative_eq};
     use proptest::prelude::*;
@@ -140,6 +204,65 @@ mod tests {
         assert_abs_diff_eq!(f.value(), 360.0, epsilon = 1e-9);
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Angular ↔ linear velocity
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn earth_equatorial_rotation_speed() {
+        // 360° per sidereal day at the equator (6378.137 km) ≈ 0.4651 km/s.
+        let omega: Frequency<Degree, SiderealDay> = Frequency::new(360.0);
+        let v = omega
+            .to::<Per<Degree, Second>>()
+            .tangential_velocity(Kilometers::new(6_378.137));
+        assert_relative_eq!(v.value(), 0.465_1, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn tangential_velocity_keeps_units() {
+        // One radian per hour at 3 m is 3 m/h, whatever unit the rate was written in.
+        let omega: Frequency<Radian, Hour> = Frequency::new(1.0);
+        let v: Quantity<Per<Meter, Hour>> = omega.tangential_velocity(Meters::new(3.0));
+        assert_relative_eq!(v.value(), 3.0);
+        let v_deg = omega
+            .to::<Per<Degree, Hour>>()
+            .tangential_velocity(Meters::new(3.0));
+        assert_relative_eq!(v_deg.value(), 3.0, max_relative = 1e-15);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Sampled angular velocity
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn angular_velocity_across_wrap() {
+        let samples = [10.0, 4.0, 358.0, 352.0].map(Degrees::new);
+        let rates: Vec<Frequency<Degree, Hour>> =
+            angular_velocity(&samples, Quantity::<Hour>::new(2.0)).collect();
+        assert_eq!(rates.len(), 3);
+        for w in &rates {
+            assert_abs_diff_eq!(w.value(), -3.0, epsilon = 1e-12);
+        }
+        let per_second: Frequency<Radian, Second> = rates[0].to();
+        assert_relative_eq!(
+            per_second.value(),
+            -3.0 * PI / 180.0 / 3_600.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn angular_velocity_needs_two_samples() {
+        assert_eq!(
+            angular_velocity(&[Degrees::new(1.0)], Days::new(1.0)).len(),
+            0
+        );
+        assert_eq!(
+            angular_velocity::<Degree, Day>(&[], Days::new(1.0)).len(),
+            0
+        );
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Roundtrip conversions
     // ─────────────────────────────────────────────────────────────────────────────
@@ -177,5 +300,16 @@ mod tests {
             let f_back: Frequency<Degree, Day> = angle / t;
             prop_assert!((f_back.value() - f.value()).abs() / f.value() < 1e-12);
         }
+
+        #[test]
+        fn prop_tangential_angular_roundtrip(w in -1e3..1e3f64, r in 1e-3..1e6f64) {
+            let omega: Frequency<Degree, Second> = Frequency::new(w);
+            let radius = Kilometers::new(r);
+            let back: Frequency<Degree, Second> = omega
+                .tangential_velocity(radius)
+                .angular_velocity(radius.to::<Meter>())
+                .to();
+            prop_assert!((back.value() - w).abs() <= 1e-12 * w.abs().max(1.0));
+        }
     }
 }
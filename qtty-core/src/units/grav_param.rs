@@ -0,0 +1,137 @@
+//! Gravitational parameter units (`GM`, `Length³ / Time²`).
+//!
+//! Orbit determination works with the product `GM` rather than `G` and `M` separately, since `GM` is known to far
+//! more digits than either factor. Dividing a [`cubed`](crate::Quantity::cubed) length by a [`squared`](crate::Quantity::squared) time yields a [`GravParam`] quantity,
+//! and the aliases below cover the two systems in common use: km³/s² (planetary ephemerides) and au³/day² (the
+//! Gaussian system).
+//!
+//! ```rust
+//! use qtty_core::grav_param::{Au3PerDay2, GM_SUN};
+//!
+//! // In au³/day², GM☉ is the square of the Gaussian gravitational constant.
+//! let k2 = GM_SUN.to::<Au3PerDay2>().value();
+//! assert!((k2 - 0.017_202_098_95_f64.powi(2)).abs() < 1e-12);
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::units::length::{AstronomicalUnit, Kilometer};
+use crate::units::mass::{Kilogram, MassUnit};
+use crate::units::time::{Day, Second, Time};
+use crate::units::volume::{CubicMeter, Volume};
+use crate::{DivDim, MulDim, Per, Prod, Quantity, Unit};
+
+/// Dimension alias for a gravitational parameter (`Length³ / Time²`).
+pub type GravParam = DivDim<Volume, MulDim<Time, Time>>;
+
+/// Marker trait for any unit whose dimension is [`GravParam`].
+pub trait GravParamUnit: Unit<Dim = GravParam> {}
+impl<T: Unit<Dim = GravParam>> GravParamUnit for T {}
+
+/// Cubic metre per second squared (`m^3/s^2`), the SI unit of `GM`.
+pub type CubicMeterPerSecondSquared = Per<CubicMeter, Prod<Second, Second>>;
+/// A quantity measured in cubic metres per second squared.
+pub type CubicMetersPerSecondSquared = Quantity<CubicMeterPerSecondSquared>;
+
+/// Cubic kilometre per second squared (`km^3/s^2`, `1e9 m^3/s^2`).
+pub type CubicKilometerPerSecondSquared =
+    Per<Prod<Prod<Kilometer, Kilometer>, Kilometer>, Prod<Second, Second>>;
+/// Type alias shorthand for [`CubicKilometerPerSecondSquared`].
+pub type Km3PerS2 = CubicKilometerPerSecondSquared;
+/// A quantity measured in cubic kilometres per second squared.
+pub type CubicKilometersPerSecondSquared = Quantity<CubicKilometerPerSecondSquared>;
+
+/// Cubic astronomical unit per day squared (`au^3/d^2`), the unit of the Gaussian system.
+pub type CubicAstronomicalUnitPerDaySquared =
+    Per<Prod<Prod<AstronomicalUnit, AstronomicalUnit>, AstronomicalUnit>, Prod<Day, Day>>;
+/// Type alias shorthand for [`CubicAstronomicalUnitPerDaySquared`].
+pub type Au3PerDay2 = CubicAstronomicalUnitPerDaySquared;
+/// A quantity measured in cubic astronomical units per day squared.
+pub type CubicAstronomicalUnitsPerDaySquared = Quantity<CubicAstronomicalUnitPerDaySquared>;
+
+/// Nominal solar gravitational parameter `GM☉ᴺ` (IAU 2015 Resolution B3, exact by definition).
+pub const GM_SUN: CubicKilometersPerSecondSquared = Quantity::new(1.327_124_4e11);
+
+/// Nominal terrestrial gravitational parameter `GM⊕ᴺ` (IAU 2015 Resolution B3, exact by definition).
+pub const GM_EARTH: CubicKilometersPerSecondSquared = Quantity::new(3.986_004e5);
+
+/// Lunar gravitational parameter (JPL DE430).
+pub const GM_MOON: CubicKilometersPerSecondSquared = Quantity::new(4_902.800_066);
+
+/// Gravitational parameter of a body from its mass, `GM`, with `G = 6.674 30 × 10⁻¹¹ m³ kg⁻¹ s⁻²` (CODATA 2018).
+///
+/// `G` is only known to about five digits; prefer a published `GM` where one exists.
+#[inline]
+pub fn from_mass<M: MassUnit>(mass: Quantity<M>) -> CubicMetersPerSecondSquared {
+    const G: f64 = 6.674_30e-11;
+    Quantity::new(G * mass.to::<Kilogram>().value())
+}
+
+/// Registry entries for the gravitational parameter units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "gravitational parameter";
+    CubicMeterPerSecondSquared = "m^3/s^2",
+    CubicKilometerPerSecondSquared = "Km^3/s^2",
+    CubicAstronomicalUnitPerDaySquared = "au^3/d^2"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::{AstronomicalUnits, Kilometers};
+    use crate::units::mass::SolarMasses;
+    use crate::units::time::{Days, Seconds};
+    use approx::assert_relative_eq;
+
+    /// Gaussian gravitational constant (au^(3/2) / day).
+    const K: f64 = 0.017_202_098_95;
+
+    #[test]
+    fn sun_in_gaussian_units() {
+        assert_relative_eq!(
+            GM_SUN.to::<Au3PerDay2>().value(),
+            K * K,
+            max_relative = 1e-9
+        );
+        let back = CubicAstronomicalUnitsPerDaySquared::new(K * K).to::<Km3PerS2>();
+        assert_relative_eq!(back.value(), GM_SUN.value(), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn km3_s2_to_si() {
+        assert_relative_eq!(
+            GM_EARTH.to::<CubicMeterPerSecondSquared>().value(),
+            3.986_004e14,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn length_cubed_over_time_squared() {
+        let gm = Kilometers::new(3.0).cubed() / Seconds::new(2.0).squared();
+        assert_relative_eq!(gm.to::<Km3PerS2>().value(), 6.75, max_relative = 1e-12);
+
+        let gm = AstronomicalUnits::new(1.0).cubed() / Days::new(1.0).squared();
+        assert_relative_eq!(gm.to::<Au3PerDay2>().value(), 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn moon_to_earth_ratio() {
+        // DE430 Earth/Moon mass ratio: 81.300 569.
+        assert_relative_eq!(
+            GM_EARTH.value() / GM_MOON.value(),
+            81.300_569,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn from_solar_mass() {
+        let gm = from_mass(SolarMasses::new(1.0)).to::<Km3PerS2>();
+        assert_relative_eq!(gm.value(), GM_SUN.value(), max_relative = 1e-3);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{GM_MOON}"), "4902.800066 Km^3/s^2");
+    }
+}
@@ -34,7 +34,8 @@
 //! assert_eq!(km.value(), 149_597_870.7);
 //! ```
 
-use crate::{Dimension, Quantity, Unit};
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
 use core::f64::consts::PI;
 use qtty_derive::Unit;
 
@@ -52,7 +53,7 @@ impl<T: Unit<Dim = Length>> LengthUnit for T {}
 
 /// Metre (SI base unit).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "m", dimension = Length, ratio = 1.0)]
+#[unit(symbol = "m", dimension = Length, ratio_num = 1, siunitx = r"\metre")]
 pub struct Meter;
 /// A quantity measured in metres.
 pub type Meters = Quantity<Meter>;
@@ -61,7 +62,7 @@ pub const M: Meters = Meters::new(1.0);
 
 /// Kilometre (`1000 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Km", dimension = Length, ratio = 1_000.0)]
+#[unit(symbol = "Km", dimension = Length, ratio_num = 1_000, siunitx = r"\kilo\metre")]
 pub struct Kilometer;
 /// Type alias shorthand for [`Kilometer`].
 pub type Km = Kilometer;
@@ -72,7 +73,7 @@ pub const KM: Kilometers = Kilometers::new(1.0);
 
 /// Centimetre (`1e-2 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cm", dimension = Length, ratio = 1e-2)]
+#[unit(symbol = "cm", dimension = Length, ratio_num = 1, ratio_den = 100, siunitx = r"\centi\metre")]
 pub struct Centimeter;
 /// Type alias shorthand for [`Centimeter`].
 pub type Cm = Centimeter;
@@ -83,7 +84,7 @@ pub const CM: Centimeters = Centimeters::new(1.0);
 
 /// Millimetre (`1e-3 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mm", dimension = Length, ratio = 1e-3)]
+#[unit(symbol = "mm", dimension = Length, ratio_num = 1, ratio_den = 1_000, siunitx = r"\milli\metre")]
 pub struct Millimeter;
 /// Type alias shorthand for [`Millimeter`].
 pub type Mm = Millimeter;
@@ -94,7 +95,7 @@ pub const MM: Millimeters = Millimeters::new(1.0);
 
 /// Micrometre (`1e-6 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "um", dimension = Length, ratio = 1e-6)]
+#[unit(symbol = "um", dimension = Length, ratio_num = 1, ratio_den = 1_000_000, siunitx = r"\micro\metre")]
 pub struct Micrometer;
 /// Type alias shorthand for [`Micrometer`].
 pub type Um = Micrometer;
@@ -102,10 +103,14 @@ pub type Um = Micrometer;
 pub type Micrometers = Quantity<Um>;
 /// One micrometre.
 pub const UM: Micrometers = Micrometers::new(1.0);
+/// Micron, the customary name for the micrometre in infrared astronomy and optics.
+pub type Micron = Micrometer;
+/// A quantity measured in microns.
+pub type Microns = Micrometers;
 
 /// Nanometre (`1e-9 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "nm", dimension = Length, ratio = 1e-9)]
+#[unit(symbol = "nm", dimension = Length, ratio_num = 1, ratio_den = 1_000_000_000, siunitx = r"\nano\metre")]
 pub struct Nanometer;
 /// Type alias shorthand for [`Nanometer`].
 pub type Nm = Nanometer;
@@ -114,9 +119,18 @@ pub type Nanometers = Quantity<Nm>;
 /// One nanometre.
 pub const NM: Nanometers = Nanometers::new(1.0);
 
+/// Ångström (`1e-10 m`), the traditional unit of optical and atomic wavelengths.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "Å", dimension = Length, ratio_num = 1, ratio_den = 10_000_000_000, siunitx = r"\angstrom")]
+pub struct Angstrom;
+/// A quantity measured in ångströms.
+pub type Angstroms = Quantity<Angstrom>;
+/// One ångström.
+pub const ANGSTROM: Angstroms = Angstroms::new(1.0);
+
 /// Picometre (`1e-12 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "pm", dimension = Length, ratio = 1e-12)]
+#[unit(symbol = "pm", dimension = Length, ratio_num = 1, ratio_den = 1_000_000_000_000, siunitx = r"\pico\metre")]
 pub struct Picometer;
 /// A quantity measured in picometres.
 pub type Picometers = Quantity<Picometer>;
@@ -125,7 +139,7 @@ pub const PMETER: Picometers = Picometers::new(1.0);
 
 /// Femtometre (`1e-15 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fm", dimension = Length, ratio = 1e-15)]
+#[unit(symbol = "fm", dimension = Length, ratio_num = 1, ratio_den = 1_000_000_000_000_000, siunitx = r"\femto\metre")]
 pub struct Femtometer;
 /// A quantity measured in femtometres.
 pub type Femtometers = Quantity<Femtometer>;
@@ -134,7 +148,7 @@ pub const FM: Femtometers = Femtometers::new(1.0);
 
 /// Attometre (`1e-18 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "am", dimension = Length, ratio = 1e-18)]
+#[unit(symbol = "am", dimension = Length, ratio_num = 1, ratio_den = 1_000_000_000_000_000_000, siunitx = r"\atto\metre")]
 pub struct Attometer;
 /// A quantity measured in attometres.
 pub type Attometers = Quantity<Attometer>;
@@ -143,7 +157,7 @@ pub const AM: Attometers = Attometers::new(1.0);
 
 /// Zeptometre (`1e-21 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "zm", dimension = Length, ratio = 1e-21)]
+#[unit(symbol = "zm", dimension = Length, ratio = 1e-21, siunitx = r"\zepto\metre")]
 pub struct Zeptometer;
 /// A quantity measured in zeptometres.
 pub type Zeptometers = Quantity<Zeptometer>;
@@ -152,7 +166,7 @@ pub const ZMETER: Zeptometers = Zeptometers::new(1.0);
 
 /// Yoctometre (`1e-24 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ym", dimension = Length, ratio = 1e-24)]
+#[unit(symbol = "ym", dimension = Length, ratio = 1e-24, siunitx = r"\yocto\metre")]
 pub struct Yoctometer;
 /// A quantity measured in yoctometres.
 pub type Yoctometers = Quantity<Yoctometer>;
@@ -161,7 +175,7 @@ pub const YMETER: Yoctometers = Yoctometers::new(1.0);
 
 /// Megametre (`1e6 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Mm", dimension = Length, ratio = 1e6)]
+#[unit(symbol = "Mm", dimension = Length, ratio_num = 1_000_000, siunitx = r"\mega\metre")]
 pub struct Megameter;
 /// Type alias shorthand for [`Megameter`].
 pub type MegaMeter = Megameter;
@@ -172,7 +186,7 @@ pub const MEGAMETER: Megameters = Megameters::new(1.0);
 
 /// Decimetre (`1e-1 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dm", dimension = Length, ratio = 1e-1)]
+#[unit(symbol = "dm", dimension = Length, ratio_num = 1, ratio_den = 10, siunitx = r"\deci\metre")]
 pub struct Decimeter;
 /// A quantity measured in decimetres.
 pub type Decimeters = Quantity<Decimeter>;
@@ -181,7 +195,7 @@ pub const DM: Decimeters = Decimeters::new(1.0);
 
 /// Decametre (`1e1 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dam", dimension = Length, ratio = 1e1)]
+#[unit(symbol = "dam", dimension = Length, ratio_num = 10, siunitx = r"\deca\metre")]
 pub struct Decameter;
 /// A quantity measured in decametres.
 pub type Decameters = Quantity<Decameter>;
@@ -190,7 +204,7 @@ pub const DAM: Decameters = Decameters::new(1.0);
 
 /// Hectometre (`1e2 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hm", dimension = Length, ratio = 1e2)]
+#[unit(symbol = "hm", dimension = Length, ratio_num = 100, siunitx = r"\hecto\metre")]
 pub struct Hectometer;
 /// A quantity measured in hectometres.
 pub type Hectometers = Quantity<Hectometer>;
@@ -199,7 +213,7 @@ pub const HM: Hectometers = Hectometers::new(1.0);
 
 /// Gigametre (`1e9 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gm", dimension = Length, ratio = 1e9)]
+#[unit(symbol = "Gm", dimension = Length, ratio_num = 1_000_000_000, siunitx = r"\giga\metre")]
 pub struct Gigameter;
 /// A quantity measured in gigametres.
 pub type Gigameters = Quantity<Gigameter>;
@@ -208,7 +222,7 @@ pub const GM: Gigameters = Gigameters::new(1.0);
 
 /// Terametre (`1e12 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Tm", dimension = Length, ratio = 1e12)]
+#[unit(symbol = "Tm", dimension = Length, ratio_num = 1_000_000_000_000, siunitx = r"\tera\metre")]
 pub struct Terameter;
 /// A quantity measured in terametres.
 pub type Terameters = Quantity<Terameter>;
@@ -217,7 +231,7 @@ pub const TM: Terameters = Terameters::new(1.0);
 
 /// Petametre (`1e15 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Pm", dimension = Length, ratio = 1e15)]
+#[unit(symbol = "Pm", dimension = Length, ratio_num = 1_000_000_000_000_000, siunitx = r"\peta\metre")]
 pub struct Petameter;
 /// A quantity measured in petametres.
 pub type Petameters = Quantity<Petameter>;
@@ -226,7 +240,7 @@ pub const PM: Petameters = Petameters::new(1.0);
 
 /// Exametre (`1e18 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Em", dimension = Length, ratio = 1e18)]
+#[unit(symbol = "Em", dimension = Length, ratio_num = 1_000_000_000_000_000_000, siunitx = r"\exa\metre")]
 pub struct Exameter;
 /// A quantity measured in exametres.
 pub type Exameters = Quantity<Exameter>;
@@ -235,7 +249,7 @@ pub const EM: Exameters = Exameters::new(1.0);
 
 /// Zettametre (`1e21 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Zm", dimension = Length, ratio = 1e21)]
+#[unit(symbol = "Zm", dimension = Length, ratio = 1e21, siunitx = r"\zetta\metre")]
 pub struct Zettameter;
 /// A quantity measured in zettametres.
 pub type Zettameters = Quantity<Zettameter>;
@@ -244,7 +258,7 @@ pub const ZM: Zettameters = Zettameters::new(1.0);
 
 /// Yottametre (`1e24 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ym", dimension = Length, ratio = 1e24)]
+#[unit(symbol = "Ym", dimension = Length, ratio = 1e24, siunitx = r"\yotta\metre")]
 pub struct Yottameter;
 /// A quantity measured in yottametres.
 pub type Yottameters = Quantity<Yottameter>;
@@ -257,7 +271,7 @@ pub const YM: Yottameters = Yottameters::new(1.0);
 
 /// Astronomical unit (au). Exact (IAU 2012): metres per au.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "au", dimension = Length, ratio = 149_597_870_700.0)]
+#[unit(symbol = "au", dimension = Length, ratio_num = 149_597_870_700, siunitx = r"\astronomicalunit")]
 pub struct AstronomicalUnit;
 /// Type alias shorthand for [`AstronomicalUnit`].
 pub type Au = AstronomicalUnit;
@@ -275,7 +289,7 @@ const METERS_PER_LIGHT_YEAR: f64 = SPEED_OF_LIGHT_M_PER_S * SECONDS_PER_JULIAN_Y
 
 /// Light-year (ly): distance light travels in one Julian year (`365.25 d`) at `c = 299_792_458 m/s`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ly", dimension = Length, ratio = METERS_PER_LIGHT_YEAR)]
+#[unit(symbol = "ly", dimension = Length, ratio_num = METERS_PER_LIGHT_YEAR as u64)]
 pub struct LightYear;
 /// Type alias shorthand for [`LightYear`].
 pub type Ly = LightYear;
@@ -286,7 +300,7 @@ pub const LY: LightYears = LightYears::new(1.0);
 
 /// Parsec (pc): `pc = au * 648000 / π` (exact given au).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "pc", dimension = Length, ratio = 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(symbol = "pc", dimension = Length, ratio = AstronomicalUnit::RATIO * (648_000.0 / PI))]
 pub struct Parsec;
 /// Type alias shorthand for [`Parsec`].
 pub type Pc = Parsec;
@@ -297,7 +311,7 @@ pub const PC: Parsecs = Parsecs::new(1.0);
 
 /// Kiloparsec (kpc): `1e3 pc`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kpc", dimension = Length, ratio = 1_000.0 * 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(symbol = "kpc", dimension = Length, ratio = 1_000.0 * Parsec::RATIO)]
 pub struct Kiloparsec;
 /// A quantity measured in kiloparsecs.
 pub type Kiloparsecs = Quantity<Kiloparsec>;
@@ -306,7 +320,7 @@ pub const KPC: Kiloparsecs = Kiloparsecs::new(1.0);
 
 /// Megaparsec (Mpc): `1e6 pc`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Mpc", dimension = Length, ratio = 1_000_000.0 * 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(symbol = "Mpc", dimension = Length, ratio = 1_000_000.0 * Parsec::RATIO)]
 pub struct Megaparsec;
 /// A quantity measured in megaparsecs.
 pub type Megaparsecs = Quantity<Megaparsec>;
@@ -315,7 +329,7 @@ pub const MPC: Megaparsecs = Megaparsecs::new(1.0);
 
 /// Gigaparsec (Gpc): `1e9 pc`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gpc", dimension = Length, ratio = 1_000_000_000.0 * 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(symbol = "Gpc", dimension = Length, ratio = 1_000_000_000.0 * Parsec::RATIO)]
 pub struct Gigaparsec;
 /// A quantity measured in gigaparsecs.
 pub type Gigaparsecs = Quantity<Gigaparsec>;
@@ -328,7 +342,7 @@ pub const GPC: Gigaparsecs = Gigaparsecs::new(1.0);
 
 /// Inch (`0.0254 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "in", dimension = Length, ratio = 254.0 / 10_000.0)]
+#[unit(symbol = "in", dimension = Length, ratio_num = 254, ratio_den = 10_000)]
 pub struct Inch;
 /// A quantity measured in inches.
 pub type Inches = Quantity<Inch>;
@@ -337,7 +351,7 @@ pub const INCH: Inches = Inches::new(1.0);
 
 /// Foot (`0.3048 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ft", dimension = Length, ratio = 3048.0 / 10_000.0)]
+#[unit(symbol = "ft", dimension = Length, ratio_num = 3_048, ratio_den = 10_000)]
 pub struct Foot;
 /// A quantity measured in feet.
 pub type Feet = Quantity<Foot>;
@@ -346,7 +360,7 @@ pub const FT: Feet = Feet::new(1.0);
 
 /// Yard (`0.9144 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "yd", dimension = Length, ratio = 9144.0 / 10_000.0)]
+#[unit(symbol = "yd", dimension = Length, ratio_num = 9_144, ratio_den = 10_000)]
 pub struct Yard;
 /// A quantity measured in yards.
 pub type Yards = Quantity<Yard>;
@@ -355,7 +369,7 @@ pub const YD: Yards = Yards::new(1.0);
 
 /// (Statute) mile (`1609.344 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mi", dimension = Length, ratio = 1_609_344.0 / 1_000.0)]
+#[unit(symbol = "mi", dimension = Length, ratio_num = 1_609_344, ratio_den = 1_000)]
 pub struct Mile;
 /// A quantity measured in miles.
 pub type Miles = Quantity<Mile>;
@@ -364,7 +378,7 @@ pub const MI: Miles = Miles::new(1.0);
 
 /// Nautical mile (`1852 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "nmi", dimension = Length, ratio = 1_852.0)]
+#[unit(symbol = "nmi", dimension = Length, ratio_num = 1_852)]
 pub struct NauticalMile;
 /// A quantity measured in nautical miles.
 pub type NauticalMiles = Quantity<NauticalMile>;
@@ -373,7 +387,7 @@ pub const NMI: NauticalMiles = NauticalMiles::new(1.0);
 
 /// Chain (`66 ft` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ch", dimension = Length, ratio = 66.0 * (3048.0 / 10_000.0))]
+#[unit(symbol = "ch", dimension = Length, ratio_num = 66 * 3_048, ratio_den = 10_000)]
 pub struct Chain;
 /// A quantity measured in chains.
 pub type Chains = Quantity<Chain>;
@@ -382,7 +396,7 @@ pub const CHAIN: Chains = Chains::new(1.0);
 
 /// Rod / pole / perch (`16.5 ft` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "rd", dimension = Length, ratio = 16.5 * (3048.0 / 10_000.0))]
+#[unit(symbol = "rd", dimension = Length, ratio_num = 165 * 3_048, ratio_den = 100_000)]
 pub struct Rod;
 /// A quantity measured in rods/poles/perches.
 pub type Rods = Quantity<Rod>;
@@ -391,7 +405,7 @@ pub const ROD: Rods = Rods::new(1.0);
 
 /// Link (`1/100 of a chain`, i.e. `0.66 ft`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lk", dimension = Length, ratio = (66.0 / 100.0) * (3048.0 / 10_000.0))]
+#[unit(symbol = "lk", dimension = Length, ratio_num = 66 * 3_048, ratio_den = 1_000_000)]
 pub struct Link;
 /// A quantity measured in links.
 pub type Links = Quantity<Link>;
@@ -400,7 +414,7 @@ pub const LINK: Links = Links::new(1.0);
 
 /// Fathom (`6 ft` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ftm", dimension = Length, ratio = 6.0 * (3048.0 / 10_000.0))]
+#[unit(symbol = "ftm", dimension = Length, ratio_num = 6 * 3_048, ratio_den = 10_000)]
 pub struct Fathom;
 /// A quantity measured in fathoms.
 pub type Fathoms = Quantity<Fathom>;
@@ -481,7 +495,7 @@ pub const LAMBDA_BAR_E: ElectronReducedComptonWavelengths =
 pub mod nominal {
     use super::*;
 
-    /// Solar radius (R☉). Nominal value: metres per R☉.
+    /// Nominal solar radius `R☉ᴺ = 695 700 km` (IAU 2015 Resolution B3), exact by definition.
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
     #[unit(symbol = "Rsun", dimension = Length, ratio = 695_700_000.0)]
     pub struct SolarRadius;
@@ -490,7 +504,7 @@ pub mod nominal {
     /// One solar radius.
     pub const RSUN: SolarRadiuses = SolarRadiuses::new(1.0);
 
-    /// Earth mean radius (nominal).
+    /// Earth mean radius, `6 371 km` (the IUGG mean radius `R₁ = 6 371.0088 km`, rounded).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
     #[unit(symbol = "Rearth", dimension = Length, ratio = 6_371_000.0)]
     pub struct EarthRadius;
@@ -499,7 +513,7 @@ pub mod nominal {
     /// One Earth radius (mean).
     pub const R_EARTH: EarthRadii = EarthRadii::new(1.0);
 
-    /// Earth equatorial radius (WGS84).
+    /// Earth equatorial radius, `6 378.137 km` (the WGS 84 semi-major axis `a`).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
     #[unit(symbol = "Rearth_eq", dimension = Length, ratio = 6_378_137.0)]
     pub struct EarthEquatorialRadius;
@@ -508,7 +522,7 @@ pub mod nominal {
     /// One Earth equatorial radius.
     pub const R_EARTH_EQ: EarthEquatorialRadii = EarthEquatorialRadii::new(1.0);
 
-    /// Earth polar radius.
+    /// Earth polar radius, `6 356.752 314 2 km` (the WGS 84 semi-minor axis `b`).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
     #[unit(symbol = "Rearth_p", dimension = Length, ratio = 6_356_752.314_2)]
     pub struct EarthPolarRadius;
@@ -517,6 +531,24 @@ pub mod nominal {
     /// One Earth polar radius.
     pub const R_EARTH_P: EarthPolarRadii = EarthPolarRadii::new(1.0);
 
+    /// Nominal Earth equatorial radius `R_Eᴺ = 6 378.1 km` (IAU 2015 Resolution B3), exact by definition.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+    #[unit(symbol = "Rearth_eN", dimension = Length, ratio = 6_378_100.0)]
+    pub struct IauEarthEquatorialRadius;
+    /// A quantity measured in IAU nominal Earth equatorial radii.
+    pub type IauEarthEquatorialRadii = Quantity<IauEarthEquatorialRadius>;
+    /// One IAU nominal Earth equatorial radius.
+    pub const R_EARTH_EQ_N: IauEarthEquatorialRadii = IauEarthEquatorialRadii::new(1.0);
+
+    /// Nominal Earth polar radius `R_Pᴺ = 6 356.8 km` (IAU 2015 Resolution B3), exact by definition.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+    #[unit(symbol = "Rearth_pN", dimension = Length, ratio = 6_356_800.0)]
+    pub struct IauEarthPolarRadius;
+    /// A quantity measured in IAU nominal Earth polar radii.
+    pub type IauEarthPolarRadii = Quantity<IauEarthPolarRadius>;
+    /// One IAU nominal Earth polar radius.
+    pub const R_EARTH_P_N: IauEarthPolarRadii = IauEarthPolarRadii::new(1.0);
+
     /// Lunar radius (mean, nominal).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
     #[unit(symbol = "Rmoon", dimension = Length, ratio = 1_737_400.0)]
@@ -535,7 +567,8 @@ pub mod nominal {
     /// One Jupiter radius.
     pub const R_JUPITER: JupiterRadii = JupiterRadii::new(1.0);
 
-    /// Lunar distance (Earth–Moon mean distance, LD).
+    /// Lunar distance (LD), `384 400 km`: the conventional mean Earth–Moon distance used for near-Earth object
+    /// approaches.
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
     #[unit(symbol = "LD", dimension = Length, ratio = 384_400_000.0)]
     pub struct LunarDistance;
@@ -546,7 +579,7 @@ pub mod nominal {
 
     /// Solar diameter (twice the solar radius).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Dsun", dimension = Length, ratio = 2.0 * 695_700_000.0)]
+    #[unit(symbol = "Dsun", dimension = Length, ratio = 2.0 * SolarRadius::RATIO)]
     pub struct SolarDiameter;
     /// A quantity measured in solar diameters.
     pub type SolarDiameters = Quantity<SolarDiameter>;
@@ -557,6 +590,24 @@ pub mod nominal {
     // length units (e.g., SolarRadius <-> Kilometer) without polluting the
     // main length namespace with nominal types.
     crate::impl_unit_conversions!(SolarRadius, Kilometer);
+    crate::impl_unit_conversions!(EarthRadius, Kilometer);
+    crate::impl_unit_conversions!(EarthEquatorialRadius, Kilometer);
+    crate::impl_unit_conversions!(LunarDistance, Kilometer);
+
+    /// Registry entries for the nominal length units (see [`crate::registry`]).
+    pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+        "length";
+        SolarRadius,
+        EarthRadius,
+        EarthEquatorialRadius,
+        EarthPolarRadius,
+        IauEarthEquatorialRadius,
+        IauEarthPolarRadius,
+        LunarRadius,
+        JupiterRadius,
+        LunarDistance,
+        SolarDiameter
+    );
 }
 
 // Generate all bidirectional From implementations between length units.
@@ -571,6 +622,7 @@ crate::impl_unit_conversions!(
     Millimeter,
     Micrometer,
     Nanometer,
+    Angstrom,
     Picometer,
     Femtometer,
     Attometer,
@@ -609,6 +661,71 @@ crate::impl_unit_conversions!(
     ElectronReducedComptonWavelength
 );
 
+/// Preferred units for [`Quantity::display_auto`] on length quantities.
+impl AutoScale for Length {
+    const DISPLAY_UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Nanometer>(),
+        UnitInfo::of::<Micrometer>(),
+        UnitInfo::of::<Millimeter>(),
+        UnitInfo::of::<Meter>(),
+        UnitInfo::of::<Kilometer>(),
+        UnitInfo::of::<AstronomicalUnit>(),
+        UnitInfo::of::<LightYear>(),
+        UnitInfo::of::<Parsec>(),
+        UnitInfo::of::<Kiloparsec>(),
+        UnitInfo::of::<Megaparsec>(),
+        UnitInfo::of::<Gigaparsec>(),
+    ];
+}
+
+/// Registry entries for the length units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "length";
+    Meter,
+    Kilometer,
+    Centimeter,
+    Millimeter,
+    Micrometer,
+    Nanometer,
+    Angstrom,
+    Picometer,
+    Femtometer,
+    Attometer,
+    Zeptometer,
+    Yoctometer,
+    Megameter,
+    Decimeter,
+    Decameter,
+    Hectometer,
+    Gigameter,
+    Terameter,
+    Petameter,
+    Exameter,
+    Zettameter,
+    Yottameter,
+    AstronomicalUnit,
+    LightYear,
+    Parsec,
+    Kiloparsec,
+    Megaparsec,
+    Gigaparsec,
+    Inch,
+    Foot,
+    Yard,
+    Mile,
+    NauticalMile,
+    Chain,
+    Rod,
+    Link,
+    Fathom,
+    EarthMeridionalCircumference,
+    EarthEquatorialCircumference,
+    BohrRadius,
+    ClassicalElectronRadius,
+    PlanckLength,
+    ElectronReducedComptonWavelength
+);
+
 #[cfg(test)]
 mod tests {
     use super::nominal::SolarRadiuses;
@@ -722,6 +839,26 @@ mod tests {
         assert_relative_eq!(au.value(), 3.26 * 63241.0, max_relative = 1e-2);
     }
 
+    #[test]
+    fn wavelength_scales() {
+        // H-alpha: 6562.8 Å = 656.28 nm = 0.65628 µm.
+        let h_alpha = Angstroms::new(6_562.8);
+        assert_relative_eq!(
+            h_alpha.to::<Nanometer>().value(),
+            656.28,
+            max_relative = 1e-15
+        );
+        assert_relative_eq!(
+            h_alpha.to::<Micron>().value(),
+            0.656_28,
+            max_relative = 1e-15
+        );
+        assert_eq!(ANGSTROM.to::<Picometer>().value(), 100.0);
+        assert_eq!(Microns::new(1.0), UM);
+        assert_eq!(format!("{}", Angstroms::new(5.0)), "5 Å");
+        assert_eq!(Millimeters::new(25.4).to::<Inch>().value(), 1.0);
+    }
+
     #[test]
     fn parsec_ratio_sanity() {
         // Parsec is defined from AU: pc = au * 648000 / π
@@ -730,6 +867,15 @@ mod tests {
         assert_relative_eq!(lhs, rhs, max_relative = 1e-12);
     }
 
+    #[test]
+    fn parsec_in_light_years_and_multiples() {
+        // 1 pc = 3.261 563 777 ly, not the rounded 3.26.
+        let ly = Parsecs::new(1.0).to::<LightYear>();
+        assert_relative_eq!(ly.value(), 3.261_563_777, max_relative = 1e-9);
+        assert_relative_eq!(KPC.to::<Parsec>().value(), 1e3, max_relative = 1e-15);
+        assert_relative_eq!(GPC.to::<Megaparsec>().value(), 1e3, max_relative = 1e-15);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Solar radius
     // ─────────────────────────────────────────────────────────────────────────────
@@ -750,6 +896,32 @@ mod tests {
         assert_abs_diff_eq!(km.value(), 695_700.0, epsilon = 1e-6);
     }
 
+    #[test]
+    fn near_earth_scales() {
+        use super::nominal::{
+            EarthEquatorialRadii, LunarDistances, D_SUN, LD, RSUN, R_EARTH, R_EARTH_EQ,
+        };
+
+        let km: Kilometers = R_EARTH_EQ.into();
+        assert_eq!(km.value(), 6_378.137);
+        let km: Kilometers = LD.into();
+        assert_eq!(km.value(), 384_400.0);
+        assert_eq!(Kilometers::from(R_EARTH).value(), 6_371.0);
+        // The Moon sits at about 60.27 equatorial Earth radii.
+        let ld_in_re: EarthEquatorialRadii = Kilometers::from(LunarDistances::new(1.0)).into();
+        assert_relative_eq!(ld_in_re.value(), 60.268, max_relative = 1e-4);
+        assert_eq!(D_SUN.to::<nominal::SolarRadius>().value(), 2.0);
+        assert_eq!(RSUN.to::<Kilometer>().value(), 695_700.0);
+    }
+
+    #[test]
+    fn iau_nominal_earth_radii() {
+        use super::nominal::{R_EARTH_EQ_N, R_EARTH_P_N};
+
+        assert_eq!(R_EARTH_EQ_N.to::<Kilometer>().value(), 6_378.1);
+        assert_eq!(R_EARTH_P_N.to::<Kilometer>().value(), 6_356.8);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Roundtrip conversions
     // ─────────────────────────────────────────────────────────────────────────────
@@ -831,6 +1003,18 @@ mod tests {
     // ─────────────────────────────────────────────────────────────────────────────
 
     proptest! {
+        #[test]
+        fn prop_eq_across_own_conversion(v in -1e6..1e6f64) {
+            let mm = Millimeters::new(v);
+            prop_assert!(mm.eq_across(mm.to::<Kilometer>()));
+            prop_assert_eq!(
+                mm.partial_cmp_across(mm.to::<Kilometer>()),
+                Some(core::cmp::Ordering::Equal)
+            );
+            let au = AstronomicalUnits::new(v);
+            prop_assert!(au.eq_across(au.to::<Kilometer>()));
+        }
+
         #[test]
         fn prop_roundtrip_km_m(k in -1e6..1e6f64) {
             let original = Kilometers::new(k);
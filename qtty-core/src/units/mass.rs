@@ -5,7 +5,7 @@
 //! This module aims for practical completeness while avoiding avoidable precision loss:
 //! - **SI grams**: full prefix ladder (yocto … yotta).
 //! - **Defined non-SI**: tonne, avoirdupois units, carat, grain.
-//! - **Science/astro**: atomic mass unit (u/Da), nominal solar mass.
+//! - **Science/astro**: atomic mass unit (u/Da), nominal solar mass, and Earth, Jupiter and lunar masses.
 //!
 //! ```rust
 //! use qtty_core::mass::{Kilograms, SolarMass};
@@ -15,7 +15,8 @@
 //! assert!(sm.value() < 1.0);
 //! ```
 
-use crate::{Dimension, Quantity, Unit};
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
 use qtty_derive::Unit;
 
 /// Dimension tag for mass.
@@ -28,7 +29,7 @@ impl<T: Unit<Dim = Mass>> MassUnit for T {}
 
 /// Gram.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "g", dimension = Mass, ratio = 1.0)]
+#[unit(symbol = "g", dimension = Mass, ratio = 1.0, siunitx = r"\gram")]
 pub struct Gram;
 /// A quantity measured in grams.
 pub type Grams = Quantity<Gram>;
@@ -43,14 +44,16 @@ pub const G: Grams = Grams::new(1.0);
 /// - a quantity type `$qty` (e.g. `Kilograms`), and
 /// - a constant `$one` equal to `1.0` of that quantity.
 ///
+/// `$si` is the `siunitx` spelling used by LaTeX output (e.g. `\kilo\gram`).
+///
 /// The `$ratio` argument is the conversion factor to grams, i.e.
 /// `$name::RATIO` such that `1 $sym = $ratio g`.
 macro_rules! si_gram {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident, $si:literal) => {
         #[doc = concat!("SI mass unit `", stringify!($name), "` with gram-based prefix (symbol `", $sym,"`).")]
         #[doc = concat!("By definition, `1 ", $sym, " = ", stringify!($ratio), " g`.")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Mass, ratio = $ratio)]
+        #[unit(symbol = $sym, dimension = Mass, ratio = $ratio, siunitx = $si)]
         pub struct $name;
 
         #[doc = concat!("Shorthand alias for [`", stringify!($name), "`]." )]
@@ -65,31 +68,55 @@ macro_rules! si_gram {
 }
 
 // Full SI prefix ladder (gram-based)
-si_gram!(Yoctogram, "yg", 1e-24, Yg, Yoctograms, YG);
-si_gram!(Zeptogram, "zg", 1e-21, Zg, Zeptograms, ZG);
-si_gram!(Attogram, "ag", 1e-18, Ag, Attograms, AG);
-si_gram!(Femtogram, "fg", 1e-15, Fg, Femtograms, FG);
-si_gram!(Picogram, "pg", 1e-12, Pg, Picograms, PG);
-si_gram!(Nanogram, "ng", 1e-9, Ng, Nanograms, NG);
-si_gram!(Microgram, "µg", 1e-6, Ug, Micrograms, UG);
-si_gram!(Milligram, "mg", 1e-3, Mg, Milligrams, MG);
-si_gram!(Centigram, "cg", 1e-2, Cg, Centigrams, CG);
-si_gram!(Decigram, "dg", 1e-1, Dg, Decigrams, DG);
-
-si_gram!(Decagram, "dag", 1e1, Dag, Decagrams, DAG);
-si_gram!(Hectogram, "hg", 1e2, Hg, Hectograms, HG);
-si_gram!(Kilogram, "kg", 1e3, Kg, Kilograms, KG);
-si_gram!(Megagram, "Mg", 1e6, MgG, Megagrams, MEGAGRAM);
-si_gram!(Gigagram, "Gg", 1e9, Gg, Gigagrams, GG);
-si_gram!(Teragram, "Tg", 1e12, Tg, Teragrams, TG);
-si_gram!(Petagram, "Pg", 1e15, PgG, Petagrams, PETAGRAM);
-si_gram!(Exagram, "Eg", 1e18, Eg, Exagrams, EG);
-si_gram!(Zettagram, "Zg", 1e21, ZgG, Zettagrams, ZETTAGRAM);
-si_gram!(Yottagram, "Yg", 1e24, YgG, Yottagrams, YOTTAGRAM);
+si_gram!(Yoctogram, "yg", 1e-24, Yg, Yoctograms, YG, r"\yocto\gram");
+si_gram!(Zeptogram, "zg", 1e-21, Zg, Zeptograms, ZG, r"\zepto\gram");
+si_gram!(Attogram, "ag", 1e-18, Ag, Attograms, AG, r"\atto\gram");
+si_gram!(Femtogram, "fg", 1e-15, Fg, Femtograms, FG, r"\femto\gram");
+si_gram!(Picogram, "pg", 1e-12, Pg, Picograms, PG, r"\pico\gram");
+si_gram!(Nanogram, "ng", 1e-9, Ng, Nanograms, NG, r"\nano\gram");
+si_gram!(Microgram, "µg", 1e-6, Ug, Micrograms, UG, r"\micro\gram");
+si_gram!(Milligram, "mg", 1e-3, Mg, Milligrams, MG, r"\milli\gram");
+si_gram!(Centigram, "cg", 1e-2, Cg, Centigrams, CG, r"\centi\gram");
+si_gram!(Decigram, "dg", 1e-1, Dg, Decigrams, DG, r"\deci\gram");
+
+si_gram!(Decagram, "dag", 1e1, Dag, Decagrams, DAG, r"\deca\gram");
+si_gram!(Hectogram, "hg", 1e2, Hg, Hectograms, HG, r"\hecto\gram");
+si_gram!(Kilogram, "kg", 1e3, Kg, Kilograms, KG, r"\kilo\gram");
+si_gram!(Megagram, "Mg", 1e6, MgG, Megagrams, MEGAGRAM, r"\mega\gram");
+si_gram!(Gigagram, "Gg", 1e9, Gg, Gigagrams, GG, r"\giga\gram");
+si_gram!(Teragram, "Tg", 1e12, Tg, Teragrams, TG, r"\tera\gram");
+si_gram!(
+    Petagram,
+    "Pg",
+    1e15,
+    PgG,
+    Petagrams,
+    PETAGRAM,
+    r"\peta\gram"
+);
+si_gram!(Exagram, "Eg", 1e18, Eg, Exagrams, EG, r"\exa\gram");
+si_gram!(
+    Zettagram,
+    "Zg",
+    1e21,
+    ZgG,
+    Zettagrams,
+    ZETTAGRAM,
+    r"\zetta\gram"
+);
+si_gram!(
+    Yottagram,
+    "Yg",
+    1e24,
+    YgG,
+    Yottagrams,
+    YOTTAGRAM,
+    r"\yotta\gram"
+);
 
 /// Tonne (metric ton): `1 t = 1_000_000 g` (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "t", dimension = Mass, ratio = 1_000_000.0)]
+#[unit(symbol = "t", dimension = Mass, ratio = 1_000_000.0, siunitx = r"\tonne")]
 pub struct Tonne;
 /// Shorthand type alias for [`Tonne`].
 pub type T = Tonne;
@@ -111,7 +138,7 @@ pub const CT: Carats = Carats::new(1.0);
 
 /// Grain: `1 gr = 64.79891 mg` (exact) == `0.064_798_91 g`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "gr", dimension = Mass, ratio = 6_479_891.0 / 1_000_000_000.0)]
+#[unit(symbol = "gr", dimension = Mass, ratio = 6_479_891.0 / 100_000_000.0)]
 pub struct Grain;
 /// Shorthand type alias for [`Grain`].
 pub type Gr = Grain;
@@ -175,7 +202,7 @@ pub const TON_UK: LongTons = LongTons::new(1.0);
 ///
 /// Stored in grams using the CODATA recommended value for `m_u` in kilograms, converted by `1 kg = 1000 g`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "u", dimension = Mass, ratio = 1.660_539_068_92e-24)]
+#[unit(symbol = "u", dimension = Mass, ratio = 1.660_539_068_92e-24, siunitx = r"\dalton")]
 pub struct AtomicMassUnit;
 /// Type alias shorthand for [`AtomicMassUnit`].
 pub type Dalton = AtomicMassUnit;
@@ -195,6 +222,33 @@ pub type SolarMasses = Quantity<SolarMass>;
 /// One nominal solar mass.
 pub const MSUN: SolarMasses = SolarMasses::new(1.0);
 
+/// Earth mass (`M⊕ = 5.9722 × 10²⁴ kg`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "M⊕", dimension = Mass, ratio = 5.9722e27, long_name = "Earth mass")]
+pub struct EarthMass;
+/// A quantity measured in Earth masses.
+pub type EarthMasses = Quantity<EarthMass>;
+/// One Earth mass.
+pub const MEARTH: EarthMasses = EarthMasses::new(1.0);
+
+/// Jupiter mass (`M♃ = 1.898 × 10²⁷ kg`), the usual scale for giant planets and brown dwarfs.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "M♃", dimension = Mass, ratio = 1.898e30, long_name = "Jupiter mass")]
+pub struct JupiterMass;
+/// A quantity measured in Jupiter masses.
+pub type JupiterMasses = Quantity<JupiterMass>;
+/// One Jupiter mass.
+pub const MJUP: JupiterMasses = JupiterMasses::new(1.0);
+
+/// Lunar mass (`M☾ = 7.342 × 10²² kg`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "M☾", dimension = Mass, ratio = 7.342e25, long_name = "lunar mass")]
+pub struct LunarMass;
+/// A quantity measured in lunar masses.
+pub type LunarMasses = Quantity<LunarMass>;
+/// One lunar mass.
+pub const MMOON: LunarMasses = LunarMasses::new(1.0);
+
 // Generate all bidirectional From implementations between mass units
 crate::impl_unit_conversions!(
     Gram,
@@ -227,7 +281,58 @@ crate::impl_unit_conversions!(
     ShortTon,
     LongTon,
     AtomicMassUnit,
-    SolarMass
+    SolarMass,
+    EarthMass,
+    JupiterMass,
+    LunarMass
+);
+
+/// Preferred units for [`Quantity::display_auto`] on mass quantities.
+impl AutoScale for Mass {
+    const DISPLAY_UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Microgram>(),
+        UnitInfo::of::<Milligram>(),
+        UnitInfo::of::<Gram>(),
+        UnitInfo::of::<Kilogram>(),
+        UnitInfo::of::<Tonne>(),
+        UnitInfo::of::<SolarMass>(),
+    ];
+}
+
+/// Registry entries for the mass units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "mass";
+    Gram,
+    Yoctogram,
+    Zeptogram,
+    Attogram,
+    Femtogram,
+    Picogram,
+    Nanogram,
+    Microgram,
+    Milligram,
+    Centigram,
+    Decigram,
+    Decagram,
+    Hectogram,
+    Kilogram,
+    Megagram,
+    Gigagram,
+    Teragram,
+    Exagram,
+    Tonne,
+    Carat,
+    Grain,
+    Pound,
+    Ounce,
+    Stone,
+    ShortTon,
+    LongTon,
+    AtomicMassUnit,
+    SolarMass,
+    EarthMass,
+    JupiterMass,
+    LunarMass
 );
 
 #[cfg(test)]
@@ -247,6 +352,12 @@ mod tests {
         assert_abs_diff_eq!(kg.value(), 1.0, epsilon = 1e-12);
     }
 
+    #[test]
+    fn seven_thousand_grains_to_the_pound() {
+        let lb = Quantity::<Grain>::new(7_000.0).to::<Pound>();
+        assert_relative_eq!(lb.value(), 1.0, max_relative = 1e-12);
+    }
+
     #[test]
     fn kilogram_to_gram() {
         let kg = Kilograms::new(1.0);
@@ -297,6 +408,49 @@ mod tests {
         assert!(kg.value() < 1e31);
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Planetary masses
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn planetary_masses_in_kilograms() {
+        assert_relative_eq!(
+            MEARTH.to::<Kilogram>().value(),
+            5.9722e24,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            MJUP.to::<Kilogram>().value(),
+            1.898e27,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            MMOON.to::<Kilogram>().value(),
+            7.342e22,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(TONE.to::<Kilogram>().value(), 1_000.0);
+    }
+
+    #[test]
+    fn planetary_mass_ratios() {
+        // Jupiter ≈ 317.8 M⊕, Earth ≈ 81.3 M☾, Sun ≈ 1047.6 M♃.
+        assert_relative_eq!(MJUP.to::<EarthMass>().value(), 317.8, max_relative = 1e-3);
+        assert_relative_eq!(MEARTH.to::<LunarMass>().value(), 81.3, max_relative = 1e-3);
+        assert_relative_eq!(
+            MSUN.to::<JupiterMass>().value(),
+            1_047.6,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn planetary_mass_symbols() {
+        assert_eq!(format!("{}", EarthMasses::new(1.0)), "1 M⊕");
+        assert_eq!(format!("{:#}", JupiterMasses::new(2.0)), "2 Jupiter mass");
+        assert_eq!(LunarMass::SYMBOL, "M☾");
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Roundtrip conversions
     // ─────────────────────────────────────────────────────────────────────────────
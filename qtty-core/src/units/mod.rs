@@ -10,15 +10,31 @@
 //! - [`length`]: length units (SI metre is canonical scaling unit) plus astronomy/geodesy helpers.
 //! - [`mass`]: mass units (gram is canonical scaling unit).
 //! - [`power`]: power units (watt is canonical scaling unit).
+//! - [`pressure`]: pressure units (pascal is canonical scaling unit).
+//! - [`temperature`]: absolute temperature units (kelvin is canonical scaling unit) plus Celsius/Fahrenheit readings.
 //! - [`velocity`]: velocity aliases (`Length / Time`) built from [`length`] and [`time`].
 //! - [`frequency`]: angular frequency aliases (`Angular / Time`) built from [`angular`] and [`time`].
+//! - [`angular_acceleration`]: angular acceleration aliases (`Angular / Time²`) for slew profiles.
+//! - [`area`]: area aliases (`Length · Length`) plus the hectare.
+//! - [`volume`]: volume aliases (`Area · Length`) plus the litre.
+//! - [`density`]: density aliases (`Mass / Volume`), the mean solar density and a sphere mean-density helper.
+//! - [`grav_param`]: gravitational parameter aliases (`Length³ / Time²`) and `GM` constants for the Sun, Earth and Moon.
+//! - [`flux`]: flux (`Power / Area`) and spectral flux density (jansky) plus inverse-square-law helpers.
 //! - [`unitless`]: helpers for dimensionless quantities.
 
 pub mod angular;
+pub mod angular_acceleration;
+pub mod area;
+pub mod density;
+pub mod flux;
 pub mod frequency;
+pub mod grav_param;
 pub mod length;
 pub mod mass;
 pub mod power;
+pub mod pressure;
+pub mod temperature;
 pub mod time;
 pub mod unitless;
 pub mod velocity;
+pub mod volume;
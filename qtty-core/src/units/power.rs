@@ -4,8 +4,9 @@
 //!
 //! This module focuses on completeness without baking in avoidable precision loss:
 //! - Full SI prefix ladder on the watt (yocto … yotta).
-//! - A small set of widely used non-SI units with unambiguous definitions.
-//! - Nominal astronomical reference: solar luminosity (IAU).
+//! - A small set of widely used non-SI units with unambiguous definitions, including the CGS erg per second used
+//!   throughout the astrophysics literature.
+//! - Nominal astronomical reference: solar luminosity (IAU 2015 Resolution B3), under [`nominal`].
 //!
 //! ```rust
 //! use qtty_core::power::{SolarLuminosities, Watt};
@@ -15,7 +16,8 @@
 //! assert!((w.value() - 3.828e26).abs() < 1e18);
 //! ```
 
-use crate::{Dimension, Quantity, Unit};
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
 use qtty_derive::Unit;
 
 /// Fundamental dimension – power.
@@ -28,7 +30,7 @@ impl<T: Unit<Dim = Power>> PowerUnit for T {}
 
 /// Watt (SI coherent derived unit).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "W", dimension = Power, ratio = 1.0)]
+#[unit(symbol = "W", dimension = Power, ratio = 1.0, siunitx = r"\watt")]
 pub struct Watt;
 /// Type alias shorthand for [`Watt`].
 pub type W = Watt;
@@ -38,10 +40,10 @@ pub type Watts = Quantity<W>;
 pub const WATT: Watts = Watts::new(1.0);
 
 macro_rules! si_watt {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident, $si:literal) => {
         #[doc = concat!("SI-prefixed watt unit (", stringify!($ratio), " W).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Power, ratio = $ratio)]
+        #[unit(symbol = $sym, dimension = Power, ratio = $ratio, siunitx = $si)]
         pub struct $name;
         #[doc = concat!("Type alias shorthand for [`", stringify!($name), "`].")]
         pub type $alias = $name;
@@ -53,39 +55,41 @@ macro_rules! si_watt {
 }
 
 // Full SI prefix ladder on watt
-si_watt!(Yoctowatt, "yW", 1e-24, Yw, Yoctowatts, YW);
-si_watt!(Zeptowatt, "zW", 1e-21, Zw, Zeptowatts, ZW);
-si_watt!(Attowatt, "aW", 1e-18, Aw, Attowatts, AW);
-si_watt!(Femtowatt, "fW", 1e-15, Fw, Femtowatts, FW);
-si_watt!(Picowatt, "pW", 1e-12, Pw, Picowatts, PW);
-si_watt!(Nanowatt, "nW", 1e-9, Nw, Nanowatts, NW);
-si_watt!(Microwatt, "µW", 1e-6, Uw, Microwatts, UW);
-si_watt!(Milliwatt, "mW", 1e-3, Mw, Milliwatts, MW_1);
-
-si_watt!(Deciwatt, "dW", 1e-1, Dw, Deciwatts, DW);
-si_watt!(Decawatt, "daW", 1e1, Daw, Decawatts, DAW);
-si_watt!(Hectowatt, "hW", 1e2, Hw, Hectowatts, HW);
-si_watt!(Kilowatt, "kW", 1e3, Kw, Kilowatts, KW);
-si_watt!(Megawatt, "MW", 1e6, MW, Megawatts, MEGAWATT);
-si_watt!(Gigawatt, "GW", 1e9, GW, Gigawatts, GW_1);
-si_watt!(Terawatt, "TW", 1e12, TW, Terawatts, TW_1);
-si_watt!(Petawatt, "PW", 1e15, PW, Petawatts, PETAWATT);
-si_watt!(Exawatt, "EW", 1e18, EW, Exawatts, EW_1);
-si_watt!(Zettawatt, "ZW", 1e21, ZW, Zettawatts, ZW_1);
-si_watt!(Yottawatt, "YW", 1e24, YW, Yottawatts, YW_1);
-
-/// Erg per second (`erg/s`).
+si_watt!(Yoctowatt, "yW", 1e-24, Yw, Yoctowatts, YW, r"\yocto\watt");
+si_watt!(Zeptowatt, "zW", 1e-21, Zw, Zeptowatts, ZW, r"\zepto\watt");
+si_watt!(Attowatt, "aW", 1e-18, Aw, Attowatts, AW, r"\atto\watt");
+si_watt!(Femtowatt, "fW", 1e-15, Fw, Femtowatts, FW, r"\femto\watt");
+si_watt!(Picowatt, "pW", 1e-12, Pw, Picowatts, PW, r"\pico\watt");
+si_watt!(Nanowatt, "nW", 1e-9, Nw, Nanowatts, NW, r"\nano\watt");
+si_watt!(Microwatt, "µW", 1e-6, Uw, Microwatts, UW, r"\micro\watt");
+si_watt!(Milliwatt, "mW", 1e-3, Mw, Milliwatts, MW_1, r"\milli\watt");
+
+si_watt!(Deciwatt, "dW", 1e-1, Dw, Deciwatts, DW, r"\deci\watt");
+si_watt!(Decawatt, "daW", 1e1, Daw, Decawatts, DAW, r"\deca\watt");
+si_watt!(Hectowatt, "hW", 1e2, Hw, Hectowatts, HW, r"\hecto\watt");
+si_watt!(Kilowatt, "kW", 1e3, Kw, Kilowatts, KW, r"\kilo\watt");
+si_watt!(Megawatt, "MW", 1e6, MW, Megawatts, MEGAWATT, r"\mega\watt");
+si_watt!(Gigawatt, "GW", 1e9, GW, Gigawatts, GW_1, r"\giga\watt");
+si_watt!(Terawatt, "TW", 1e12, TW, Terawatts, TW_1, r"\tera\watt");
+si_watt!(Petawatt, "PW", 1e15, PW, Petawatts, PETAWATT, r"\peta\watt");
+si_watt!(Exawatt, "EW", 1e18, EW, Exawatts, EW_1, r"\exa\watt");
+si_watt!(Zettawatt, "ZW", 1e21, ZW, Zettawatts, ZW_1, r"\zetta\watt");
+si_watt!(Yottawatt, "YW", 1e24, YW, Yottawatts, YW_1, r"\yotta\watt");
+
+/// Erg per second (`erg/s`), the CGS unit of power.
 ///
 /// Exact: `1 erg = 1e-7 J`, so `1 erg/s = 1e-7 W`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "erg/s", dimension = Power, ratio = 1e-7)]
+#[unit(symbol = "erg/s", dimension = Power, ratio_num = 1, ratio_den = 10_000_000, long_name = "erg per second")]
 pub struct ErgPerSecond;
+/// A quantity measured in ergs per second.
+pub type ErgsPerSecond = Quantity<ErgPerSecond>;
 /// One erg/s.
-pub const ERG_PER_S: Quantity<ErgPerSecond> = Quantity::new(1.0);
+pub const ERG_PER_S: ErgsPerSecond = ErgsPerSecond::new(1.0);
 
 /// Metric horsepower (`PS`), defined as exactly `735.49875 W`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "PS", dimension = Power, ratio = 73_549_875.0 / 100_000.0)]
+#[unit(symbol = "PS", dimension = Power, ratio = 73_549_875.0 / 100_000.0, long_name = "metric horsepower")]
 pub struct HorsepowerMetric;
 /// A quantity measured in metric horsepower.
 pub type HorsepowerMetrics = Quantity<HorsepowerMetric>;
@@ -94,23 +98,40 @@ pub const PS: HorsepowerMetrics = HorsepowerMetrics::new(1.0);
 
 /// Electric horsepower (`hp_e`), defined as exactly `746 W`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hp_e", dimension = Power, ratio = 746.0)]
+#[unit(symbol = "hp_e", dimension = Power, ratio = 746.0, long_name = "electric horsepower")]
 pub struct HorsepowerElectric;
 /// A quantity measured in electric horsepower.
 pub type HorsepowerElectrics = Quantity<HorsepowerElectric>;
 /// One electric horsepower.
 pub const HP_E: HorsepowerElectrics = HorsepowerElectrics::new(1.0);
 
-/// Solar luminosity (IAU nominal constant; watts per L☉).
+// ─────────────────────────────────────────────────────────────────────────────
+// IAU nominal values
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// IAU 2015 Resolution B3 nominal solar luminosity.
 ///
-/// This is a *nominal reference* value intended for consistent conversion.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "L☉", dimension = Power, ratio = 3.828e26)]
-pub struct SolarLuminosity;
-/// A quantity measured in solar luminosities.
-pub type SolarLuminosities = Quantity<SolarLuminosity>;
-/// One solar luminosity.
-pub const L_SUN: SolarLuminosities = SolarLuminosities::new(1.0);
+/// The nominal constants are exact by definition, so code that must reproduce published conversions bit for bit can
+/// name them explicitly. [`SolarLuminosity`] is also re-exported at the top of this module.
+pub mod nominal {
+    use super::*;
+
+    /// Nominal solar luminosity `L☉ᴺ = 3.828 × 10²⁶ W` (IAU 2015 Resolution B3), exact by definition.
+    pub const SOLAR_LUMINOSITY_NOMINAL_W: f64 = 3.828e26;
+
+    /// Solar luminosity, fixed at the IAU nominal value [`SOLAR_LUMINOSITY_NOMINAL_W`].
+    ///
+    /// This is a *nominal reference* value intended for consistent conversion, not a measurement of the Sun's output.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+    #[unit(symbol = "L☉", dimension = Power, ratio = SOLAR_LUMINOSITY_NOMINAL_W)]
+    pub struct SolarLuminosity;
+    /// A quantity measured in solar luminosities.
+    pub type SolarLuminosities = Quantity<SolarLuminosity>;
+    /// One solar luminosity.
+    pub const L_SUN: SolarLuminosities = SolarLuminosities::new(1.0);
+}
+
+pub use nominal::{SolarLuminosities, SolarLuminosity, L_SUN, SOLAR_LUMINOSITY_NOMINAL_W};
 
 // Generate all bidirectional From implementations between power units
 crate::impl_unit_conversions!(
@@ -140,6 +161,49 @@ crate::impl_unit_conversions!(
     SolarLuminosity
 );
 
+/// Preferred units for [`Quantity::display_auto`] on power quantities.
+impl AutoScale for Power {
+    const DISPLAY_UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Microwatt>(),
+        UnitInfo::of::<Milliwatt>(),
+        UnitInfo::of::<Watt>(),
+        UnitInfo::of::<Kilowatt>(),
+        UnitInfo::of::<Megawatt>(),
+        UnitInfo::of::<Gigawatt>(),
+        UnitInfo::of::<Terawatt>(),
+        UnitInfo::of::<SolarLuminosity>(),
+    ];
+}
+
+/// Registry entries for the power units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "power";
+    Watt,
+    Yoctowatt,
+    Zeptowatt,
+    Attowatt,
+    Femtowatt,
+    Picowatt,
+    Nanowatt,
+    Microwatt,
+    Milliwatt,
+    Deciwatt,
+    Decawatt,
+    Hectowatt,
+    Kilowatt,
+    Megawatt,
+    Gigawatt,
+    Terawatt,
+    Petawatt,
+    Exawatt,
+    Zettawatt,
+    Yottawatt,
+    ErgPerSecond,
+    HorsepowerMetric,
+    HorsepowerElectric,
+    SolarLuminosity
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +236,26 @@ mod tests {
         assert_relative_eq!(w.value(), 3.0 * 3.828e26, max_relative = 1e-9);
     }
 
+    #[test]
+    fn si_multiples() {
+        assert_relative_eq!(KW.to::<Watt>().value(), 1e3);
+        assert_relative_eq!(MEGAWATT.to::<Kilowatt>().value(), 1e3);
+        assert_relative_eq!(GW_1.to::<Megawatt>().value(), 1e3);
+    }
+
+    #[test]
+    fn erg_per_second() {
+        assert_relative_eq!(ERG_PER_S.to::<Watt>().value(), 1e-7);
+        assert_eq!(ErgPerSecond::RATIO_DEN, 10_000_000);
+        // L☉ = 3.828e33 erg/s.
+        assert_relative_eq!(
+            L_SUN.to::<ErgPerSecond>().value(),
+            3.828e33,
+            max_relative = 1e-12
+        );
+        assert_eq!(format!("{:#}", ErgsPerSecond::new(2.0)), "2 erg per second");
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Solar luminosity sanity checks
     // ─────────────────────────────────────────────────────────────────────────────
@@ -180,6 +264,8 @@ mod tests {
     fn solar_luminosity_ratio_sanity() {
         // RATIO should be 3.828e26
         assert_relative_eq!(SolarLuminosity::RATIO, 3.828e26, max_relative = 1e-9);
+        assert_eq!(SolarLuminosity::RATIO, SOLAR_LUMINOSITY_NOMINAL_W);
+        assert_eq!(nominal::L_SUN, L_SUN);
     }
 
     #[test]
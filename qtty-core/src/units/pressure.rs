@@ -0,0 +1,166 @@
+//! Pressure units.
+//!
+//! The canonical scaling unit for this dimension is [`Pascal`] (`Pascal::RATIO == 1.0`). Observatory weather
+//! stations report in hectopascals or millibars (the same size), older tables in millimetres of mercury; all of them are
+//! exact multiples of the pascal and convert without rounding through their integer ratios.
+//!
+//! ```rust
+//! use qtty_core::pressure::{Atmospheres, Hectopascal, Millibar};
+//!
+//! let p = Atmospheres::new(1.0).to::<Hectopascal>();
+//! assert_eq!(p.value(), 1_013.25);
+//! assert_eq!(p.to::<Millibar>().value(), 1_013.25);
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
+use qtty_derive::Unit;
+
+/// Dimension tag for pressure.
+pub enum Pressure {}
+impl Dimension for Pressure {}
+
+/// Marker trait for any [`Unit`] whose dimension is [`Pressure`].
+pub trait PressureUnit: Unit<Dim = Pressure> {}
+impl<T: Unit<Dim = Pressure>> PressureUnit for T {}
+
+/// Pascal (SI coherent derived unit, `N/m²`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "Pa", dimension = Pressure, ratio_num = 1, long_name = "pascal", siunitx = r"\pascal")]
+pub struct Pascal;
+/// A quantity measured in pascals.
+pub type Pascals = Quantity<Pascal>;
+/// One pascal.
+pub const PA: Pascals = Pascals::new(1.0);
+
+/// Hectopascal (`100 Pa`), the unit of most meteorological reports.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "hPa", dimension = Pressure, ratio_num = 100, long_name = "hectopascal", siunitx = r"\hecto\pascal")]
+pub struct Hectopascal;
+/// A quantity measured in hectopascals.
+pub type Hectopascals = Quantity<Hectopascal>;
+/// One hectopascal.
+pub const HPA: Hectopascals = Hectopascals::new(1.0);
+
+/// Kilopascal (`1000 Pa`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "kPa", dimension = Pressure, ratio_num = 1_000, long_name = "kilopascal", siunitx = r"\kilo\pascal")]
+pub struct Kilopascal;
+/// A quantity measured in kilopascals.
+pub type Kilopascals = Quantity<Kilopascal>;
+/// One kilopascal.
+pub const KPA: Kilopascals = Kilopascals::new(1.0);
+
+/// Bar (`100 000 Pa`, exact).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "bar", dimension = Pressure, ratio_num = 100_000, long_name = "bar", siunitx = r"\bar")]
+pub struct Bar;
+/// A quantity measured in bars.
+pub type Bars = Quantity<Bar>;
+/// One bar.
+pub const BAR: Bars = Bars::new(1.0);
+
+/// Millibar (`100 Pa`, exact; the same size as the hectopascal).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "mbar", dimension = Pressure, ratio_num = 100, long_name = "millibar", siunitx = r"\milli\bar")]
+pub struct Millibar;
+/// A quantity measured in millibars.
+pub type Millibars = Quantity<Millibar>;
+/// One millibar.
+pub const MBAR: Millibars = Millibars::new(1.0);
+
+/// Standard atmosphere (`101 325 Pa`, exact).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "atm", dimension = Pressure, ratio_num = 101_325, long_name = "standard atmosphere")]
+pub struct Atmosphere;
+/// A quantity measured in standard atmospheres.
+pub type Atmospheres = Quantity<Atmosphere>;
+/// One standard atmosphere.
+pub const ATM: Atmospheres = Atmospheres::new(1.0);
+
+/// Torr (`1/760 atm`, exact).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "Torr", dimension = Pressure, ratio_num = 101_325, ratio_den = 760, long_name = "torr")]
+pub struct Torr;
+/// A quantity measured in torr.
+pub type Torrs = Quantity<Torr>;
+/// One torr.
+pub const TORR: Torrs = Torrs::new(1.0);
+
+/// Conventional millimetre of mercury (`133.322 387 415 Pa`, exact; within 0.000 015 % of the torr).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(
+    symbol = "mmHg",
+    dimension = Pressure,
+    ratio_num = 133_322_387_415,
+    ratio_den = 1_000_000_000,
+    long_name = "millimetre of mercury",
+    siunitx = r"\mmHg"
+)]
+pub struct MillimeterOfMercury;
+/// A quantity measured in millimetres of mercury.
+pub type MillimetersOfMercury = Quantity<MillimeterOfMercury>;
+/// One millimetre of mercury.
+pub const MMHG: MillimetersOfMercury = MillimetersOfMercury::new(1.0);
+
+// Generate all bidirectional From implementations between pressure units
+crate::impl_unit_conversions!(
+    Pascal,
+    Hectopascal,
+    Kilopascal,
+    Bar,
+    Millibar,
+    Atmosphere,
+    Torr,
+    MillimeterOfMercury
+);
+
+/// Preferred units for [`Quantity::display_auto`] on pressure quantities.
+impl AutoScale for Pressure {
+    const DISPLAY_UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Pascal>(),
+        UnitInfo::of::<Hectopascal>(),
+        UnitInfo::of::<Bar>(),
+    ];
+}
+
+/// Registry entries for the pressure units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "pressure";
+    Pascal,
+    Hectopascal,
+    Kilopascal,
+    Bar,
+    Millibar,
+    Atmosphere,
+    Torr,
+    MillimeterOfMercury
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn exact_multiples_of_the_pascal() {
+        assert_eq!(BAR.to::<Pascal>().value(), 100_000.0);
+        assert_eq!(MBAR.to::<Hectopascal>().value(), 1.0);
+        assert_eq!(ATM.to::<Kilopascal>().value(), 101.325);
+        assert_eq!(Atmospheres::new(1.0).to::<Torr>().value(), 760.0);
+    }
+
+    #[test]
+    fn mercury_and_torr() {
+        assert_relative_eq!(
+            MMHG.to::<Pascal>().value(),
+            133.322_387_415,
+            max_relative = 1e-15
+        );
+        assert_relative_eq!(MMHG.to::<Torr>().value(), 1.0, max_relative = 2e-7);
+        assert_eq!(
+            format!("{:#}", Hectopascals::new(1_010.0)),
+            "1010 hectopascal"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Thermodynamic temperature units.
+//!
+//! The canonical scaling unit for this dimension is [`Kelvin`] (`Kelvin::RATIO == 1.0`). Only absolute scales are
+//! units here: a quantity conversion is a pure scale factor, and the Celsius and Fahrenheit scales also shift the
+//! zero point. Readings on those scales enter and leave through [`Kelvins::from_celsius`] /
+//! [`Kelvins::to_celsius`] and their Fahrenheit counterparts.
+//!
+//! ```rust
+//! use qtty_core::temperature::{Kelvins, Rankine};
+//!
+//! let t = Kelvins::from_celsius(10.0);
+//! assert_eq!(t.value(), 283.15);
+//! assert!((t.to::<Rankine>().value() - 509.67).abs() < 1e-9);
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
+use qtty_derive::Unit;
+
+/// Dimension tag for thermodynamic temperature.
+pub enum Temperature {}
+impl Dimension for Temperature {}
+
+/// Marker trait for any [`Unit`] whose dimension is [`Temperature`].
+pub trait TemperatureUnit: Unit<Dim = Temperature> {}
+impl<T: Unit<Dim = Temperature>> TemperatureUnit for T {}
+
+/// Kelvin (SI base unit).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "K", dimension = Temperature, ratio_num = 1, long_name = "kelvin", siunitx = r"\kelvin")]
+pub struct Kelvin;
+/// A quantity measured in kelvins.
+pub type Kelvins = Quantity<Kelvin>;
+/// One kelvin.
+pub const KELVIN: Kelvins = Kelvins::new(1.0);
+
+/// Millikelvin (`1e-3 K`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "mK", dimension = Temperature, ratio_num = 1, ratio_den = 1_000, long_name = "millikelvin", siunitx = r"\milli\kelvin")]
+pub struct Millikelvin;
+/// A quantity measured in millikelvins.
+pub type Millikelvins = Quantity<Millikelvin>;
+/// One millikelvin.
+pub const MILLIKELVIN: Millikelvins = Millikelvins::new(1.0);
+
+/// Degree Rankine (`5/9 K`, exact), the absolute scale with Fahrenheit-sized degrees.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "°R", dimension = Temperature, ratio_num = 5, ratio_den = 9, long_name = "degree Rankine")]
+pub struct Rankine;
+/// A quantity measured in degrees Rankine.
+pub type Rankines = Quantity<Rankine>;
+/// One degree Rankine.
+pub const RANKINE: Rankines = Rankines::new(1.0);
+
+/// `0 °C` in kelvins.
+const CELSIUS_ZERO: f64 = 273.15;
+
+impl Kelvins {
+    /// The absolute temperature of a Celsius reading.
+    #[inline]
+    pub const fn from_celsius(celsius: f64) -> Self {
+        Self::new(celsius + CELSIUS_ZERO)
+    }
+
+    /// This temperature as a Celsius reading.
+    #[inline]
+    pub const fn to_celsius(self) -> f64 {
+        self.value() - CELSIUS_ZERO
+    }
+
+    /// The absolute temperature of a Fahrenheit reading.
+    #[inline]
+    pub const fn from_fahrenheit(fahrenheit: f64) -> Self {
+        Self::new((fahrenheit - 32.0) * 5.0 / 9.0 + CELSIUS_ZERO)
+    }
+
+    /// This temperature as a Fahrenheit reading.
+    #[inline]
+    pub const fn to_fahrenheit(self) -> f64 {
+        self.to_celsius() * 9.0 / 5.0 + 32.0
+    }
+}
+
+// Generate all bidirectional From implementations between temperature units
+crate::impl_unit_conversions!(Kelvin, Millikelvin, Rankine);
+
+/// Preferred units for [`Quantity::display_auto`] on temperatures.
+impl AutoScale for Temperature {
+    const DISPLAY_UNITS: &'static [UnitInfo] =
+        &[UnitInfo::of::<Millikelvin>(), UnitInfo::of::<Kelvin>()];
+}
+
+/// Registry entries for the temperature units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] =
+    crate::macros::registry_table!("temperature"; Kelvin, Millikelvin, Rankine);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn celsius_and_fahrenheit_readings() {
+        assert_eq!(Kelvins::from_celsius(-273.15).value(), 0.0);
+        assert_abs_diff_eq!(
+            Kelvins::from_fahrenheit(212.0).to_celsius(),
+            100.0,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            Kelvins::from_celsius(-40.0).to_fahrenheit(),
+            -40.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn scale_units() {
+        assert_eq!(Kelvins::new(1.5).to::<Millikelvin>().value(), 1_500.0);
+        assert_eq!(Rankines::new(9.0).to::<Kelvin>().value(), 5.0);
+        assert_eq!(format!("{}", Kelvins::new(2.7)), "2.7 K");
+    }
+}
@@ -22,7 +22,8 @@
 //! assert!((two_hours.value() - 0.5).abs() < 1e-12);
 //! ```
 
-use crate::{Dimension, Quantity, Unit};
+use crate::registry::RegisteredUnit;
+use crate::{AutoScale, Dimension, Quantity, Unit, UnitInfo};
 use qtty_derive::Unit;
 
 /// Dimension tag for time.
@@ -40,7 +41,7 @@ pub const SECONDS_PER_DAY: f64 = 86_400.0;
 
 /// Attoseconds (`1 as = 10^-18 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "as", dimension = Time, ratio = 1e-18)]
+#[unit(symbol = "as", dimension = Time, ratio_num = 1, ratio_den = 1_000_000_000_000_000_000, siunitx = r"\atto\second")]
 pub struct Attosecond;
 /// A quantity measured in attoseconds.
 pub type Attoseconds = Quantity<Attosecond>;
@@ -49,7 +50,7 @@ pub const ATTOSEC: Attoseconds = Attoseconds::new(1.0);
 
 /// Femtoseconds (`1 fs = 10^-15 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fs", dimension = Time, ratio = 1e-15)]
+#[unit(symbol = "fs", dimension = Time, ratio_num = 1, ratio_den = 1_000_000_000_000_000, siunitx = r"\femto\second")]
 pub struct Femtosecond;
 /// A quantity measured in femtoseconds.
 pub type Femtoseconds = Quantity<Femtosecond>;
@@ -58,7 +59,7 @@ pub const FEMTOSEC: Femtoseconds = Femtoseconds::new(1.0);
 
 /// Picoseconds (`1 ps = 10^-12 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ps", dimension = Time, ratio = 1e-12)]
+#[unit(symbol = "ps", dimension = Time, ratio_num = 1, ratio_den = 1_000_000_000_000, siunitx = r"\pico\second")]
 pub struct Picosecond;
 /// A quantity measured in picoseconds.
 pub type Picoseconds = Quantity<Picosecond>;
@@ -67,7 +68,7 @@ pub const PICOSEC: Picoseconds = Picoseconds::new(1.0);
 
 /// Nanoseconds (`1 ns = 10^-9 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ns", dimension = Time, ratio = 1e-9)]
+#[unit(symbol = "ns", dimension = Time, ratio_num = 1, ratio_den = 1_000_000_000, siunitx = r"\nano\second")]
 pub struct Nanosecond;
 /// A quantity measured in nanoseconds.
 pub type Nanoseconds = Quantity<Nanosecond>;
@@ -76,7 +77,7 @@ pub const NANOSEC: Nanoseconds = Nanoseconds::new(1.0);
 
 /// Microseconds (`1 µs = 10^-6 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µs", dimension = Time, ratio = 1e-6)]
+#[unit(symbol = "µs", dimension = Time, ratio_num = 1, ratio_den = 1_000_000, siunitx = r"\micro\second")]
 pub struct Microsecond;
 /// A quantity measured in microseconds.
 pub type Microseconds = Quantity<Microsecond>;
@@ -85,7 +86,7 @@ pub const MICROSEC: Microseconds = Microseconds::new(1.0);
 
 /// Milliseconds (`1 ms = 10^-3 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ms", dimension = Time, ratio = 1e-3)]
+#[unit(symbol = "ms", dimension = Time, ratio_num = 1, ratio_den = 1_000, siunitx = r"\milli\second")]
 pub struct Millisecond;
 /// A quantity measured in milliseconds.
 pub type Milliseconds = Quantity<Millisecond>;
@@ -94,7 +95,7 @@ pub const MILLISEC: Milliseconds = Milliseconds::new(1.0);
 
 /// Centiseconds (`1 cs = 10^-2 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cs", dimension = Time, ratio = 1e-2)]
+#[unit(symbol = "cs", dimension = Time, ratio_num = 1, ratio_den = 100, siunitx = r"\centi\second")]
 pub struct Centisecond;
 /// A quantity measured in centiseconds.
 pub type Centiseconds = Quantity<Centisecond>;
@@ -103,7 +104,7 @@ pub const CENTISEC: Centiseconds = Centiseconds::new(1.0);
 
 /// Deciseconds (`1 ds = 10^-1 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ds", dimension = Time, ratio = 1e-1)]
+#[unit(symbol = "ds", dimension = Time, ratio_num = 1, ratio_den = 10, siunitx = r"\deci\second")]
 pub struct Decisecond;
 /// A quantity measured in deciseconds.
 pub type Deciseconds = Quantity<Decisecond>;
@@ -112,7 +113,7 @@ pub const DECISEC: Deciseconds = Deciseconds::new(1.0);
 
 /// Seconds (SI base unit).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "s", dimension = Time, ratio = 1.0)]
+#[unit(symbol = "s", dimension = Time, ratio_num = 1, siunitx = r"\second")]
 pub struct Second;
 /// A quantity measured in seconds.
 pub type Seconds = Quantity<Second>;
@@ -123,7 +124,7 @@ pub const SEC: Seconds = Seconds::new(1.0);
 
 /// Decaseconds (`1 das = 10 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "das", dimension = Time, ratio = 10.0)]
+#[unit(symbol = "das", dimension = Time, ratio_num = 10, siunitx = r"\deca\second")]
 pub struct Decasecond;
 /// A quantity measured in decaseconds.
 pub type Decaseconds = Quantity<Decasecond>;
@@ -132,7 +133,7 @@ pub const DECASEC: Decaseconds = Decaseconds::new(1.0);
 
 /// Hectoseconds (`1 hs = 100 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hs", dimension = Time, ratio = 100.0)]
+#[unit(symbol = "hs", dimension = Time, ratio_num = 100, siunitx = r"\hecto\second")]
 pub struct Hectosecond;
 /// A quantity measured in hectoseconds.
 pub type Hectoseconds = Quantity<Hectosecond>;
@@ -141,7 +142,7 @@ pub const HECTOSEC: Hectoseconds = Hectoseconds::new(1.0);
 
 /// Kiloseconds (`1 ks = 1_000 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ks", dimension = Time, ratio = 1_000.0)]
+#[unit(symbol = "ks", dimension = Time, ratio_num = 1_000, siunitx = r"\kilo\second")]
 pub struct Kilosecond;
 /// A quantity measured in kiloseconds.
 pub type Kiloseconds = Quantity<Kilosecond>;
@@ -150,7 +151,7 @@ pub const KILOSEC: Kiloseconds = Kiloseconds::new(1.0);
 
 /// Megaseconds (`1 Ms = 10^6 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ms", dimension = Time, ratio = 1e6)]
+#[unit(symbol = "Ms", dimension = Time, ratio_num = 1_000_000, siunitx = r"\mega\second")]
 pub struct Megasecond;
 /// A quantity measured in megaseconds.
 pub type Megaseconds = Quantity<Megasecond>;
@@ -159,7 +160,7 @@ pub const MEGASEC: Megaseconds = Megaseconds::new(1.0);
 
 /// Gigaseconds (`1 Gs = 10^9 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gs", dimension = Time, ratio = 1e9)]
+#[unit(symbol = "Gs", dimension = Time, ratio_num = 1_000_000_000, siunitx = r"\giga\second")]
 pub struct Gigasecond;
 /// A quantity measured in gigaseconds.
 pub type Gigaseconds = Quantity<Gigasecond>;
@@ -168,7 +169,7 @@ pub const GIGASEC: Gigaseconds = Gigaseconds::new(1.0);
 
 /// Teraseconds (`1 Ts = 10^12 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ts", dimension = Time, ratio = 1e12)]
+#[unit(symbol = "Ts", dimension = Time, ratio_num = 1_000_000_000_000, siunitx = r"\tera\second")]
 pub struct Terasecond;
 /// A quantity measured in teraseconds.
 pub type Teraseconds = Quantity<Terasecond>;
@@ -179,7 +180,7 @@ pub const TERASEC: Teraseconds = Teraseconds::new(1.0);
 
 /// Minutes (`60 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "min", dimension = Time, ratio = 60.0)]
+#[unit(symbol = "min", dimension = Time, ratio_num = 60, siunitx = r"\minute")]
 pub struct Minute;
 /// A quantity measured in minutes.
 pub type Minutes = Quantity<Minute>;
@@ -188,7 +189,7 @@ pub const MIN: Minutes = Minutes::new(1.0);
 
 /// Hours (`3_600 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "h", dimension = Time, ratio = 3_600.0)]
+#[unit(symbol = "h", dimension = Time, ratio_num = 3_600, siunitx = r"\hour")]
 pub struct Hour;
 /// A quantity measured in hours.
 pub type Hours = Quantity<Hour>;
@@ -197,7 +198,7 @@ pub const HOUR: Hours = Hours::new(1.0);
 
 /// Mean solar day (`86_400 s` by convention; leap seconds ignored).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "d", dimension = Time, ratio = SECONDS_PER_DAY)]
+#[unit(symbol = "d", dimension = Time, ratio_num = SECONDS_PER_DAY as u64, siunitx = r"\day")]
 pub struct Day;
 /// A quantity measured in days.
 pub type Days = Quantity<Day>;
@@ -206,7 +207,7 @@ pub const DAY: Days = Days::new(1.0);
 
 /// Week (`7 d = 604_800 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "wk", dimension = Time, ratio = 7.0 * SECONDS_PER_DAY)]
+#[unit(symbol = "wk", dimension = Time, ratio_num = 7 * SECONDS_PER_DAY as u64)]
 pub struct Week;
 /// A quantity measured in weeks.
 pub type Weeks = Quantity<Week>;
@@ -215,7 +216,7 @@ pub const WEEK: Weeks = Weeks::new(1.0);
 
 /// Fortnight (`14 d = 1_209_600 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fn", dimension = Time, ratio = 14.0 * SECONDS_PER_DAY)]
+#[unit(symbol = "fn", dimension = Time, ratio_num = 14 * SECONDS_PER_DAY as u64)]
 pub struct Fortnight;
 /// A quantity measured in fortnights.
 pub type Fortnights = Quantity<Fortnight>;
@@ -226,7 +227,7 @@ pub const FORTNIGHT: Fortnights = Fortnights::new(1.0);
 ///
 /// Convention used: `365.2425 d`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "yr", dimension = Time, ratio = 365.242_5 * SECONDS_PER_DAY)]
+#[unit(symbol = "yr", dimension = Time, ratio_num = 3_652_425 * SECONDS_PER_DAY as u64, ratio_den = 10_000)]
 pub struct Year;
 /// A quantity measured in years.
 pub type Years = Quantity<Year>;
@@ -235,7 +236,7 @@ pub const YEAR: Years = Years::new(1.0);
 
 /// Decade (`10` mean tropical years).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dec", dimension = Time, ratio = 10.0 * 365.242_5 * SECONDS_PER_DAY)]
+#[unit(symbol = "dec", dimension = Time, ratio_num = 3_652_425 * SECONDS_PER_DAY as u64, ratio_den = 1_000)]
 pub struct Decade;
 /// A quantity measured in decades.
 pub type Decades = Quantity<Decade>;
@@ -244,7 +245,7 @@ pub const DECADE: Decades = Decades::new(1.0);
 
 /// Century (`100` mean tropical years).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cent", dimension = Time, ratio = 100.0 * 365.242_5 * SECONDS_PER_DAY)]
+#[unit(symbol = "cent", dimension = Time, ratio_num = 3_652_425 * SECONDS_PER_DAY as u64, ratio_den = 100)]
 pub struct Century;
 /// A quantity measured in centuries.
 pub type Centuries = Quantity<Century>;
@@ -253,7 +254,7 @@ pub const CENTURY: Centuries = Centuries::new(1.0);
 
 /// Millennium (`1000` mean tropical years).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mill", dimension = Time, ratio = 1000.0 * 365.242_5 * SECONDS_PER_DAY)]
+#[unit(symbol = "mill", dimension = Time, ratio_num = 3_652_425 * SECONDS_PER_DAY as u64, ratio_den = 10)]
 pub struct Millennium;
 /// A quantity measured in millennia.
 pub type Millennia = Quantity<Millennium>;
@@ -264,7 +265,7 @@ pub const MILLENNIUM: Millennia = Millennia::new(1.0);
 
 /// Julian year (`365.25 d`), expressed in seconds.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "a", dimension = Time, ratio = 365.25 * SECONDS_PER_DAY)]
+#[unit(symbol = "a", dimension = Time, ratio_num = 36_525 * SECONDS_PER_DAY as u64, ratio_den = 100)]
 pub struct JulianYear;
 /// A quantity measured in Julian years.
 pub type JulianYears = Quantity<JulianYear>;
@@ -273,20 +274,29 @@ pub const JULIAN_YEAR: JulianYears = JulianYears::new(1.0);
 
 /// Julian century (`36_525 d`), expressed in seconds.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "JC", dimension = Time, ratio = 36_525.0 * SECONDS_PER_DAY)]
+#[unit(symbol = "JC", dimension = Time, ratio_num = 36_525 * SECONDS_PER_DAY as u64)]
 pub struct JulianCentury;
 /// A quantity measured in Julian centuries.
 pub type JulianCenturies = Quantity<JulianCentury>;
 /// A constant representing one Julian century.
 pub const JULIAN_CENTURY: JulianCenturies = JulianCenturies::new(1.0);
 
+/// Julian millennium (`365_250 d`), the time argument of the VSOP87 planetary theories.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "JM", dimension = Time, ratio_num = 365_250 * SECONDS_PER_DAY as u64)]
+pub struct JulianMillennium;
+/// A quantity measured in Julian millennia.
+pub type JulianMillennia = Quantity<JulianMillennium>;
+/// A constant representing one Julian millennium.
+pub const JULIAN_MILLENNIUM: JulianMillennia = JulianMillennia::new(1.0);
+
 // --- Astronomical mean units (explicitly approximate) ---
 
 /// Mean sidereal day (Earth), expressed in SI seconds.
 ///
 /// Convention used: `1 sidereal day ≈ 86_164.0905 s`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "sd", dimension = Time, ratio = 86_164.090_5)]
+#[unit(symbol = "sd", dimension = Time, ratio_num = 861_640_905, ratio_den = 10_000)]
 pub struct SiderealDay;
 /// A quantity measured in sidereal days.
 pub type SiderealDays = Quantity<SiderealDay>;
@@ -297,7 +307,7 @@ pub const SIDEREAL_DAY: SiderealDays = SiderealDays::new(1.0);
 ///
 /// Convention used: `1 synodic month ≈ 29.530588 d`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "synmo", dimension = Time, ratio = 29.530_588 * SECONDS_PER_DAY)]
+#[unit(symbol = "synmo", dimension = Time, ratio_num = 29_530_588 * SECONDS_PER_DAY as u64, ratio_den = 1_000_000)]
 pub struct SynodicMonth;
 /// A quantity measured in synodic months.
 pub type SynodicMonths = Quantity<SynodicMonth>;
@@ -308,13 +318,190 @@ pub const SYNODIC_MONTH: SynodicMonths = SynodicMonths::new(1.0);
 ///
 /// Common convention: `1 sidereal year ≈ 365.256363004 d`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "syr", dimension = Time, ratio = 365.256_363_004 * SECONDS_PER_DAY)]
+#[unit(symbol = "syr", dimension = Time, ratio_num = 365_256_363_004 * SECONDS_PER_DAY as u64, ratio_den = 1_000_000_000)]
 pub struct SiderealYear;
 /// A quantity measured in sidereal years.
 pub type SiderealYears = Quantity<SiderealYear>;
 /// A constant representing one sidereal year.
 pub const SIDEREAL_YEAR: SiderealYears = SiderealYears::new(1.0);
 
+impl<U: TimeUnit> Quantity<U> {
+    /// Splits the duration into `(days, hours, minutes, seconds)`, with hours `< 24`, minutes `< 60` and seconds
+    /// in `[0, 60)`.
+    ///
+    /// Negative durations are decomposed by magnitude and every component carries the sign, so the parts always sum
+    /// back to the original value (`-90 s` → `(0, 0, -1, -30.0)`). Non-finite values saturate.
+    ///
+    /// ```rust
+    /// use qtty_core::time::Days;
+    ///
+    /// assert_eq!(Days::new(1.5).decompose(), (1, 12, 0, 0.0));
+    /// assert_eq!(Days::new(-0.25).decompose(), (0, -6, 0, -0.0));
+    /// ```
+    #[inline]
+    pub const fn decompose(self) -> (i64, i32, i32, f64) {
+        let (hours, minutes, seconds) = self.to_hms();
+        (hours / 24, (hours % 24) as i32, minutes, seconds)
+    }
+
+    /// Splits the duration into `(hours, minutes, seconds)` with minutes `< 60` and seconds in `[0, 60)`; the sign
+    /// convention is that of [`decompose`](Self::decompose).
+    ///
+    /// ```rust
+    /// use qtty_core::time::Seconds;
+    ///
+    /// assert_eq!(Seconds::new(3_725.5).to_hms(), (1, 2, 5.5));
+    /// assert_eq!(Seconds::new(-90.0).to_hms(), (0, -1, -30.0));
+    /// ```
+    #[inline]
+    pub const fn to_hms(self) -> (i64, i32, f64) {
+        let total = self.to::<Second>().value();
+        let magnitude = if total < 0.0 { -total } else { total };
+        let whole = magnitude as u64;
+        let seconds = (whole % 60) as f64 + (magnitude - whole as f64);
+        let minutes = ((whole / 60) % 60) as i32;
+        let hours = (whole / 3_600) as i64;
+        if total < 0.0 {
+            (-hours, -minutes, -seconds)
+        } else {
+            (hours, minutes, seconds)
+        }
+    }
+}
+
+/// Entry point for composing a time span from calendar-style parts; see [`TimeSpanBuilder`].
+///
+/// ```rust
+/// use qtty_core::time::{Days, Seconds, TimeSpan};
+///
+/// let span: Seconds = TimeSpan::builder().days(1).hours(3).minutes(20).seconds(5.5).build();
+/// assert_eq!(span.value(), 98_405.5);
+/// let days: Days = TimeSpan::builder().hours(36).build();
+/// assert_eq!(days.value(), 1.5);
+/// ```
+pub enum TimeSpan {}
+
+impl TimeSpan {
+    /// Starts an empty (zero-length) span.
+    #[inline]
+    pub const fn builder() -> TimeSpanBuilder {
+        TimeSpanBuilder {
+            total: Seconds::new(0.0),
+        }
+    }
+}
+
+/// Accumulates days, hours, minutes and seconds into a single time quantity.
+///
+/// Every part is added with its own sign, so `hours(-1).minutes(30)` is half an hour *before*; call
+/// [`negative`](Self::negative) instead to negate a span written as positive parts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[must_use = "call `build()` to obtain the quantity"]
+pub struct TimeSpanBuilder {
+    total: Seconds,
+}
+
+impl TimeSpanBuilder {
+    /// Adds whole or fractional days (`86 400 s`).
+    #[inline]
+    pub fn days(self, days: impl Into<f64>) -> Self {
+        self.add(Days::new(days.into()))
+    }
+
+    /// Adds hours.
+    #[inline]
+    pub fn hours(self, hours: impl Into<f64>) -> Self {
+        self.add(Hours::new(hours.into()))
+    }
+
+    /// Adds minutes.
+    #[inline]
+    pub fn minutes(self, minutes: impl Into<f64>) -> Self {
+        self.add(Minutes::new(minutes.into()))
+    }
+
+    /// Adds seconds.
+    #[inline]
+    pub fn seconds(self, seconds: impl Into<f64>) -> Self {
+        self.add(Seconds::new(seconds.into()))
+    }
+
+    /// Adds milliseconds.
+    #[inline]
+    pub fn milliseconds(self, milliseconds: impl Into<f64>) -> Self {
+        self.add(Milliseconds::new(milliseconds.into()))
+    }
+
+    /// Negates the span accumulated so far.
+    #[inline]
+    pub fn negative(self) -> Self {
+        Self { total: -self.total }
+    }
+
+    /// Returns the span in the requested time unit (usually inferred, e.g. `let d: Days = ….build()`).
+    #[inline]
+    pub fn build<U: TimeUnit>(self) -> Quantity<U> {
+        self.total.to::<U>()
+    }
+
+    #[inline]
+    fn add<U: TimeUnit>(self, part: Quantity<U>) -> Self {
+        Self {
+            total: self.total + part.to::<Second>(),
+        }
+    }
+}
+
+/// Preferred units for [`Quantity::display_auto`] on time quantities.
+impl AutoScale for Time {
+    const DISPLAY_UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Picosecond>(),
+        UnitInfo::of::<Nanosecond>(),
+        UnitInfo::of::<Microsecond>(),
+        UnitInfo::of::<Millisecond>(),
+        UnitInfo::of::<Second>(),
+        UnitInfo::of::<Minute>(),
+        UnitInfo::of::<Hour>(),
+        UnitInfo::of::<Day>(),
+        UnitInfo::of::<Year>(),
+    ];
+}
+
+/// Registry entries for the time units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "time";
+    Attosecond,
+    Femtosecond,
+    Picosecond,
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Centisecond,
+    Decisecond,
+    Second,
+    Decasecond,
+    Hectosecond,
+    Kilosecond,
+    Megasecond,
+    Gigasecond,
+    Terasecond,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Fortnight,
+    Year,
+    Decade,
+    Century,
+    Millennium,
+    JulianYear,
+    JulianCentury,
+    JulianMillennium,
+    SiderealDay,
+    SynodicMonth,
+    SiderealYear
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,6 +575,12 @@ mod tests {
         assert_abs_diff_eq!(jy.value(), 100.0, epsilon = 1e-9);
     }
 
+    #[test]
+    fn julian_millennium_to_julian_centuries() {
+        let jm = JulianMillennia::new(1.0);
+        assert_eq!(jm.to::<JulianCentury>().value(), 10.0);
+    }
+
     #[test]
     fn tropical_year_to_days() {
         let y = Years::new(1.0);
@@ -402,6 +595,20 @@ mod tests {
         assert_abs_diff_eq!(day.value(), 36524.25, epsilon = 1e-9);
     }
 
+    #[test]
+    fn sub_microsecond_scales_are_exact() {
+        // Factors such as 1e18 lie beyond 2^53 but are still exact in f64, so these take a single rounding.
+        assert_eq!(Seconds::new(1.0).to::<Attosecond>().value(), 1e18);
+        assert_eq!(Days::new(1.0).to::<Picosecond>().value(), 8.64e16);
+        assert_eq!(Femtoseconds::new(1e15).to::<Second>().value(), 1.0);
+        assert_eq!(Microseconds::new(1_000.0).to::<Nanosecond>().value(), 1e6);
+        assert_eq!(
+            format!("{}", Seconds::new(2.5e-12).display_auto()),
+            "2.5 ps"
+        );
+        assert_eq!(format!("{}", Seconds::new(1.5e-6).display_auto()), "1.5 µs");
+    }
+
     #[test]
     fn milliseconds_to_seconds() {
         let ms = Milliseconds::new(1000.0);
@@ -451,11 +658,80 @@ mod tests {
         assert_abs_diff_eq!(Hour::RATIO, 3_600.0, epsilon = 1e-15);
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // TimeSpan builder
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn time_span_builder_sums_parts() {
+        let span: Seconds = TimeSpan::builder()
+            .days(1)
+            .hours(3)
+            .minutes(20)
+            .seconds(5.5)
+            .build();
+        assert_abs_diff_eq!(span.value(), 98_405.5, epsilon = 1e-9);
+
+        let days: Days = TimeSpan::builder().hours(36).build();
+        assert_abs_diff_eq!(days.value(), 1.5, epsilon = 1e-12);
+        let ms: Milliseconds = TimeSpan::builder().seconds(1).milliseconds(250).build();
+        assert_abs_diff_eq!(ms.value(), 1_250.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn time_span_builder_signs() {
+        let before: Minutes = TimeSpan::builder().hours(-1).minutes(30).build();
+        assert_abs_diff_eq!(before.value(), -30.0, epsilon = 1e-12);
+        let negated: Minutes = TimeSpan::builder().hours(1).minutes(30).negative().build();
+        assert_abs_diff_eq!(negated.value(), -90.0, epsilon = 1e-12);
+        assert_eq!(TimeSpan::builder().build::<Day>().value(), 0.0);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Decomposition
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn decompose_positive() {
+        assert_eq!(Seconds::new(98_405.5).decompose(), (1, 3, 20, 5.5));
+        assert_eq!(Hours::new(49.0).decompose(), (2, 1, 0, 0.0));
+        assert_eq!(Minutes::new(59.5).to_hms(), (0, 59, 30.0));
+        assert_eq!(Days::new(2.0).to_hms(), (48, 0, 0.0));
+    }
+
+    #[test]
+    fn decompose_negative_carries_sign() {
+        assert_eq!(Seconds::new(-0.5).to_hms(), (0, 0, -0.5));
+        assert_eq!(Seconds::new(-3_661.0).to_hms(), (-1, -1, -1.0));
+        assert_eq!(Seconds::new(-98_405.5).decompose(), (-1, -3, -20, -5.5));
+    }
+
+    #[test]
+    fn decompose_matches_builder() {
+        let (d, h, m, s) = Days::new(-1.234_567).decompose();
+        let back: Days = TimeSpan::builder()
+            .days(d as f64)
+            .hours(h)
+            .minutes(m)
+            .seconds(s)
+            .build();
+        assert_abs_diff_eq!(back.value(), -1.234_567, epsilon = 1e-12);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Property-based tests
     // ─────────────────────────────────────────────────────────────────────────────
 
     proptest! {
+        #[test]
+        fn prop_decompose_recomposes(t in -1e9..1e9f64) {
+            let (d, h, m, s) = Seconds::new(t).decompose();
+            prop_assert!(h.abs() < 24 && m.abs() < 60 && s.abs() < 60.0);
+            prop_assert!(d <= 0 && h <= 0 && m <= 0 && s <= 0.0 || d >= 0 && h >= 0 && m >= 0 && s >= 0.0);
+            let back = d as f64 * 86_400.0 + h as f64 * 3_600.0 + m as f64 * 60.0 + s;
+            prop_assert!((back - t).abs() <= 1e-6);
+        }
+
         #[test]
         fn prop_roundtrip_day_second(d in -1e6..1e6f64) {
             let original = Days::new(d);
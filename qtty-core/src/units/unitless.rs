@@ -28,7 +28,6 @@ mod tests {
     use super::*;
     use crate::units::length::Meters;
     use crate::Unit;
-    use approx::assert_abs_diff_eq;
     use proptest::prelude::*;
 
     // ─────────────────────────────────────────────────────────────────────────────
@@ -42,9 +41,10 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "strict"))]
     fn unitless_from_f64() {
         let u: Quantity<Unitless> = 1.23456.into();
-        assert_abs_diff_eq!(u.value(), 1.23456, epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(u.value(), 1.23456, epsilon = 1e-12);
     }
 
     // ─────────────────────────────────────────────────────────────────────────────
@@ -65,6 +65,12 @@ mod tests {
         assert_eq!(s, "42");
     }
 
+    #[test]
+    fn display_unitless_precision() {
+        let u: Quantity<Unitless> = Quantity::new(0.123456);
+        assert_eq!(format!("{:.3}", u), "0.123");
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Conversion from length
     // ─────────────────────────────────────────────────────────────────────────────
@@ -3,8 +3,9 @@
 //! This module defines velocity units as *pure type aliases* over [`Per`] using
 //! length and time units already defined elsewhere in the crate.
 //!
-//! No standalone velocity units are introduced: every velocity is represented as
-//! `Length / Time` at the type level.
+//! Velocities are represented as `Length / Time` at the type level. The one standalone unit is
+//! [`SpeedOfLight`], for speeds given as fractions of `c`; [`light_travel_time`] and [`light_distance`] convert
+//! between distances and light times with it.
 //!
 //! ## Design notes
 //!
@@ -34,10 +35,23 @@
 //! let v: Velocity<Meter, Hour> = Meters::new(3_600.0) / Hours::new(1.0);
 //! assert!((v.value() - 3_600.0).abs() < 1e-12);
 //! ```
+//!
+//! ```rust
+//! use qtty_core::length::Kilometer;
+//! use qtty_core::time::Second;
+//! use qtty_core::velocity::{Velocity, C};
+//!
+//! // A relativistic jet at a tenth of the speed of light.
+//! let jet: Velocity<Kilometer, Second> = (0.1 * C).to();
+//! assert!((jet.value() - 29_979.245_8).abs() < 1e-9);
+//! ```
 
-use crate::units::length::Length;
-use crate::units::time::Time;
+use crate::registry::RegisteredUnit;
+use crate::units::angular::Radian;
+use crate::units::length::{Kilometer, Length, LengthUnit, Meter, Meters, Mile, NauticalMile};
+use crate::units::time::{Hour, Second, Seconds, Time, TimeUnit};
 use crate::{DivDim, Per, Quantity, Unit};
+use qtty_derive::Unit;
 
 /// Dimension alias for velocities (`Length / Time`).
 pub type VelocityDim = DivDim<Length, Time>;
@@ -60,12 +74,117 @@ impl<T: Unit<Dim = VelocityDim>> VelocityUnit for T {}
 /// ```
 pub type Velocity<N, D> = Quantity<Per<N, D>>;
 
+/// Speed of light in vacuum, `c = 299 792 458 m/s` (exact by the SI definition of the metre).
+///
+/// Its ratio is an exact integer number of metres per second, so conversions to m/s and km/s are exact up to the final
+/// rounding.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "c", dimension = VelocityDim, ratio_num = 299_792_458, long_name = "speed of light")]
+pub struct SpeedOfLight;
+/// A velocity measured as a multiple of the speed of light.
+pub type SpeedsOfLight = Quantity<SpeedOfLight>;
+/// The speed of light, so `0.1 * C` is a tenth of `c`.
+pub const C: SpeedsOfLight = SpeedsOfLight::new(1.0);
+
+/// One-way light time over `distance`, in seconds: `distance / c`.
+///
+/// The distance may be in any length unit. Ephemeris light-time corrections iterate this with the distance at the
+/// retarded time.
+///
+/// ```rust
+/// use qtty_core::length::AstronomicalUnits;
+/// use qtty_core::velocity::light_travel_time;
+///
+/// let t = light_travel_time(AstronomicalUnits::new(1.0));
+/// assert!((t.value() - 499.004_783_8).abs() < 1e-6);
+/// ```
+#[inline]
+pub const fn light_travel_time<L: LengthUnit>(distance: Quantity<L>) -> Seconds {
+    Seconds::new(distance.to::<Meter>().value() / C.to::<Per<Meter, Second>>().value())
+}
+
+/// Distance light travels in `time`, in metres: `c · time`.
+///
+/// The inverse of [`light_travel_time`]; the duration may be in any time unit.
+///
+/// ```rust
+/// use qtty_core::length::LightYear;
+/// use qtty_core::time::JulianYears;
+/// use qtty_core::velocity::light_distance;
+///
+/// let d = light_distance(JulianYears::new(1.0));
+/// assert_eq!(d.to::<LightYear>().value(), 1.0);
+/// ```
+#[inline]
+pub const fn light_distance<T: TimeUnit>(time: Quantity<T>) -> Meters {
+    Meters::new(time.to::<Second>().value() * C.to::<Per<Meter, Second>>().value())
+}
+
+crate::impl_unit_conversions!(
+    SpeedOfLight,
+    Per<Meter, Second>,
+    Per<Kilometer, Second>
+);
+
+/// Knot: one nautical mile per hour (`1852 / 3600 m/s` exactly), the usual unit of reported wind speed.
+pub type NauticalMilePerHour = Per<NauticalMile, Hour>;
+/// Type alias shorthand for [`NauticalMilePerHour`].
+pub type Knot = NauticalMilePerHour;
+/// A quantity measured in knots.
+pub type Knots = Quantity<Knot>;
+
+/// Mile per hour (`0.447 04 m/s` exactly).
+pub type MilePerHour = Per<Mile, Hour>;
+/// Type alias shorthand for [`MilePerHour`].
+pub type Mph = MilePerHour;
+/// A quantity measured in miles per hour.
+pub type MilesPerHour = Quantity<MilePerHour>;
+
+impl<L: LengthUnit, T: TimeUnit> Velocity<L, T> {
+    /// Rotation rate that moves a point at distance `radius` from the axis at this speed, `ω = v / r`.
+    ///
+    /// The inverse of [`Frequency::tangential_velocity`](crate::frequency::Frequency::tangential_velocity); the
+    /// radius may be in any length unit and the rate comes out in radians per this velocity's time unit.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::Degree;
+    /// use qtty_core::frequency::Frequency;
+    /// use qtty_core::length::{Centimeters, Meter};
+    /// use qtty_core::time::Second;
+    /// use qtty_core::velocity::Velocity;
+    ///
+    /// // A drive wheel of 5 cm radius rolling at 0.1 m/s turns at 2 rad/s.
+    /// let omega = Velocity::<Meter, Second>::new(0.1).angular_velocity(Centimeters::new(5.0));
+    /// assert!((omega.value() - 2.0).abs() < 1e-12);
+    /// let deg: Frequency<Degree, Second> = omega.to();
+    /// assert!((deg.value() - 2.0_f64.to_degrees()).abs() < 1e-9);
+    /// ```
+    #[inline]
+    pub const fn angular_velocity<R: LengthUnit>(
+        self,
+        radius: Quantity<R>,
+    ) -> Quantity<Per<Radian, T>> {
+        Quantity::new(self.value() / radius.to::<L>().value())
+    }
+}
+
+/// Registry entries for the velocity units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "velocity";
+    Per<Meter, Second> = "m/s",
+    Per<Kilometer, Second> = "Km/s",
+    Per<Kilometer, Hour> = "Km/h",
+    NauticalMilePerHour = "nmi/h",
+    MilePerHour = "mi/h",
+    SpeedOfLight
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::units::length::{Au, Kilometer, Kilometers, Meter};
     use crate::units::time::{Day, Hour, Second, Seconds};
-    use crate::Per;
+    use crate::{Per, Prod};
     use approx::{assert_abs_diff_eq, assert_relative_eq};
     use proptest::prelude::*;
 
@@ -163,6 +282,119 @@ mod tests {
         assert_abs_diff_eq!(v.value(), 10.0, epsilon = 1e-9);
     }
 
+    #[test]
+    fn acceleration_display() {
+        let v: Velocity<Meter, Second> = Velocity::new(9.8);
+        let a: Quantity<Per<Per<Meter, Second>, Second>> = v / Seconds::new(1.0);
+        assert_eq!(format!("{}", a), "9.8 m/s^2");
+        assert_eq!(format!("{:#}", a), "9.8 meter per second per second");
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Nested Per (acceleration-style units)
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn nested_per_ratio() {
+        type KmPerHourPerSecond = Per<Per<Kilometer, Hour>, Second>;
+        assert_eq!(KmPerHourPerSecond::RATIO, 1_000.0 / 3_600.0);
+        assert_eq!(
+            (KmPerHourPerSecond::RATIO_NUM, KmPerHourPerSecond::RATIO_DEN),
+            (5, 18)
+        );
+        // 0–100 km/h in 10 s.
+        let a = Quantity::<KmPerHourPerSecond>::new(10.0);
+        assert_relative_eq!(
+            a.to::<Per<Per<Meter, Second>, Second>>().value(),
+            2.777_777_777_777_778,
+            max_relative = 1e-15
+        );
+    }
+
+    #[test]
+    fn nested_per_display() {
+        let a = Quantity::<Per<Per<Kilometer, Hour>, Second>>::new(1.0);
+        assert_eq!(format!("{a}"), "1 Km/(h·s)");
+        assert_eq!(format!("{a:#}"), "1 kilometer per hour per second");
+
+        // A rate in the denominator is grouped in the long name.
+        let b = Quantity::<Per<Meter, Per<Kilometer, Second>>>::new(2.0);
+        assert_eq!(format!("{b}"), "2 m·s/Km");
+        assert_eq!(format!("{b:#}"), "2 meter per (kilometer per second)");
+
+        let jerk = Quantity::<Per<Per<Per<Meter, Second>, Second>, Second>>::new(3.0);
+        assert_eq!(format!("{jerk}"), "3 m/s^3");
+    }
+
+    #[test]
+    fn nested_per_flatten() {
+        let a = Quantity::<Per<Per<Meter, Second>, Second>>::new(9.81);
+        let flat = a.flatten();
+        assert_eq!(flat.value(), 9.81);
+        assert_eq!(format!("{flat}"), "9.81 m/s^2");
+        // The flat form shares its dimension with `Length / Time²` units built from `Prod`.
+        let flat_kmh_s = flat.to::<Per<Kilometer, Prod<Hour, Second>>>();
+        let nested_kmh_s = a.to::<Per<Per<Kilometer, Hour>, Second>>();
+        assert_relative_eq!(
+            flat_kmh_s.value(),
+            nested_kmh_s.value(),
+            max_relative = 1e-15
+        );
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Speed of light
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn speed_of_light_is_exact() {
+        assert_eq!(C.to::<Per<Meter, Second>>().value(), 299_792_458.0);
+        assert_eq!(C.to::<Per<Kilometer, Second>>().value(), 299_792.458);
+        let back: SpeedsOfLight = Velocity::<Kilometer, Second>::new(299_792.458).into();
+        assert_eq!(back.value(), 1.0);
+        assert_eq!(format!("{}", 0.5 * C), "0.5 c");
+    }
+
+    #[test]
+    fn light_time_round_trips() {
+        const ONE_LIGHT_SECOND: Seconds = light_travel_time(Kilometers::new(299_792.458));
+        assert_eq!(ONE_LIGHT_SECOND.value(), 1.0);
+        let t = light_travel_time(Quantity::<Au>::new(5.2));
+        assert_relative_eq!(
+            t.to::<Hour>().value(),
+            0.720_784_687_763,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            light_distance(t).to::<Au>().value(),
+            5.2,
+            max_relative = 1e-15
+        );
+        assert_eq!(light_distance(Quantity::<Day>::new(0.0)).value(), 0.0);
+    }
+
+    #[test]
+    fn light_year_per_julian_year_is_c() {
+        use crate::units::length::LightYear;
+        use crate::units::time::JulianYear;
+        let v: Velocity<LightYear, JulianYear> = Velocity::new(1.0);
+        assert_relative_eq!(v.to::<SpeedOfLight>().value(), 1.0, max_relative = 1e-15);
+    }
+
+    #[test]
+    fn wind_speeds() {
+        assert_eq!(Knots::new(1.0).to::<Per<Meter, Hour>>().value(), 1_852.0);
+        assert_eq!(
+            MilesPerHour::new(1.0).to::<Per<Meter, Second>>().value(),
+            0.447_04
+        );
+        // A 40 kn wind limit, checked against a 45 mph gust.
+        let gust: Knots = MilesPerHour::new(45.0).to();
+        assert_relative_eq!(gust.value(), 39.103_931, max_relative = 1e-6);
+        assert!(gust < Knots::new(40.0));
+        assert_eq!(format!("{}", Knots::new(12.0)), "12 nmi/h");
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Roundtrip conversions
     // ─────────────────────────────────────────────────────────────────────────────
@@ -196,6 +428,24 @@ mod tests {
             prop_assert!((mps.value() / kps.value() - 1000.0).abs() < 1e-9);
         }
 
+        #[test]
+        fn prop_nested_per_roundtrip(a in -1e6..1e6f64) {
+            let mps2 = Quantity::<Per<Per<Meter, Second>, Second>>::new(a);
+            let kmh_s: Quantity<Per<Per<Kilometer, Hour>, Second>> = mps2.to();
+            prop_assert!((kmh_s.value() - a * 3.6).abs() <= 1e-12 * a.abs().max(1.0));
+            let back: Quantity<Per<Per<Meter, Second>, Second>> = kmh_s.to();
+            prop_assert!((back.value() - a).abs() <= 1e-12 * a.abs().max(1.0));
+        }
+
+        #[test]
+        fn prop_nested_per_times_time(a in -1e3..1e3f64, t in 1e-3..1e3f64) {
+            let accel = Quantity::<Per<Per<Kilometer, Second>, Second>>::new(a);
+            let v: Velocity<Kilometer, Second> = accel * Seconds::new(t);
+            prop_assert!((v.value() - a * t).abs() <= 1e-12 * (a * t).abs().max(1.0));
+            let again = v / Seconds::new(t);
+            prop_assert!((again.value() - a).abs() <= 1e-12 * a.abs().max(1.0));
+        }
+
         #[test]
         fn prop_velocity_time_roundtrip(
             v_val in 1e-3..1e3f64,
@@ -0,0 +1,87 @@
+//! Volume units (`Area · Length`).
+//!
+//! Volumes are products of an area and a length ([`Prod`]), nested to the left so that the cube of a length,
+//! `Prod<Prod<L, L>, L>`, has the [`Volume`] dimension. The canonical scaling unit is the cubic metre
+//! (`CubicMeter::RATIO == 1.0`). The litre is provided as a named unit.
+//!
+//! ```rust
+//! use qtty_core::length::Centimeters;
+//! use qtty_core::volume::Liter;
+//!
+//! let cube = Centimeters::new(10.0).cubed();
+//! assert!((cube.to::<Liter>().value() - 1.0).abs() < 1e-12);
+//! ```
+
+use crate::registry::RegisteredUnit;
+use crate::units::area::Area;
+use crate::units::length::{Length, LengthUnit, Meter};
+use crate::{MulDim, Prod, Quantity, Unit};
+use qtty_derive::Unit;
+
+/// Dimension alias for volumes (`Area · Length`).
+pub type Volume = MulDim<Area, Length>;
+
+/// Marker trait for any unit whose dimension is [`Volume`].
+pub trait VolumeUnit: Unit<Dim = Volume> {}
+impl<T: Unit<Dim = Volume>> VolumeUnit for T {}
+
+/// Cubic metre (`m^3`).
+pub type CubicMeter = Prod<Prod<Meter, Meter>, Meter>;
+/// A quantity measured in cubic metres.
+pub type CubicMeters = Quantity<CubicMeter>;
+
+/// Litre (`1e-3 m^3`).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
+#[unit(symbol = "L", dimension = Volume, ratio = 1e-3, siunitx = r"\litre")]
+pub struct Liter;
+/// A quantity measured in litres.
+pub type Liters = Quantity<Liter>;
+/// One litre.
+pub const L: Liters = Liters::new(1.0);
+
+impl<U: LengthUnit> Quantity<U> {
+    /// Cubes a length, producing a volume in the same length unit.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    /// use qtty_core::volume::CubicMeters;
+    ///
+    /// let v: CubicMeters = Meters::new(2.0).cubed();
+    /// assert_eq!(v.value(), 8.0);
+    /// ```
+    #[inline]
+    pub const fn cubed(self) -> Quantity<Prod<Prod<U, U>, U>> {
+        Quantity::new(self.value() * self.value() * self.value())
+    }
+}
+
+/// Registry entries for the volume units (see [`crate::registry`]).
+pub(crate) static REGISTRY: &[RegisteredUnit] = crate::macros::registry_table!(
+    "volume";
+    CubicMeter = "m^3",
+    Liter
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::length::Kilometer;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn cubic_meter_to_liters() {
+        assert_relative_eq!(CubicMeters::new(1.0).to::<Liter>().value(), 1_000.0);
+    }
+
+    #[test]
+    fn cubed_km_to_cubic_meters() {
+        let v = Quantity::<Kilometer>::new(1.0).cubed();
+        assert_relative_eq!(v.to::<CubicMeter>().value(), 1e9);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", CubicMeters::new(2.0)), "2 m^3");
+        assert_eq!(format!("{:#}", Liters::new(0.5)), "0.5 liter");
+    }
+}
@@ -11,7 +11,8 @@
 //! For a unit marker type `MyUnit`, the derive implements:
 //!
 //! - `crate::Unit for MyUnit`
-//! - `core::fmt::Display for crate::Quantity<MyUnit>` (formats as `<value> <symbol>`)
+//! - `core::fmt::Display for crate::Quantity<MyUnit>` (formats as `<value> <symbol>`, or `<value> <long name>` with
+//!   the alternate flag `{:#}`); width, precision and sign flags are forwarded to the numeric value
 //!
 //! # Attributes
 //!
@@ -19,7 +20,13 @@
 //!
 //! - `symbol = "m"`: displayed unit symbol
 //! - `dimension = SomeDim`: dimension marker type
-//! - `ratio = 1000.0`: conversion ratio to the canonical unit of the dimension
+//! - `ratio = 1000.0`: conversion ratio to the canonical unit of the dimension. Any constant `f64` expression is
+//!   accepted, including other units' ratios (`ratio = AstronomicalUnit::RATIO * (648_000.0 / PI)`), and the result
+//!   is checked at compile time to be finite and positive
+//! - `ratio_num = 1, ratio_den = 3600` (instead of `ratio`): the ratio as an exact integer fraction; sets
+//!   `RATIO_NUM`/`RATIO_DEN` and derives `RATIO` from them. `ratio_den` defaults to `1`.
+//! - `long_name = "kilometre"` (optional): human-readable name; defaults to the type name split into lowercase words
+//! - `siunitx = r"\kilo\metre"` (optional): `siunitx` unit macro used by LaTeX output
 
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
@@ -34,7 +41,8 @@ use syn::{
 
 /// Derive `crate::Unit` and a `Display` impl for `crate::Quantity<ThisUnit>`.
 ///
-/// The derive must be paired with a `#[unit(...)]` attribute providing `symbol`, `dimension`, and `ratio`.
+/// The derive must be paired with a `#[unit(...)]` attribute providing `symbol`, `dimension`, and either `ratio` or
+/// `ratio_num` (with an optional `ratio_den`).
 ///
 /// This macro is intended for use by `qtty-core`.
 #[proc_macro_derive(Unit, attributes(unit))]
@@ -55,18 +63,43 @@ fn derive_unit_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
 
     let symbol = &unit_attr.symbol;
     let dimension = &unit_attr.dimension;
-    let ratio = &unit_attr.ratio;
+    let ratio = match &unit_attr.ratio {
+        Ratio::Float(ratio) => quote! { const RATIO: f64 = #ratio; },
+        Ratio::Exact { num, den } => quote! {
+            const RATIO_NUM: u64 = #num;
+            const RATIO_DEN: u64 = #den;
+            const RATIO: f64 = Self::RATIO_NUM as f64 / Self::RATIO_DEN as f64;
+        },
+    };
+    let long_name = unit_attr
+        .long_name
+        .unwrap_or_else(|| LitStr::new(&default_long_name(&name.to_string()), name.span()));
+    let siunitx = unit_attr
+        .siunitx
+        .map(|s| quote! { const SIUNITX: &'static str = #s; });
 
     let expanded = quote! {
         impl crate::Unit for #name {
-            const RATIO: f64 = #ratio;
+            #ratio
             type Dim = #dimension;
             const SYMBOL: &'static str = #symbol;
+            const LONG_NAME: &'static str = #long_name;
+            #siunitx
         }
 
+        const _: () = ::core::assert!(
+            <#name as crate::Unit>::RATIO.is_finite() && <#name as crate::Unit>::RATIO > 0.0,
+            ::core::concat!("the ratio of `", ::core::stringify!(#name), "` must be finite and positive"),
+        );
+
         impl ::core::fmt::Display for crate::Quantity<#name> {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                write!(f, "{} {}", self.value(), <#name as crate::Unit>::SYMBOL)
+                ::core::fmt::Display::fmt(&self.value(), f)?;
+                if f.alternate() {
+                    write!(f, " {}", <#name as crate::Unit>::LONG_NAME)
+                } else {
+                    write!(f, " {}", <#name as crate::Unit>::SYMBOL)
+                }
             }
         }
     };
@@ -74,13 +107,20 @@ fn derive_unit_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
     Ok(expanded)
 }
 
+/// Conversion ratio given either as an `f64` expression or as an exact integer fraction.
+enum Ratio {
+    Float(Expr),
+    Exact { num: Expr, den: Expr },
+}
+
 /// Parsed contents of the `#[unit(...)]` attribute.
 struct UnitAttribute {
     symbol: LitStr,
     dimension: Expr,
-    ratio: Expr,
+    ratio: Ratio,
+    long_name: Option<LitStr>,
+    siunitx: Option<LitStr>,
     // Future extensions:
-    // long_name: Option<LitStr>,
     // plural: Option<LitStr>,
     // system: Option<LitStr>,
     // base_unit: Option<bool>,
@@ -92,6 +132,10 @@ impl Parse for UnitAttribute {
         let mut symbol: Option<LitStr> = None;
         let mut dimension: Option<Expr> = None;
         let mut ratio: Option<Expr> = None;
+        let mut ratio_num: Option<Expr> = None;
+        let mut ratio_den: Option<Expr> = None;
+        let mut long_name: Option<LitStr> = None;
+        let mut siunitx: Option<LitStr> = None;
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
@@ -107,8 +151,19 @@ impl Parse for UnitAttribute {
                 "ratio" => {
                     ratio = Some(input.parse()?);
                 }
+                "ratio_num" => {
+                    ratio_num = Some(input.parse()?);
+                }
+                "ratio_den" => {
+                    ratio_den = Some(input.parse()?);
+                }
+                "long_name" => {
+                    long_name = Some(input.parse()?);
+                }
+                "siunitx" => {
+                    siunitx = Some(input.parse()?);
+                }
                 // Future extensions would be handled here:
-                // "long_name" => { ... }
                 // "plural" => { ... }
                 // "system" => { ... }
                 // "base_unit" => { ... }
@@ -132,17 +187,54 @@ impl Parse for UnitAttribute {
         let dimension = dimension.ok_or_else(|| {
             syn::Error::new(input.span(), "missing required attribute `dimension`")
         })?;
-        let ratio = ratio
-            .ok_or_else(|| syn::Error::new(input.span(), "missing required attribute `ratio`"))?;
+        let ratio = match (ratio, ratio_num, ratio_den) {
+            (Some(ratio), None, None) => Ratio::Float(ratio),
+            (None, Some(num), den) => Ratio::Exact {
+                num,
+                den: den.unwrap_or_else(|| syn::parse_quote!(1)),
+            },
+            (None, None, Some(_)) => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`ratio_den` requires `ratio_num`",
+                ))
+            }
+            (Some(_), _, _) => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`ratio` and `ratio_num`/`ratio_den` are mutually exclusive",
+                ))
+            }
+            (None, None, None) => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "missing required attribute `ratio`",
+                ))
+            }
+        };
 
         Ok(UnitAttribute {
             symbol,
             dimension,
             ratio,
+            long_name,
+            siunitx,
         })
     }
 }
 
+/// Split a `CamelCase` type name into lowercase words (`AstronomicalUnit` → `"astronomical unit"`).
+fn default_long_name(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len() + 4);
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push(' ');
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
 fn parse_unit_attribute(attrs: &[Attribute]) -> syn::Result<UnitAttribute> {
     for attr in attrs {
         if attr.path().is_ident("unit") {
@@ -173,6 +265,20 @@ mod tests {
         assert_eq!(attr.symbol.value(), "m");
     }
 
+    #[test]
+    fn test_expansion_checks_ratio_at_compile_time() {
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "pc", dimension = Length, ratio = AstronomicalUnit::RATIO * (648_000.0 / PI))]
+            pub struct Parsec;
+        };
+
+        let expanded = derive_unit_impl(input).unwrap().to_string();
+        assert!(
+            expanded.contains("const RATIO : f64 = AstronomicalUnit :: RATIO * (648_000.0 / PI)")
+        );
+        assert!(expanded.contains("must be finite and positive"));
+    }
+
     #[test]
     fn test_parse_unit_attribute_missing() {
         let input: DeriveInput = parse_quote! {
@@ -257,6 +363,40 @@ mod tests {
         assert!(code.contains("const RATIO : f64 = 1.0"));
         assert!(code.contains("const SYMBOL : & 'static str = \"m\""));
         assert!(code.contains("type Dim = Length"));
+        assert!(code.contains("const LONG_NAME : & 'static str = \"meter\""));
+        assert!(code.contains("f . alternate ()"));
+    }
+
+    #[test]
+    fn test_parse_unit_attribute_long_name() {
+        let tokens = quote! {
+            symbol = "km", dimension = Length, ratio = 1000.0, long_name = "kilometre"
+        };
+        let attr: UnitAttribute = syn::parse2(tokens).unwrap();
+        assert_eq!(attr.long_name.unwrap().value(), "kilometre");
+    }
+
+    #[test]
+    fn test_derive_unit_impl_siunitx() {
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "km", dimension = Length, ratio = 1000.0, siunitx = r"\kilo\metre")]
+            pub enum Kilometer {}
+        };
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("const SIUNITX : & 'static str = r\"\\kilo\\metre\""));
+
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "m", dimension = Length, ratio = 1.0)]
+            pub enum Meter {}
+        };
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(!code.contains("SIUNITX"));
+    }
+
+    #[test]
+    fn test_default_long_name_splits_camel_case() {
+        assert_eq!(default_long_name("Meter"), "meter");
+        assert_eq!(default_long_name("AstronomicalUnit"), "astronomical unit");
     }
 
     #[test]
@@ -273,6 +413,43 @@ mod tests {
         assert!(code.contains("const RATIO : f64 = 1000.0"));
     }
 
+    #[test]
+    fn test_derive_unit_impl_exact_ratio() {
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "Arcs", dimension = Angular, ratio_num = 1, ratio_den = 3_600)]
+            pub enum Arcsecond {}
+        };
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code
+            .contains("const RATIO : f64 = Self :: RATIO_NUM as f64 / Self :: RATIO_DEN as f64"));
+        assert!(code.contains("const RATIO_NUM : u64 = 1"));
+        assert!(code.contains("const RATIO_DEN : u64 = 3_600"));
+
+        let attr: UnitAttribute =
+            syn::parse2(quote! { symbol = "h", dimension = Time, ratio_num = 3_600 }).unwrap();
+        assert!(matches!(attr.ratio, Ratio::Exact { .. }));
+    }
+
+    #[test]
+    fn test_exact_ratio_conflicts() {
+        let both = syn::parse2::<UnitAttribute>(quote! {
+            symbol = "h", dimension = Time, ratio = 3600.0, ratio_num = 3_600
+        });
+        assert!(both
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("mutually exclusive"));
+        let den_only = syn::parse2::<UnitAttribute>(quote! {
+            symbol = "h", dimension = Time, ratio_den = 2
+        });
+        assert!(den_only
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("requires `ratio_num`"));
+    }
+
     #[test]
     fn test_unit_attribute_parse_with_trailing_comma() {
         let tokens = quote! {
@@ -1,205 +1,13 @@
 use std::env;
-use std::fs;
 use std::path::PathBuf;
 
 fn main() {
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let out_dir = env::var("OUT_DIR").unwrap();
 
-    // Re-run if units.csv changes
-    println!("cargo:rerun-if-changed=units.csv");
-
-    // Parse units from CSV
-    let units = parse_units_csv(&crate_dir);
-
-    // Generate code files
-    generate_unit_enum(&units, &out_dir);
-    generate_unit_names(&units, &out_dir);
-    generate_unit_names_cstr(&units, &out_dir);
-    generate_unit_symbols(&units, &out_dir);
-    generate_from_u32(&units, &out_dir);
-    generate_registry(&units, &out_dir);
-
-    eprintln!(
-        "cargo:warning=Generated FFI bindings for {} units from units.csv",
-        units.len()
-    );
-
-    // Generate C header (existing functionality)
+    // The unit table lives in `src/types.rs` (`define_unit_ids!`); the build script only renders the C header.
     generate_c_header(&crate_dir);
 }
 
-#[derive(Debug, Clone)]
-struct UnitDef {
-    name: String,
-    symbol: String,
-    dimension: String,
-    discriminant: u32,
-    ratio: String,
-}
-
-fn parse_units_csv(crate_dir: &str) -> Vec<UnitDef> {
-    let csv_path = PathBuf::from(crate_dir).join("units.csv");
-    let content = fs::read_to_string(&csv_path).expect("Failed to read units.csv");
-
-    let mut units = Vec::new();
-
-    for line in content.lines() {
-        let line = line.trim();
-
-        // Skip comments and empty lines
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-
-        let parts: Vec<&str> = line.split(',').collect();
-        if parts.len() != 5 {
-            eprintln!("cargo:warning=Skipping invalid line: {}", line);
-            continue;
-        }
-
-        units.push(UnitDef {
-            discriminant: parts[0]
-                .parse()
-                .unwrap_or_else(|_| panic!("Invalid discriminant: {}", parts[0])),
-            dimension: parts[1].to_string(),
-            name: parts[2].to_string(),
-            symbol: parts[3].to_string(),
-            ratio: parts[4].to_string(),
-        });
-    }
-
-    units
-}
-
-fn generate_unit_enum(units: &[UnitDef], out_dir: &str) {
-    let mut code = String::from("// Auto-generated from units.csv\n");
-    code.push_str("/// Unit identifier for FFI.\n");
-    code.push_str("///\n");
-    code.push_str("/// Each variant corresponds to a specific unit supported by the FFI layer.\n");
-    code.push_str(
-        "/// All discriminant values are explicitly assigned and are part of the ABI contract.\n",
-    );
-    code.push_str("#[repr(u32)]\n");
-    code.push_str("#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]\n");
-    code.push_str(
-        "#[cfg_attr(feature = \"python\", pyo3::pyclass(eq, eq_int, module = \"qtty\"))]\n",
-    );
-    code.push_str("pub enum UnitId {\n");
-
-    for unit in units {
-        code.push_str(&format!("    /// {} ({})\n", unit.name, unit.symbol));
-        code.push_str(&format!("    {} = {},\n", unit.name, unit.discriminant));
-    }
-
-    code.push_str("}\n\n");
-
-    // Add pickle support methods when python feature is enabled
-    code.push_str("#[cfg(feature = \"python\")]\n");
-    code.push_str("#[pyo3::pymethods]\n");
-    code.push_str("impl UnitId {\n");
-    code.push_str("    #[new]\n");
-    code.push_str("    fn __new__(value: u32) -> pyo3::PyResult<Self> {\n");
-    code.push_str("        Self::from_u32(value).ok_or_else(|| {\n");
-    code.push_str("            pyo3::exceptions::PyValueError::new_err(format!(\"Invalid UnitId: {}\", value))\n");
-    code.push_str("        })\n");
-    code.push_str("    }\n");
-    code.push_str("    \n");
-    code.push_str("    fn __getnewargs__(&self) -> (u32,) {\n");
-    code.push_str("        (*self as u32,)\n");
-    code.push_str("    }\n");
-    code.push_str("}\n");
-
-    let dest_path = PathBuf::from(out_dir).join("unit_id_enum.rs");
-    fs::write(&dest_path, code).expect("Failed to write unit_id_enum.rs");
-}
-
-fn generate_unit_names(units: &[UnitDef], out_dir: &str) {
-    let mut code = String::from("// Auto-generated from units.csv\n");
-    code.push_str("match self {\n");
-
-    for unit in units {
-        code.push_str(&format!(
-            "    UnitId::{} => \"{}\",\n",
-            unit.name, unit.name
-        ));
-    }
-
-    code.push_str("}\n");
-
-    let dest_path = PathBuf::from(out_dir).join("unit_names.rs");
-    fs::write(&dest_path, code).expect("Failed to write unit_names.rs");
-}
-
-fn generate_unit_names_cstr(units: &[UnitDef], out_dir: &str) {
-    let mut code = String::from("// Auto-generated from units.csv\n");
-    code.push_str("match self {\n");
-
-    for unit in units {
-        code.push_str(&format!(
-            "    UnitId::{} => c\"{}\".as_ptr(),\n",
-            unit.name, unit.name
-        ));
-    }
-
-    code.push_str("}\n");
-
-    let dest_path = PathBuf::from(out_dir).join("unit_names_cstr.rs");
-    fs::write(&dest_path, code).expect("Failed to write unit_names_cstr.rs");
-}
-
-fn generate_unit_symbols(units: &[UnitDef], out_dir: &str) {
-    let mut code = String::from("// Auto-generated from units.csv\n");
-    code.push_str("match self {\n");
-
-    for unit in units {
-        code.push_str(&format!(
-            "    UnitId::{} => \"{}\",\n",
-            unit.name, unit.symbol
-        ));
-    }
-
-    code.push_str("}\n");
-
-    let dest_path = PathBuf::from(out_dir).join("unit_symbols.rs");
-    fs::write(&dest_path, code).expect("Failed to write unit_symbols.rs");
-}
-
-fn generate_from_u32(units: &[UnitDef], out_dir: &str) {
-    let mut code = String::from("// Auto-generated from units.csv\n");
-    code.push_str("match value {\n");
-
-    for unit in units {
-        code.push_str(&format!(
-            "    {} => Some(UnitId::{}),\n",
-            unit.discriminant, unit.name
-        ));
-    }
-
-    code.push_str("    _ => None,\n}\n");
-
-    let dest_path = PathBuf::from(out_dir).join("unit_from_u32.rs");
-    fs::write(&dest_path, code).expect("Failed to write unit_from_u32.rs");
-}
-
-fn generate_registry(units: &[UnitDef], out_dir: &str) {
-    let mut code = String::from("// Auto-generated from units.csv\n");
-    code.push_str("match id {\n");
-
-    for unit in units {
-        code.push_str(&format!("    UnitId::{} => Some(UnitMeta {{\n", unit.name));
-        code.push_str(&format!("        dim: DimensionId::{},\n", unit.dimension));
-        code.push_str(&format!("        scale_to_canonical: {},\n", unit.ratio));
-        code.push_str(&format!("        name: \"{}\",\n", unit.name));
-        code.push_str("    }),\n");
-    }
-
-    code.push_str("}\n");
-
-    let dest_path = PathBuf::from(out_dir).join("unit_registry.rs");
-    fs::write(&dest_path, code).expect("Failed to write unit_registry.rs");
-}
-
 fn generate_c_header(crate_dir: &str) {
     if env::var("DOCS_RS").is_ok() {
         return;
@@ -33,7 +33,8 @@
  *
  * # Thread Safety
  *
- * All functions are thread-safe. The library contains no global mutable state.
+ * All functions are thread-safe. The only mutable state is the per-thread
+ * last-error message returned by qtty_last_error_message().
  *
  * # ABI Stability
  *
@@ -78,6 +79,14 @@
  */
 #define QTTY_ERR_INVALID_VALUE -4
 
+/*
+ ABI version this header and library were built for.
+
+ Incremented whenever an existing discriminant, status code, struct layout or function signature changes.
+ Dynamically loading consumers compare it against `qtty_abi_version()` before trusting the discriminant tables.
+ */
+#define QTTY_ABI_VERSION 1
+
 /*
  Dimension identifier for FFI.
 
@@ -218,6 +227,10 @@ enum UnitId
    Yottameter (Ym)
    */
   UNIT_ID_YOTTAMETER = 10021,
+  /*
+   Angstrom (Å)
+   */
+  UNIT_ID_ANGSTROM = 10022,
   /*
    BohrRadius (a₀)
    */
@@ -906,16 +919,217 @@ int32_t qtty_quantity_convert_value(double value,
  */
 const char *qtty_unit_name(UnitId unit);
 
+/*
+ Builds an angle in degrees from sign and magnitude **DMS** components.
+
+ Mirrors `Degrees::from_dms_sign`: `sign < 0` makes the whole angle negative, any other
+ value keeps it positive, so `-0° 30′` is `(-1, 0, 30, 0.0)`.
+
+ # Arguments
+
+ * `sign` - Sign of the angle (negative for south/west)
+ * `degrees`, `arcminutes`, `arcseconds` - Magnitude components
+ * `out` - Pointer to store the resulting `UnitId::Degree` quantity
+
+ # Returns
+
+ * `QTTY_OK` on success
+ * `QTTY_ERR_NULL_OUT` if `out` is null
+ * `QTTY_ERR_INVALID_VALUE` if `arcminutes >= 60` or `arcseconds` is outside `[0, 60)`
+
+ # Safety
+
+ The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+ or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_from_dms(int32_t sign,
+                            uint32_t degrees,
+                            uint32_t arcminutes,
+                            double arcseconds,
+                            qtty_quantity_t *out);
+
+/*
+ Splits an angle into sign and magnitude **DMS** components.
+
+ The angle may be in any angle unit. `sign` is `-1` or `+1`, arcminutes are `< 60` and
+ arcseconds are in `[0, 60)`; carries are exact, so the parts fed back into
+ [`qtty_angle_from_dms`] reproduce the angle. No rounding is applied.
+
+ # Returns
+
+ * `QTTY_OK` on success
+ * `QTTY_ERR_NULL_OUT` if any output pointer is null
+ * `QTTY_ERR_UNKNOWN_UNIT` if the unit is not recognized
+ * `QTTY_ERR_INCOMPATIBLE_DIM` if `angle` is not an angle
+
+ # Safety
+
+ The caller must ensure that every output pointer points to valid, writable memory of its
+ type, or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_to_dms(qtty_quantity_t angle,
+                          int32_t *out_sign,
+                          uint32_t *out_degrees,
+                          uint32_t *out_arcminutes,
+                          double *out_arcseconds);
+
+/*
+ Builds an angle in hours of right ascension from sign and magnitude **HMS** components.
+
+ Mirrors `HourAngles::from_hms_sign`; the sign convention is that of [`qtty_angle_from_dms`].
+
+ # Returns
+
+ * `QTTY_OK` on success
+ * `QTTY_ERR_NULL_OUT` if `out` is null
+ * `QTTY_ERR_INVALID_VALUE` if `minutes >= 60` or `seconds` is outside `[0, 60)`
+
+ # Safety
+
+ The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+ or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_from_hms(int32_t sign,
+                            uint32_t hours,
+                            uint32_t minutes,
+                            double seconds,
+                            qtty_quantity_t *out);
+
+/*
+ Splits an angle into sign and magnitude **HMS** components of hour angle.
+
+ Conventions and status codes as for [`qtty_angle_to_dms`].
+
+ # Safety
+
+ The caller must ensure that every output pointer points to valid, writable memory of its
+ type, or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_to_hms(qtty_quantity_t angle,
+                          int32_t *out_sign,
+                          uint32_t *out_hours,
+                          uint32_t *out_minutes,
+                          double *out_seconds);
+
+/*
+ Wraps an angle into `[0, FULL_TURN)` of its own unit (`[0, 360)` for degrees).
+
+ Same result as `Quantity::wrap_pos` on the matching `qtty` unit type.
+
+ # Arguments
+
+ * `angle` - The angle to wrap, in any angle unit
+ * `out` - Pointer to store the wrapped angle (same unit as `angle`)
+
+ # Returns
+
+ * `QTTY_OK` on success
+ * `QTTY_ERR_NULL_OUT` if `out` is null
+ * `QTTY_ERR_UNKNOWN_UNIT` if the unit is not recognized
+ * `QTTY_ERR_INCOMPATIBLE_DIM` if `angle` is not an angle
+
+ # Safety
+
+ The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+ or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_wrap_pos(qtty_quantity_t angle,
+                            qtty_quantity_t *out);
+
+/*
+ Wraps an angle into `(-HALF_TURN, HALF_TURN]` of its own unit (`(-180, 180]` for degrees).
+
+ Same result as `Quantity::wrap_signed` on the matching `qtty` unit type. Arguments and
+ status codes as for [`qtty_angle_wrap_pos`].
+
+ # Safety
+
+ The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+ or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_wrap_signed(qtty_quantity_t angle,
+                               qtty_quantity_t *out);
+
+/*
+ Computes the smallest angular separation between two angles, in `[0, HALF_TURN]`.
+
+ `b` is converted into the unit of `a` first, and the result is expressed in the unit of `a`.
+ Same result as `Quantity::abs_separation` on the matching `qtty` unit type.
+
+ # Returns
+
+ * `QTTY_OK` on success
+ * `QTTY_ERR_NULL_OUT` if `out` is null
+ * `QTTY_ERR_UNKNOWN_UNIT` if either unit is not recognized
+ * `QTTY_ERR_INCOMPATIBLE_DIM` if either quantity is not an angle
+
+ # Safety
+
+ The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+ or is null (in which case an error is returned).
+ */
+int32_t qtty_angle_separation(qtty_quantity_t a,
+                              qtty_quantity_t b,
+                              qtty_quantity_t *out);
+
+/*
+ Returns the detail message of the last failed call on the calling thread.
+
+ Messages name the failing function and its offending argument, e.g.
+ `"qtty_quantity_convert: cannot convert Meter (Length) to Second (Time): the dimensions differ"`.
+ Successful calls leave the message in place; use [`qtty_clear_error`] to reset it.
+
+ # Returns
+
+ A pointer to a NUL-terminated C string, or a null pointer if no error has been recorded
+ on this thread (or it was cleared).
+
+ # Safety
+
+ The returned string is owned by the library and is thread-local. It stays valid until the
+ next failing call or [`qtty_clear_error`] on the same thread; copy it to keep it longer.
+ The caller must not free or modify it.
+ */
+const char *qtty_last_error_message(void);
+
+/*
+ Clears the last error message of the calling thread.
+ */
+void qtty_clear_error(void);
+
 /*
  Returns the FFI ABI version.
 
  This can be used by consumers to verify compatibility. The version is
  incremented when breaking changes are made to the ABI.
 
- Current version: 1
+ Same value as [`qtty_abi_version`], kept for existing callers.
  */
 uint32_t qtty_ffi_version(void);
 
+/*
+ Returns the ABI version of the loaded library.
+
+ Compare against the `QTTY_ABI_VERSION` constant of the header the caller was compiled
+ with: a mismatch means unit/dimension discriminants, status codes or struct layouts may
+ differ, and the library must not be used.
+ */
+uint32_t qtty_abi_version(void);
+
+/*
+ Returns the semantic version of the `qtty-ffi` crate the library was built from.
+
+ # Returns
+
+ A pointer to a static, NUL-terminated C string such as `"0.2.1"`.
+
+ # Safety
+
+ The returned pointer points to static memory and is valid for the lifetime
+ of the program. The caller must not attempt to free or modify the returned string.
+ */
+const char *qtty_crate_version(void);
+
 #ifdef __cplusplus
 }  // extern "C"
 #endif  // __cplusplus
@@ -0,0 +1,104 @@
+//! Per-thread detail message for the last failed FFI call.
+//!
+//! Status codes say *what* went wrong; the message recorded here says *where* (which argument, which unit ID,
+//! which dimensions). Every entry point records a message before returning an error code, and leaves the previous
+//! message untouched on success, so callers can check the status first and fetch the detail afterwards.
+
+use crate::registry;
+use crate::types::{
+    UnitId, QTTY_ERR_INCOMPATIBLE_DIM, QTTY_ERR_INVALID_VALUE, QTTY_ERR_NULL_OUT,
+    QTTY_ERR_UNKNOWN_UNIT,
+};
+use core::ffi::c_char;
+use std::cell::RefCell;
+use std::ffi::CString;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records `message` as the calling thread's last error.
+pub(crate) fn set(message: String) {
+    // Interior NULs would truncate the C string; drop them rather than lose the message.
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(message));
+}
+
+/// Clears the calling thread's last error.
+pub(crate) fn clear() {
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = None);
+}
+
+/// Pointer to the calling thread's last error message, or null if there is none.
+pub(crate) fn message_ptr() -> *const c_char {
+    LAST_ERROR.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .map_or(core::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// The calling thread's last error message, if any (Rust-side accessor, mainly for tests).
+pub fn last_error_message() -> Option<String> {
+    LAST_ERROR.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .map(|message| message.to_string_lossy().into_owned())
+    })
+}
+
+/// Records a null output pointer named `argument` and returns [`QTTY_ERR_NULL_OUT`].
+pub(crate) fn null_out(function: &str, argument: &str) -> i32 {
+    set(format!("{function}: output pointer `{argument}` is null"));
+    QTTY_ERR_NULL_OUT
+}
+
+/// Records an unregistered unit and returns [`QTTY_ERR_UNKNOWN_UNIT`].
+pub(crate) fn unknown_unit(function: &str, unit: UnitId) -> i32 {
+    set(format!(
+        "{function}: unit id {} is not registered",
+        unit as u32
+    ));
+    QTTY_ERR_UNKNOWN_UNIT
+}
+
+/// Records a failed `src` → `dst` conversion for the status `code` returned by the registry, and returns `code`.
+pub(crate) fn conversion(function: &str, code: i32, src: UnitId, dst: UnitId) -> i32 {
+    match (registry::dimension(src), registry::dimension(dst)) {
+        (Some(src_dim), Some(dst_dim)) if code == QTTY_ERR_INCOMPATIBLE_DIM => {
+            set(format!(
+                "{function}: cannot convert {} ({src_dim:?}) to {} ({dst_dim:?}): the dimensions differ",
+                src.name(),
+                dst.name()
+            ));
+            code
+        }
+        (None, _) => unknown_unit(function, src),
+        _ => unknown_unit(function, dst),
+    }
+}
+
+/// Records a quantity that should have been an angle and returns [`QTTY_ERR_INCOMPATIBLE_DIM`].
+pub(crate) fn not_angle(function: &str, unit: UnitId) -> i32 {
+    match registry::dimension(unit) {
+        Some(dim) => {
+            set(format!(
+                "{function}: {} ({dim:?}) is not an angle unit",
+                unit.name()
+            ));
+            QTTY_ERR_INCOMPATIBLE_DIM
+        }
+        None => unknown_unit(function, unit),
+    }
+}
+
+/// Records an out-of-range argument and returns [`QTTY_ERR_INVALID_VALUE`].
+pub(crate) fn invalid_value(function: &str, detail: &str) -> i32 {
+    set(format!("{function}: {detail}"));
+    QTTY_ERR_INVALID_VALUE
+}
+
+/// Records a panic caught inside `function`.
+pub(crate) fn panicked(function: &str) {
+    set(format!("{function}: internal panic"));
+}
@@ -17,23 +17,34 @@
 //! - `QTTY_ERR_INCOMPATIBLE_DIM` (-2): Units have different dimensions
 //! - `QTTY_ERR_NULL_OUT` (-3): Required output pointer was null
 //! - `QTTY_ERR_INVALID_VALUE` (-4): Invalid value (reserved)
+//!
+//! Every failing call also records a detail message for the calling thread, readable with
+//! [`qtty_last_error_message`].
 
+use crate::error;
 use crate::registry;
 use crate::types::{
-    DimensionId, QttyQuantity, UnitId, QTTY_ERR_NULL_OUT, QTTY_ERR_UNKNOWN_UNIT, QTTY_OK,
+    DimensionId, QttyQuantity, UnitId, QTTY_ABI_VERSION, QTTY_ERR_UNKNOWN_UNIT, QTTY_OK,
 };
 use core::ffi::c_char;
+use qtty::angular::{self, AngularUnit, Degrees, HourAngles};
+use qtty::Quantity;
 
 // =============================================================================
 // Helper macro to catch panics
 // =============================================================================
 
 /// Catches any panic and returns an error code instead of unwinding across FFI.
+///
+/// The panic is recorded as the thread's last error, attributed to `$function`.
 macro_rules! catch_panic {
-    ($default:expr, $body:expr) => {{
+    ($function:literal, $default:expr, $body:expr) => {{
         match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)) {
             Ok(result) => result,
-            Err(_) => $default,
+            Err(_) => {
+                error::panicked($function);
+                $default
+            }
         }
     }};
 }
@@ -57,7 +68,7 @@ macro_rules! catch_panic {
 /// This function is safe to call from any context.
 #[no_mangle]
 pub extern "C" fn qtty_unit_is_valid(unit: UnitId) -> bool {
-    catch_panic!(false, registry::meta(unit).is_some())
+    catch_panic!("qtty_unit_is_valid", false, registry::meta(unit).is_some())
 }
 
 /// Gets the dimension of a unit.
@@ -79,9 +90,9 @@ pub extern "C" fn qtty_unit_is_valid(unit: UnitId) -> bool {
 /// or is null (in which case an error is returned).
 #[no_mangle]
 pub unsafe extern "C" fn qtty_unit_dimension(unit: UnitId, out: *mut DimensionId) -> i32 {
-    catch_panic!(QTTY_ERR_UNKNOWN_UNIT, {
+    catch_panic!("qtty_unit_dimension", QTTY_ERR_UNKNOWN_UNIT, {
         if out.is_null() {
-            return QTTY_ERR_NULL_OUT;
+            return error::null_out("qtty_unit_dimension", "out");
         }
 
         match registry::dimension(unit) {
@@ -90,7 +101,7 @@ pub unsafe extern "C" fn qtty_unit_dimension(unit: UnitId, out: *mut DimensionId
                 unsafe { *out = dim };
                 QTTY_OK
             }
-            None => QTTY_ERR_UNKNOWN_UNIT,
+            None => error::unknown_unit("qtty_unit_dimension", unit),
         }
     })
 }
@@ -115,14 +126,16 @@ pub unsafe extern "C" fn qtty_unit_dimension(unit: UnitId, out: *mut DimensionId
 /// or is null (in which case an error is returned).
 #[no_mangle]
 pub unsafe extern "C" fn qtty_units_compatible(a: UnitId, b: UnitId, out: *mut bool) -> i32 {
-    catch_panic!(QTTY_ERR_UNKNOWN_UNIT, {
+    catch_panic!("qtty_units_compatible", QTTY_ERR_UNKNOWN_UNIT, {
         if out.is_null() {
-            return QTTY_ERR_NULL_OUT;
+            return error::null_out("qtty_units_compatible", "out");
         }
 
         // Validate both units exist
-        if registry::meta(a).is_none() || registry::meta(b).is_none() {
-            return QTTY_ERR_UNKNOWN_UNIT;
+        for unit in [a, b] {
+            if registry::meta(unit).is_none() {
+                return error::unknown_unit("qtty_units_compatible", unit);
+            }
         }
 
         // SAFETY: We checked that `out` is not null
@@ -159,14 +172,14 @@ pub unsafe extern "C" fn qtty_quantity_make(
     unit: UnitId,
     out: *mut QttyQuantity,
 ) -> i32 {
-    catch_panic!(QTTY_ERR_UNKNOWN_UNIT, {
+    catch_panic!("qtty_quantity_make", QTTY_ERR_UNKNOWN_UNIT, {
         if out.is_null() {
-            return QTTY_ERR_NULL_OUT;
+            return error::null_out("qtty_quantity_make", "out");
         }
 
         // Validate unit exists
         if registry::meta(unit).is_none() {
-            return QTTY_ERR_UNKNOWN_UNIT;
+            return error::unknown_unit("qtty_quantity_make", unit);
         }
 
         // SAFETY: We checked that `out` is not null
@@ -202,9 +215,9 @@ pub unsafe extern "C" fn qtty_quantity_convert(
     dst_unit: UnitId,
     out: *mut QttyQuantity,
 ) -> i32 {
-    catch_panic!(QTTY_ERR_UNKNOWN_UNIT, {
+    catch_panic!("qtty_quantity_convert", QTTY_ERR_UNKNOWN_UNIT, {
         if out.is_null() {
-            return QTTY_ERR_NULL_OUT;
+            return error::null_out("qtty_quantity_convert", "out");
         }
 
         match registry::convert_value(src.value, src.unit, dst_unit) {
@@ -215,7 +228,7 @@ pub unsafe extern "C" fn qtty_quantity_convert(
                 }
                 QTTY_OK
             }
-            Err(code) => code,
+            Err(code) => error::conversion("qtty_quantity_convert", code, src.unit, dst_unit),
         }
     })
 }
@@ -249,9 +262,9 @@ pub unsafe extern "C" fn qtty_quantity_convert_value(
     dst_unit: UnitId,
     out_value: *mut f64,
 ) -> i32 {
-    catch_panic!(QTTY_ERR_UNKNOWN_UNIT, {
+    catch_panic!("qtty_quantity_convert_value", QTTY_ERR_UNKNOWN_UNIT, {
         if out_value.is_null() {
-            return QTTY_ERR_NULL_OUT;
+            return error::null_out("qtty_quantity_convert_value", "out_value");
         }
 
         match registry::convert_value(value, src_unit, dst_unit) {
@@ -262,7 +275,7 @@ pub unsafe extern "C" fn qtty_quantity_convert_value(
                 }
                 QTTY_OK
             }
-            Err(code) => code,
+            Err(code) => error::conversion("qtty_quantity_convert_value", code, src_unit, dst_unit),
         }
     })
 }
@@ -284,15 +297,423 @@ pub unsafe extern "C" fn qtty_quantity_convert_value(
 /// of the program. The caller must not attempt to free or modify the returned string.
 #[no_mangle]
 pub extern "C" fn qtty_unit_name(unit: UnitId) -> *const c_char {
-    catch_panic!(core::ptr::null(), {
+    catch_panic!("qtty_unit_name", core::ptr::null(), {
         if registry::meta(unit).is_some() {
             unit.name_cstr()
         } else {
+            error::unknown_unit("qtty_unit_name", unit);
             core::ptr::null()
         }
     })
 }
 
+// =============================================================================
+// Sexagesimal Angles
+// =============================================================================
+
+/// Checks the minutes/seconds fields of a sexagesimal input.
+fn check_sexagesimal(function: &str, minutes: u32, seconds: f64) -> Result<(), i32> {
+    if minutes >= 60 {
+        return Err(error::invalid_value(function, "minutes must be < 60"));
+    }
+    if !(0.0..60.0).contains(&seconds) {
+        return Err(error::invalid_value(function, "seconds must be in [0, 60)"));
+    }
+    Ok(())
+}
+
+/// Writes the sexagesimal parts of `angle` through the four output pointers, named by `names` in error messages.
+///
+/// # Safety
+///
+/// Each pointer must be null or valid for writes.
+#[allow(clippy::too_many_arguments)]
+unsafe fn write_sexagesimal(
+    function: &str,
+    names: [&str; 4],
+    angle: QttyQuantity,
+    split: fn(Degrees) -> (i8, u32, u32, f64),
+    out_sign: *mut i32,
+    out_whole: *mut u32,
+    out_minutes: *mut u32,
+    out_seconds: *mut f64,
+) -> i32 {
+    let nulls = [
+        out_sign.is_null(),
+        out_whole.is_null(),
+        out_minutes.is_null(),
+        out_seconds.is_null(),
+    ];
+    if let Some(index) = nulls.iter().position(|&is_null| is_null) {
+        return error::null_out(function, names[index]);
+    }
+
+    match registry::convert_value(angle.value, angle.unit, UnitId::Degree) {
+        Ok(degrees) => {
+            let (sign, whole, minutes, seconds) = split(Degrees::new(degrees));
+            // SAFETY: We checked that every output pointer is not null
+            unsafe {
+                *out_sign = sign.into();
+                *out_whole = whole;
+                *out_minutes = minutes;
+                *out_seconds = seconds;
+            }
+            QTTY_OK
+        }
+        Err(code) => error::conversion(function, code, angle.unit, UnitId::Degree),
+    }
+}
+
+/// Builds an angle in degrees from sign and magnitude **DMS** components.
+///
+/// Mirrors `Degrees::from_dms_sign`: `sign < 0` makes the whole angle negative, any other
+/// value keeps it positive, so `-0° 30′` is `(-1, 0, 30, 0.0)`.
+///
+/// # Arguments
+///
+/// * `sign` - Sign of the angle (negative for south/west)
+/// * `degrees`, `arcminutes`, `arcseconds` - Magnitude components
+/// * `out` - Pointer to store the resulting `UnitId::Degree` quantity
+///
+/// # Returns
+///
+/// * `QTTY_OK` on success
+/// * `QTTY_ERR_NULL_OUT` if `out` is null
+/// * `QTTY_ERR_INVALID_VALUE` if `arcminutes >= 60` or `arcseconds` is outside `[0, 60)`
+///
+/// # Safety
+///
+/// The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+/// or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_from_dms(
+    sign: i32,
+    degrees: u32,
+    arcminutes: u32,
+    arcseconds: f64,
+    out: *mut QttyQuantity,
+) -> i32 {
+    catch_panic!("qtty_angle_from_dms", QTTY_ERR_UNKNOWN_UNIT, {
+        if out.is_null() {
+            return error::null_out("qtty_angle_from_dms", "out");
+        }
+        if let Err(code) = check_sexagesimal("qtty_angle_from_dms", arcminutes, arcseconds) {
+            return code;
+        }
+
+        let angle = Degrees::from_dms_sign(sign.signum() as i8, degrees, arcminutes, arcseconds);
+        // SAFETY: We checked that `out` is not null
+        unsafe {
+            *out = QttyQuantity::new(angle.value(), UnitId::Degree);
+        }
+        QTTY_OK
+    })
+}
+
+/// Splits an angle into sign and magnitude **DMS** components.
+///
+/// The angle may be in any angle unit. `sign` is `-1` or `+1`, arcminutes are `< 60` and
+/// arcseconds are in `[0, 60)`; carries are exact, so the parts fed back into
+/// [`qtty_angle_from_dms`] reproduce the angle. No rounding is applied.
+///
+/// # Returns
+///
+/// * `QTTY_OK` on success
+/// * `QTTY_ERR_NULL_OUT` if any output pointer is null
+/// * `QTTY_ERR_UNKNOWN_UNIT` if the unit is not recognized
+/// * `QTTY_ERR_INCOMPATIBLE_DIM` if `angle` is not an angle
+///
+/// # Safety
+///
+/// The caller must ensure that every output pointer points to valid, writable memory of its
+/// type, or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_to_dms(
+    angle: QttyQuantity,
+    out_sign: *mut i32,
+    out_degrees: *mut u32,
+    out_arcminutes: *mut u32,
+    out_arcseconds: *mut f64,
+) -> i32 {
+    catch_panic!("qtty_angle_to_dms", QTTY_ERR_UNKNOWN_UNIT, {
+        // SAFETY: forwarded caller guarantee
+        unsafe {
+            write_sexagesimal(
+                "qtty_angle_to_dms",
+                [
+                    "out_sign",
+                    "out_degrees",
+                    "out_arcminutes",
+                    "out_arcseconds",
+                ],
+                angle,
+                Degrees::dms_parts,
+                out_sign,
+                out_degrees,
+                out_arcminutes,
+                out_arcseconds,
+            )
+        }
+    })
+}
+
+/// Builds an angle in hours of right ascension from sign and magnitude **HMS** components.
+///
+/// Mirrors `HourAngles::from_hms_sign`; the sign convention is that of [`qtty_angle_from_dms`].
+///
+/// # Returns
+///
+/// * `QTTY_OK` on success
+/// * `QTTY_ERR_NULL_OUT` if `out` is null
+/// * `QTTY_ERR_INVALID_VALUE` if `minutes >= 60` or `seconds` is outside `[0, 60)`
+///
+/// # Safety
+///
+/// The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+/// or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_from_hms(
+    sign: i32,
+    hours: u32,
+    minutes: u32,
+    seconds: f64,
+    out: *mut QttyQuantity,
+) -> i32 {
+    catch_panic!("qtty_angle_from_hms", QTTY_ERR_UNKNOWN_UNIT, {
+        if out.is_null() {
+            return error::null_out("qtty_angle_from_hms", "out");
+        }
+        if let Err(code) = check_sexagesimal("qtty_angle_from_hms", minutes, seconds) {
+            return code;
+        }
+
+        let angle = HourAngles::from_hms_sign(sign.signum() as i8, hours, minutes, seconds);
+        // SAFETY: We checked that `out` is not null
+        unsafe {
+            *out = QttyQuantity::new(angle.value(), UnitId::HourAngle);
+        }
+        QTTY_OK
+    })
+}
+
+/// Splits an angle into sign and magnitude **HMS** components of hour angle.
+///
+/// Conventions and status codes as for [`qtty_angle_to_dms`].
+///
+/// # Safety
+///
+/// The caller must ensure that every output pointer points to valid, writable memory of its
+/// type, or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_to_hms(
+    angle: QttyQuantity,
+    out_sign: *mut i32,
+    out_hours: *mut u32,
+    out_minutes: *mut u32,
+    out_seconds: *mut f64,
+) -> i32 {
+    catch_panic!("qtty_angle_to_hms", QTTY_ERR_UNKNOWN_UNIT, {
+        // SAFETY: forwarded caller guarantee
+        unsafe {
+            write_sexagesimal(
+                "qtty_angle_to_hms",
+                ["out_sign", "out_hours", "out_minutes", "out_seconds"],
+                angle,
+                Degrees::hms_parts,
+                out_sign,
+                out_hours,
+                out_minutes,
+                out_seconds,
+            )
+        }
+    })
+}
+
+// =============================================================================
+// Angle Wrapping
+// =============================================================================
+
+/// Evaluates `$op::<U>(args)` for the `qtty` angle unit `U` behind `$unit`, or `None` if `$unit` is not an angle.
+macro_rules! with_angle_unit {
+    ($unit:expr, $op:ident($($arg:expr),*)) => {
+        match $unit {
+            UnitId::Milliradian => Some($op::<angular::Milliradian>($($arg),*)),
+            UnitId::Radian => Some($op::<angular::Radian>($($arg),*)),
+            UnitId::MicroArcsecond => Some($op::<angular::MicroArcsecond>($($arg),*)),
+            UnitId::MilliArcsecond => Some($op::<angular::MilliArcsecond>($($arg),*)),
+            UnitId::Arcsecond => Some($op::<angular::Arcsecond>($($arg),*)),
+            UnitId::Arcminute => Some($op::<angular::Arcminute>($($arg),*)),
+            UnitId::Degree => Some($op::<angular::Degree>($($arg),*)),
+            UnitId::Gradian => Some($op::<angular::Gradian>($($arg),*)),
+            UnitId::Turn => Some($op::<angular::Turn>($($arg),*)),
+            UnitId::HourAngle => Some($op::<angular::HourAngle>($($arg),*)),
+            _ => None,
+        }
+    };
+}
+
+fn wrap_pos_in<U: AngularUnit + Copy>(value: f64) -> f64 {
+    Quantity::<U>::new(value).wrap_pos().value()
+}
+
+fn wrap_signed_in<U: AngularUnit + Copy>(value: f64) -> f64 {
+    Quantity::<U>::new(value).wrap_signed().value()
+}
+
+fn separation_in<U: AngularUnit + Copy>(a: f64, b: f64) -> f64 {
+    Quantity::<U>::new(a)
+        .abs_separation(Quantity::new(b))
+        .value()
+}
+
+/// Wraps an angle into `[0, FULL_TURN)` of its own unit (`[0, 360)` for degrees).
+///
+/// Same result as `Quantity::wrap_pos` on the matching `qtty` unit type.
+///
+/// # Arguments
+///
+/// * `angle` - The angle to wrap, in any angle unit
+/// * `out` - Pointer to store the wrapped angle (same unit as `angle`)
+///
+/// # Returns
+///
+/// * `QTTY_OK` on success
+/// * `QTTY_ERR_NULL_OUT` if `out` is null
+/// * `QTTY_ERR_UNKNOWN_UNIT` if the unit is not recognized
+/// * `QTTY_ERR_INCOMPATIBLE_DIM` if `angle` is not an angle
+///
+/// # Safety
+///
+/// The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+/// or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_wrap_pos(angle: QttyQuantity, out: *mut QttyQuantity) -> i32 {
+    catch_panic!("qtty_angle_wrap_pos", QTTY_ERR_UNKNOWN_UNIT, {
+        if out.is_null() {
+            return error::null_out("qtty_angle_wrap_pos", "out");
+        }
+
+        match with_angle_unit!(angle.unit, wrap_pos_in(angle.value)) {
+            Some(value) => {
+                // SAFETY: We checked that `out` is not null
+                unsafe { *out = QttyQuantity::new(value, angle.unit) };
+                QTTY_OK
+            }
+            None => error::not_angle("qtty_angle_wrap_pos", angle.unit),
+        }
+    })
+}
+
+/// Wraps an angle into `(-HALF_TURN, HALF_TURN]` of its own unit (`(-180, 180]` for degrees).
+///
+/// Same result as `Quantity::wrap_signed` on the matching `qtty` unit type. Arguments and
+/// status codes as for [`qtty_angle_wrap_pos`].
+///
+/// # Safety
+///
+/// The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+/// or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_wrap_signed(
+    angle: QttyQuantity,
+    out: *mut QttyQuantity,
+) -> i32 {
+    catch_panic!("qtty_angle_wrap_signed", QTTY_ERR_UNKNOWN_UNIT, {
+        if out.is_null() {
+            return error::null_out("qtty_angle_wrap_signed", "out");
+        }
+
+        match with_angle_unit!(angle.unit, wrap_signed_in(angle.value)) {
+            Some(value) => {
+                // SAFETY: We checked that `out` is not null
+                unsafe { *out = QttyQuantity::new(value, angle.unit) };
+                QTTY_OK
+            }
+            None => error::not_angle("qtty_angle_wrap_signed", angle.unit),
+        }
+    })
+}
+
+/// Computes the smallest angular separation between two angles, in `[0, HALF_TURN]`.
+///
+/// `b` is converted into the unit of `a` first, and the result is expressed in the unit of `a`.
+/// Same result as `Quantity::abs_separation` on the matching `qtty` unit type.
+///
+/// # Returns
+///
+/// * `QTTY_OK` on success
+/// * `QTTY_ERR_NULL_OUT` if `out` is null
+/// * `QTTY_ERR_UNKNOWN_UNIT` if either unit is not recognized
+/// * `QTTY_ERR_INCOMPATIBLE_DIM` if either quantity is not an angle
+///
+/// # Safety
+///
+/// The caller must ensure that `out` points to valid, writable memory for a `QttyQuantity`,
+/// or is null (in which case an error is returned).
+#[no_mangle]
+pub unsafe extern "C" fn qtty_angle_separation(
+    a: QttyQuantity,
+    b: QttyQuantity,
+    out: *mut QttyQuantity,
+) -> i32 {
+    catch_panic!("qtty_angle_separation", QTTY_ERR_UNKNOWN_UNIT, {
+        if out.is_null() {
+            return error::null_out("qtty_angle_separation", "out");
+        }
+        if registry::dimension(a.unit) != Some(DimensionId::Angle) {
+            return error::not_angle("qtty_angle_separation", a.unit);
+        }
+        let b_value = match registry::convert_value(b.value, b.unit, a.unit) {
+            Ok(value) => value,
+            Err(code) => return error::conversion("qtty_angle_separation", code, b.unit, a.unit),
+        };
+
+        match with_angle_unit!(a.unit, separation_in(a.value, b_value)) {
+            Some(value) => {
+                // SAFETY: We checked that `out` is not null
+                unsafe { *out = QttyQuantity::new(value, a.unit) };
+                QTTY_OK
+            }
+            None => error::not_angle("qtty_angle_separation", a.unit),
+        }
+    })
+}
+
+// =============================================================================
+// Error Details
+// =============================================================================
+
+/// Returns the detail message of the last failed call on the calling thread.
+///
+/// Messages name the failing function and its offending argument, e.g.
+/// `"qtty_quantity_convert: cannot convert Meter (Length) to Second (Time): the dimensions differ"`.
+/// Successful calls leave the message in place; use [`qtty_clear_error`] to reset it.
+///
+/// # Returns
+///
+/// A pointer to a NUL-terminated C string, or a null pointer if no error has been recorded
+/// on this thread (or it was cleared).
+///
+/// # Safety
+///
+/// The returned string is owned by the library and is thread-local. It stays valid until the
+/// next failing call or [`qtty_clear_error`] on the same thread; copy it to keep it longer.
+/// The caller must not free or modify it.
+#[no_mangle]
+pub extern "C" fn qtty_last_error_message() -> *const c_char {
+    catch_panic!(
+        "qtty_last_error_message",
+        core::ptr::null(),
+        error::message_ptr()
+    )
+}
+
+/// Clears the last error message of the calling thread.
+#[no_mangle]
+pub extern "C" fn qtty_clear_error() {
+    catch_panic!("qtty_clear_error", (), error::clear())
+}
+
 // =============================================================================
 // Version Info
 // =============================================================================
@@ -302,16 +723,41 @@ pub extern "C" fn qtty_unit_name(unit: UnitId) -> *const c_char {
 /// This can be used by consumers to verify compatibility. The version is
 /// incremented when breaking changes are made to the ABI.
 ///
-/// Current version: 1
+/// Same value as [`qtty_abi_version`], kept for existing callers.
 #[no_mangle]
 pub extern "C" fn qtty_ffi_version() -> u32 {
-    1
+    QTTY_ABI_VERSION
+}
+
+/// Returns the ABI version of the loaded library.
+///
+/// Compare against the `QTTY_ABI_VERSION` constant of the header the caller was compiled
+/// with: a mismatch means unit/dimension discriminants, status codes or struct layouts may
+/// differ, and the library must not be used.
+#[no_mangle]
+pub extern "C" fn qtty_abi_version() -> u32 {
+    QTTY_ABI_VERSION
+}
+
+/// Returns the semantic version of the `qtty-ffi` crate the library was built from.
+///
+/// # Returns
+///
+/// A pointer to a static, NUL-terminated C string such as `"0.2.1"`.
+///
+/// # Safety
+///
+/// The returned pointer points to static memory and is valid for the lifetime
+/// of the program. The caller must not attempt to free or modify the returned string.
+#[no_mangle]
+pub extern "C" fn qtty_crate_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::QTTY_ERR_INCOMPATIBLE_DIM;
+    use crate::{QTTY_ERR_INCOMPATIBLE_DIM, QTTY_ERR_NULL_OUT};
     use approx::assert_relative_eq;
     use core::f64::consts::PI;
 
@@ -468,8 +914,171 @@ mod tests {
         assert_eq!(name.to_str().unwrap(), "Meter");
     }
 
+    fn last_error() -> String {
+        let ptr = qtty_last_error_message();
+        assert!(!ptr.is_null());
+        // SAFETY: non-null and owned by this thread's error slot until the next failing call
+        unsafe { std::ffi::CStr::from_ptr(ptr) }
+            .to_str()
+            .unwrap()
+            .to_owned()
+    }
+
+    #[test]
+    fn test_last_error_describes_failure() {
+        qtty_clear_error();
+        assert!(qtty_last_error_message().is_null());
+
+        let src = QttyQuantity::new(100.0, UnitId::Meter);
+        let mut dst = QttyQuantity::default();
+        let status = unsafe { qtty_quantity_convert(src, UnitId::Second, &mut dst) };
+        assert_eq!(status, QTTY_ERR_INCOMPATIBLE_DIM);
+        assert_eq!(
+            last_error(),
+            "qtty_quantity_convert: cannot convert Meter (Length) to Second (Time): the dimensions differ"
+        );
+
+        // Success leaves the message in place.
+        let status = unsafe { qtty_quantity_convert(src, UnitId::Kilometer, &mut dst) };
+        assert_eq!(status, QTTY_OK);
+        assert!(last_error().contains("Second (Time)"));
+
+        let status = unsafe { qtty_unit_dimension(UnitId::Meter, core::ptr::null_mut()) };
+        assert_eq!(status, QTTY_ERR_NULL_OUT);
+        assert_eq!(
+            last_error(),
+            "qtty_unit_dimension: output pointer `out` is null"
+        );
+
+        qtty_clear_error();
+        assert!(qtty_last_error_message().is_null());
+    }
+
+    #[test]
+    fn test_last_error_is_thread_local() {
+        let status = unsafe { qtty_quantity_make(1.0, UnitId::Meter, core::ptr::null_mut()) };
+        assert_eq!(status, QTTY_ERR_NULL_OUT);
+        std::thread::spawn(|| assert!(qtty_last_error_message().is_null()))
+            .join()
+            .unwrap();
+        assert!(last_error().starts_with("qtty_quantity_make"));
+    }
+
+    #[test]
+    fn test_angle_dms_round_trip() {
+        let mut q = QttyQuantity::default();
+        let status = unsafe { qtty_angle_from_dms(-1, 0, 30, 0.0, &mut q) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!(q, QttyQuantity::new(-0.5, UnitId::Degree));
+
+        let (mut sign, mut d, mut m, mut s) = (0, 0, 0, 0.0);
+        let rad = QttyQuantity::new(-0.5_f64.to_radians(), UnitId::Radian);
+        let status = unsafe { qtty_angle_to_dms(rad, &mut sign, &mut d, &mut m, &mut s) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!((sign, d, m), (-1, 0, 30));
+        assert_relative_eq!(s, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_angle_hms_round_trip() {
+        let mut q = QttyQuantity::default();
+        let status = unsafe { qtty_angle_from_hms(1, 5, 34, 30.0, &mut q) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!(q.unit, UnitId::HourAngle);
+
+        let deg = QttyQuantity::new(83.625, UnitId::Degree);
+        let (mut sign, mut h, mut m, mut s) = (0, 0, 0, 0.0);
+        let status = unsafe { qtty_angle_to_hms(deg, &mut sign, &mut h, &mut m, &mut s) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!((sign, h, m), (1, 5, 34));
+        assert_relative_eq!(s, 30.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_angle_sexagesimal_errors() {
+        let mut q = QttyQuantity::default();
+        let status = unsafe { qtty_angle_from_dms(1, 10, 60, 0.0, &mut q) };
+        assert_eq!(status, crate::QTTY_ERR_INVALID_VALUE);
+        let status = unsafe { qtty_angle_from_hms(1, 10, 0, 60.0, &mut q) };
+        assert_eq!(status, crate::QTTY_ERR_INVALID_VALUE);
+
+        let (mut sign, mut d, mut m, mut s) = (0, 0, 0, 0.0);
+        let length = QttyQuantity::new(1.0, UnitId::Meter);
+        let status = unsafe { qtty_angle_to_dms(length, &mut sign, &mut d, &mut m, &mut s) };
+        assert_eq!(status, QTTY_ERR_INCOMPATIBLE_DIM);
+        let angle = QttyQuantity::new(1.0, UnitId::Degree);
+        let status =
+            unsafe { qtty_angle_to_dms(angle, &mut sign, core::ptr::null_mut(), &mut m, &mut s) };
+        assert_eq!(status, QTTY_ERR_NULL_OUT);
+        assert_eq!(
+            last_error(),
+            "qtty_angle_to_dms: output pointer `out_degrees` is null"
+        );
+    }
+
+    #[test]
+    fn test_every_angle_unit_dispatches() {
+        for raw in 30_000..40_000 {
+            if let Some(unit) = UnitId::from_u32(raw) {
+                assert!(
+                    with_angle_unit!(unit, wrap_pos_in(1.0)).is_some(),
+                    "{unit:?} missing from with_angle_unit!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_angle_wrap() {
+        let mut q = QttyQuantity::default();
+        let status =
+            unsafe { qtty_angle_wrap_pos(QttyQuantity::new(-90.0, UnitId::Degree), &mut q) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!(q, QttyQuantity::new(270.0, UnitId::Degree));
+
+        let status =
+            unsafe { qtty_angle_wrap_signed(QttyQuantity::new(1.5 * PI, UnitId::Radian), &mut q) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!(q.unit, UnitId::Radian);
+        assert_relative_eq!(q.value, -0.5 * PI, epsilon = 1e-12);
+
+        let status =
+            unsafe { qtty_angle_wrap_signed(QttyQuantity::new(25.0, UnitId::HourAngle), &mut q) };
+        assert_eq!(status, QTTY_OK);
+        assert_relative_eq!(q.value, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_angle_separation() {
+        let mut q = QttyQuantity::default();
+        let a = QttyQuantity::new(350.0, UnitId::Degree);
+        let b = QttyQuantity::new(PI / 18.0, UnitId::Radian);
+        let status = unsafe { qtty_angle_separation(a, b, &mut q) };
+        assert_eq!(status, QTTY_OK);
+        assert_eq!(q.unit, UnitId::Degree);
+        assert_relative_eq!(q.value, 20.0, epsilon = 1e-9);
+
+        let length = QttyQuantity::new(1.0, UnitId::Meter);
+        let status = unsafe { qtty_angle_separation(a, length, &mut q) };
+        assert_eq!(status, QTTY_ERR_INCOMPATIBLE_DIM);
+        let status = unsafe { qtty_angle_wrap_pos(length, &mut q) };
+        assert_eq!(status, QTTY_ERR_INCOMPATIBLE_DIM);
+        assert_eq!(
+            last_error(),
+            "qtty_angle_wrap_pos: Meter (Length) is not an angle unit"
+        );
+    }
+
     #[test]
     fn test_ffi_version() {
         assert_eq!(qtty_ffi_version(), 1);
+        assert_eq!(qtty_abi_version(), QTTY_ABI_VERSION);
+    }
+
+    #[test]
+    fn test_crate_version() {
+        // SAFETY: points to a static NUL-terminated string
+        let version = unsafe { std::ffi::CStr::from_ptr(qtty_crate_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
     }
 }
@@ -0,0 +1,156 @@
+//! Golden conversion table shared with bindings in other languages.
+//!
+//! [`golden_json`] dumps every [`UnitId`] (discriminant, name, symbol, dimension, scale factor) together with a set
+//! of sample conversions computed by the `qtty` registry ([`RegisteredUnit::convert_to`], the exact-factor path
+//! typed code uses), not by the FFI under test. The result is checked in as `qtty-ffi/tests/golden/conversions.json`;
+//! `tests/golden.rs` verifies that the C header declares the same discriminants and that the exported functions
+//! reproduce every sample. Bindings for other languages can run their own conformance tests against the same file.
+//!
+//! This module only exists in test builds. Regenerate the file after adding or changing units with:
+//!
+//! ```text
+//! QTTY_BLESS=1 cargo test -p qtty-ffi --lib golden
+//! ```
+//!
+//! [`RegisteredUnit::convert_to`]: qtty::registry::RegisteredUnit::convert_to
+
+use crate::types::{UnitId, QTTY_ABI_VERSION};
+use core::fmt::Write;
+
+/// Relative tolerance consumers should allow when comparing sample results.
+const GOLDEN_RELATIVE_TOLERANCE: f64 = 1e-12;
+
+/// Input values converted for every unit in the sample set.
+const SAMPLE_VALUES: [f64; 3] = [1.0, -2.5, 1234.5678];
+
+/// Renders the golden table as pretty-printed JSON.
+///
+/// The output is deterministic: units appear in table order, and the samples convert every value in
+/// the sample values from each unit to the canonical unit of its dimension and to the next unit of the same dimension.
+fn golden_json() -> String {
+    let mut out = String::new();
+    out.push_str("{\n");
+    let _ = writeln!(out, "  \"abi_version\": {},", QTTY_ABI_VERSION);
+    let _ = writeln!(
+        out,
+        "  \"relative_tolerance\": {:?},",
+        GOLDEN_RELATIVE_TOLERANCE
+    );
+
+    out.push_str("  \"units\": [\n");
+    for (i, &unit) in UnitId::ALL.iter().enumerate() {
+        let meta = unit.meta();
+        let _ = write!(
+            out,
+            "    {{\"id\": {}, \"name\": {}, \"symbol\": {}, \"dimension\": \"{:?}\", \"scale_to_canonical\": {:?}}}",
+            unit as u32,
+            json_string(unit.name()),
+            json_string(unit.symbol()),
+            meta.dim,
+            meta.scale_to_canonical
+        );
+        out.push_str(if i + 1 < UnitId::ALL.len() {
+            ",\n"
+        } else {
+            "\n"
+        });
+    }
+    out.push_str("  ],\n");
+
+    let samples = samples();
+    out.push_str("  \"conversions\": [\n");
+    for (i, (value, src, dst, result)) in samples.iter().enumerate() {
+        let _ = write!(
+            out,
+            "    {{\"from\": {}, \"to\": {}, \"value\": {:?}, \"result\": {:?}}}",
+            json_string(src.name()),
+            json_string(dst.name()),
+            value,
+            result
+        );
+        out.push_str(if i + 1 < samples.len() { ",\n" } else { "\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+/// Sample conversions as `(value, src, dst, result)`.
+fn samples() -> Vec<(f64, UnitId, UnitId, f64)> {
+    let mut samples = Vec::new();
+    for (i, &src) in UnitId::ALL.iter().enumerate() {
+        let dim = src.meta().dim;
+        let canonical = UnitId::ALL
+            .iter()
+            .copied()
+            .find(|u| u.meta().dim == dim && u.meta().scale_to_canonical == 1.0);
+        let next = UnitId::ALL[i + 1..]
+            .iter()
+            .chain(&UnitId::ALL[..i])
+            .copied()
+            .find(|u| u.meta().dim == dim);
+        for dst in canonical.into_iter().chain(next) {
+            for value in SAMPLE_VALUES {
+                if let Some(result) = src.qtty_unit().convert_to(value, &dst.qtty_unit()) {
+                    samples.push((value, src, dst, result));
+                }
+            }
+        }
+    }
+    samples
+}
+
+/// Quotes `text` as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_golden_file_is_current() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/conversions.json");
+        let expected = golden_json();
+        if std::env::var_os("QTTY_BLESS").is_some() {
+            std::fs::write(&path, &expected).unwrap();
+        }
+        let actual = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            actual == expected,
+            "{} is stale; regenerate it with `QTTY_BLESS=1 cargo test -p qtty-ffi --lib golden`",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("µm"), "\"µm\"");
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\u000a\"");
+    }
+
+    #[test]
+    fn test_samples_cover_every_unit() {
+        let samples = samples();
+        for &unit in UnitId::ALL {
+            assert!(
+                samples.iter().any(|&(_, src, _, _)| src == unit),
+                "{unit:?}"
+            );
+        }
+    }
+}
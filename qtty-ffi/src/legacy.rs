@@ -0,0 +1,56 @@
+//! Frozen unit definitions kept for ABI compatibility.
+//!
+//! A few [`UnitId`](crate::UnitId) values predate the mapping of every ID onto a `qtty` unit type and were published
+//! with conversion factors that differ from the closest `qtty` unit. Changing the factor behind an existing ID would
+//! silently change results for C callers, so those IDs map onto the marker types below, which carry the original
+//! factors. `tests/golden.rs` pins every pre-existing ID against the original table.
+
+use qtty::length::Length;
+use qtty::time::Time;
+use qtty::Unit;
+
+macro_rules! frozen_unit {
+    ($(#[$doc:meta])* $name:ident, $symbol:literal, $dim:ty, $ratio:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        pub struct $name;
+
+        impl Unit for $name {
+            const RATIO: f64 = $ratio;
+            type Dim = $dim;
+            const SYMBOL: &'static str = $symbol;
+        }
+    };
+}
+
+frozen_unit!(
+    /// Decade of Julian years (`3 652.5 d`).
+    JulianDecade,
+    "dec",
+    Time,
+    10.0 * qtty::time::JulianYear::RATIO
+);
+
+frozen_unit!(
+    /// Mean synodic month, `29.530 59 d` (the FFI's original five-decimal value).
+    SynodicMonth,
+    "mo_s",
+    Time,
+    2_551_442.976
+);
+
+frozen_unit!(
+    /// WGS 84 meridional circumference, `40 007 862.917 m`.
+    EarthMeridionalCircumference,
+    "C_mer",
+    Length,
+    40_007_862.917
+);
+
+frozen_unit!(
+    /// WGS 84 equatorial circumference `2π·a`, `40 075 016.686 m`.
+    EarthEquatorialCircumference,
+    "C_eq",
+    Length,
+    40_075_016.686
+);
@@ -60,6 +60,9 @@
 //! New variants may be added to enums (with new discriminant values), and new functions
 //! may be added, but existing items will remain stable.
 //!
+//! Consumers that load the library at runtime should check [`qtty_abi_version`] against the
+//! [`QTTY_ABI_VERSION`] constant of their header; [`qtty_crate_version`] reports the crate release.
+//!
 //! # Supported Units (v1)
 //!
 //! ## Length
@@ -86,32 +89,49 @@
 //! - [`QTTY_ERR_NULL_OUT`] (-3): Null output pointer
 //! - [`QTTY_ERR_INVALID_VALUE`] (-4): Invalid value (reserved)
 //!
+//! A failing call also records a detail message (function, unit IDs, dimensions), read back with
+//! [`qtty_last_error_message`] and reset with [`qtty_clear_error`].
+//!
 //! # Thread Safety
 //!
-//! All functions are thread-safe. The library contains no global mutable state.
+//! All functions are thread-safe. The only mutable state is the per-thread last-error message.
 
 #![deny(missing_docs)]
 // PyO3 generated code contains unsafe operations, so we can't enforce this when python feature is enabled
 #![cfg_attr(not(feature = "python"), deny(unsafe_op_in_unsafe_fn))]
 
 // Core modules
+pub mod error;
 mod ffi;
+#[cfg(test)]
+mod golden;
 pub mod helpers;
+pub mod legacy;
 #[macro_use]
 pub mod macros;
 pub mod registry;
 mod types;
 
+/// Unit types the [`UnitId`] table maps onto: everything in `qtty` plus the frozen [`legacy`] units.
+mod units {
+    pub(crate) use crate::legacy;
+    pub(crate) use qtty::*;
+}
+
 // Re-export FFI functions
 pub use ffi::{
-    qtty_ffi_version, qtty_quantity_convert, qtty_quantity_convert_value, qtty_quantity_make,
-    qtty_unit_dimension, qtty_unit_is_valid, qtty_unit_name, qtty_units_compatible,
+    qtty_abi_version, qtty_angle_from_dms, qtty_angle_from_hms, qtty_angle_separation,
+    qtty_angle_to_dms, qtty_angle_to_hms, qtty_angle_wrap_pos, qtty_angle_wrap_signed,
+    qtty_clear_error, qtty_crate_version, qtty_ffi_version, qtty_last_error_message,
+    qtty_quantity_convert, qtty_quantity_convert_value, qtty_quantity_make, qtty_unit_dimension,
+    qtty_unit_is_valid, qtty_unit_name, qtty_units_compatible,
 };
 
 // Re-export types
 pub use types::{
-    DimensionId, QttyDerivedQuantity, QttyQuantity, UnitId, QTTY_ERR_INCOMPATIBLE_DIM,
-    QTTY_ERR_INVALID_VALUE, QTTY_ERR_NULL_OUT, QTTY_ERR_UNKNOWN_UNIT, QTTY_OK,
+    DimensionId, QttyDerivedQuantity, QttyQuantity, UnitId, QTTY_ABI_VERSION,
+    QTTY_ERR_INCOMPATIBLE_DIM, QTTY_ERR_INVALID_VALUE, QTTY_ERR_NULL_OUT, QTTY_ERR_UNKNOWN_UNIT,
+    QTTY_OK,
 };
 
 // The impl_unit_ffi! macro is automatically exported at crate root by #[macro_export]
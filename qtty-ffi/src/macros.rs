@@ -1,8 +1,8 @@
 //! Macros for implementing FFI conversions for qtty unit types.
 //!
 //! This module provides macros that make it easy to implement `From` and `TryFrom`
-//! conversions between `qtty` unit types and [`QttyQuantity`], as well as code generation
-//! macros for defining all FFI units in one place.
+//! conversions between `qtty` unit types and [`QttyQuantity`], as well as the macro that
+//! defines all FFI units in one place.
 //!
 //! # Example
 //!
@@ -22,43 +22,6 @@
 //! let quantity: QttyQuantity = meters.into();
 //! ```
 
-/// Internal helper macro for generating unit match arms in `UnitId` methods.
-#[doc(hidden)]
-#[macro_export]
-macro_rules! unit_match_arms {
-    ($(($variant:ident, $name:expr)),* $(,)?) => {
-        $(
-            UnitId::$variant => $name
-        ),*
-    };
-}
-
-/// Internal helper macro for generating unit `from_u32` match arms.
-#[doc(hidden)]
-#[macro_export]
-macro_rules! unit_from_u32_arms {
-    ($(($discriminant:expr, $variant:ident)),* $(,)?) => {
-        $(
-            $discriminant => Some(UnitId::$variant)
-        ),*
-    };
-}
-
-/// Internal helper macro for generating registry metadata match arms.
-#[doc(hidden)]
-#[macro_export]
-macro_rules! registry_match_arms {
-    ($(($variant:ident, $dim:ident, $scale:expr, $name:expr)),* $(,)?) => {
-        $(
-            UnitId::$variant => Some(UnitMeta {
-                dim: DimensionId::$dim,
-                scale_to_canonical: $scale,
-                name: $name,
-            })
-        ),*
-    };
-}
-
 /// Implements `From<$qty_type>` for `QttyQuantity` and `TryFrom<QttyQuantity>` for `$qty_type`.
 ///
 /// This macro generates bidirectional conversion implementations between a specific
@@ -119,3 +82,120 @@ macro_rules! impl_unit_ffi {
         }
     };
 }
+
+/// Defines the ABI-stable [`UnitId`](crate::UnitId) enum and its lookup methods from one table of
+/// `Name = discriminant, "symbol" => path::Type;` rows, where paths resolve against `qtty` and
+/// [`legacy`](crate::legacy).
+///
+/// Each row's dimension and scale factor are read from the `qtty` type (its `Unit::Dim` and `Unit::RATIO`), so a
+/// row naming a missing type, or a type whose dimension has no [`DimensionId`](crate::DimensionId), fails to compile.
+///
+/// The symbol is the one C callers see. It should be the type's `Unit::SYMBOL`; the published symbols that differ are
+/// listed in the `types` tests, which fail on any new mismatch.
+///
+/// The IDs live in this one table rather than in a `#[unit(ffi_id = N)]` attribute on each `#[derive(Unit)]`: a
+/// derive only sees its own item, so it cannot emit the single `#[repr(u32)]` enum that cbindgen turns into the C
+/// header, and the frozen [`legacy`](crate::legacy) types are not derived in `qtty` at all.
+macro_rules! define_unit_ids {
+    ($($name:ident = $discriminant:literal, $symbol:literal => $($path:ident)::+;)+) => {
+        /// Unit identifier for FFI.
+        ///
+        /// Each variant corresponds to a specific unit supported by the FFI layer.
+        /// All discriminant values are explicitly assigned and are part of the ABI contract.
+        #[repr(u32)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int, module = "qtty"))]
+        pub enum UnitId {
+            $(
+                #[doc = concat!(stringify!($name), " (", $symbol, ")")]
+                $name = $discriminant,
+            )+
+        }
+
+        #[cfg(feature = "python")]
+        #[pyo3::pymethods]
+        impl UnitId {
+            #[new]
+            fn __new__(value: u32) -> pyo3::PyResult<Self> {
+                Self::from_u32(value).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Invalid UnitId: {}", value))
+                })
+            }
+
+            fn __getnewargs__(&self) -> (u32,) {
+                (*self as u32,)
+            }
+        }
+
+        impl UnitId {
+            /// Every unit ID, in table (discriminant) order.
+            pub const ALL: &'static [UnitId] = &[$(UnitId::$name),+];
+
+            /// Returns the unit name as a static NUL-terminated C string.
+            ///
+            /// This is safe to call from C code and returns a pointer to static memory.
+            #[inline]
+            pub const fn name_cstr(&self) -> *const c_char {
+                match self {
+                    $(UnitId::$name => concat!(stringify!($name), "\0").as_ptr().cast(),)+
+                }
+            }
+
+            /// Returns the unit name as a Rust string slice.
+            #[inline]
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(UnitId::$name => stringify!($name),)+
+                }
+            }
+
+            /// Returns the unit symbol as a Rust string slice (e.g., "m", "km", "s").
+            #[inline]
+            pub const fn symbol(&self) -> &'static str {
+                match self {
+                    $(UnitId::$name => $symbol,)+
+                }
+            }
+
+            /// Attempts to create a `UnitId` from a raw `u32` discriminant value.
+            ///
+            /// Returns `None` if the value does not correspond to a valid unit.
+            #[inline]
+            pub const fn from_u32(value: u32) -> Option<Self> {
+                match value {
+                    $($discriminant => Some(UnitId::$name),)+
+                    _ => None,
+                }
+            }
+
+            /// The [`Unit::SYMBOL`](qtty::Unit::SYMBOL) of the unit's `qtty` type, which may differ from [`Self::symbol`].
+            #[cfg(test)]
+            pub(crate) const fn qtty_symbol(&self) -> &'static str {
+                match self {
+                    $(UnitId::$name => <$crate::units::$($path)::+ as qtty::Unit>::SYMBOL,)+
+                }
+            }
+
+            /// The `qtty` registry entry of the unit's type, built directly so that the [`legacy`](crate::legacy) units
+            /// (which `qtty` does not register) are covered too.
+            #[cfg(test)]
+            pub(crate) const fn qtty_unit(&self) -> qtty::registry::RegisteredUnit {
+                match self {
+                    $(UnitId::$name => qtty::registry::RegisteredUnit::of::<$crate::units::$($path)::+>(""),)+
+                }
+            }
+
+            /// Registry metadata taken from the unit's `qtty` type.
+            #[inline]
+            pub(crate) const fn meta(self) -> $crate::registry::UnitMeta {
+                match self {
+                    $(UnitId::$name => $crate::registry::UnitMeta {
+                        dim: <<$crate::units::$($path)::+ as qtty::Unit>::Dim as $crate::registry::FfiDimension>::ID,
+                        scale_to_canonical: <$crate::units::$($path)::+ as qtty::Unit>::RATIO,
+                        name: stringify!($name),
+                    },)+
+                }
+            }
+        }
+    };
+}
@@ -5,10 +5,15 @@
 //!
 //! # Conversion Formula
 //!
-//! Conversions use a canonical unit per dimension:
+//! Conversions use the canonical unit of each dimension in `qtty` (the unit with `Unit::RATIO == 1.0`):
 //! - Length: Meter
 //! - Time: Second
-//! - Angle: Radian
+//! - Angle: Degree
+//! - Mass: Gram
+//! - Power: Watt
+//!
+//! Every dimension and scale factor is read from the `qtty` unit type listed for the ID in the `UnitId` table, so the
+//! FFI registry and the Rust unit types share one set of ratios.
 //!
 //! The conversion formula is:
 //! ```text
@@ -44,6 +49,35 @@ pub struct UnitMeta {
     pub name: &'static str,
 }
 
+/// Maps a `qtty` dimension to its FFI [`DimensionId`].
+///
+/// Every unit in the `UnitId` table takes its dimension through this trait, so listing a unit whose dimension has no
+/// FFI counterpart is a compile error rather than a silently wrong registry entry.
+pub trait FfiDimension {
+    /// The FFI identifier of the dimension.
+    const ID: DimensionId;
+}
+
+impl FfiDimension for qtty::length::Length {
+    const ID: DimensionId = DimensionId::Length;
+}
+
+impl FfiDimension for qtty::time::Time {
+    const ID: DimensionId = DimensionId::Time;
+}
+
+impl FfiDimension for qtty::angular::Angular {
+    const ID: DimensionId = DimensionId::Angle;
+}
+
+impl FfiDimension for qtty::mass::Mass {
+    const ID: DimensionId = DimensionId::Mass;
+}
+
+impl FfiDimension for qtty::power::Power {
+    const ID: DimensionId = DimensionId::Power;
+}
+
 // =============================================================================
 // Registry Functions
 // =============================================================================
@@ -53,7 +87,7 @@ pub struct UnitMeta {
 /// Returns `None` if the unit ID is not recognized.
 #[inline]
 pub fn meta(id: UnitId) -> Option<UnitMeta> {
-    include!(concat!(env!("OUT_DIR"), "/unit_registry.rs"))
+    Some(id.meta())
 }
 
 /// Returns the dimension for the given unit ID.
@@ -271,4 +305,72 @@ mod tests {
         assert_eq!(status, QTTY_ERR_INCOMPATIBLE_DIM);
         assert_relative_eq!(out, -1.0, epsilon = 1e-12);
     }
+
+    #[test]
+    fn test_every_unit_has_one_canonical_per_dimension() {
+        let ids: Vec<UnitId> = (0..100_000).filter_map(UnitId::from_u32).collect();
+        assert!(ids.len() > 100);
+        for dim in [
+            DimensionId::Length,
+            DimensionId::Time,
+            DimensionId::Angle,
+            DimensionId::Mass,
+            DimensionId::Power,
+        ] {
+            let canonical: Vec<UnitId> = ids
+                .iter()
+                .copied()
+                .filter(|&id| meta(id).unwrap().dim == dim)
+                .filter(|&id| meta(id).unwrap().scale_to_canonical == 1.0)
+                .collect();
+            assert_eq!(canonical.len(), 1, "{dim:?}: {canonical:?}");
+        }
+    }
+
+    #[test]
+    fn test_discriminant_leading_digit_matches_dimension() {
+        for id in (0..100_000).filter_map(UnitId::from_u32) {
+            assert_eq!(
+                id as u32 / 10_000,
+                meta(id).unwrap().dim as u32,
+                "{} is filed under the wrong DSSCC range",
+                id.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions_match_qtty() {
+        use qtty::{length, mass, time, Quantity};
+
+        let cases = [
+            (
+                convert_value(1.0, UnitId::Year, UnitId::Day),
+                Quantity::<time::JulianYear>::new(1.0)
+                    .to::<time::Day>()
+                    .value(),
+            ),
+            (
+                convert_value(7_000.0, UnitId::Grain, UnitId::Pound),
+                Quantity::<mass::Grain>::new(7_000.0)
+                    .to::<mass::Pound>()
+                    .value(),
+            ),
+            (
+                convert_value(1.0, UnitId::Parsec, UnitId::AstronomicalUnit),
+                Quantity::<length::Parsec>::new(1.0)
+                    .to::<length::AstronomicalUnit>()
+                    .value(),
+            ),
+            (
+                convert_value(1.0, UnitId::NominalEarthRadius, UnitId::Kilometer),
+                Quantity::<length::nominal::IauEarthEquatorialRadius>::new(1.0)
+                    .to::<length::Kilometer>()
+                    .value(),
+            ),
+        ];
+        for (ffi, rust) in cases {
+            assert_relative_eq!(ffi.unwrap(), rust, max_relative = 1e-15);
+        }
+    }
 }
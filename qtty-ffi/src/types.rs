@@ -31,6 +31,16 @@ pub const QTTY_ERR_NULL_OUT: i32 = -3;
 /// Error: the provided value is invalid (reserved for future use).
 pub const QTTY_ERR_INVALID_VALUE: i32 = -4;
 
+// =============================================================================
+// Versioning
+// =============================================================================
+
+/// ABI version this header and library were built for.
+///
+/// Incremented whenever an existing discriminant, status code, struct layout or function signature changes.
+/// Dynamically loading consumers compare it against `qtty_abi_version()` before trusting the discriminant tables.
+pub const QTTY_ABI_VERSION: u32 = 1;
+
 // =============================================================================
 // Dimension Identifiers
 // =============================================================================
@@ -63,46 +73,166 @@ pub enum DimensionId {
 // Unit Identifiers
 // =============================================================================
 
-// =============================================================================
-// Unit Identifiers
-// =============================================================================
-
-// The UnitId enum is generated by build.rs from qtty-core unit definitions.
+// The UnitId enum and its lookup methods are generated by `define_unit_ids!` from the table below.
 // All discriminant values are explicitly assigned and are part of the ABI contract.
-// Discriminant encoding: DSSCC where D=dimension (1 digit), SS=system (2 digits), CC=counter (2 digits)
-// Units are grouped by dimension with ranges:
-// - Length units: 1xxxx (10000-19999), Time units: 2xxxx (20000-29999), Angle units: 3xxxx (30000-39999)
-// - Mass units: 4xxxx (40000-49999), Power units: 5xxxx (50000-59999)
-include!(concat!(env!("OUT_DIR"), "/unit_id_enum.rs"));
-
-impl UnitId {
-    /// Returns the unit name as a static NUL-terminated C string.
-    ///
-    /// This is safe to call from C code and returns a pointer to static memory.
-    #[inline]
-    pub const fn name_cstr(&self) -> *const c_char {
-        include!(concat!(env!("OUT_DIR"), "/unit_names_cstr.rs"))
-    }
-
-    /// Returns the unit name as a Rust string slice.
-    #[inline]
-    pub const fn name(&self) -> &'static str {
-        include!(concat!(env!("OUT_DIR"), "/unit_names.rs"))
-    }
-
-    /// Returns the unit symbol as a Rust string slice (e.g., "m", "km", "s").
-    #[inline]
-    pub const fn symbol(&self) -> &'static str {
-        include!(concat!(env!("OUT_DIR"), "/unit_symbols.rs"))
-    }
-
-    /// Attempts to create a `UnitId` from a raw `u32` discriminant value.
-    ///
-    /// Returns `None` if the value does not correspond to a valid unit.
-    #[inline]
-    pub const fn from_u32(value: u32) -> Option<Self> {
-        include!(concat!(env!("OUT_DIR"), "/unit_from_u32.rs"))
-    }
+// Discriminant encoding: DSSCC where D=dimension (1 digit), SS=system (2 digits), CC=counter (2 digits).
+// The dimension and scale factor of each unit come from its `qtty` type, so they cannot drift from the Rust side.
+// IDs whose published factor differs from the closest `qtty` unit map onto the frozen types in `legacy`.
+define_unit_ids! {
+    // Length (1xxxx): 100xx SI, 110xx astronomical, 120xx imperial, 130xx nautical, 150xx nominal
+    PlanckLength = 10000, "l_P" => length::PlanckLength;
+    Yoctometer = 10001, "ym" => length::Yoctometer;
+    Zeptometer = 10002, "zm" => length::Zeptometer;
+    Attometer = 10003, "am" => length::Attometer;
+    Femtometer = 10004, "fm" => length::Femtometer;
+    Picometer = 10005, "pm" => length::Picometer;
+    Nanometer = 10006, "nm" => length::Nanometer;
+    Micrometer = 10007, "µm" => length::Micrometer;
+    Millimeter = 10008, "mm" => length::Millimeter;
+    Centimeter = 10009, "cm" => length::Centimeter;
+    Decimeter = 10010, "dm" => length::Decimeter;
+    Meter = 10011, "m" => length::Meter;
+    Decameter = 10012, "dam" => length::Decameter;
+    Hectometer = 10013, "hm" => length::Hectometer;
+    Kilometer = 10014, "km" => length::Kilometer;
+    Megameter = 10015, "Mm" => length::Megameter;
+    Gigameter = 10016, "Gm" => length::Gigameter;
+    Terameter = 10017, "Tm" => length::Terameter;
+    Petameter = 10018, "Pm" => length::Petameter;
+    Exameter = 10019, "Em" => length::Exameter;
+    Zettameter = 10020, "Zm" => length::Zettameter;
+    Yottameter = 10021, "Ym" => length::Yottameter;
+    Angstrom = 10022, "Å" => length::Angstrom;
+    BohrRadius = 11000, "a₀" => length::BohrRadius;
+    ClassicalElectronRadius = 11001, "r_e" => length::ClassicalElectronRadius;
+    ElectronReducedComptonWavelength = 11002, "λ̄_e" => length::ElectronReducedComptonWavelength;
+    AstronomicalUnit = 11003, "au" => length::AstronomicalUnit;
+    LightYear = 11004, "ly" => length::LightYear;
+    Parsec = 11005, "pc" => length::Parsec;
+    Kiloparsec = 11006, "kpc" => length::Kiloparsec;
+    Megaparsec = 11007, "Mpc" => length::Megaparsec;
+    Gigaparsec = 11008, "Gpc" => length::Gigaparsec;
+    Inch = 12000, "in" => length::Inch;
+    Foot = 12001, "ft" => length::Foot;
+    Yard = 12002, "yd" => length::Yard;
+    Mile = 12003, "mi" => length::Mile;
+    Link = 13000, "lk" => length::Link;
+    Fathom = 13001, "ftm" => length::Fathom;
+    Rod = 13002, "rd" => length::Rod;
+    Chain = 13003, "ch" => length::Chain;
+    NauticalMile = 13004, "nmi" => length::NauticalMile;
+    NominalLunarRadius = 15000, "R_☾" => length::nominal::LunarRadius;
+    NominalLunarDistance = 15001, "LD" => length::nominal::LunarDistance;
+    NominalEarthPolarRadius = 15002, "R_⊕pol" => length::nominal::IauEarthPolarRadius;
+    NominalEarthRadius = 15003, "R_⊕" => length::nominal::IauEarthEquatorialRadius;
+    NominalEarthEquatorialRadius = 15004, "R_⊕eq" => length::nominal::IauEarthEquatorialRadius;
+    EarthMeridionalCircumference = 15005, "C_mer" => legacy::EarthMeridionalCircumference;
+    EarthEquatorialCircumference = 15006, "C_eq" => legacy::EarthEquatorialCircumference;
+    NominalJupiterRadius = 15007, "R_♃" => length::nominal::JupiterRadius;
+    NominalSolarRadius = 15008, "R_☉" => length::nominal::SolarRadius;
+    NominalSolarDiameter = 15009, "D_☉" => length::nominal::SolarDiameter;
+
+    // Time (2xxxx): 200xx SI, 210xx common, 220xx calendar, 230xx astronomical
+    Attosecond = 20000, "as" => time::Attosecond;
+    Femtosecond = 20001, "fs" => time::Femtosecond;
+    Picosecond = 20002, "ps" => time::Picosecond;
+    Nanosecond = 20003, "ns" => time::Nanosecond;
+    Microsecond = 20004, "µs" => time::Microsecond;
+    Millisecond = 20005, "ms" => time::Millisecond;
+    Centisecond = 20006, "cs" => time::Centisecond;
+    Decisecond = 20007, "ds" => time::Decisecond;
+    Second = 20008, "s" => time::Second;
+    Decasecond = 20009, "das" => time::Decasecond;
+    Hectosecond = 20010, "hs" => time::Hectosecond;
+    Kilosecond = 20011, "ks" => time::Kilosecond;
+    Megasecond = 20012, "Ms" => time::Megasecond;
+    Gigasecond = 20013, "Gs" => time::Gigasecond;
+    Terasecond = 20014, "Ts" => time::Terasecond;
+    Minute = 21000, "min" => time::Minute;
+    Hour = 21001, "h" => time::Hour;
+    Day = 21002, "d" => time::Day;
+    Week = 21003, "wk" => time::Week;
+    Fortnight = 21004, "fn" => time::Fortnight;
+    Year = 22000, "yr" => time::JulianYear;
+    Decade = 22001, "dec" => legacy::JulianDecade;
+    Century = 22002, "c" => time::JulianCentury;
+    Millennium = 22003, "mill" => time::JulianMillennium;
+    JulianYear = 22004, "a" => time::JulianYear;
+    JulianCentury = 22005, "jc" => time::JulianCentury;
+    SiderealDay = 23000, "sd" => time::SiderealDay;
+    SynodicMonth = 23001, "mo_s" => legacy::SynodicMonth;
+    SiderealYear = 23002, "yr_s" => time::SiderealYear;
+
+    // Angle (3xxxx): 300xx radian-based, 310xx degree-based, 320xx other
+    Milliradian = 30000, "mrad" => angular::Milliradian;
+    Radian = 30001, "rad" => angular::Radian;
+    MicroArcsecond = 31000, "µas" => angular::MicroArcsecond;
+    MilliArcsecond = 31001, "mas" => angular::MilliArcsecond;
+    Arcsecond = 31002, "″" => angular::Arcsecond;
+    Arcminute = 31003, "′" => angular::Arcminute;
+    Degree = 31004, "°" => angular::Degree;
+    Gradian = 32000, "gon" => angular::Gradian;
+    Turn = 32001, "tr" => angular::Turn;
+    HourAngle = 32002, "ʰ" => angular::HourAngle;
+
+    // Mass (4xxxx): 400xx SI, 410xx imperial, 420xx special
+    Yoctogram = 40000, "yg" => mass::Yoctogram;
+    Zeptogram = 40001, "zg" => mass::Zeptogram;
+    Attogram = 40002, "ag" => mass::Attogram;
+    Femtogram = 40003, "fg" => mass::Femtogram;
+    Picogram = 40004, "pg" => mass::Picogram;
+    Nanogram = 40005, "ng" => mass::Nanogram;
+    Microgram = 40006, "µg" => mass::Microgram;
+    Milligram = 40007, "mg" => mass::Milligram;
+    Centigram = 40008, "cg" => mass::Centigram;
+    Decigram = 40009, "dg" => mass::Decigram;
+    Gram = 40010, "g" => mass::Gram;
+    Decagram = 40011, "dag" => mass::Decagram;
+    Hectogram = 40012, "hg" => mass::Hectogram;
+    Kilogram = 40013, "kg" => mass::Kilogram;
+    Megagram = 40014, "Mg" => mass::Megagram;
+    Gigagram = 40015, "Gg" => mass::Gigagram;
+    Teragram = 40016, "Tg" => mass::Teragram;
+    Petagram = 40017, "Pg" => mass::Petagram;
+    Exagram = 40018, "Eg" => mass::Exagram;
+    Zettagram = 40019, "Zg" => mass::Zettagram;
+    Yottagram = 40020, "Yg" => mass::Yottagram;
+    Grain = 41000, "gr" => mass::Grain;
+    Ounce = 41001, "oz" => mass::Ounce;
+    Pound = 41002, "lb" => mass::Pound;
+    Stone = 41003, "st" => mass::Stone;
+    ShortTon = 41004, "ton" => mass::ShortTon;
+    LongTon = 41005, "ton_l" => mass::LongTon;
+    Carat = 42000, "ct" => mass::Carat;
+    Tonne = 42001, "t" => mass::Tonne;
+    AtomicMassUnit = 42002, "u" => mass::AtomicMassUnit;
+    SolarMass = 42003, "M_☉" => mass::SolarMass;
+
+    // Power (5xxxx): 500xx SI, 510xx other
+    Yoctowatt = 50000, "yW" => power::Yoctowatt;
+    Zeptowatt = 50001, "zW" => power::Zeptowatt;
+    Attowatt = 50002, "aW" => power::Attowatt;
+    Femtowatt = 50003, "fW" => power::Femtowatt;
+    Picowatt = 50004, "pW" => power::Picowatt;
+    Nanowatt = 50005, "nW" => power::Nanowatt;
+    Microwatt = 50006, "µW" => power::Microwatt;
+    Milliwatt = 50007, "mW" => power::Milliwatt;
+    Deciwatt = 50008, "dW" => power::Deciwatt;
+    Watt = 50009, "W" => power::Watt;
+    Decawatt = 50010, "daW" => power::Decawatt;
+    Hectowatt = 50011, "hW" => power::Hectowatt;
+    Kilowatt = 50012, "kW" => power::Kilowatt;
+    Megawatt = 50013, "MW" => power::Megawatt;
+    Gigawatt = 50014, "GW" => power::Gigawatt;
+    Terawatt = 50015, "TW" => power::Terawatt;
+    Petawatt = 50016, "PW" => power::Petawatt;
+    Exawatt = 50017, "EW" => power::Exawatt;
+    Zettawatt = 50018, "ZW" => power::Zettawatt;
+    Yottawatt = 50019, "YW" => power::Yottawatt;
+    ErgPerSecond = 51000, "erg/s" => power::ErgPerSecond;
+    HorsepowerMetric = 51001, "PS" => power::HorsepowerMetric;
+    HorsepowerElectric = 51002, "hp_e" => power::HorsepowerElectric;
+    SolarLuminosity = 51003, "L_☉" => power::SolarLuminosity;
 }
 
 // =============================================================================
@@ -476,4 +606,57 @@ mod tests {
             assert!(!unit.name().is_empty());
         }
     }
+
+    /// C symbols that deliberately differ from the `Unit::SYMBOL` of the unit's `qtty` type: the C table was published
+    /// first and keeps its spelling. A new row must reuse the `qtty` symbol or be added here.
+    const FROZEN_SYMBOLS: &[(UnitId, &str, &str)] = &[
+        (UnitId::PlanckLength, "l_P", "lp"),
+        (UnitId::Micrometer, "µm", "um"),
+        (UnitId::Kilometer, "km", "Km"),
+        (UnitId::BohrRadius, "a₀", "a0"),
+        (UnitId::ClassicalElectronRadius, "r_e", "re"),
+        (
+            UnitId::ElectronReducedComptonWavelength,
+            "λ̄_e",
+            "lambda_bar_e",
+        ),
+        (UnitId::NominalLunarRadius, "R_☾", "Rmoon"),
+        (UnitId::NominalEarthPolarRadius, "R_⊕pol", "Rearth_pN"),
+        (UnitId::NominalEarthRadius, "R_⊕", "Rearth_eN"),
+        (UnitId::NominalEarthEquatorialRadius, "R_⊕eq", "Rearth_eN"),
+        (UnitId::NominalJupiterRadius, "R_♃", "Rjup"),
+        (UnitId::NominalSolarRadius, "R_☉", "Rsun"),
+        (UnitId::NominalSolarDiameter, "D_☉", "Dsun"),
+        (UnitId::Year, "yr", "a"),
+        (UnitId::Century, "c", "JC"),
+        (UnitId::Millennium, "mill", "JM"),
+        (UnitId::JulianCentury, "jc", "JC"),
+        (UnitId::SiderealYear, "yr_s", "syr"),
+        (UnitId::Radian, "rad", "Rad"),
+        (UnitId::MicroArcsecond, "µas", "μas"),
+        (UnitId::MilliArcsecond, "mas", "Mas"),
+        (UnitId::Arcsecond, "″", "Arcs"),
+        (UnitId::Arcminute, "′", "Arcm"),
+        (UnitId::Degree, "°", "Deg"),
+        (UnitId::Gradian, "gon", "Gon"),
+        (UnitId::Turn, "tr", "Turn"),
+        (UnitId::HourAngle, "ʰ", "Hms"),
+        (UnitId::ShortTon, "ton", "ton_us"),
+        (UnitId::LongTon, "ton_l", "ton_uk"),
+        (UnitId::SolarMass, "M_☉", "M☉"),
+        (UnitId::SolarLuminosity, "L_☉", "L☉"),
+    ];
+
+    #[test]
+    fn symbols_match_qtty_except_frozen_ones() {
+        for &unit in UnitId::ALL {
+            match FROZEN_SYMBOLS.iter().find(|(id, ..)| *id == unit) {
+                Some(&(_, c_symbol, qtty_symbol)) => {
+                    assert_eq!(unit.symbol(), c_symbol, "{}", unit.name());
+                    assert_eq!(unit.qtty_symbol(), qtty_symbol, "{}", unit.name());
+                }
+                None => assert_eq!(unit.symbol(), unit.qtty_symbol(), "{}", unit.name()),
+            }
+        }
+    }
 }
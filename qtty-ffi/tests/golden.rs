@@ -0,0 +1,142 @@
+//! Conformance tests against the golden conversion table in `tests/golden/conversions.json`.
+//!
+//! The table is generated from the `qtty` registry by the crate's `golden` unit tests, which also check that it is up
+//! to date. These tests check that the C header declares the same unit IDs and that the exported functions reproduce
+//! it within the tolerance it records.
+
+use qtty_ffi::{
+    qtty_quantity_convert_value, qtty_unit_dimension, qtty_unit_name, DimensionId, UnitId, QTTY_OK,
+};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::path::PathBuf;
+
+fn manifest_path(relative: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+fn golden() -> Value {
+    let text = std::fs::read_to_string(manifest_path("tests/golden/conversions.json"))
+        .expect("golden file is readable");
+    serde_json::from_str(&text).expect("golden file is valid JSON")
+}
+
+fn unit_id(golden: &Value, name: &str) -> UnitId {
+    let id = golden["units"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|unit| unit["name"] == name)
+        .and_then(|unit| unit["id"].as_u64())
+        .unwrap_or_else(|| panic!("{name} is not listed in the golden units"));
+    UnitId::from_u32(id as u32).unwrap_or_else(|| panic!("{name} ({id}) is not a UnitId"))
+}
+
+#[test]
+fn test_header_declares_golden_units() {
+    let header = std::fs::read_to_string(manifest_path("include/qtty_ffi.h")).unwrap();
+    let declared: BTreeMap<u64, String> = header
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("UNIT_ID_"))
+        .filter_map(|rest| rest.trim_end_matches(',').split_once(" = "))
+        .map(|(name, id)| (id.parse().unwrap(), name.replace('_', "")))
+        .collect();
+
+    let golden = golden();
+    let units = golden["units"].as_array().unwrap();
+    assert_eq!(declared.len(), units.len(), "header and golden unit counts");
+    for unit in units {
+        let id = unit["id"].as_u64().unwrap();
+        let name = unit["name"].as_str().unwrap();
+        assert_eq!(
+            declared.get(&id).map(String::as_str),
+            Some(name.to_uppercase().as_str()),
+            "header entry for {name} ({id})"
+        );
+    }
+}
+
+#[test]
+fn test_ffi_reproduces_golden_units() {
+    let golden = golden();
+    for unit in golden["units"].as_array().unwrap() {
+        let name = unit["name"].as_str().unwrap();
+        let id = unit_id(&golden, name);
+
+        let c_name = unsafe { CStr::from_ptr(qtty_unit_name(id)) };
+        assert_eq!(c_name.to_str().unwrap(), name);
+
+        let mut dim = DimensionId::Length;
+        assert_eq!(unsafe { qtty_unit_dimension(id, &mut dim) }, QTTY_OK);
+        assert_eq!(format!("{dim:?}"), unit["dimension"].as_str().unwrap());
+    }
+}
+
+#[test]
+fn test_ffi_reproduces_golden_conversions() {
+    let golden = golden();
+    let tolerance = golden["relative_tolerance"].as_f64().unwrap();
+    let conversions = golden["conversions"].as_array().unwrap();
+    assert!(!conversions.is_empty());
+
+    for case in conversions {
+        let src = unit_id(&golden, case["from"].as_str().unwrap());
+        let dst = unit_id(&golden, case["to"].as_str().unwrap());
+        let value = case["value"].as_f64().unwrap();
+        let expected = case["result"].as_f64().unwrap();
+
+        let mut out = f64::NAN;
+        let status = unsafe { qtty_quantity_convert_value(value, src, dst, &mut out) };
+        assert_eq!(status, QTTY_OK, "{case}");
+        assert!(
+            (out - expected).abs() <= tolerance * expected.abs(),
+            "{case}: got {out}"
+        );
+    }
+}
+
+/// Parses a ratio from the original table, which used Rust literal syntax (`1_000.0`, `std::f64::consts::TAU`).
+fn baseline_ratio(text: &str) -> f64 {
+    match text {
+        "std::f64::consts::TAU" => std::f64::consts::TAU,
+        _ => text
+            .replace('_', "")
+            .parse()
+            .expect("ratio is a float literal"),
+    }
+}
+
+#[test]
+fn test_pre_existing_ids_keep_their_baseline_ratios() {
+    let text = std::fs::read_to_string(manifest_path("tests/golden/baseline_units.csv")).unwrap();
+    let rows: Vec<(UnitId, &str, &str, f64)> = text
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            let id: u32 = fields[0].parse().unwrap();
+            let unit = UnitId::from_u32(id).unwrap_or_else(|| panic!("{id} was removed"));
+            (unit, fields[1], fields[2], baseline_ratio(fields[4]))
+        })
+        .collect();
+
+    for &(unit, dimension, name, ratio) in &rows {
+        assert_eq!(unit.name(), name, "{} was renamed", unit as u32);
+        let canonical = rows
+            .iter()
+            .find(|row| row.1 == dimension && row.3 == 1.0)
+            .map(|row| row.0)
+            .unwrap();
+
+        let mut out = f64::NAN;
+        let status = unsafe { qtty_quantity_convert_value(1.0, unit, canonical, &mut out) };
+        assert_eq!(status, QTTY_OK, "{name}");
+        assert!(
+            (out - ratio).abs() <= 1e-12 * ratio,
+            "{name} ({}) is {out} {}, the baseline is {ratio}",
+            unit as u32,
+            canonical.name()
+        );
+    }
+}
@@ -5,9 +5,10 @@
 use approx::assert_relative_eq;
 use core::f64::consts::PI;
 use qtty_ffi::{
-    qtty_ffi_version, qtty_quantity_convert, qtty_quantity_convert_value, qtty_quantity_make,
-    qtty_unit_dimension, qtty_unit_is_valid, qtty_unit_name, qtty_units_compatible, DimensionId,
-    QttyQuantity, UnitId, QTTY_ERR_INCOMPATIBLE_DIM, QTTY_ERR_NULL_OUT, QTTY_OK,
+    qtty_abi_version, qtty_crate_version, qtty_ffi_version, qtty_quantity_convert,
+    qtty_quantity_convert_value, qtty_quantity_make, qtty_unit_dimension, qtty_unit_is_valid,
+    qtty_unit_name, qtty_units_compatible, DimensionId, QttyQuantity, UnitId, QTTY_ABI_VERSION,
+    QTTY_ERR_INCOMPATIBLE_DIM, QTTY_ERR_NULL_OUT, QTTY_OK,
 };
 use std::ffi::CStr;
 
@@ -292,6 +293,13 @@ fn test_ffi_version() {
     assert_eq!(qtty_ffi_version(), 1);
 }
 
+#[test]
+fn test_abi_version_matches_header_constant() {
+    assert_eq!(qtty_abi_version(), QTTY_ABI_VERSION);
+    let version = unsafe { CStr::from_ptr(qtty_crate_version()) };
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+}
+
 // =============================================================================
 // Rust Integration Tests
 // =============================================================================
@@ -0,0 +1,19 @@
+//! Prints the worst-case round-trip conversion error for every pair of registered units that share a dimension.
+
+use qtty::registry;
+
+fn main() {
+    let units: Vec<_> = registry::iter().collect();
+    for (i, a) in units.iter().enumerate() {
+        for b in &units[i + 1..] {
+            if let Some(error) = a.conversion_error_to(b) {
+                println!(
+                    "{:<12} {:>10} <-> {:<10} {error:.2e}",
+                    a.dimension_name,
+                    a.symbol(),
+                    b.symbol()
+                );
+            }
+        }
+    }
+}
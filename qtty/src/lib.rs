@@ -59,15 +59,53 @@
 //! - `qtty::angular` (degrees, radians, arcseconds, wrapping/trigonometry helpers)
 //! - `qtty::time` (seconds, days, years, …)
 //! - `qtty::length` (metres, kilometres, AU, light-year, …)
-//! - `qtty::mass` (grams, kilograms, solar mass)
+//! - `qtty::mass` (grams, kilograms, tonnes, solar, Jupiter, Earth and lunar masses)
 //! - `qtty::power` (watts, solar luminosity)
-//! - `qtty::velocity` (`Length / Time` aliases)
+//! - `qtty::pressure` / `qtty::temperature` (pascal, hPa, bar, atm, mmHg; kelvin with Celsius/Fahrenheit readings)
+//! - `qtty::velocity` (`Length / Time` aliases and the speed of light `C`)
+//! - `qtty::area` / `qtty::volume` (square and cubic lengths, hectare, litre)
+//! - `qtty::density` (`Mass / Volume` aliases, solar density)
+//! - `qtty::flux` (W/m², jansky, inverse-square law)
 //! - `qtty::frequency` (`Angular / Time` aliases)
+//! - `qtty::angular_acceleration` (°/s², rad/s² for slew profiles)
+//! - `qtty::grav_param` (km³/s², au³/day², `GM` of the Sun, Earth and Moon)
+//!
+//! Astronomy helpers built on these units live in their own modules:
+//!
+//! - `qtty::coordinates` (range-checked right ascension, declination, latitude and longitude)
+//! - `qtty::kepler` (mean motion, period, and semi-major axis via Kepler's third law)
+//! - `qtty::timescale` / `qtty::leap_seconds` (TAI/TT/TDB/UTC-tagged epochs, IERS leap-second tables)
+//! - `qtty::sidereal` (Greenwich mean and apparent sidereal time)
+//! - `qtty::transform` (hour angle/declination ↔ altitude/azimuth, ecliptic ↔ equatorial, mean obliquity)
+//! - `qtty::nutation` (truncatable IAU 1980 nutation series, `Δψ`/`Δε` in milliarcseconds)
+//! - `qtty::precession` (IAU 2006 precession angles, J2000.0 → mean of date)
+//! - `qtty::phase` (cyclic phase in turns for folded light curves and pulsar timing)
+//! - `qtty::refraction` (apparent ↔ true altitude for a given air pressure and temperature)
+//! - `qtty::series` (dimension-checked polynomial and harmonic series; resampling, splines, derivatives and integrals
+//!   of sampled data)
+//! - `qtty::solve` (root finding and zero-crossing scans over quantity-valued functions)
+//! - `qtty::stats` (`Histogram<U>` with typed bin edges and centres; weighted mean, median, MAD and sigma clipping;
+//!   requires `std`)
+//! - `qtty::grid` (evenly spaced `linspace`/`grid_by` sequences and (RA, Dec) meshes for survey tiling)
+//!
+//! `qtty::ratio_table` builds flat `f64`/`f32` conversion-ratio tables for GPU buffers from the same unit definitions.
 //!
 //! # Feature flags
 //!
 //! - `std` (default): enables `std` support in `qtty-core`.
 //! - `serde`: enables `serde` support for `Quantity<U>`; serialization is the raw `f64` value only.
+//! - `table`: exposes `qtty::table`, unit-checked conversion of table columns whose headers carry a unit (`"rv [km/s]"`).
+//! - `twofloat`: exposes `qtty::twofloat`, a double-double scalar for `Quantity<U, TwoFloat>` on long time baselines.
+//! - `strict`: removes the implicit `From<f64>` and `PartialEq<f64>` impls on `Quantity<U>` (use `of` / `raw`).
+//! - `defmt`: `defmt::Format` for `Quantity<U>` (value and symbol) for embedded logging.
+//! - `schemars`: `JsonSchema` for `Quantity<U>` (and the tagged `serde_with_unit` form) for OpenAPI generation.
+//! - `sqlx` / `diesel`: `Quantity<U>` as a `DOUBLE PRECISION` database column.
+//! - `bytemuck`: `Pod`/`Zeroable` for `Quantity<U>`, for zero-copy casts of binary buffers.
+//! - `num-traits`: `Zero`, `FromPrimitive` and `ToPrimitive` for `Quantity<U>`, for generic numeric code.
+//! - `rayon`: exposes `qtty::parallel`, parallel bulk unit conversion of quantity slices.
+//! - `clap`: every `Quantity<U>` parses as a typed `clap` argument; `qtty::cli::QuantityParser` adds bounds.
+//! - `test-util`: exposes `qtty::testutil`, proptest strategies for generating typed quantities in property tests.
+//! - `arbitrary` / `quickcheck`: `Arbitrary` implementations for `Quantity<U>` for fuzzing and quickcheck properties.
 //!
 //! Disable default features for `no_std`:
 //!
@@ -97,18 +135,37 @@ pub use qtty_core::*;
 pub use qtty_derive::Unit;
 
 pub use qtty_core::units::angular;
+pub use qtty_core::units::angular_acceleration;
+pub use qtty_core::units::area;
+pub use qtty_core::units::density;
+pub use qtty_core::units::flux;
 pub use qtty_core::units::frequency;
+pub use qtty_core::units::grav_param;
 pub use qtty_core::units::length;
 pub use qtty_core::units::mass;
 pub use qtty_core::units::power;
+pub use qtty_core::units::pressure;
+pub use qtty_core::units::temperature;
 pub use qtty_core::units::time;
 pub use qtty_core::units::unitless;
 pub use qtty_core::units::velocity;
+pub use qtty_core::units::volume;
 
 pub use qtty_core::units::angular::*;
+pub use qtty_core::units::angular_acceleration::*;
+pub use qtty_core::units::area::*;
+pub use qtty_core::units::density::*;
+pub use qtty_core::units::flux::*;
 pub use qtty_core::units::frequency::*;
+pub use qtty_core::units::grav_param::*;
 pub use qtty_core::units::length::*;
 pub use qtty_core::units::mass::*;
 pub use qtty_core::units::power::*;
+pub use qtty_core::units::pressure::*;
+pub use qtty_core::units::temperature::*;
 pub use qtty_core::units::time::*;
 pub use qtty_core::units::velocity::*;
+pub use qtty_core::units::volume::*;
+
+// Several unit modules have a `nominal` submodule; the root keeps pointing at the length one, as it always has.
+pub use qtty_core::units::length::nominal;